@@ -0,0 +1,259 @@
+//! Benchmarks for frequency-based L1 admission under a Zipfian workload
+//!
+//! Replays the same request stream (Zipfian hot set mixed with one-off scan
+//! keys) against small L1s with and without `AdmissionControlled`. Every miss
+//! writes the key back, as a promotion from L2 would. The hot-key hit rate of
+//! each variant is printed before timing.
+//!
+//! With a plain LRU L1 the scan keys push hot keys out, so admission control
+//! raises the hot-key hit rate. Moka already evicts with `TinyLFU`, so there
+//! the hit rates stay close and the gain is the L1 writes skipped for one-off
+//! keys (also printed).
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use futures_util::future::BoxFuture;
+use multi_tier_cache::backends::{AdmissionConfig, AdmissionControlled};
+use multi_tier_cache::{Bytes, CacheBackend, CacheResult, MokaCache, MokaCacheConfig};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Distinct keys in the Zipfian key space
+const KEY_SPACE: usize = 10_000;
+/// Keys counted as "hot" when reporting hit rates
+const HOT_KEYS: usize = 100;
+/// L1 capacity (entries)
+const L1_CAPACITY: u64 = 500;
+/// Requests per replay
+const REQUESTS: usize = 20_000;
+/// Share of requests that are one-off scan keys
+const SCAN_RATIO: f64 = 0.3;
+
+/// A request: Zipfian key rank, or a unique scan key
+enum Request {
+    Ranked(usize),
+    Scan(usize),
+}
+
+impl Request {
+    fn key(&self) -> String {
+        match self {
+            Self::Ranked(rank) => format!("zipf:{rank}"),
+            Self::Scan(id) => format!("scan:{id}"),
+        }
+    }
+}
+
+/// Deterministic request stream (Zipf exponent 1.0)
+fn workload() -> Vec<Request> {
+    let mut cdf = Vec::with_capacity(KEY_SPACE);
+    let mut total = 0.0;
+    for rank in 1..=KEY_SPACE {
+        #[allow(clippy::cast_precision_loss)]
+        {
+            total += 1.0 / rank as f64;
+        }
+        cdf.push(total);
+    }
+
+    let mut rng = StdRng::seed_from_u64(42);
+    (0..REQUESTS)
+        .map(|id| {
+            if rng.gen_bool(SCAN_RATIO) {
+                Request::Scan(id)
+            } else {
+                let target = rng.gen_range(0.0..total);
+                Request::Ranked(cdf.partition_point(|&c| c < target))
+            }
+        })
+        .collect()
+}
+
+fn moka() -> MokaCache {
+    MokaCache::new(MokaCacheConfig {
+        max_capacity: L1_CAPACITY,
+        time_to_live: Duration::from_mins(5),
+        time_to_idle: Duration::from_mins(5),
+    })
+    .unwrap_or_else(|_| panic!("Failed to create Moka cache"))
+}
+
+/// Recency state of `Lru`: value and last use per key, keys by last use
+#[derive(Default)]
+struct LruState {
+    entries: HashMap<String, (Bytes, u64)>,
+    by_use: BTreeMap<u64, String>,
+    clock: u64,
+}
+
+/// Minimal LRU L1 (no TTLs, no frequency-aware eviction)
+struct Lru {
+    state: Mutex<LruState>,
+    capacity: usize,
+}
+
+impl Lru {
+    fn new() -> Self {
+        Self {
+            state: Mutex::new(LruState::default()),
+            capacity: usize::try_from(L1_CAPACITY).unwrap_or(usize::MAX),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl LruState {
+    /// Mark `key` as just used
+    fn bump(&mut self, key: &str) {
+        self.clock += 1;
+        let clock = self.clock;
+        if let Some((_, used)) = self.entries.get_mut(key) {
+            self.by_use.remove(used);
+            *used = clock;
+            self.by_use.insert(clock, key.to_string());
+        }
+    }
+}
+
+impl CacheBackend for Lru {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<Bytes>> {
+        Box::pin(async move {
+            let mut state = self.lock();
+            let value = state.entries.get(key).map(|(value, _)| value.clone());
+            state.bump(key);
+            value
+        })
+    }
+
+    fn set_with_ttl<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        _ttl: Duration,
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            let mut state = self.lock();
+            if let Some((current, _)) = state.entries.get_mut(key) {
+                *current = value;
+            } else {
+                if state.entries.len() >= self.capacity
+                    && let Some((_, oldest)) = state.by_use.pop_first()
+                {
+                    state.entries.remove(&oldest);
+                }
+                state.entries.insert(key.to_string(), (value, 0));
+            }
+            state.bump(key);
+            Ok(())
+        })
+    }
+
+    fn remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            let mut state = self.lock();
+            if let Some((_, used)) = state.entries.remove(key) {
+                state.by_use.remove(&used);
+            }
+            Ok(())
+        })
+    }
+
+    fn health_check(&self) -> BoxFuture<'_, bool> {
+        Box::pin(async { true })
+    }
+
+    fn capacity(&self) -> Option<u64> {
+        Some(L1_CAPACITY)
+    }
+
+    fn entry_count(&self) -> Option<u64> {
+        u64::try_from(self.lock().entries.len()).ok()
+    }
+
+    fn name(&self) -> &'static str {
+        "Lru"
+    }
+}
+
+/// Replay `requests`, writing back every miss; returns (hot hits, hot requests)
+async fn replay(l1: &dyn CacheBackend, requests: &[Request]) -> (u64, u64) {
+    let value = Bytes::from_static(b"value");
+    let (mut hot_hits, mut hot_requests) = (0, 0);
+    for request in requests {
+        let key = request.key();
+        let hit = l1.get(&key).await.is_some();
+        if !hit {
+            let _ = l1
+                .set_with_ttl(&key, value.clone(), Duration::from_mins(5))
+                .await;
+        }
+        if matches!(request, Request::Ranked(rank) if *rank < HOT_KEYS) {
+            hot_requests += 1;
+            hot_hits += u64::from(hit);
+        }
+    }
+    (hot_hits, hot_requests)
+}
+
+/// Benchmark the replay with and without admission control
+fn bench_admission(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap_or_else(|_| panic!("Failed to create runtime"));
+    let requests = workload();
+    let mut group = c.benchmark_group("admission");
+
+    let report = |name: &str, (hits, total): (u64, u64)| {
+        #[allow(clippy::cast_precision_loss)]
+        let rate = hits as f64 / total.max(1) as f64 * 100.0;
+        println!("{name}: hot-key hit rate {rate:.1}% ({hits}/{total})");
+    };
+
+    // One L1 read per request here (vs two per missed manager lookup), so
+    // admit on the second read, like the default does behind a `CacheManager`
+    let config = AdmissionConfig {
+        min_frequency: 2,
+        ..AdmissionConfig::default()
+    };
+
+    report("plain_lru", rt.block_on(replay(&Lru::new(), &requests)));
+    group.bench_function("plain_lru", |b| {
+        b.iter(|| rt.block_on(replay(&Lru::new(), &requests)));
+    });
+
+    let admission_lru = || AdmissionControlled::new(Lru::new(), config);
+    let l1 = admission_lru();
+    report("admission_lru", rt.block_on(replay(&l1, &requests)));
+    println!("admission_lru: {} L1 writes skipped", l1.rejected());
+    group.bench_function("admission_lru", |b| {
+        b.iter(|| rt.block_on(replay(&admission_lru(), &requests)));
+    });
+
+    report("plain_moka", rt.block_on(replay(&moka(), &requests)));
+    group.bench_function("plain_moka", |b| {
+        b.iter(|| rt.block_on(replay(&moka(), &requests)));
+    });
+
+    let admission = || AdmissionControlled::new(moka(), config);
+    let l1 = admission();
+    report("admission_moka", rt.block_on(replay(&l1, &requests)));
+    println!("admission_moka: {} L1 writes skipped", l1.rejected());
+    group.bench_function("admission_moka", |b| {
+        b.iter(|| rt.block_on(replay(&admission(), &requests)));
+    });
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = bench_admission
+}
+criterion_main!(benches);
@@ -127,7 +127,7 @@ fn bench_l2_hit(c: &mut Criterion) {
         for i in 0..100 {
             let key = format!("bench:l2:{i}");
             if let Some(l2) = &cache.l2_cache {
-                l2.set_with_ttl(&key, test_data(1024), Duration::from_secs(300))
+                l2.set_with_ttl(&key, test_data(1024), Duration::from_mins(5))
                     .await
                     .unwrap_or_else(|_| panic!("Failed to set cache"));
             }
@@ -277,7 +277,7 @@ fn bench_cache_strategies(c: &mut Criterion) {
         ("short_term", CacheStrategy::ShortTerm),
         ("medium_term", CacheStrategy::MediumTerm),
         ("long_term", CacheStrategy::LongTerm),
-        ("custom", CacheStrategy::Custom(Duration::from_secs(60))),
+        ("custom", CacheStrategy::Custom(Duration::from_mins(1))),
     ];
 
     for (name, strategy) in &strategies {
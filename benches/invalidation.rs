@@ -96,7 +96,7 @@ fn bench_update_cache(c: &mut Criterion) {
                         .unwrap_or_else(|e| panic!("Failed to serialize test data: {e}")),
                 );
                 let _: () = cache
-                    .update_cache(&key, new_value, Some(Duration::from_secs(300)))
+                    .update_cache(&key, new_value, Some(Duration::from_mins(5)))
                     .await
                     .unwrap_or_else(|_| panic!("Failed to update"));
                 black_box(());
@@ -0,0 +1,87 @@
+//! Benchmarks for the L1-hit fast path of `get` and `get_or_compute_*`
+//!
+//! L1 hits must not touch the in-flight request map used for stampede
+//! protection. Each benchmark checks `CacheManagerStats::in_flight_lookups`
+//! after running and panics if any L1 hit went through the map.
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use multi_tier_cache::{Bytes, CacheManager, CacheStrategy, CacheTier, DashMapCache};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+fn setup_manager(rt: &Runtime) -> CacheManager {
+    let tiers = vec![
+        CacheTier::new(Arc::new(DashMapCache::new()), 1, false, 1, 1.0),
+        CacheTier::new(Arc::new(DashMapCache::new()), 2, true, 1, 1.0),
+    ];
+    let manager = CacheManager::new_with_tiers(tiers, None)
+        .unwrap_or_else(|_| panic!("Failed to create cache manager"));
+
+    rt.block_on(async {
+        manager
+            .set_with_strategy("bench:hot", Bytes::from("42"), CacheStrategy::LongTerm)
+            .await
+            .unwrap_or_else(|_| panic!("Failed to seed cache"));
+    });
+    manager
+}
+
+/// Panic unless every lookup so far was served by the fast path
+fn assert_no_in_flight_lookups(manager: &CacheManager, bench: &str) {
+    let lookups = manager.get_stats().in_flight_lookups;
+    assert_eq!(
+        lookups, 0,
+        "{bench}: L1 hits made {lookups} in-flight map lookups"
+    );
+}
+
+/// Benchmark L1 hits through `get`, `get_or_compute_with` and `get_or_compute_typed`
+fn bench_l1_fast_path(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap_or_else(|_| panic!("Failed to create runtime"));
+    let mut group = c.benchmark_group("l1_fast_path");
+
+    let manager = setup_manager(&rt);
+    group.bench_function("get", |b| {
+        b.iter(|| rt.block_on(async { black_box(manager.get("bench:hot").await) }));
+    });
+    assert_no_in_flight_lookups(&manager, "get");
+
+    let manager = setup_manager(&rt);
+    group.bench_function("get_or_compute_with", |b| {
+        b.iter(|| {
+            rt.block_on(async {
+                black_box(
+                    manager
+                        .get_or_compute_with("bench:hot", CacheStrategy::LongTerm, || async {
+                            Ok(Bytes::from("42"))
+                        })
+                        .await,
+                )
+            })
+        });
+    });
+    assert_no_in_flight_lookups(&manager, "get_or_compute_with");
+
+    let manager = setup_manager(&rt);
+    group.bench_function("get_or_compute_typed", |b| {
+        b.iter(|| {
+            rt.block_on(async {
+                black_box(
+                    manager
+                        .get_or_compute_typed::<u64, _, _>(
+                            "bench:hot",
+                            CacheStrategy::LongTerm,
+                            || async { Ok(42) },
+                        )
+                        .await,
+                )
+            })
+        });
+    });
+    assert_no_in_flight_lookups(&manager, "get_or_compute_typed");
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_l1_fast_path);
+criterion_main!(benches);
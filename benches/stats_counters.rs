@@ -0,0 +1,72 @@
+//! Benchmarks for request statistics counters under concurrent L1 hits
+//!
+//! Compares single shared atomics with striped counters. The gap grows with the
+//! number of cores; on small machines the two are expected to be close.
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use multi_tier_cache::{Bytes, CacheManager, CacheStrategy, CacheTier, DashMapCache};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::runtime::Runtime;
+
+/// Reads performed by each task per iteration
+const READS_PER_TASK: u64 = 1_000;
+
+fn setup_manager(rt: &Runtime, striped: bool) -> Arc<CacheManager> {
+    let tiers = vec![
+        CacheTier::new(Arc::new(DashMapCache::new()), 1, false, 1, 1.0),
+        CacheTier::new(Arc::new(DashMapCache::new()), 2, true, 1, 1.0),
+    ];
+    let manager = CacheManager::new_with_tiers(tiers, None)
+        .unwrap_or_else(|_| panic!("Failed to create cache manager"))
+        .with_striped_counters(striped);
+
+    rt.block_on(async {
+        manager
+            .set_with_strategy("bench:hot", Bytes::from("value"), CacheStrategy::LongTerm)
+            .await
+            .unwrap_or_else(|_| panic!("Failed to seed cache"));
+    });
+    Arc::new(manager)
+}
+
+/// Benchmark L1 hits from one task per core, single vs striped counters
+fn bench_stats_counters(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap_or_else(|_| panic!("Failed to create runtime"));
+    let tasks = std::thread::available_parallelism().map_or(4, std::num::NonZeroUsize::get);
+    let mut group = c.benchmark_group("stats_counters");
+
+    for (name, striped) in [("single_atomic", false), ("striped", true)] {
+        let manager = setup_manager(&rt, striped);
+        group.bench_with_input(BenchmarkId::new(name, tasks), &tasks, |b, &tasks| {
+            b.iter_custom(|iters| {
+                rt.block_on(async {
+                    let mut elapsed = Duration::ZERO;
+                    for _ in 0..iters {
+                        let start = Instant::now();
+                        let handles: Vec<_> = (0..tasks)
+                            .map(|_| {
+                                let manager = Arc::clone(&manager);
+                                tokio::spawn(async move {
+                                    for _ in 0..READS_PER_TASK {
+                                        let _ = manager.get("bench:hot").await;
+                                    }
+                                })
+                            })
+                            .collect();
+                        for handle in handles {
+                            handle.await.unwrap_or_else(|_| panic!("Bench task failed"));
+                        }
+                        elapsed += start.elapsed();
+                    }
+                    elapsed
+                })
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_stats_counters);
+criterion_main!(benches);
@@ -103,7 +103,7 @@ async fn setup_l2_only_bench() -> (Arc<CacheManager>, Vec<String>, usize) {
         let val = Bytes::from(unique_content);
 
         // Set directly in L2 backend to ensure L1 starts empty
-        l2.set_with_ttl(&key, val, Duration::from_secs(10800))
+        l2.set_with_ttl(&key, val, Duration::from_hours(3))
             .await
             .expect("Failed to set L2");
         keys.push(key);
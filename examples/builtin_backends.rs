@@ -143,11 +143,7 @@ async fn demo_memcached_backend() -> Result<()> {
 
             // Set with TTL
             memcached
-                .set_with_ttl(
-                    "product:laptop",
-                    test_data.clone(),
-                    Duration::from_secs(300),
-                )
+                .set_with_ttl("product:laptop", test_data.clone(), Duration::from_mins(5))
                 .await?;
 
             // Get the value
@@ -7,7 +7,9 @@
 use bytes::Bytes;
 use futures_util::future::BoxFuture;
 use multi_tier_cache::error::CacheResult;
-use multi_tier_cache::{CacheBackend, CacheSystemBuilder, L2CacheBackend};
+use multi_tier_cache::{
+    CacheBackend, CacheSystemBuilder, L1Cache, L2Cache, L2CacheBackend, MokaCacheConfig, TierConfig,
+};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
@@ -124,23 +126,20 @@ async fn main() -> anyhow::Result<()> {
     println!("=== Multi-Tier Cache: 3-Tier Architecture Example ===\n");
 
     // 1. Initialize Backends
-    // L1 and L2 will use defaults (Moka and Redis) if we don't provide them,
-    // but here we'll use the builder's convenience methods to set up a 3-tier system.
-    //
-    // For this example, we'll use:
-    // - Tier 1 (L1): Default Moka (In-Memory)
-    // - Tier 2 (L2): Default Redis (Distributed)
+    // In tier mode every tier is configured explicitly. For this example, we'll use:
+    // - Tier 1 (L1): Moka (In-Memory)
+    // - Tier 2 (L2): Redis (Distributed)
     // - Tier 3 (L3): MockL3Cache (Simulated Cold Storage)
 
     // Note: We need a Redis instance for L2. If not available, this might fail.
-    // You can use `CacheSystemBuilder::with_tier` to use custom backends for L1/L2 too.
-
+    let l1_backend = Arc::new(L1Cache::new(MokaCacheConfig::default())?);
+    let l2_backend = Arc::new(L2Cache::new().await?);
     let l3_backend = Arc::new(MockL3Cache::new("Mock L3 (Disk)"));
 
     println!("Building 3-tier cache system...");
     let cache = CacheSystemBuilder::new()
-        //.with_l1(...) // Optional: Custom L1
-        //.with_l2(...) // Optional: Custom L2
+        .with_tier(l1_backend, TierConfig::as_l1())
+        .with_tier(l2_backend, TierConfig::as_l2())
         .with_l3(l3_backend.clone()) // Add L3 tier (automatically configures as Tier 3)
         .build()
         .await?;
@@ -188,7 +187,7 @@ async fn main() -> anyhow::Result<()> {
     // Note: We need to cast/use the trait methods
     let l3_ref = l3_backend.as_ref();
     l3_ref
-        .set_with_ttl("archive:doc1", cold_data, Duration::from_secs(3600))
+        .set_with_ttl("archive:doc1", cold_data, Duration::from_hours(1))
         .await?;
     println!("(Seeded 'archive:doc1' directly into L3 only)");
 
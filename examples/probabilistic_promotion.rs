@@ -41,7 +41,7 @@ async fn main() -> anyhow::Result<()> {
 
     // 3. Populate L2 directly (bypass L1)
     l2_concrete
-        .set_with_ttl(key, value.clone(), Duration::from_secs(300))
+        .set_with_ttl(key, value.clone(), Duration::from_mins(5))
         .await?;
     info!("Key '{}' stored in L2. L1 is empty.", key);
 
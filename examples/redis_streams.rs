@@ -18,26 +18,30 @@ async fn main() -> anyhow::Result<()> {
     // 1. Publish events to stream
     println!("Publishing events to 'events_stream'...\n");
 
-    for i in 1..=5 {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or(Duration::from_secs(0))
-            .as_secs();
-
-        let fields = vec![
-            ("event_id".to_string(), i.to_string()),
-            ("event_type".to_string(), "user_action".to_string()),
-            ("user_id".to_string(), format!("user_{i}")),
-            ("timestamp".to_string(), timestamp.to_string()),
-            ("action".to_string(), "login".to_string()),
-        ];
-
-        let entry_id = cache
-            .cache_manager()
-            .publish_to_stream("events_stream", fields, Some(1000))
-            .await?;
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::from_secs(0))
+        .as_secs();
+    let events: Vec<Vec<(String, String)>> = (1..=5)
+        .map(|i| {
+            vec![
+                ("event_id".to_string(), i.to_string()),
+                ("event_type".to_string(), "user_action".to_string()),
+                ("user_id".to_string(), format!("user_{i}")),
+                ("timestamp".to_string(), timestamp.to_string()),
+                ("action".to_string(), "login".to_string()),
+            ]
+        })
+        .collect();
+
+    // One round-trip for the whole batch
+    let entry_ids = cache
+        .cache_manager()
+        .publish_to_stream_batch("events_stream", events, Some(1000))
+        .await?;
 
-        println!("  ✅ Published event {i} with ID: {entry_id}");
+    for (i, entry_id) in entry_ids.iter().enumerate() {
+        println!("  ✅ Published event {} with ID: {entry_id}", i + 1);
     }
 
     println!();
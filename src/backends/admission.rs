@@ -0,0 +1,410 @@
+//! Admission-Controlled L1 - Frequency-based admission in front of any backend
+//!
+//! Wraps an in-memory backend and, once it is nearly full, only lets a write
+//! through if the key has been seen often enough, estimated with a count-min
+//! sketch (as in `TinyLFU`). One-off keys (scans, crawlers, long-tail lookups) then never displace hot
+//! keys; they are still served from L2.
+
+use crate::error::CacheResult;
+use crate::traits::{CacheBackend, KeyPage, KeyPredicate, L2CacheBackend};
+use crate::transaction::Transaction;
+use bytes::Bytes;
+use futures_util::future::BoxFuture;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, RandomState};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::time::Duration;
+use tracing::{debug, info};
+
+/// Rows in the count-min sketch (independent hash functions)
+const SKETCH_DEPTH: usize = 4;
+
+/// Configuration for `AdmissionControlled`
+#[derive(Debug, Clone, Copy)]
+pub struct AdmissionConfig {
+    /// Counters per sketch row (rounded up to a power of two)
+    ///
+    /// Size it around the number of keys L1 holds; a smaller sketch
+    /// overestimates frequencies and admits more.
+    pub sketch_width: usize,
+    /// Minimum estimated number of reads (including the miss that led to
+    /// the write) for a key to be admitted
+    ///
+    /// A `CacheManager` lookup that misses L1 reads it twice (fast path and
+    /// the stampede-protection re-check), so the default of 3 admits a key on
+    /// its second lookup.
+    pub min_frequency: u32,
+    /// Share of the inner backend's capacity (0.0 to 1.0) from which on the
+    /// frequency filter applies
+    ///
+    /// While the inner backend holds fewer entries, every write is admitted:
+    /// there is room for one-off keys without displacing anything. Backends
+    /// that report no `capacity` or `entry_count` are always filtered. Moka
+    /// updates its entry count lazily, so the switch is approximate.
+    pub fill_threshold: f64,
+}
+
+impl Default for AdmissionConfig {
+    fn default() -> Self {
+        Self {
+            sketch_width: 4096,
+            min_frequency: 3,
+            fill_threshold: 0.9,
+        }
+    }
+}
+
+/// Count-min sketch of key access frequencies with periodic aging
+///
+/// Once `sample_size` accesses have been recorded all counters are halved, so
+/// keys that were hot a while ago don't stay admitted forever.
+pub(crate) struct FrequencySketch {
+    counters: Vec<AtomicU32>,
+    mask: usize,
+    hasher: RandomState,
+    additions: AtomicU64,
+    sample_size: u64,
+}
+
+impl FrequencySketch {
+    pub(crate) fn new(width: usize) -> Self {
+        let width = width.max(1).next_power_of_two();
+        Self {
+            counters: (0..width * SKETCH_DEPTH)
+                .map(|_| AtomicU32::new(0))
+                .collect(),
+            mask: width - 1,
+            hasher: RandomState::new(),
+            additions: AtomicU64::new(0),
+            sample_size: 10 * width as u64,
+        }
+    }
+
+    /// Counter index for `key` in each row (double hashing)
+    fn slots(&self, key: &str) -> impl Iterator<Item = usize> + use<> {
+        let hash = self.hasher.hash_one(key);
+        // Truncation is intended: the two halves act as independent hashes
+        #[allow(clippy::cast_possible_truncation)]
+        let (h1, h2) = (hash as usize, ((hash >> 32) as usize) | 1);
+        let mask = self.mask;
+        (0..SKETCH_DEPTH)
+            .map(move |row| row * (mask + 1) + (h1.wrapping_add(row.wrapping_mul(h2)) & mask))
+    }
+
+    /// Record one access to `key`
+    pub(crate) fn increment(&self, key: &str) {
+        for slot in self.slots(key) {
+            if let Some(counter) = self.counters.get(slot) {
+                let _ = counter.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |count| {
+                    count.checked_add(1)
+                });
+            }
+        }
+        if self.additions.fetch_add(1, Ordering::Relaxed) + 1 >= self.sample_size {
+            self.age();
+        }
+    }
+
+    /// Estimated access count of `key` (never underestimates)
+    pub(crate) fn estimate(&self, key: &str) -> u32 {
+        self.slots(key)
+            .filter_map(|slot| self.counters.get(slot))
+            .map(|counter| counter.load(Ordering::Relaxed))
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Halve every counter (approximate under concurrent increments)
+    fn age(&self) {
+        self.additions.store(0, Ordering::Relaxed);
+        for counter in &self.counters {
+            let _ = counter.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |count| {
+                Some(count / 2)
+            });
+        }
+    }
+}
+
+/// L1 wrapper that only caches keys read at least `min_frequency` times
+///
+/// Every read counts as an access; writes don't, so a key that was read (and
+/// missed), then computed and written, has a frequency of 1. Once the inner
+/// backend is filled to `fill_threshold` of its capacity, a write for a key
+/// below the frequency threshold is skipped, and any older copy of the key is removed
+/// from the inner backend so it can't be served stale. Use it as the L1 tier;
+/// skipped values are still written to, and read from, the lower tiers.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use multi_tier_cache::backends::{AdmissionConfig, AdmissionControlled};
+/// use multi_tier_cache::{MokaCache, MokaCacheConfig};
+///
+/// # fn example() -> anyhow::Result<()> {
+/// let l1 = AdmissionControlled::new(
+///     MokaCache::new(MokaCacheConfig::default())?,
+///     AdmissionConfig::default(),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub struct AdmissionControlled<B> {
+    /// Wrapped backend
+    inner: B,
+    /// Access frequency estimates
+    sketch: FrequencySketch,
+    /// Minimum frequency for admission
+    min_frequency: u32,
+    /// Fill level of `inner` from which on writes are filtered
+    fill_threshold: f64,
+    /// Writes skipped by the admission filter
+    rejected: AtomicU64,
+}
+
+impl<B> AdmissionControlled<B> {
+    /// Wrap `inner` with frequency-based admission
+    #[must_use]
+    pub fn new(inner: B, config: AdmissionConfig) -> Self {
+        info!(
+            sketch_width = config.sketch_width,
+            min_frequency = config.min_frequency,
+            fill_threshold = config.fill_threshold,
+            "Initializing admission-controlled cache"
+        );
+        Self {
+            inner,
+            sketch: FrequencySketch::new(config.sketch_width),
+            min_frequency: config.min_frequency,
+            fill_threshold: if config.fill_threshold.is_finite() {
+                config.fill_threshold.clamp(0.0, 1.0)
+            } else {
+                0.0
+            },
+            rejected: AtomicU64::new(0),
+        }
+    }
+
+    /// The wrapped backend
+    #[must_use]
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Number of writes skipped because the key was not frequent enough
+    #[must_use]
+    pub fn rejected(&self) -> u64 {
+        self.rejected.load(Ordering::Relaxed)
+    }
+}
+
+impl<B: CacheBackend> AdmissionControlled<B> {
+    /// Whether `inner` is full enough for the frequency filter to apply
+    fn near_capacity(&self) -> bool {
+        match (self.inner.entry_count(), self.inner.capacity()) {
+            // Precision loss is irrelevant for a fill ratio
+            #[allow(clippy::cast_precision_loss)]
+            (Some(count), Some(capacity)) => count as f64 >= capacity as f64 * self.fill_threshold,
+            _ => true,
+        }
+    }
+}
+
+impl<B: CacheBackend> CacheBackend for AdmissionControlled<B> {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<Bytes>> {
+        self.sketch.increment(key);
+        self.inner.get(key)
+    }
+
+    fn set_with_ttl<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        if !self.near_capacity() || self.sketch.estimate(key) >= self.min_frequency {
+            return self.inner.set_with_ttl(key, value, ttl);
+        }
+
+        self.rejected.fetch_add(1, Ordering::Relaxed);
+        debug!(key = %key, "[Admission] Key not frequent enough, skipping L1 write");
+        self.inner.remove(key)
+    }
+
+    fn remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        self.inner.remove(key)
+    }
+
+    fn batch_remove<'a>(&'a self, keys: &'a [String]) -> BoxFuture<'a, CacheResult<()>> {
+        self.inner.batch_remove(keys)
+    }
+
+    fn get_and_remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<Option<Bytes>>> {
+        self.inner.get_and_remove(key)
+    }
+
+    fn set_if_present<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> BoxFuture<'a, CacheResult<bool>> {
+        self.inner.set_if_present(key, value, ttl)
+    }
+
+    fn touch<'a>(&'a self, key: &'a str, ttl: Duration) -> BoxFuture<'a, CacheResult<bool>> {
+        self.inner.touch(key, ttl)
+    }
+
+    fn remove_pattern<'a>(&'a self, pattern: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        self.inner.remove_pattern(pattern)
+    }
+
+    fn remove_if(&self, predicate: KeyPredicate) -> BoxFuture<'_, CacheResult<()>> {
+        self.inner.remove_if(predicate)
+    }
+
+    fn keys(&self) -> BoxFuture<'_, CacheResult<Option<Vec<String>>>> {
+        self.inner.keys()
+    }
+
+    fn keys_matching<'a>(
+        &'a self,
+        pattern: &'a str,
+        limit: usize,
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        self.inner.keys_matching(pattern, limit)
+    }
+
+    fn scan_keys_page<'a>(
+        &'a self,
+        pattern: &'a str,
+        cursor: u64,
+        count: usize,
+    ) -> BoxFuture<'a, CacheResult<Option<KeyPage>>> {
+        self.inner.scan_keys_page(pattern, cursor, count)
+    }
+
+    fn set_pinned<'a>(&'a self, key: &'a str, pinned: bool) -> BoxFuture<'a, CacheResult<bool>> {
+        self.inner.set_pinned(key, pinned)
+    }
+
+    fn health_check(&self) -> BoxFuture<'_, bool> {
+        self.inner.health_check()
+    }
+
+    fn capacity(&self) -> Option<u64> {
+        self.inner.capacity()
+    }
+
+    fn entry_count(&self) -> Option<u64> {
+        self.inner.entry_count()
+    }
+
+    fn estimated_size_bytes(&self) -> Option<u64> {
+        self.inner.estimated_size_bytes()
+    }
+
+    fn evict_bytes(&self, bytes: u64) -> BoxFuture<'_, CacheResult<u64>> {
+        self.inner.evict_bytes(bytes)
+    }
+
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+}
+
+impl<B: L2CacheBackend> L2CacheBackend for AdmissionControlled<B> {
+    fn get_with_ttl<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> BoxFuture<'a, Option<(Bytes, Option<Duration>)>> {
+        self.sketch.increment(key);
+        self.inner.get_with_ttl(key)
+    }
+
+    fn server_info(&self) -> BoxFuture<'_, CacheResult<Option<HashMap<String, String>>>> {
+        self.inner.server_info()
+    }
+
+    fn age<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<Option<Duration>>> {
+        self.inner.age(key)
+    }
+
+    fn next_version<'a>(
+        &'a self,
+        key: &'a str,
+        floor: u64,
+    ) -> BoxFuture<'a, CacheResult<Option<u64>>> {
+        self.inner.next_version(key, floor)
+    }
+
+    fn execute_transaction<'a>(
+        &'a self,
+        transaction: &'a Transaction,
+    ) -> BoxFuture<'a, CacheResult<bool>> {
+        self.inner.execute_transaction(transaction)
+    }
+
+    fn tag_key<'a>(&'a self, key: &'a str, tags: &'a [String]) -> BoxFuture<'a, CacheResult<()>> {
+        self.inner.tag_key(key, tags)
+    }
+
+    fn keys_with_all_tags<'a>(
+        &'a self,
+        tags: &'a [String],
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        self.inner.keys_with_all_tags(tags)
+    }
+
+    fn untag_keys<'a>(
+        &'a self,
+        keys: &'a [String],
+        tags: &'a [String],
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        self.inner.untag_keys(keys, tags)
+    }
+
+    fn index_add<'a>(&'a self, index: &'a str, member: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        self.inner.index_add(index, member)
+    }
+
+    fn index_remove<'a>(
+        &'a self,
+        index: &'a str,
+        member: &'a str,
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        self.inner.index_remove(index, member)
+    }
+
+    fn index_drop<'a>(&'a self, index: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        self.inner.index_drop(index)
+    }
+
+    fn index_members<'a>(
+        &'a self,
+        index: &'a str,
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        self.inner.index_members(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FrequencySketch;
+
+    #[test]
+    fn test_sketch_estimates_and_ages() {
+        let sketch = FrequencySketch::new(64);
+        for _ in 0..8 {
+            sketch.increment("hot");
+        }
+        sketch.increment("cold");
+
+        assert!(sketch.estimate("hot") >= 8);
+        assert!(sketch.estimate("cold") >= 1);
+        assert!(sketch.estimate("hot") > sketch.estimate("cold"));
+
+        sketch.age();
+        assert!(sketch.estimate("hot") >= 4);
+    }
+}
@@ -1,8 +1,10 @@
 use crate::error::CacheResult;
-use crate::traits::{CacheBackend, L2CacheBackend};
+use crate::eviction::{EvictionCause, EvictionEvent, EvictionListener};
+use crate::traits::{CacheBackend, KeyPredicate, L2CacheBackend};
 use bytes::Bytes;
 use dashmap::DashMap;
 use futures_util::future::BoxFuture;
+use std::collections::{BTreeSet, HashSet};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
@@ -12,14 +14,17 @@ use tracing::{debug, info};
 #[derive(Debug, Clone)]
 struct CacheEntry {
     value: Bytes,
+    created_at: Instant,
     expires_at: Option<Instant>,
 }
 
 impl CacheEntry {
     fn new(value: Bytes, ttl: Duration) -> Self {
+        let now = Instant::now();
         Self {
             value,
-            expires_at: Some(Instant::now() + ttl),
+            created_at: now,
+            expires_at: Some(now + ttl),
         }
     }
 
@@ -29,6 +34,11 @@ impl CacheEntry {
     }
 }
 
+/// Bytes accounted for an entry stored under `key`
+fn entry_size(key: &str, entry: &CacheEntry) -> u64 {
+    u64::try_from(key.len() + entry.value.len()).unwrap_or(u64::MAX)
+}
+
 pub struct DashMapCache {
     /// Concurrent `HashMap`
     map: Arc<DashMap<String, CacheEntry>>,
@@ -38,6 +48,16 @@ pub struct DashMapCache {
     misses: Arc<AtomicU64>,
     /// Set counter
     sets: Arc<AtomicU64>,
+    /// Keys of each tag
+    tags: Arc<DashMap<String, HashSet<String>>>,
+    /// Members of each secondary index, kept sorted
+    indexes: Arc<DashMap<String, BTreeSet<String>>>,
+    /// Version counter of each key (see `next_version`)
+    versions: Arc<DashMap<String, u64>>,
+    /// Bytes held by `map` (keys plus values)
+    size_bytes: Arc<AtomicU64>,
+    /// Called for entries dropped on expiry or by `evict_bytes`
+    eviction_listener: Option<EvictionListener>,
 }
 
 impl DashMapCache {
@@ -50,24 +70,48 @@ impl DashMapCache {
             hits: Arc::new(AtomicU64::new(0)),
             misses: Arc::new(AtomicU64::new(0)),
             sets: Arc::new(AtomicU64::new(0)),
+            tags: Arc::new(DashMap::new()),
+            indexes: Arc::new(DashMap::new()),
+            versions: Arc::new(DashMap::new()),
+            size_bytes: Arc::new(AtomicU64::new(0)),
+            eviction_listener: None,
         }
     }
 
+    /// Report entries dropped on expiry (on read or in `cleanup_expired`) or
+    /// by `evict_bytes` to `listener`
+    ///
+    /// Explicit removals and overwrites are not reported.
+    #[must_use]
+    pub fn with_eviction_listener(mut self, listener: EvictionListener) -> Self {
+        self.eviction_listener = Some(listener);
+        self
+    }
+
     /// Cleanup expired entries
     pub fn cleanup_expired(&self) -> usize {
-        let mut removed = 0;
-        self.map.retain(|_, entry| {
+        let mut expired = Vec::new();
+        self.map.retain(|key, entry| {
             if entry.is_expired() {
-                removed += 1;
+                expired.push(key.clone());
+                self.size_bytes
+                    .fetch_sub(entry_size(key, entry), Ordering::Relaxed);
                 false
             } else {
                 true
             }
         });
-        if removed > 0 {
-            debug!(count = removed, "[DashMap] Cleaned up expired entries");
+        // Notified after `retain` so a listener can't deadlock on the map
+        for key in &expired {
+            self.notify_evicted(key, EvictionCause::Expired);
+        }
+        if !expired.is_empty() {
+            debug!(
+                count = expired.len(),
+                "[DashMap] Cleaned up expired entries"
+            );
         }
-        removed
+        expired.len()
     }
 
     /// Get current cache size
@@ -81,6 +125,34 @@ impl DashMapCache {
     pub fn is_empty(&self) -> bool {
         self.map.is_empty()
     }
+
+    /// Report an entry dropped without an explicit removal
+    fn notify_evicted(&self, key: &str, cause: EvictionCause) {
+        if let Some(listener) = &self.eviction_listener {
+            listener(EvictionEvent::new(key, cause));
+        }
+    }
+
+    /// Remove `key`, releasing its bytes
+    fn remove_entry(&self, key: &str) -> Option<CacheEntry> {
+        let (key, entry) = self.map.remove(key)?;
+        self.size_bytes
+            .fetch_sub(entry_size(&key, &entry), Ordering::Relaxed);
+        Some(entry)
+    }
+
+    /// Remove entries for which `remove` returns true, releasing their bytes
+    fn remove_where(&self, remove: impl Fn(&str) -> bool) {
+        self.map.retain(|key, entry| {
+            if remove(key) {
+                self.size_bytes
+                    .fetch_sub(entry_size(key, entry), Ordering::Relaxed);
+                false
+            } else {
+                true
+            }
+        });
+    }
 }
 
 impl Default for DashMapCache {
@@ -99,7 +171,9 @@ impl CacheBackend for DashMapCache {
                 Some(entry) => {
                     if entry.is_expired() {
                         drop(entry);
-                        self.map.remove(key);
+                        if self.remove_entry(key).is_some() {
+                            self.notify_evicted(key, EvictionCause::Expired);
+                        }
                         None
                     } else {
                         Some(entry.value.clone())
@@ -118,7 +192,14 @@ impl CacheBackend for DashMapCache {
     ) -> BoxFuture<'a, CacheResult<()>> {
         Box::pin(async move {
             let entry = CacheEntry::new(value, ttl);
-            self.map.insert(key.to_string(), entry);
+            // Counted before the insert, so a concurrent removal of the new
+            // entry can't release its bytes first and underflow the counter
+            self.size_bytes
+                .fetch_add(entry_size(key, &entry), Ordering::Relaxed);
+            if let Some(old) = self.map.insert(key.to_string(), entry) {
+                self.size_bytes
+                    .fetch_sub(entry_size(key, &old), Ordering::Relaxed);
+            }
             self.sets.fetch_add(1, Ordering::Relaxed);
             debug!(key = %key, ttl_secs = %ttl.as_secs(), "[DashMap] Cached key bytes with TTL");
             Ok(())
@@ -127,29 +208,271 @@ impl CacheBackend for DashMapCache {
 
     fn remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<()>> {
         Box::pin(async move {
-            self.map.remove(key);
+            self.remove_entry(key);
             Ok(())
         })
     }
 
+    fn get_and_remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<Option<Bytes>>> {
+        Box::pin(async move {
+            // Atomic: only one caller can remove the entry
+            match self.remove_entry(key) {
+                Some(entry) if !entry.is_expired() => {
+                    self.hits.fetch_add(1, Ordering::Relaxed);
+                    Ok(Some(entry.value))
+                }
+                _ => {
+                    self.misses.fetch_add(1, Ordering::Relaxed);
+                    Ok(None)
+                }
+            }
+        })
+    }
+
+    fn set_if_present<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move {
+            // Atomic: the shard stays write-locked between the check and the write
+            match self.map.get_mut(key) {
+                Some(mut entry) if !entry.is_expired() => {
+                    let new_entry = CacheEntry::new(value, ttl);
+                    self.size_bytes
+                        .fetch_add(entry_size(key, &new_entry), Ordering::Relaxed);
+                    self.size_bytes
+                        .fetch_sub(entry_size(key, &entry), Ordering::Relaxed);
+                    *entry = new_entry;
+                    self.sets.fetch_add(1, Ordering::Relaxed);
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        })
+    }
+
+    /// Moves the expiry in place; the entry's age still counts from its write
+    fn touch<'a>(&'a self, key: &'a str, ttl: Duration) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move {
+            match self.map.get_mut(key) {
+                Some(mut entry) if !entry.is_expired() => {
+                    entry.expires_at = Some(Instant::now() + ttl);
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        })
+    }
+
     fn remove_pattern<'a>(&'a self, pattern: &'a str) -> BoxFuture<'a, CacheResult<()>> {
         Box::pin(async move {
-            self.map
-                .retain(|key, _| !crate::backends::matches_pattern(key, pattern));
+            self.remove_where(|key| crate::backends::matches_pattern(key, pattern));
+            Ok(())
+        })
+    }
+
+    fn remove_if(&self, predicate: KeyPredicate) -> BoxFuture<'_, CacheResult<()>> {
+        Box::pin(async move {
+            self.remove_where(|key| predicate(key));
             Ok(())
         })
     }
 
+    fn keys(&self) -> BoxFuture<'_, CacheResult<Option<Vec<String>>>> {
+        Box::pin(async move {
+            Ok(Some(
+                self.map
+                    .iter()
+                    .filter(|entry| !entry.value().is_expired())
+                    .map(|entry| entry.key().clone())
+                    .collect(),
+            ))
+        })
+    }
+
+    /// Always honored: entries are only removed by TTL or explicitly, never for capacity
+    fn set_pinned<'a>(&'a self, _key: &'a str, _pinned: bool) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async { Ok(true) })
+    }
+
     fn health_check(&self) -> BoxFuture<'_, bool> {
         Box::pin(async move { true })
     }
 
+    /// Includes expired entries not yet cleaned up (see `cleanup_expired`)
+    fn entry_count(&self) -> Option<u64> {
+        u64::try_from(self.map.len()).ok()
+    }
+
+    fn estimated_size_bytes(&self) -> Option<u64> {
+        Some(self.size_bytes.load(Ordering::Relaxed))
+    }
+
+    /// Evicts expired entries first, then those closest to expiring
+    fn evict_bytes(&self, bytes: u64) -> BoxFuture<'_, CacheResult<u64>> {
+        Box::pin(async move {
+            let mut candidates: Vec<(Option<Instant>, String)> = self
+                .map
+                .iter()
+                .map(|entry| (entry.value().expires_at, entry.key().clone()))
+                .collect();
+            // No expiry sorts last
+            candidates.sort_by_key(|(expires_at, _)| (expires_at.is_none(), *expires_at));
+
+            let mut freed = 0;
+            for (_, key) in candidates {
+                if freed >= bytes {
+                    break;
+                }
+                if let Some(entry) = self.remove_entry(&key) {
+                    freed += entry_size(&key, &entry);
+                    let cause = if entry.is_expired() {
+                        EvictionCause::Expired
+                    } else {
+                        EvictionCause::Size
+                    };
+                    self.notify_evicted(&key, cause);
+                }
+            }
+            debug!(freed, "[DashMap] Evicted entries to free memory");
+            Ok(freed)
+        })
+    }
+
     fn name(&self) -> &'static str {
         "DashMap"
     }
 }
 
 impl L2CacheBackend for DashMapCache {
+    fn tag_key<'a>(&'a self, key: &'a str, tags: &'a [String]) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            for tag in tags {
+                self.tags
+                    .entry(tag.clone())
+                    .or_default()
+                    .insert(key.to_string());
+            }
+            Ok(())
+        })
+    }
+
+    fn keys_with_all_tags<'a>(
+        &'a self,
+        tags: &'a [String],
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        Box::pin(async move {
+            let Some((first, rest)) = tags.split_first() else {
+                return Ok(Some(Vec::new()));
+            };
+            // Copied out so no shard lock is held while reading the other tags
+            let candidates: Vec<String> = self
+                .tags
+                .get(first)
+                .map(|members| members.iter().cloned().collect())
+                .unwrap_or_default();
+            let keys = candidates
+                .into_iter()
+                .filter(|key| {
+                    rest.iter().all(|tag| {
+                        self.tags
+                            .get(tag)
+                            .is_some_and(|members| members.contains(key))
+                    })
+                })
+                .collect();
+            Ok(Some(keys))
+        })
+    }
+
+    fn untag_keys<'a>(
+        &'a self,
+        keys: &'a [String],
+        tags: &'a [String],
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            for tag in tags {
+                if let Some(mut members) = self.tags.get_mut(tag) {
+                    for key in keys {
+                        members.remove(key);
+                    }
+                }
+                self.tags.remove_if(tag, |_, members| members.is_empty());
+            }
+            Ok(())
+        })
+    }
+
+    fn index_add<'a>(&'a self, index: &'a str, member: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            self.indexes
+                .entry(index.to_string())
+                .or_default()
+                .insert(member.to_string());
+            Ok(())
+        })
+    }
+
+    fn index_remove<'a>(
+        &'a self,
+        index: &'a str,
+        member: &'a str,
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            // An index left without members is dropped
+            self.indexes.remove_if_mut(index, |_, members| {
+                members.remove(member);
+                members.is_empty()
+            });
+            Ok(())
+        })
+    }
+
+    fn index_drop<'a>(&'a self, index: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            self.indexes.remove(index);
+            Ok(())
+        })
+    }
+
+    fn index_members<'a>(
+        &'a self,
+        index: &'a str,
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        Box::pin(async move {
+            Ok(Some(
+                self.indexes
+                    .get(index)
+                    .map(|members| members.iter().cloned().collect())
+                    .unwrap_or_default(),
+            ))
+        })
+    }
+
+    fn next_version<'a>(
+        &'a self,
+        key: &'a str,
+        floor: u64,
+    ) -> BoxFuture<'a, CacheResult<Option<u64>>> {
+        Box::pin(async move {
+            let mut version = self.versions.entry(key.to_string()).or_insert(0);
+            *version = version.saturating_add(1).max(floor);
+            Ok(Some(*version))
+        })
+    }
+
+    fn age<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<Option<Duration>>> {
+        Box::pin(async move {
+            Ok(self
+                .map
+                .get(key)
+                .filter(|entry| !entry.is_expired())
+                .map(|entry| entry.created_at.elapsed()))
+        })
+    }
+
     fn get_with_ttl<'a>(
         &'a self,
         key: &'a str,
@@ -158,7 +481,7 @@ impl L2CacheBackend for DashMapCache {
             if let Some(entry) = self.map.get(key) {
                 if entry.is_expired() {
                     drop(entry);
-                    self.map.remove(key);
+                    self.remove_entry(key);
                     self.misses.fetch_add(1, Ordering::Relaxed);
                     None
                 } else {
@@ -168,7 +491,7 @@ impl L2CacheBackend for DashMapCache {
                         if ttl.is_none() {
                             // Expired
                             drop(entry);
-                            self.map.remove(key);
+                            self.remove_entry(key);
                             self.misses.fetch_add(1, Ordering::Relaxed);
                             return None;
                         }
@@ -0,0 +1,324 @@
+//! Locked Backend - Per-key locking for read-modify-write operations
+//!
+//! In-memory backends like `DashMap` and Moka have no atomic read-modify-write
+//! commands. `LockedBackend` serializes operations on the same key through a
+//! striped lock map, so `increment`, `compare_and_set`, `get_and_remove` and
+//! `set_if_present` are atomic within one process for any wrapped backend.
+
+use crate::error::{CacheError, CacheResult};
+use crate::traits::{CacheBackend, KeyPage, KeyPredicate, L2CacheBackend, ValueWithTtl};
+use crate::transaction::Transaction;
+use bytes::Bytes;
+use futures_util::future::BoxFuture;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, RandomState};
+use std::time::Duration;
+use tokio::sync::{Mutex, MutexGuard};
+
+/// Default number of lock stripes
+const DEFAULT_STRIPES: usize = 64;
+
+/// Backend wrapper making per-key read-modify-write operations atomic
+///
+/// Keys hash onto a fixed set of mutexes (stripes); unrelated keys sharing a
+/// stripe only contend, they never deadlock since each operation holds a single
+/// stripe. Writes and removals also take the key's lock, so they never
+/// interleave with a read-modify-write. Plain reads don't.
+///
+/// Atomicity is per process: other processes writing the same backend (e.g. a
+/// shared Redis) are not serialized.
+pub struct LockedBackend<B> {
+    /// Wrapped backend
+    inner: B,
+    /// Striped per-key locks
+    stripes: Vec<Mutex<()>>,
+    /// Maps keys to stripes
+    hasher: RandomState,
+}
+
+impl<B> LockedBackend<B> {
+    /// Wrap `inner` with the default number of lock stripes
+    #[must_use]
+    pub fn new(inner: B) -> Self {
+        Self::with_stripes(inner, DEFAULT_STRIPES)
+    }
+
+    /// Wrap `inner` with `stripes` locks (at least 1)
+    ///
+    /// More stripes mean less contention between unrelated keys.
+    #[must_use]
+    pub fn with_stripes(inner: B, stripes: usize) -> Self {
+        Self {
+            inner,
+            stripes: (0..stripes.max(1)).map(|_| Mutex::new(())).collect(),
+            hasher: RandomState::new(),
+        }
+    }
+
+    /// The wrapped backend
+    #[must_use]
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Acquire the lock guarding `key`
+    async fn lock(&self, key: &str) -> Option<MutexGuard<'_, ()>> {
+        // Truncation is fine: only the low bits pick the stripe
+        #[allow(clippy::cast_possible_truncation)]
+        let index = self.hasher.hash_one(key) as usize % self.stripes.len();
+        let stripe = self.stripes.get(index)?;
+        Some(stripe.lock().await)
+    }
+}
+
+impl<B: CacheBackend> LockedBackend<B> {
+    /// Atomically add `delta` to the integer stored at `key`
+    ///
+    /// A missing key counts as 0. The value is stored as a decimal string (like
+    /// Redis `INCRBY`) with `ttl`. Returns the new value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stored value is not an integer, the result
+    /// overflows, or the write fails.
+    pub async fn increment(&self, key: &str, delta: i64, ttl: Duration) -> CacheResult<i64> {
+        let _guard = self.lock(key).await;
+        let current = match self.inner.get(key).await {
+            Some(bytes) => std::str::from_utf8(&bytes)
+                .ok()
+                .and_then(|s| s.parse::<i64>().ok())
+                .ok_or_else(|| {
+                    CacheError::SerializationError(format!("Value of '{key}' is not an integer"))
+                })?,
+            None => 0,
+        };
+        let next = current
+            .checked_add(delta)
+            .ok_or_else(|| CacheError::InternalError(format!("Increment of '{key}' overflows")))?;
+        self.inner
+            .set_with_ttl(key, Bytes::from(next.to_string()), ttl)
+            .await?;
+        Ok(next)
+    }
+
+    /// Atomically replace the value at `key` if it currently equals `expected`
+    ///
+    /// `expected = None` means "only if the key is missing". Returns whether
+    /// the value was written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub async fn compare_and_set(
+        &self,
+        key: &str,
+        expected: Option<&[u8]>,
+        value: Bytes,
+        ttl: Duration,
+    ) -> CacheResult<bool> {
+        let _guard = self.lock(key).await;
+        let current = self.inner.get(key).await;
+        if current.as_deref() != expected {
+            return Ok(false);
+        }
+        self.inner.set_with_ttl(key, value, ttl).await?;
+        Ok(true)
+    }
+}
+
+impl<B: CacheBackend> CacheBackend for LockedBackend<B> {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<Bytes>> {
+        self.inner.get(key)
+    }
+
+    fn set_with_ttl<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            let _guard = self.lock(key).await;
+            self.inner.set_with_ttl(key, value, ttl).await
+        })
+    }
+
+    fn remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            let _guard = self.lock(key).await;
+            self.inner.remove(key).await
+        })
+    }
+
+    fn get_and_remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<Option<Bytes>>> {
+        Box::pin(async move {
+            let _guard = self.lock(key).await;
+            self.inner.get_and_remove(key).await
+        })
+    }
+
+    fn set_if_present<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move {
+            let _guard = self.lock(key).await;
+            self.inner.set_if_present(key, value, ttl).await
+        })
+    }
+
+    fn touch<'a>(&'a self, key: &'a str, ttl: Duration) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move {
+            let _guard = self.lock(key).await;
+            self.inner.touch(key, ttl).await
+        })
+    }
+
+    fn remove_pattern<'a>(&'a self, pattern: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        self.inner.remove_pattern(pattern)
+    }
+
+    fn remove_if(&self, predicate: KeyPredicate) -> BoxFuture<'_, CacheResult<()>> {
+        self.inner.remove_if(predicate)
+    }
+
+    fn keys(&self) -> BoxFuture<'_, CacheResult<Option<Vec<String>>>> {
+        self.inner.keys()
+    }
+
+    fn keys_matching<'a>(
+        &'a self,
+        pattern: &'a str,
+        limit: usize,
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        self.inner.keys_matching(pattern, limit)
+    }
+
+    fn scan_keys_page<'a>(
+        &'a self,
+        pattern: &'a str,
+        cursor: u64,
+        count: usize,
+    ) -> BoxFuture<'a, CacheResult<Option<KeyPage>>> {
+        self.inner.scan_keys_page(pattern, cursor, count)
+    }
+
+    fn set_pinned<'a>(&'a self, key: &'a str, pinned: bool) -> BoxFuture<'a, CacheResult<bool>> {
+        self.inner.set_pinned(key, pinned)
+    }
+
+    fn health_check(&self) -> BoxFuture<'_, bool> {
+        self.inner.health_check()
+    }
+
+    fn capacity(&self) -> Option<u64> {
+        self.inner.capacity()
+    }
+
+    fn entry_count(&self) -> Option<u64> {
+        self.inner.entry_count()
+    }
+
+    fn estimated_size_bytes(&self) -> Option<u64> {
+        self.inner.estimated_size_bytes()
+    }
+
+    fn evict_bytes(&self, bytes: u64) -> BoxFuture<'_, CacheResult<u64>> {
+        self.inner.evict_bytes(bytes)
+    }
+
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+}
+
+impl<B: L2CacheBackend> L2CacheBackend for LockedBackend<B> {
+    fn get_with_ttl<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> BoxFuture<'a, Option<(Bytes, Option<Duration>)>> {
+        self.inner.get_with_ttl(key)
+    }
+
+    fn get_many_with_ttl<'a>(
+        &'a self,
+        keys: &'a [String],
+    ) -> BoxFuture<'a, Vec<Option<ValueWithTtl>>> {
+        self.inner.get_many_with_ttl(keys)
+    }
+
+    fn server_info(&self) -> BoxFuture<'_, CacheResult<Option<HashMap<String, String>>>> {
+        self.inner.server_info()
+    }
+
+    fn age<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<Option<Duration>>> {
+        self.inner.age(key)
+    }
+
+    fn next_version<'a>(
+        &'a self,
+        key: &'a str,
+        floor: u64,
+    ) -> BoxFuture<'a, CacheResult<Option<u64>>> {
+        self.inner.next_version(key, floor)
+    }
+
+    fn wait_for_replicas(
+        &self,
+        replicas: usize,
+        timeout: Duration,
+    ) -> BoxFuture<'_, CacheResult<usize>> {
+        self.inner.wait_for_replicas(replicas, timeout)
+    }
+
+    fn execute_transaction<'a>(
+        &'a self,
+        transaction: &'a Transaction,
+    ) -> BoxFuture<'a, CacheResult<bool>> {
+        self.inner.execute_transaction(transaction)
+    }
+
+    fn tag_key<'a>(&'a self, key: &'a str, tags: &'a [String]) -> BoxFuture<'a, CacheResult<()>> {
+        self.inner.tag_key(key, tags)
+    }
+
+    fn keys_with_all_tags<'a>(
+        &'a self,
+        tags: &'a [String],
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        self.inner.keys_with_all_tags(tags)
+    }
+
+    fn untag_keys<'a>(
+        &'a self,
+        keys: &'a [String],
+        tags: &'a [String],
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        self.inner.untag_keys(keys, tags)
+    }
+
+    fn index_add<'a>(&'a self, index: &'a str, member: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        self.inner.index_add(index, member)
+    }
+
+    fn index_remove<'a>(
+        &'a self,
+        index: &'a str,
+        member: &'a str,
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        self.inner.index_remove(index, member)
+    }
+
+    fn index_drop<'a>(&'a self, index: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        self.inner.index_drop(index)
+    }
+
+    fn index_members<'a>(
+        &'a self,
+        index: &'a str,
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        self.inner.index_members(index)
+    }
+}
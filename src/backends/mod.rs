@@ -11,8 +11,15 @@
 //!
 //! ## Distributed (L2 Tier)
 //! - **Redis** - Industry-standard distributed cache with persistence (default L2)
+//! - **Redis Hash** - Redis storage mode with field-level reads and updates of JSON objects
 //! - **Memcached** - Lightweight distributed cache (feature: `backend-memcached`)
 //!
+//! ## Composite
+//! - **Admission-Controlled** - Frequency-based admission filter in front of an L1 backend
+//! - **Locked** - Per-key locking making read-modify-write operations atomic in-process
+//! - **Sharded** - Consistent-hashing router spreading keys over several caches
+//! - **Tiered** - A whole `CacheManager` acting as a single tier
+//!
 //! ## On-Disk (L3/L4 Tier)
 //! - **`RocksDB`** - Embedded persistent key-value store (coming soon)
 //!
@@ -36,7 +43,11 @@
 
 // Core backends (now optional via features)
 // Core backends
+pub mod admission;
 pub mod dashmap_cache;
+pub mod locked;
+pub mod sharded;
+pub mod tiered_backend;
 
 #[cfg(feature = "moka")]
 #[cfg_attr(docsrs, doc(cfg(feature = "moka")))]
@@ -46,6 +57,10 @@ pub mod moka_cache;
 #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
 pub mod redis_cache;
 
+#[cfg(feature = "redis")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
+pub mod redis_hash_cache;
+
 // Optional backends (feature-gated)
 #[cfg(feature = "memcached")]
 #[cfg_attr(docsrs, doc(cfg(feature = "memcached")))]
@@ -56,7 +71,11 @@ pub mod memcached_cache;
 pub mod quickcache_cache;
 
 // Re-export backend types
+pub use admission::{AdmissionConfig, AdmissionControlled};
 pub use dashmap_cache::DashMapCache;
+pub use locked::LockedBackend;
+pub use sharded::ShardedBackend;
+pub use tiered_backend::TieredBackend;
 
 #[cfg(feature = "moka")]
 #[cfg_attr(docsrs, doc(cfg(feature = "moka")))]
@@ -64,7 +83,11 @@ pub use moka_cache::{MokaCache, MokaCacheConfig};
 
 #[cfg(feature = "redis")]
 #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
-pub use redis_cache::RedisCache;
+pub use redis_cache::{RedisCache, RedisReconnectConfig};
+
+#[cfg(feature = "redis")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
+pub use redis_hash_cache::RedisHashCache;
 
 #[cfg(feature = "memcached")]
 #[cfg_attr(docsrs, doc(cfg(feature = "memcached")))]
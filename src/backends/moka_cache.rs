@@ -1,7 +1,12 @@
-use crate::error::CacheResult;
+use crate::error::{CacheError, CacheResult};
+use crate::eviction::{EvictionCause, EvictionEvent, EvictionListener};
 use bytes::Bytes;
+use dashmap::DashMap;
 use futures_util::future::BoxFuture;
+use moka::PredicateError;
 use moka::future::Cache;
+use moka::notification::RemovalCause;
+use moka::ops::compute::{CompResult, Op};
 use std::any::Any;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -15,6 +20,11 @@ struct CacheEntry {
     expires_at: Instant,
 }
 
+/// Estimated bytes held by an entry: key plus value
+fn entry_size(key: &str, entry: &CacheEntry) -> u64 {
+    u64::try_from(key.len() + entry.value.len()).unwrap_or(u64::MAX)
+}
+
 impl CacheEntry {
     fn new(value: Bytes, ttl: Duration) -> Self {
         Self {
@@ -76,12 +86,16 @@ pub struct MokaCache {
     cache: Cache<String, CacheEntry>,
     /// Moka cache instance for typed objects (Zero-cost optimization)
     typed_cache: Cache<String, TypedCacheEntry>,
+    /// Pinned keys and their entries, kept outside the bounded `cache`
+    pinned: DashMap<String, Option<CacheEntry>>,
     /// Hit counter
     hits: Arc<AtomicU64>,
     /// Miss counter
     misses: Arc<AtomicU64>,
     /// Set counter
     sets: Arc<AtomicU64>,
+    /// Estimated bytes held by `cache`, maintained on insert and removal
+    size_bytes: Arc<AtomicU64>,
     /// Coalesced requests counter
     #[allow(dead_code)]
     coalesced_requests: Arc<AtomicU64>,
@@ -94,18 +108,59 @@ impl MokaCache {
     ///
     /// Returns an error if cache configuration is invalid.
     pub fn new(config: MokaCacheConfig) -> CacheResult<Self> {
+        Ok(Self::build(config, None))
+    }
+
+    /// Create new Moka cache that reports evicted entries to `listener`
+    ///
+    /// The listener is called when an entry is evicted for capacity or its
+    /// TTL elapses, not when it is removed or overwritten. Only the byte store
+    /// reports evictions; the typed store mirrors the same keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if cache configuration is invalid.
+    pub fn with_eviction_listener(
+        config: MokaCacheConfig,
+        listener: EvictionListener,
+    ) -> CacheResult<Self> {
+        Ok(Self::build(config, Some(listener)))
+    }
+
+    fn build(config: MokaCacheConfig, listener: Option<EvictionListener>) -> Self {
         info!("Initializing Moka Cache");
 
+        let size_bytes = Arc::new(AtomicU64::new(0));
+        let released = Arc::clone(&size_bytes);
+        // Every removal (including replaced values) releases the entry's bytes
         let cache = Cache::builder()
             .max_capacity(config.max_capacity)
             .time_to_live(config.time_to_live)
             .time_to_idle(config.time_to_idle)
+            .support_invalidation_closures()
+            .eviction_listener(
+                move |key: Arc<String>, entry: CacheEntry, cause: RemovalCause| {
+                    released.fetch_sub(entry_size(&key, &entry), Ordering::Relaxed);
+                    let Some(listener) = &listener else {
+                        return;
+                    };
+                    let cause = match cause {
+                        RemovalCause::Size => EvictionCause::Size,
+                        RemovalCause::Expired => EvictionCause::Expired,
+                        // Per-key TTLs lapse through an explicit removal on read
+                        RemovalCause::Explicit if entry.is_expired() => EvictionCause::Expired,
+                        RemovalCause::Explicit | RemovalCause::Replaced => return,
+                    };
+                    listener(EvictionEvent::new(key.as_str(), cause));
+                },
+            )
             .build();
 
         let typed_cache = Cache::builder()
             .max_capacity(config.max_capacity)
             .time_to_live(config.time_to_live)
             .time_to_idle(config.time_to_idle)
+            .support_invalidation_closures()
             .build();
 
         info!(
@@ -113,14 +168,16 @@ impl MokaCache {
             "Moka Cache initialized with Byte and Typed storage"
         );
 
-        Ok(Self {
+        Self {
             cache,
             typed_cache,
+            pinned: DashMap::new(),
             hits: Arc::new(AtomicU64::new(0)),
             misses: Arc::new(AtomicU64::new(0)),
             sets: Arc::new(AtomicU64::new(0)),
+            size_bytes,
             coalesced_requests: Arc::new(AtomicU64::new(0)),
-        })
+        }
     }
 
     /// Set a typed value in the L1 cache (zero-cost optimization)
@@ -156,16 +213,54 @@ impl MokaCache {
             _ => None,
         }
     }
+
+    /// Run Moka's pending maintenance (evictions, expirations, entry count)
+    ///
+    /// Moka applies these lazily as the cache is used; call this before
+    /// reading `entry_count` when an up-to-date value matters.
+    pub async fn run_pending_tasks(&self) {
+        self.cache.run_pending_tasks().await;
+        self.typed_cache.run_pending_tasks().await;
+    }
+
+    /// Insert into the bounded store, counting the entry's bytes
+    async fn insert(&self, key: &str, entry: CacheEntry) {
+        self.size_bytes
+            .fetch_add(entry_size(key, &entry), Ordering::Relaxed);
+        self.cache.insert(key.to_string(), entry).await;
+    }
+
+    /// Read the entry of a pinned `key`, counting the hit or miss
+    ///
+    /// An expired entry is dropped, but the key stays pinned.
+    fn read_pinned(&self, key: &str) -> Option<CacheEntry> {
+        let entry = self.pinned.get_mut(key).and_then(|mut slot| {
+            if slot.as_ref().is_some_and(CacheEntry::is_expired) {
+                *slot = None;
+            }
+            slot.clone()
+        });
+        let counter = if entry.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        entry
+    }
 }
 
 // ===== Trait Implementations =====
 
-use crate::traits::{CacheBackend, L2CacheBackend};
+use crate::traits::{CacheBackend, KeyPredicate, L2CacheBackend};
 
 /// Implement `CacheBackend` trait for `MokaCache`
 impl CacheBackend for MokaCache {
     fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<Bytes>> {
         Box::pin(async move {
+            if self.pinned.contains_key(key) {
+                return self.read_pinned(key).map(|entry| entry.value);
+            }
             if let Some(entry) = self.cache.get(key).await {
                 if entry.is_expired() {
                     let _ = self.cache.remove(key).await;
@@ -190,7 +285,11 @@ impl CacheBackend for MokaCache {
     ) -> BoxFuture<'a, CacheResult<()>> {
         Box::pin(async move {
             let entry = CacheEntry::new(value, ttl);
-            self.cache.insert(key.to_string(), entry).await;
+            if let Some(mut slot) = self.pinned.get_mut(key) {
+                *slot = Some(entry);
+            } else {
+                self.insert(key, entry).await;
+            }
             self.sets.fetch_add(1, Ordering::Relaxed);
             debug!(key = %key, ttl_secs = %ttl.as_secs(), "[Moka] Cached key bytes with TTL");
             Ok(())
@@ -199,12 +298,166 @@ impl CacheBackend for MokaCache {
 
     fn remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<()>> {
         Box::pin(async move {
+            if let Some(mut slot) = self.pinned.get_mut(key) {
+                *slot = None;
+            }
             self.cache.invalidate(key).await;
             self.typed_cache.invalidate(key).await;
             Ok(())
         })
     }
 
+    fn get_and_remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<Option<Bytes>>> {
+        Box::pin(async move {
+            self.typed_cache.invalidate(key).await;
+            if self.pinned.contains_key(key) {
+                let entry = self
+                    .pinned
+                    .get_mut(key)
+                    .and_then(|mut slot| slot.take())
+                    .filter(|entry| !entry.is_expired());
+                let counter = if entry.is_some() {
+                    &self.hits
+                } else {
+                    &self.misses
+                };
+                counter.fetch_add(1, Ordering::Relaxed);
+                return Ok(entry.map(|entry| entry.value));
+            }
+            // Atomic: `remove` returns the entry to exactly one caller
+            match self.cache.remove(key).await {
+                Some(entry) if !entry.is_expired() => {
+                    self.hits.fetch_add(1, Ordering::Relaxed);
+                    Ok(Some(entry.value))
+                }
+                _ => {
+                    self.misses.fetch_add(1, Ordering::Relaxed);
+                    Ok(None)
+                }
+            }
+        })
+    }
+
+    fn set_if_present<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move {
+            if let Some(mut slot) = self.pinned.get_mut(key) {
+                // Atomic: the map entry stays locked across the check
+                let replaced = slot.as_ref().is_some_and(|entry| !entry.is_expired());
+                if replaced {
+                    *slot = Some(CacheEntry::new(value, ttl));
+                    self.sets.fetch_add(1, Ordering::Relaxed);
+                }
+                drop(slot);
+                if replaced {
+                    self.typed_cache.invalidate(key).await;
+                }
+                return Ok(replaced);
+            }
+            // Atomic: `and_compute_with` holds the entry's lock across the check
+            let result = self
+                .cache
+                .entry(key.to_string())
+                .and_compute_with(|current| async move {
+                    match current {
+                        Some(entry) if !entry.value().is_expired() => {
+                            let entry = CacheEntry::new(value, ttl);
+                            // Counted before the swap releases the old entry's bytes
+                            self.size_bytes
+                                .fetch_add(entry_size(key, &entry), Ordering::Relaxed);
+                            Op::Put(entry)
+                        }
+                        _ => Op::Nop,
+                    }
+                })
+                .await;
+            let replaced = matches!(result, CompResult::ReplacedWith(_));
+            if replaced {
+                self.typed_cache.invalidate(key).await;
+                self.sets.fetch_add(1, Ordering::Relaxed);
+            }
+            Ok(replaced)
+        })
+    }
+
+    /// Re-inserts the current entry with the new expiry under the entry's lock
+    ///
+    /// Not counted as a hit or set; a typed entry of `key` keeps its value
+    /// and gets the same expiry.
+    fn touch<'a>(&'a self, key: &'a str, ttl: Duration) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move {
+            let expires_at = Instant::now() + ttl;
+            if let Some(mut slot) = self.pinned.get_mut(key) {
+                let Some(entry) = slot.as_mut().filter(|entry| !entry.is_expired()) else {
+                    return Ok(false);
+                };
+                entry.expires_at = expires_at;
+                return Ok(true);
+            }
+            let result = self
+                .cache
+                .entry(key.to_string())
+                .and_compute_with(|current| async move {
+                    match current {
+                        Some(entry) if !entry.value().is_expired() => {
+                            let entry = CacheEntry {
+                                value: entry.value().value.clone(),
+                                expires_at,
+                            };
+                            // Counted before the swap releases the old entry's bytes
+                            self.size_bytes
+                                .fetch_add(entry_size(key, &entry), Ordering::Relaxed);
+                            Op::Put(entry)
+                        }
+                        _ => Op::Nop,
+                    }
+                })
+                .await;
+            if !matches!(result, CompResult::ReplacedWith(_)) {
+                return Ok(false);
+            }
+            self.typed_cache
+                .entry(key.to_string())
+                .and_compute_with(|current| async move {
+                    match current {
+                        Some(entry) if !entry.value().is_expired() => Op::Put(TypedCacheEntry {
+                            value: Arc::clone(&entry.value().value),
+                            expires_at,
+                        }),
+                        _ => Op::Nop,
+                    }
+                })
+                .await;
+            Ok(true)
+        })
+    }
+
+    fn keys(&self) -> BoxFuture<'_, CacheResult<Option<Vec<String>>>> {
+        Box::pin(async move {
+            let mut keys: Vec<String> = self
+                .cache
+                .iter()
+                .filter(|(_, entry)| !entry.is_expired())
+                .map(|(key, _)| (*key).clone())
+                .collect();
+            keys.extend(
+                self.pinned
+                    .iter()
+                    .filter(|slot| {
+                        slot.value()
+                            .as_ref()
+                            .is_some_and(|entry| !entry.is_expired())
+                    })
+                    .map(|slot| slot.key().clone()),
+            );
+            Ok(Some(keys))
+        })
+    }
+
     fn health_check(&self) -> BoxFuture<'_, bool> {
         Box::pin(async move {
             let test_key = "health_check_moka";
@@ -227,36 +480,119 @@ impl CacheBackend for MokaCache {
     }
 
     fn remove_pattern<'a>(&'a self, pattern: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        let pattern_owned = pattern.to_string();
+        let predicate: KeyPredicate =
+            Arc::new(move |key| crate::backends::matches_pattern(key, &pattern_owned));
         Box::pin(async move {
-            let mut keys_to_invalidate = Vec::new();
-            for (key, _) in &self.cache {
-                if crate::backends::matches_pattern(&key, pattern) {
-                    keys_to_invalidate.push((*key).clone());
-                }
-            }
-            for key in &keys_to_invalidate {
-                self.cache.invalidate(key).await;
-            }
+            self.remove_if(predicate).await?;
+            debug!(pattern = %pattern, "[Moka] Invalidated matching entries due to pattern '{}' request", pattern);
+            Ok(())
+        })
+    }
 
-            let mut typed_keys_to_invalidate = Vec::new();
-            for (key, _) in &self.typed_cache {
-                if crate::backends::matches_pattern(&key, pattern) {
-                    typed_keys_to_invalidate.push((*key).clone());
+    /// Registers the predicate with Moka (`invalidate_entries_if`): matching
+    /// entries stop being served at once and are dropped by maintenance,
+    /// without listing the keys first
+    fn remove_if(&self, predicate: KeyPredicate) -> BoxFuture<'_, CacheResult<()>> {
+        Box::pin(async move {
+            let invalidation_error = |e: PredicateError| {
+                CacheError::BackendError(format!("Moka invalidation failed: {e}"))
+            };
+            let matches = Arc::clone(&predicate);
+            self.cache
+                .invalidate_entries_if(move |key, _| matches(key))
+                .map_err(invalidation_error)?;
+            let matches = Arc::clone(&predicate);
+            self.typed_cache
+                .invalidate_entries_if(move |key, _| matches(key))
+                .map_err(invalidation_error)?;
+            // Pinned keys stay pinned, only their values go
+            for mut slot in self.pinned.iter_mut() {
+                if predicate(slot.key()) {
+                    *slot.value_mut() = None;
                 }
             }
-            for key in &typed_keys_to_invalidate {
-                self.typed_cache.invalidate(key).await;
-            }
 
             // Ensure background invalidation tasks are processed
             self.cache.run_pending_tasks().await;
             self.typed_cache.run_pending_tasks().await;
-
-            debug!(pattern = %pattern, moka_count = %keys_to_invalidate.len(), typed_count = %typed_keys_to_invalidate.len(), "[Moka] Invalidated matching entries due to pattern '{}' request", pattern);
             Ok(())
         })
     }
 
+    /// Pinned entries move out of the bounded Moka store, so they skip its
+    /// capacity eviction and its `time_to_idle`; their own TTL still applies.
+    /// Typed values (`set_typed`) are never pinned.
+    fn set_pinned<'a>(&'a self, key: &'a str, pinned: bool) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move {
+            if pinned {
+                if !self.pinned.contains_key(key) {
+                    let entry = self
+                        .cache
+                        .remove(key)
+                        .await
+                        .filter(|entry| !entry.is_expired());
+                    self.pinned.entry(key.to_string()).or_insert(entry);
+                }
+            } else if let Some((_, Some(entry))) = self.pinned.remove(key)
+                && !entry.is_expired()
+            {
+                self.insert(key, entry).await;
+            }
+            debug!(key = %key, pinned, "[Moka] Updated pin");
+            Ok(true)
+        })
+    }
+
+    fn capacity(&self) -> Option<u64> {
+        self.cache.policy().max_capacity()
+    }
+
+    /// Entries in the bounded store (pinned entries excluded), as of the last
+    /// maintenance run (see `run_pending_tasks`)
+    fn entry_count(&self) -> Option<u64> {
+        Some(self.cache.entry_count())
+    }
+
+    /// Byte entries are counted as they are inserted and removed; evictions
+    /// and expirations are released when Moka's maintenance runs (see
+    /// `run_pending_tasks`). Pinned entries are summed on each call, and
+    /// typed values (`set_typed`) can't be sized and aren't counted.
+    fn estimated_size_bytes(&self) -> Option<u64> {
+        let pinned: u64 = self
+            .pinned
+            .iter()
+            .filter_map(|slot| {
+                slot.value()
+                    .as_ref()
+                    .map(|entry| entry_size(slot.key(), entry))
+            })
+            .sum();
+        Some(self.size_bytes.load(Ordering::Relaxed) + pinned)
+    }
+
+    /// Evicts unpinned byte entries in Moka's iteration order
+    fn evict_bytes(&self, bytes: u64) -> BoxFuture<'_, CacheResult<u64>> {
+        Box::pin(async move {
+            let mut freed = 0;
+            let mut victims = Vec::new();
+            for (key, entry) in &self.cache {
+                if freed >= bytes {
+                    break;
+                }
+                freed += entry_size(&key, &entry);
+                victims.push(key);
+            }
+            for key in victims {
+                self.cache.invalidate(key.as_str()).await;
+                self.typed_cache.invalidate(key.as_str()).await;
+            }
+            self.cache.run_pending_tasks().await;
+            debug!(freed, "[Moka] Evicted entries to free memory");
+            Ok(freed)
+        })
+    }
+
     fn name(&self) -> &'static str {
         "Moka"
     }
@@ -268,6 +604,12 @@ impl L2CacheBackend for MokaCache {
         key: &'a str,
     ) -> BoxFuture<'a, Option<(Bytes, Option<Duration>)>> {
         Box::pin(async move {
+            if self.pinned.contains_key(key) {
+                return self.read_pinned(key).map(|entry| {
+                    let remaining = entry.expires_at.saturating_duration_since(Instant::now());
+                    (entry.value, Some(remaining))
+                });
+            }
             // Moka doesn't easily expose remaining TTL for an entry
             if let Some(entry) = self.cache.get(key).await {
                 if entry.is_expired() {
@@ -134,6 +134,70 @@ impl CacheBackend for QuickCacheBackend {
         })
     }
 
+    fn get_and_remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<Option<Bytes>>> {
+        Box::pin(async move {
+            // Atomic: `remove` returns the entry to exactly one caller
+            let value = self.cache.remove(key).and_then(|(_, entry_lock)| {
+                let entry = entry_lock.read();
+                (!entry.is_expired()).then(|| entry.value.clone())
+            });
+            if value.is_some() {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+            } else {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+            }
+            Ok(value)
+        })
+    }
+
+    fn set_if_present<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move {
+            // Atomic: the entry is replaced in place under its write lock
+            let Some(entry_lock) = self.cache.get(key) else {
+                return Ok(false);
+            };
+            let mut entry = entry_lock.write();
+            if entry.is_expired() {
+                return Ok(false);
+            }
+            *entry = CacheEntry::new(value, ttl);
+            self.sets.fetch_add(1, Ordering::Relaxed);
+            Ok(true)
+        })
+    }
+
+    fn touch<'a>(&'a self, key: &'a str, ttl: Duration) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move {
+            // Atomic: the expiry is updated in place under the entry's write lock
+            let Some(entry_lock) = self.cache.get(key) else {
+                return Ok(false);
+            };
+            let mut entry = entry_lock.write();
+            if entry.is_expired() {
+                return Ok(false);
+            }
+            entry.expires_at = Instant::now() + ttl;
+            Ok(true)
+        })
+    }
+
+    fn keys(&self) -> BoxFuture<'_, CacheResult<Option<Vec<String>>>> {
+        Box::pin(async move {
+            Ok(Some(
+                self.cache
+                    .iter()
+                    .filter(|(_, entry)| !entry.read().is_expired())
+                    .map(|(key, _)| key)
+                    .collect(),
+            ))
+        })
+    }
+
     fn health_check(&self) -> BoxFuture<'_, bool> {
         Box::pin(async move {
             let test_key = "health_check_quickcache";
@@ -155,6 +219,14 @@ impl CacheBackend for QuickCacheBackend {
         })
     }
 
+    fn capacity(&self) -> Option<u64> {
+        Some(self.cache.capacity())
+    }
+
+    fn entry_count(&self) -> Option<u64> {
+        u64::try_from(self.cache.len()).ok()
+    }
+
     fn name(&self) -> &'static str {
         "QuickCache"
     }
@@ -1,24 +1,90 @@
 use crate::error::CacheResult;
-use crate::traits::{CacheBackend, L2CacheBackend};
+use crate::traits::{CacheBackend, KeyPage, L2CacheBackend, ValueWithTtl};
+use crate::transaction::{Transaction, TransactionWrite};
 use bytes::Bytes;
+use futures_util::Stream;
 use futures_util::future::BoxFuture;
-use redis::aio::ConnectionManager;
-use redis::{AsyncCommands, Client};
+use redis::aio::{ConnectionManager, ConnectionManagerConfig};
+use redis::auth::{BasicAuth, StreamingCredentialsProvider};
+use redis::{AsyncCommands, Client, RedisResult};
+use std::collections::HashMap;
+use std::pin::Pin;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::time::Duration;
-use tracing::{debug, info};
+use tokio::sync::OnceCell;
+use tracing::{debug, info, warn};
+
+/// Prefix of the Redis sets holding the keys of each tag
+const TAG_KEY_PREFIX: &str = "mtc:tag:";
+
+/// Redis key of the set of keys tagged with `tag`
+fn tag_set_key(tag: &str) -> String {
+    format!("{TAG_KEY_PREFIX}{tag}")
+}
+
+/// Prefix of the Redis sorted sets holding the members of each secondary index
+const INDEX_KEY_PREFIX: &str = "mtc:index:";
+
+/// Redis key of the sorted set of members of `index`
+fn index_set_key(index: &str) -> String {
+    format!("{INDEX_KEY_PREFIX}{index}")
+}
+
+/// Prefix of the Redis keys holding the version counter of each key
+const VERSION_KEY_PREFIX: &str = "mtc:version:";
+
+/// Redis key of the version counter of `key`
+fn version_key(key: &str) -> String {
+    format!("{VERSION_KEY_PREFIX}{key}")
+}
+
+/// Reconnection backoff of the Redis connection manager
+///
+/// When a connection drops, the next command starts a reconnect cycle: up to
+/// `max_retries` further attempts, the delay before each growing from
+/// `min_delay` by `backoff_factor` (with jitter) and capped at `max_delay`.
+/// The same backoff applies to the initial connection. The defaults match the
+/// `redis` crate (6 retries from 100 ms, factor 2, no cap).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RedisReconnectConfig {
+    /// Attempts after the first failed one, per reconnect cycle
+    pub max_retries: usize,
+    /// Delay before the first retry
+    pub min_delay: Duration,
+    /// Growth of the delay per failed attempt (exponent base)
+    pub backoff_factor: f32,
+    /// Longest delay between attempts (`None` = uncapped)
+    pub max_delay: Option<Duration>,
+}
+
+impl Default for RedisReconnectConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 6,
+            min_delay: Duration::from_millis(100),
+            backoff_factor: 2.0,
+            max_delay: None,
+        }
+    }
+}
 
 /// Redis distributed cache with `ConnectionManager` for automatic reconnection
 pub struct RedisCache {
-    /// Redis connection manager
+    /// Redis connection manager (primary; used for all writes)
     conn_manager: ConnectionManager,
+    /// Read replica connections (empty = read from the primary)
+    read_replicas: Vec<ConnectionManager>,
+    /// Round-robin cursor over `read_replicas`
+    next_replica: AtomicUsize,
     /// Hit counter
     hits: Arc<AtomicU64>,
     /// Miss counter
     misses: Arc<AtomicU64>,
     /// Set counter
     sets: Arc<AtomicU64>,
+    /// Whether the server has `GETDEL` (Redis 6.2+), checked on first use
+    getdel_supported: OnceCell<bool>,
 }
 
 impl RedisCache {
@@ -41,15 +107,191 @@ impl RedisCache {
     pub async fn with_url(redis_url: &str) -> CacheResult<Self> {
         info!(redis_url = %redis_url, "Initializing Redis Cache with ConnectionManager");
 
+        let conn_manager = Self::connect(redis_url).await?;
+
+        info!(redis_url = %redis_url, "Redis Cache connected successfully");
+
+        Ok(Self {
+            conn_manager,
+            read_replicas: Vec::new(),
+            next_replica: AtomicUsize::new(0),
+            hits: Arc::new(AtomicU64::new(0)),
+            misses: Arc::new(AtomicU64::new(0)),
+            sets: Arc::new(AtomicU64::new(0)),
+            getdel_supported: OnceCell::new(),
+        })
+    }
+
+    /// Create new Redis cache that writes to a primary and reads from replicas
+    ///
+    /// `get`, `get_with_ttl` and `scan_keys` are spread round-robin across
+    /// `read_urls`; `set`, `remove` and other writes go to `write_url`. With no
+    /// read URLs this behaves like `with_url(write_url)`.
+    ///
+    /// Replication is asynchronous, so a key that was just written may briefly
+    /// miss (or return its previous value) when read back from a replica.
+    /// Pattern removals scan a replica too, and may skip keys written moments earlier.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any URL is invalid or a connection fails.
+    pub async fn with_read_write_urls(write_url: &str, read_urls: &[&str]) -> CacheResult<Self> {
+        let mut cache = Self::with_url(write_url).await?;
+
+        for read_url in read_urls {
+            info!(redis_url = %read_url, "Connecting Redis read replica");
+            cache.read_replicas.push(Self::connect(read_url).await?);
+        }
+
+        Ok(cache)
+    }
+
+    /// Create new Redis cache with custom reconnection backoff
+    ///
+    /// # Behavior during an outage
+    ///
+    /// Commands issued while a reconnect cycle runs wait for it. If it fails
+    /// after `max_retries` attempts they return errors, which `CacheManager`
+    /// reads treat as L2 misses, and the next command starts a new cycle. A
+    /// longer backoff (higher `max_delay`) lowers the reconnect rate against a
+    /// struggling server, at the cost of longer waits for those commands; keep
+    /// `max_retries` low if callers should fail fast instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use multi_tier_cache::{RedisCache, RedisReconnectConfig};
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> anyhow::Result<()> {
+    /// let l2 = RedisCache::with_reconnect_config(
+    ///     "redis://127.0.0.1:6379",
+    ///     RedisReconnectConfig {
+    ///         max_retries: 4,
+    ///         max_delay: Some(Duration::from_secs(2)),
+    ///         ..RedisReconnectConfig::default()
+    ///     },
+    /// )
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Redis URL is invalid or connection fails after
+    /// the configured retries.
+    pub async fn with_reconnect_config(
+        redis_url: &str,
+        reconnect: RedisReconnectConfig,
+    ) -> CacheResult<Self> {
+        info!(redis_url = %redis_url, ?reconnect, "Initializing Redis Cache with reconnect config");
+
+        let conn_manager = Self::connect_with(redis_url, None, Some(reconnect)).await?;
+
+        info!(redis_url = %redis_url, "Redis Cache connected successfully");
+
+        Ok(Self {
+            conn_manager,
+            read_replicas: Vec::new(),
+            next_replica: AtomicUsize::new(0),
+            hits: Arc::new(AtomicU64::new(0)),
+            misses: Arc::new(AtomicU64::new(0)),
+            sets: Arc::new(AtomicU64::new(0)),
+            getdel_supported: OnceCell::new(),
+        })
+    }
+
+    /// Create new Redis cache authenticating with a rotating token
+    ///
+    /// `provider` is called for the AUTH password every time a connection is
+    /// (re)established, so reconnects after the previous token expired log in
+    /// with a fresh one. Use it for AWS `ElastiCache` IAM authentication, whose
+    /// tokens are only valid for 15 minutes; the username comes from
+    /// `redis_url` (e.g. `rediss://my-iam-user@host:6379`, default `default`).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use multi_tier_cache::RedisCache;
+    ///
+    /// # async fn example() -> anyhow::Result<()> {
+    /// # fn generate_iam_auth_token() -> String { String::new() }
+    /// let l2 = RedisCache::with_auth_token_provider(
+    ///     "rediss://my-iam-user@my-cluster.cache.amazonaws.com:6379",
+    ///     generate_iam_auth_token,
+    /// )
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Redis URL is invalid or connection fails.
+    pub async fn with_auth_token_provider<F>(redis_url: &str, provider: F) -> CacheResult<Self>
+    where
+        F: Fn() -> String + Send + Sync + 'static,
+    {
+        info!(redis_url = %redis_url, "Initializing Redis Cache with auth token provider");
+
+        let conn_manager = Self::connect_with(redis_url, Some(Arc::new(provider)), None).await?;
+
+        info!(redis_url = %redis_url, "Redis Cache connected successfully");
+
+        Ok(Self {
+            conn_manager,
+            read_replicas: Vec::new(),
+            next_replica: AtomicUsize::new(0),
+            hits: Arc::new(AtomicU64::new(0)),
+            misses: Arc::new(AtomicU64::new(0)),
+            sets: Arc::new(AtomicU64::new(0)),
+            getdel_supported: OnceCell::new(),
+        })
+    }
+
+    /// Open a connection manager for `redis_url` and verify it with PING
+    pub(crate) async fn connect(redis_url: &str) -> CacheResult<ConnectionManager> {
+        Self::connect_with(redis_url, None, None).await
+    }
+
+    /// Open a connection manager, authenticating with `auth_token` if given
+    async fn connect_with(
+        redis_url: &str,
+        auth_token: Option<AuthTokenProvider>,
+        reconnect: Option<RedisReconnectConfig>,
+    ) -> CacheResult<ConnectionManager> {
         let client = Client::open(redis_url).map_err(|e| {
             crate::error::CacheError::ConfigError(format!("Failed to create Redis client: {e}"))
         })?;
 
-        let conn_manager = ConnectionManager::new(client).await.map_err(|e| {
-            crate::error::CacheError::BackendError(format!(
-                "Failed to establish Redis connection manager: {e}"
-            ))
-        })?;
+        let mut config = ConnectionManagerConfig::new();
+        if let Some(provider) = auth_token {
+            let username = client
+                .get_connection_info()
+                .redis_settings()
+                .username()
+                .unwrap_or("default")
+                .to_string();
+            config = config.set_credentials_provider(TokenCredentials { username, provider });
+        }
+        if let Some(reconnect) = reconnect {
+            config = config
+                .set_number_of_retries(reconnect.max_retries)
+                .set_min_delay(reconnect.min_delay)
+                .set_exponent_base(reconnect.backoff_factor);
+            if let Some(max_delay) = reconnect.max_delay {
+                config = config.set_max_delay(max_delay);
+            }
+        }
+
+        let conn_manager = ConnectionManager::new_with_config(client, config)
+            .await
+            .map_err(|e| {
+                crate::error::CacheError::BackendError(format!(
+                    "Failed to establish Redis connection manager: {e}"
+                ))
+            })?;
 
         let mut conn = conn_manager.clone();
         let _: String = redis::cmd("PING")
@@ -61,23 +303,35 @@ impl RedisCache {
                 ))
             })?;
 
-        info!(redis_url = %redis_url, "Redis Cache connected successfully");
+        Ok(conn_manager)
+    }
 
-        Ok(Self {
-            conn_manager,
-            hits: Arc::new(AtomicU64::new(0)),
-            misses: Arc::new(AtomicU64::new(0)),
-            sets: Arc::new(AtomicU64::new(0)),
-        })
+    /// Connection for read commands: the next replica, or the primary if there are none
+    fn read_conn(&self) -> ConnectionManager {
+        if self.read_replicas.is_empty() {
+            return self.conn_manager.clone();
+        }
+        let index = self.next_replica.fetch_add(1, Ordering::Relaxed) % self.read_replicas.len();
+        self.read_replicas
+            .get(index)
+            .unwrap_or(&self.conn_manager)
+            .clone()
     }
 
     /// Scan keys matching a pattern
     ///
+    /// Runs on a read replica when configured (see `with_read_write_urls`).
+    ///
     /// # Errors
     ///
     /// Returns an error if the SCAN command fails.
     pub async fn scan_keys(&self, pattern: &str) -> CacheResult<Vec<String>> {
-        let mut conn = self.conn_manager.clone();
+        self.scan_keys_limited(pattern, usize::MAX).await
+    }
+
+    /// Scan keys matching a pattern, stopping once `limit` keys were found
+    async fn scan_keys_limited(&self, pattern: &str, limit: usize) -> CacheResult<Vec<String>> {
+        let mut conn = self.read_conn();
         let mut keys = Vec::new();
         let mut cursor: u64 = 0;
 
@@ -94,10 +348,11 @@ impl RedisCache {
             cursor = result.0;
             keys.extend(result.1);
 
-            if cursor == 0 {
+            if cursor == 0 || keys.len() >= limit {
                 break;
             }
         }
+        keys.truncate(limit);
 
         debug!(pattern = %pattern, count = keys.len(), "[Redis] Scanned keys matching pattern");
         Ok(keys)
@@ -118,6 +373,219 @@ impl RedisCache {
         debug!(count = count, "[Redis] Removed keys in bulk");
         Ok(count)
     }
+
+    /// Get Redis server information
+    ///
+    /// Issues `INFO memory` and `INFO stats` and merges both sections into a
+    /// single map (e.g. `used_memory`, `maxmemory`, `keyspace_hits`, `evicted_keys`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the INFO command fails.
+    pub async fn server_info(&self) -> CacheResult<HashMap<String, String>> {
+        let mut conn = self.conn_manager.clone();
+        let mut info = HashMap::new();
+
+        for section in ["memory", "stats"] {
+            let raw: String = redis::cmd("INFO")
+                .arg(section)
+                .query_async(&mut conn)
+                .await?;
+            info.extend(parse_info(&raw));
+        }
+
+        debug!(fields = info.len(), "[Redis] Retrieved server info");
+        Ok(info)
+    }
+
+    /// Whether the server supports `GETDEL`, read from `INFO server` once
+    async fn supports_getdel(&self) -> CacheResult<bool> {
+        self.getdel_supported
+            .get_or_try_init(|| async {
+                let mut conn = self.conn_manager.clone();
+                let raw: String = redis::cmd("INFO")
+                    .arg("server")
+                    .query_async(&mut conn)
+                    .await?;
+                let version = parse_info(&raw)
+                    .find(|(field, _)| field == "redis_version")
+                    .map(|(_, version)| version);
+                let supported = version.as_deref().is_some_and(has_getdel);
+                debug!(?version, supported, "[Redis] Detected GETDEL support");
+                Ok(supported)
+            })
+            .await
+            .copied()
+    }
+
+    /// Apply `transaction` atomically
+    ///
+    /// Without preconditions the writes are sent as one `MULTI`/`EXEC` block.
+    /// With preconditions a Lua script checks them and then writes, since
+    /// `MULTI` can't branch on values. Either way other clients never see a
+    /// partial update. Returns `false`, having written nothing, if a
+    /// precondition doesn't hold. In Redis Cluster all keys must hash to the
+    /// same slot (use `{hash tags}`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if Redis rejects the transaction (nothing is written)
+    /// or the connection fails (the transaction may or may not have been
+    /// applied).
+    pub async fn transaction(&self, transaction: &Transaction) -> CacheResult<bool> {
+        if transaction.is_empty() && transaction.checks().is_empty() {
+            return Ok(true);
+        }
+        let mut conn = self.conn_manager.clone();
+
+        if transaction.checks().is_empty() {
+            let mut pipe = redis::pipe();
+            pipe.atomic();
+            for write in transaction.writes() {
+                match write {
+                    TransactionWrite::Set { key, value, ttl } => {
+                        pipe.pset_ex(key, value.as_ref(), ttl_millis(*ttl)?)
+                            .ignore();
+                    }
+                    TransactionWrite::Remove { key } => {
+                        pipe.del(key).ignore();
+                    }
+                }
+            }
+            let () = pipe.query_async(&mut conn).await?;
+            debug!(
+                writes = transaction.writes().len(),
+                "[Redis] Executed MULTI/EXEC transaction"
+            );
+            return Ok(true);
+        }
+
+        let script = redis::Script::new(TRANSACTION_LUA);
+        let mut invocation = script.prepare_invoke();
+        invocation.arg(transaction.checks().len());
+        for (key, expected) in transaction.checks() {
+            // `-` = must be absent, `=<value>` = must equal value
+            let expected = expected
+                .as_ref()
+                .map_or_else(|| b"-".to_vec(), |value| [b"=".as_slice(), value].concat());
+            invocation.key(key).arg(expected);
+        }
+        for write in transaction.writes() {
+            match write {
+                TransactionWrite::Set { key, value, ttl } => {
+                    invocation
+                        .key(key)
+                        .arg("set")
+                        .arg(ttl_millis(*ttl)?)
+                        .arg(value.as_ref());
+                }
+                TransactionWrite::Remove { key } => {
+                    invocation.key(key).arg("del");
+                }
+            }
+        }
+        let applied: bool = invocation.invoke_async(&mut conn).await?;
+        debug!(applied = applied, "[Redis] Executed transaction script");
+        Ok(applied)
+    }
+}
+
+/// `ttl` in whole milliseconds, at least 1 (`PX 0` is rejected)
+fn ttl_millis(ttl: Duration) -> CacheResult<u64> {
+    Ok(u64::try_from(ttl.as_millis())?.max(1))
+}
+
+/// Callback returning the current AUTH password
+type AuthTokenProvider = Arc<dyn Fn() -> String + Send + Sync>;
+
+/// Credentials calling an `AuthTokenProvider` for every new connection
+///
+/// The connection reads the first item of a fresh subscription when it is
+/// established, so each (re)connect gets a freshly generated token.
+struct TokenCredentials {
+    /// ACL username
+    username: String,
+    /// Token source
+    provider: AuthTokenProvider,
+}
+
+impl StreamingCredentialsProvider for TokenCredentials {
+    fn subscribe(&self) -> Pin<Box<dyn Stream<Item = RedisResult<BasicAuth>> + Send + 'static>> {
+        let username = self.username.clone();
+        let provider = Arc::clone(&self.provider);
+        Box::pin(futures_util::stream::once(async move {
+            Ok(BasicAuth::new(username, provider()))
+        }))
+    }
+}
+
+/// Atomic GET + DEL for servers older than Redis 6.2 (no `GETDEL`)
+const GETDEL_LUA: &str = r"
+local value = redis.call('GET', KEYS[1])
+if value then
+    redis.call('DEL', KEYS[1])
+end
+return value
+";
+
+/// Advances a version counter to `max(current + 1, ARGV[1])` and returns it
+///
+/// Versions stay below 2^53, so Lua's doubles represent them exactly.
+const NEXT_VERSION_LUA: &str = r"
+local current = tonumber(redis.call('GET', KEYS[1]) or '0')
+local version = math.max(current + 1, tonumber(ARGV[1]))
+redis.call('SET', KEYS[1], string.format('%.0f', version))
+return version
+";
+
+/// Checks a transaction's preconditions, then applies its writes
+///
+/// `ARGV[1]` is the number of preconditions; their keys come first in `KEYS`,
+/// each with one expectation (`-` absent, `=<value>`). The remaining keys are
+/// writes: `set <ttl ms> <value>` or `del`.
+const TRANSACTION_LUA: &str = r"
+local checks = tonumber(ARGV[1])
+for i = 1, checks do
+    local expected = ARGV[i + 1]
+    local current = redis.call('GET', KEYS[i])
+    if expected == '-' then
+        if current then
+            return 0
+        end
+    elseif current ~= string.sub(expected, 2) then
+        return 0
+    end
+end
+local arg = checks + 2
+for i = checks + 1, #KEYS do
+    if ARGV[arg] == 'set' then
+        redis.call('SET', KEYS[i], ARGV[arg + 2], 'PX', ARGV[arg + 1])
+        arg = arg + 3
+    else
+        redis.call('DEL', KEYS[i])
+        arg = arg + 1
+    end
+end
+return 1
+";
+
+/// Whether a `redis_version` (e.g. `7.2.4`) is 6.2 or later, the first with `GETDEL`
+fn has_getdel(version: &str) -> bool {
+    let mut parts = version
+        .split('.')
+        .map(|part| part.parse::<u32>().unwrap_or(0));
+    let major = parts.next().unwrap_or(0);
+    let minor = parts.next().unwrap_or(0);
+    (major, minor) >= (6, 2)
+}
+
+/// Parse `INFO` output (`field:value` lines, `#` section headers) into pairs
+fn parse_info(raw: &str) -> impl Iterator<Item = (String, String)> + '_ {
+    raw.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once(':'))
+        .map(|(field, value)| (field.to_string(), value.to_string()))
 }
 
 // ===== Trait Implementations =====
@@ -126,7 +594,7 @@ impl RedisCache {
 impl CacheBackend for RedisCache {
     fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<Bytes>> {
         Box::pin(async move {
-            let mut conn = self.conn_manager.clone();
+            let mut conn = self.read_conn();
             let result: redis::RedisResult<Option<Vec<u8>>> = conn.get(key).await;
             if let Ok(Some(bytes)) = result {
                 self.hits.fetch_add(1, Ordering::Relaxed);
@@ -158,6 +626,46 @@ impl CacheBackend for RedisCache {
         })
     }
 
+    fn set_if_present<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move {
+            let mut conn = self.conn_manager.clone();
+            let ttl_ms = u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX);
+            // SET XX replies OK when written and nil when the key is missing
+            let reply: Option<String> = redis::cmd("SET")
+                .arg(key)
+                .arg(value.as_ref())
+                .arg("XX")
+                .arg("PX")
+                .arg(ttl_ms)
+                .query_async(&mut conn)
+                .await
+                .map_err(|e| {
+                    crate::error::CacheError::BackendError(format!("Redis SET XX failed: {e}"))
+                })?;
+            let replaced = reply.is_some();
+            if replaced {
+                self.sets.fetch_add(1, Ordering::Relaxed);
+                debug!(key = %key, ttl_ms = %ttl.as_millis(), "[Redis] Replaced existing key");
+            }
+            Ok(replaced)
+        })
+    }
+
+    /// `PEXPIRE`: the value isn't transferred
+    fn touch<'a>(&'a self, key: &'a str, ttl: Duration) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move {
+            let mut conn = self.conn_manager.clone();
+            let ttl_ms = i64::try_from(ttl.as_millis()).unwrap_or(i64::MAX);
+            let updated: bool = conn.pexpire(key, ttl_ms).await?;
+            Ok(updated)
+        })
+    }
+
     fn remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<()>> {
         Box::pin(async move {
             let mut conn = self.conn_manager.clone();
@@ -166,6 +674,29 @@ impl CacheBackend for RedisCache {
         })
     }
 
+    fn get_and_remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<Option<Bytes>>> {
+        Box::pin(async move {
+            let mut conn = self.conn_manager.clone();
+            // GETDEL is atomic (Redis 6.2+); older servers use an equivalent Lua script
+            let value: Option<Vec<u8>> = if self.supports_getdel().await? {
+                redis::cmd("GETDEL").arg(key).query_async(&mut conn).await?
+            } else {
+                redis::Script::new(GETDEL_LUA)
+                    .key(key)
+                    .invoke_async(&mut conn)
+                    .await?
+            };
+
+            if let Some(bytes) = value {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Ok(Some(Bytes::from(bytes)))
+            } else {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                Ok(None)
+            }
+        })
+    }
+
     fn health_check(&self) -> BoxFuture<'_, bool> {
         Box::pin(async move {
             let mut conn = self.conn_manager.clone();
@@ -175,6 +706,13 @@ impl CacheBackend for RedisCache {
         })
     }
 
+    fn batch_remove<'a>(&'a self, keys: &'a [String]) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            self.remove_bulk(keys).await?;
+            Ok(())
+        })
+    }
+
     fn remove_pattern<'a>(&'a self, pattern: &'a str) -> BoxFuture<'a, CacheResult<()>> {
         Box::pin(async move {
             let keys = self.scan_keys(pattern).await?;
@@ -185,6 +723,35 @@ impl CacheBackend for RedisCache {
         })
     }
 
+    fn keys_matching<'a>(
+        &'a self,
+        pattern: &'a str,
+        limit: usize,
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        Box::pin(async move { Ok(Some(self.scan_keys_limited(pattern, limit).await?)) })
+    }
+
+    /// One `SCAN cursor MATCH pattern COUNT count` call
+    fn scan_keys_page<'a>(
+        &'a self,
+        pattern: &'a str,
+        cursor: u64,
+        count: usize,
+    ) -> BoxFuture<'a, CacheResult<Option<KeyPage>>> {
+        Box::pin(async move {
+            let mut conn = self.read_conn();
+            let page: KeyPage = redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("MATCH")
+                .arg(pattern)
+                .arg("COUNT")
+                .arg(count.max(1))
+                .query_async(&mut conn)
+                .await?;
+            Ok(Some(page))
+        })
+    }
+
     fn name(&self) -> &'static str {
         "Redis"
     }
@@ -196,7 +763,7 @@ impl L2CacheBackend for RedisCache {
         key: &'a str,
     ) -> BoxFuture<'a, Option<(Bytes, Option<Duration>)>> {
         Box::pin(async move {
-            let mut conn = self.conn_manager.clone();
+            let mut conn = self.read_conn();
             // Pipelining is better:
             let (bytes, ttl_secs): (Option<Vec<u8>>, i64) =
                 match redis::pipe().get(key).ttl(key).query_async(&mut conn).await {
@@ -218,4 +785,267 @@ impl L2CacheBackend for RedisCache {
             }
         })
     }
+
+    /// One pipeline of `GET` + `TTL` pairs for all keys
+    fn get_many_with_ttl<'a>(
+        &'a self,
+        keys: &'a [String],
+    ) -> BoxFuture<'a, Vec<Option<ValueWithTtl>>> {
+        Box::pin(async move {
+            if keys.is_empty() {
+                return Vec::new();
+            }
+            let mut pipe = redis::pipe();
+            for key in keys {
+                pipe.get(key).ttl(key);
+            }
+            let mut conn = self.read_conn();
+            let reply: Vec<redis::Value> = match pipe.query_async(&mut conn).await {
+                Ok(reply) => reply,
+                Err(e) => {
+                    warn!("Redis batched GET failed: {}", e);
+                    return vec![None; keys.len()];
+                }
+            };
+
+            let mut reply = reply.into_iter();
+            keys.iter()
+                .map(|_| {
+                    let bytes: Option<Vec<u8>> =
+                        redis::from_redis_value(reply.next()?).ok().flatten();
+                    let ttl_secs: i64 = reply
+                        .next()
+                        .and_then(|value| redis::from_redis_value(value).ok())
+                        .unwrap_or(-1);
+                    let Some(bytes) = bytes else {
+                        self.misses.fetch_add(1, Ordering::Relaxed);
+                        return None;
+                    };
+                    self.hits.fetch_add(1, Ordering::Relaxed);
+                    let ttl = (ttl_secs > 0).then(|| Duration::from_secs(ttl_secs.unsigned_abs()));
+                    Some((Bytes::from(bytes), ttl))
+                })
+                .collect()
+        })
+    }
+
+    fn server_info(&self) -> BoxFuture<'_, CacheResult<Option<HashMap<String, String>>>> {
+        Box::pin(async move { self.server_info().await.map(Some) })
+    }
+
+    /// Approximated with `OBJECT IDLETIME` (whole seconds since the key was last
+    /// written *or read*), which costs no extra bytes per value
+    ///
+    /// Reads reset the idle time, so this is a lower bound on the real age.
+    /// Fails when `maxmemory-policy` is an LFU policy, which disables idle tracking.
+    fn age<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<Option<Duration>>> {
+        Box::pin(async move {
+            // Primary only: replicas track their own access times
+            let mut conn = self.conn_manager.clone();
+            let idle_secs: Option<u64> = redis::cmd("OBJECT")
+                .arg("IDLETIME")
+                .arg(key)
+                .query_async(&mut conn)
+                .await?;
+            Ok(idle_secs.map(Duration::from_secs))
+        })
+    }
+
+    /// Advanced by a Lua script on `mtc:version:<key>`
+    fn next_version<'a>(
+        &'a self,
+        key: &'a str,
+        floor: u64,
+    ) -> BoxFuture<'a, CacheResult<Option<u64>>> {
+        Box::pin(async move {
+            let mut conn = self.conn_manager.clone();
+            let version: u64 = redis::Script::new(NEXT_VERSION_LUA)
+                .key(version_key(key))
+                .arg(floor)
+                .invoke_async(&mut conn)
+                .await?;
+            Ok(Some(version))
+        })
+    }
+
+    fn wait_for_replicas(
+        &self,
+        replicas: usize,
+        timeout: Duration,
+    ) -> BoxFuture<'_, CacheResult<usize>> {
+        Box::pin(async move {
+            // WAIT covers writes made on the same connection: the primary's
+            let mut conn = self.conn_manager.clone();
+            let acknowledged: usize = redis::cmd("WAIT")
+                .arg(replicas)
+                .arg(u64::try_from(timeout.as_millis())?)
+                .query_async(&mut conn)
+                .await?;
+            Ok(acknowledged)
+        })
+    }
+
+    fn execute_transaction<'a>(
+        &'a self,
+        transaction: &'a Transaction,
+    ) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(self.transaction(transaction))
+    }
+
+    /// `SADD` to one set per tag (`mtc:tag:<tag>`)
+    ///
+    /// Tag sets have no TTL: members outlive expired keys until the tag is
+    /// invalidated.
+    fn tag_key<'a>(&'a self, key: &'a str, tags: &'a [String]) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            let mut pipe = redis::pipe();
+            for tag in tags {
+                pipe.sadd(tag_set_key(tag), key).ignore();
+            }
+            let mut conn = self.conn_manager.clone();
+            let () = pipe.query_async(&mut conn).await?;
+            Ok(())
+        })
+    }
+
+    /// `SINTER` of the tags' sets
+    fn keys_with_all_tags<'a>(
+        &'a self,
+        tags: &'a [String],
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        Box::pin(async move {
+            if tags.is_empty() {
+                return Ok(Some(Vec::new()));
+            }
+            let sets: Vec<String> = tags.iter().map(|tag| tag_set_key(tag)).collect();
+            let mut conn = self.read_conn();
+            let keys: Vec<String> = conn.sinter(sets).await?;
+            Ok(Some(keys))
+        })
+    }
+
+    fn untag_keys<'a>(
+        &'a self,
+        keys: &'a [String],
+        tags: &'a [String],
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let mut pipe = redis::pipe();
+            for tag in tags {
+                pipe.srem(tag_set_key(tag), keys).ignore();
+            }
+            let mut conn = self.conn_manager.clone();
+            let () = pipe.query_async(&mut conn).await?;
+            Ok(())
+        })
+    }
+
+    /// `ZADD` with score 0 to `mtc:index:<index>`
+    ///
+    /// Equal scores keep members in lexicographic order, so the set also
+    /// serves `ZRANGEBYLEX` range queries. Index sets have no TTL.
+    fn index_add<'a>(&'a self, index: &'a str, member: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            let mut conn = self.conn_manager.clone();
+            let _: i64 = conn.zadd(index_set_key(index), member, 0).await?;
+            Ok(())
+        })
+    }
+
+    /// `ZREM` from the index's sorted set
+    fn index_remove<'a>(
+        &'a self,
+        index: &'a str,
+        member: &'a str,
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            let mut conn = self.conn_manager.clone();
+            let _: i64 = conn.zrem(index_set_key(index), member).await?;
+            Ok(())
+        })
+    }
+
+    /// `DEL` of the index's sorted set
+    fn index_drop<'a>(&'a self, index: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            let mut conn = self.conn_manager.clone();
+            let _: i64 = conn.del(index_set_key(index)).await?;
+            Ok(())
+        })
+    }
+
+    /// `ZRANGE 0 -1` of the index's sorted set
+    fn index_members<'a>(
+        &'a self,
+        index: &'a str,
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        Box::pin(async move {
+            let mut conn = self.read_conn();
+            let members: Vec<String> = conn.zrange(index_set_key(index), 0, -1).await?;
+            Ok(Some(members))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TokenCredentials, has_getdel, parse_info};
+    use futures_util::StreamExt;
+    use redis::auth::StreamingCredentialsProvider;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn test_parse_info() {
+        let raw = "# Memory\r\nused_memory:1024\r\nused_memory_human:1.00K\r\n\r\n# Stats\r\nkeyspace_hits:7\r\n";
+        let info: HashMap<String, String> = parse_info(raw).collect();
+
+        assert_eq!(info.len(), 3);
+        assert_eq!(info.get("used_memory").map(String::as_str), Some("1024"));
+        assert_eq!(
+            info.get("used_memory_human").map(String::as_str),
+            Some("1.00K")
+        );
+        assert_eq!(info.get("keyspace_hits").map(String::as_str), Some("7"));
+    }
+
+    #[test]
+    fn test_has_getdel() {
+        assert!(has_getdel("6.2.0"));
+        assert!(has_getdel("7.2.4"));
+        assert!(has_getdel("10.0.1"));
+        assert!(!has_getdel("6.0.16"));
+        assert!(!has_getdel("5.0.7"));
+        assert!(!has_getdel(""));
+    }
+
+    #[tokio::test]
+    async fn test_token_credentials_refresh_per_connection() {
+        let generated = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&generated);
+        let credentials = TokenCredentials {
+            username: "iam-user".to_string(),
+            provider: Arc::new(move || format!("token-{}", counter.fetch_add(1, Ordering::SeqCst))),
+        };
+
+        // Each connection subscribes anew and must see a fresh token
+        let mut tokens = Vec::new();
+        for _ in 0..3 {
+            let auth = credentials
+                .subscribe()
+                .next()
+                .await
+                .and_then(Result::ok)
+                .unwrap_or_else(|| panic!("Credentials stream yielded no token"));
+            assert_eq!(auth.username(), "iam-user");
+            tokens.push(auth.password().to_string());
+        }
+
+        assert_eq!(tokens, ["token-0", "token-1", "token-2"]);
+        assert_eq!(generated.load(Ordering::SeqCst), 3);
+    }
 }
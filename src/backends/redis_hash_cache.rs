@@ -0,0 +1,264 @@
+//! Redis Hash storage mode
+//!
+//! `RedisHashCache` stores each value's top-level JSON object as a Redis Hash,
+//! one field per object member, so a single member can be read or rewritten
+//! (`get_field` / `update_field`) without transferring the whole object.
+//!
+//! Values must be JSON objects (use the default `Json` serializer); member
+//! values are stored as their JSON encoding. Anything else is rejected on write.
+
+use crate::backends::RedisCache;
+use crate::error::{CacheError, CacheResult};
+use crate::traits::{CacheBackend, L2CacheBackend};
+use bytes::Bytes;
+use futures_util::future::BoxFuture;
+use redis::AsyncCommands;
+use redis::aio::ConnectionManager;
+use serde_json::{Map, Value};
+use std::time::Duration;
+use tracing::{debug, info};
+
+/// HSET that only touches existing hashes, so an expired object isn't revived
+/// as a partial object without a TTL
+const HSET_IF_EXISTS_LUA: &str = r"
+if redis.call('EXISTS', KEYS[1]) == 1 then
+    redis.call('HSET', KEYS[1], ARGV[1], ARGV[2])
+    return 1
+end
+return 0
+";
+
+/// Redis cache storing JSON objects as Hashes for field-level access
+pub struct RedisHashCache {
+    /// Redis connection manager
+    conn_manager: ConnectionManager,
+}
+
+impl RedisHashCache {
+    /// Create new Redis hash cache using `REDIS_URL` (default `redis://127.0.0.1:6379`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Redis URL is invalid or connection fails.
+    pub async fn new() -> CacheResult<Self> {
+        let redis_url =
+            std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+        Self::with_url(&redis_url).await
+    }
+
+    /// Create new Redis hash cache with custom URL
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Redis URL is invalid or connection fails.
+    pub async fn with_url(redis_url: &str) -> CacheResult<Self> {
+        info!(redis_url = %redis_url, "Initializing Redis Hash Cache");
+        let conn_manager = RedisCache::connect(redis_url).await?;
+        Ok(Self { conn_manager })
+    }
+
+    /// Read one member of a cached object
+    ///
+    /// Returns `None` if the key or the field doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HGET command fails or the field doesn't deserialize into `T`.
+    pub async fn get_field<T: serde::de::DeserializeOwned>(
+        &self,
+        key: &str,
+        field: &str,
+    ) -> CacheResult<Option<T>> {
+        let mut conn = self.conn_manager.clone();
+        let raw: Option<String> = conn.hget(key, field).await?;
+        raw.map(|json| {
+            serde_json::from_str(&json).map_err(|e| {
+                CacheError::SerializationError(format!(
+                    "Failed to deserialize field '{field}' of '{key}': {e}"
+                ))
+            })
+        })
+        .transpose()
+    }
+
+    /// Overwrite (or add) one member of a cached object, keeping its TTL
+    ///
+    /// Returns `false` without writing if the key doesn't exist, since a
+    /// lone field would otherwise be read back as an incomplete object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or the Redis command fails.
+    pub async fn update_field<T: serde::Serialize>(
+        &self,
+        key: &str,
+        field: &str,
+        value: &T,
+    ) -> CacheResult<bool> {
+        let json = serde_json::to_string(value).map_err(|e| {
+            CacheError::SerializationError(format!("Failed to serialize field '{field}': {e}"))
+        })?;
+
+        let mut conn = self.conn_manager.clone();
+        let updated: i64 = redis::Script::new(HSET_IF_EXISTS_LUA)
+            .key(key)
+            .arg(field)
+            .arg(json)
+            .invoke_async(&mut conn)
+            .await?;
+
+        debug!(key = %key, field = %field, updated = updated == 1, "[RedisHash] Updated field");
+        Ok(updated == 1)
+    }
+}
+
+/// Split a JSON object into hash fields holding each member's JSON encoding
+fn object_to_fields(key: &str, value: &[u8]) -> CacheResult<Vec<(String, String)>> {
+    let object: Map<String, Value> = serde_json::from_slice(value).map_err(|e| {
+        CacheError::SerializationError(format!(
+            "RedisHashCache requires a JSON object for '{key}': {e}"
+        ))
+    })?;
+    Ok(object
+        .into_iter()
+        .map(|(field, member)| (field, member.to_string()))
+        .collect())
+}
+
+/// Rebuild the JSON object from hash fields (`None` for a missing or malformed hash)
+fn fields_to_object(fields: Vec<(String, String)>) -> Option<Bytes> {
+    if fields.is_empty() {
+        return None;
+    }
+    let object = fields
+        .into_iter()
+        .map(|(field, json)| Ok((field, serde_json::from_str(&json)?)))
+        .collect::<Result<Map<String, Value>, serde_json::Error>>()
+        .ok()?;
+    serde_json::to_vec(&object).ok().map(Bytes::from)
+}
+
+// ===== Trait Implementations =====
+
+impl CacheBackend for RedisHashCache {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<Bytes>> {
+        Box::pin(async move {
+            let mut conn = self.conn_manager.clone();
+            let fields: Vec<(String, String)> = conn.hgetall(key).await.ok()?;
+            fields_to_object(fields)
+        })
+    }
+
+    fn set_with_ttl<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            let fields = object_to_fields(key, &value)?;
+            let ttl_ms = u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX);
+
+            // Replace the whole object atomically so stale fields don't survive
+            let mut pipe = redis::pipe();
+            pipe.atomic().del(key).ignore();
+            if !fields.is_empty() {
+                pipe.hset_multiple(key, &fields)
+                    .ignore()
+                    .pexpire(key, i64::try_from(ttl_ms).unwrap_or(i64::MAX))
+                    .ignore();
+            }
+
+            let mut conn = self.conn_manager.clone();
+            let () = pipe
+                .query_async(&mut conn)
+                .await
+                .map_err(|e| CacheError::BackendError(format!("Redis hash set failed: {e}")))?;
+            debug!(key = %key, fields = fields.len(), ttl_ms = %ttl_ms, "[RedisHash] Cached object");
+            Ok(())
+        })
+    }
+
+    fn remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            let mut conn = self.conn_manager.clone();
+            let _: usize = conn.del(key).await?;
+            Ok(())
+        })
+    }
+
+    fn get_and_remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<Option<Bytes>>> {
+        Box::pin(async move {
+            let mut conn = self.conn_manager.clone();
+            let (fields,): (Vec<(String, String)>,) = redis::pipe()
+                .atomic()
+                .hgetall(key)
+                .del(key)
+                .ignore()
+                .query_async(&mut conn)
+                .await?;
+            Ok(fields_to_object(fields))
+        })
+    }
+
+    fn health_check(&self) -> BoxFuture<'_, bool> {
+        Box::pin(async move {
+            let mut conn = self.conn_manager.clone();
+            let result: redis::RedisResult<String> =
+                redis::cmd("PING").query_async(&mut conn).await;
+            result.is_ok()
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        "RedisHash"
+    }
+}
+
+impl L2CacheBackend for RedisHashCache {
+    fn get_with_ttl<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> BoxFuture<'a, Option<(Bytes, Option<Duration>)>> {
+        Box::pin(async move {
+            let mut conn = self.conn_manager.clone();
+            let (fields, ttl_ms): (Vec<(String, String)>, i64) = redis::pipe()
+                .hgetall(key)
+                .pttl(key)
+                .query_async(&mut conn)
+                .await
+                .ok()?;
+
+            let ttl = (ttl_ms > 0).then(|| Duration::from_millis(ttl_ms.unsigned_abs()));
+            fields_to_object(fields).map(|value| (value, ttl))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fields_to_object, object_to_fields};
+    use serde_json::json;
+
+    #[test]
+    fn test_object_fields_round_trip() {
+        let user = json!({"id": 7, "name": "Ada", "tags": ["a", "b"], "last_seen": null});
+        let bytes = serde_json::to_vec(&user).unwrap_or_default();
+
+        let fields = object_to_fields("user:7", &bytes)
+            .unwrap_or_else(|e| panic!("Failed to split object: {e}"));
+        assert_eq!(fields.len(), 4);
+
+        let rebuilt = fields_to_object(fields).unwrap_or_else(|| panic!("Failed to rebuild"));
+        let rebuilt: serde_json::Value = serde_json::from_slice(&rebuilt)
+            .unwrap_or_else(|e| panic!("Rebuilt object is not JSON: {e}"));
+        assert_eq!(rebuilt, user);
+    }
+
+    #[test]
+    fn test_non_object_values_are_rejected() {
+        assert!(object_to_fields("k", b"[1, 2]").is_err());
+        assert!(object_to_fields("k", b"not json").is_err());
+        assert!(fields_to_object(Vec::new()).is_none());
+    }
+}
@@ -0,0 +1,437 @@
+//! Sharded Backend - Consistent-hashing router over several caches
+//!
+//! A single in-memory cache gets unwieldy for very large working sets: one
+//! instance holds all the memory and every write contends on its internals.
+//! `ShardedBackend` spreads keys over several inner caches by consistent
+//! hashing, so each shard holds a slice of the keys and adding a shard only
+//! moves a fraction of them.
+
+use crate::error::{CacheError, CacheResult};
+use crate::traits::{CacheBackend, KeyPredicate, L2CacheBackend, ValueWithTtl};
+use crate::transaction::{Transaction, TransactionWrite};
+use bytes::Bytes;
+use futures_util::future::{BoxFuture, join_all};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::info;
+
+/// Points per shard on the hash ring
+///
+/// More points even out the key distribution at the cost of a larger ring.
+const VIRTUAL_NODES: usize = 160;
+
+/// Backend routing each key to one of several shards
+///
+/// Shards sit on a hash ring with `VIRTUAL_NODES` points each; a key belongs
+/// to the first point at or after its hash. Routing is deterministic, so the
+/// same shard list always maps a key to the same shard. Per-key operations go
+/// to that shard only; `keys`, `keys_matching` and `remove_pattern` fan out to
+/// all shards.
+///
+/// `B` defaults to `dyn CacheBackend`. Use `ShardedBackend<dyn L2CacheBackend>`
+/// (or a concrete L2 backend type) to get an `L2CacheBackend` as well.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use multi_tier_cache::backends::ShardedBackend;
+/// use multi_tier_cache::{CacheBackend, MokaCache, MokaCacheConfig};
+/// use std::sync::Arc;
+///
+/// # fn example() -> anyhow::Result<()> {
+/// let shards = (0..4)
+///     .map(|_| Ok(Arc::new(MokaCache::new(MokaCacheConfig::default())?) as Arc<dyn CacheBackend>))
+///     .collect::<anyhow::Result<Vec<_>>>()?;
+/// let l1 = ShardedBackend::new(shards)?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ShardedBackend<B: ?Sized = dyn CacheBackend> {
+    /// Inner caches
+    shards: Vec<Arc<B>>,
+    /// Hash ring: (point, shard index), sorted by point
+    ring: Vec<(u64, usize)>,
+}
+
+impl<B: ?Sized> ShardedBackend<B> {
+    /// Route keys across `shards`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `shards` is empty.
+    pub fn new(shards: Vec<Arc<B>>) -> CacheResult<Self> {
+        if shards.is_empty() {
+            return Err(CacheError::ConfigError(
+                "ShardedBackend needs at least one shard".to_string(),
+            ));
+        }
+        let mut ring: Vec<(u64, usize)> = (0..shards.len())
+            .flat_map(|shard| (0..VIRTUAL_NODES).map(move |node| (hash_of(&(shard, node)), shard)))
+            .collect();
+        ring.sort_unstable();
+        info!(shards = shards.len(), "Initializing sharded cache");
+        Ok(Self { shards, ring })
+    }
+
+    /// The inner caches, in the order given to `new`
+    #[must_use]
+    pub fn shards(&self) -> &[Arc<B>] {
+        &self.shards
+    }
+
+    /// Index of the shard `key` is routed to
+    #[must_use]
+    pub fn shard_index(&self, key: &str) -> usize {
+        let hash = hash_of(key);
+        let point = self.ring.partition_point(|&(point, _)| point < hash);
+        // Past the last point wraps around to the first
+        self.ring
+            .get(point)
+            .or_else(|| self.ring.first())
+            .map_or(0, |&(_, shard)| shard)
+    }
+
+    /// The shard `key` is routed to
+    fn shard(&self, key: &str) -> CacheResult<&Arc<B>> {
+        self.shards
+            .get(self.shard_index(key))
+            .ok_or_else(|| CacheError::InternalError("Shard index out of range".to_string()))
+    }
+}
+
+/// Stable hash of `value` (same result in every process)
+fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl<B: CacheBackend + ?Sized> CacheBackend for ShardedBackend<B> {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<Bytes>> {
+        Box::pin(async move { self.shard(key).ok()?.get(key).await })
+    }
+
+    fn set_with_ttl<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move { self.shard(key)?.set_with_ttl(key, value, ttl).await })
+    }
+
+    fn remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move { self.shard(key)?.remove(key).await })
+    }
+
+    /// Groups the keys by shard and issues one `batch_remove` per shard
+    fn batch_remove<'a>(&'a self, keys: &'a [String]) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            let mut groups = vec![Vec::new(); self.shards.len()];
+            for key in keys {
+                if let Some(group) = groups.get_mut(self.shard_index(key)) {
+                    group.push(key.clone());
+                }
+            }
+            join_all(
+                self.shards
+                    .iter()
+                    .zip(&groups)
+                    .filter(|(_, group)| !group.is_empty())
+                    .map(|(shard, group)| shard.batch_remove(group)),
+            )
+            .await
+            .into_iter()
+            .collect()
+        })
+    }
+
+    fn get_and_remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<Option<Bytes>>> {
+        Box::pin(async move { self.shard(key)?.get_and_remove(key).await })
+    }
+
+    fn set_if_present<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move { self.shard(key)?.set_if_present(key, value, ttl).await })
+    }
+
+    fn touch<'a>(&'a self, key: &'a str, ttl: Duration) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move { self.shard(key)?.touch(key, ttl).await })
+    }
+
+    fn remove_pattern<'a>(&'a self, pattern: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            join_all(
+                self.shards
+                    .iter()
+                    .map(|shard| shard.remove_pattern(pattern)),
+            )
+            .await
+            .into_iter()
+            .collect()
+        })
+    }
+
+    fn remove_if(&self, predicate: KeyPredicate) -> BoxFuture<'_, CacheResult<()>> {
+        Box::pin(async move {
+            join_all(
+                self.shards
+                    .iter()
+                    .map(|shard| shard.remove_if(Arc::clone(&predicate))),
+            )
+            .await
+            .into_iter()
+            .collect()
+        })
+    }
+
+    /// Keys of all shards; `None` if any shard can't enumerate its keys
+    fn keys(&self) -> BoxFuture<'_, CacheResult<Option<Vec<String>>>> {
+        Box::pin(async move {
+            let mut all = Vec::new();
+            for keys in join_all(self.shards.iter().map(|shard| shard.keys())).await {
+                let Some(keys) = keys? else {
+                    return Ok(None);
+                };
+                all.extend(keys);
+            }
+            Ok(Some(all))
+        })
+    }
+
+    fn keys_matching<'a>(
+        &'a self,
+        pattern: &'a str,
+        limit: usize,
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        Box::pin(async move {
+            let mut all = Vec::new();
+            let searches = self
+                .shards
+                .iter()
+                .map(|shard| shard.keys_matching(pattern, limit));
+            for keys in join_all(searches).await {
+                let Some(keys) = keys? else {
+                    return Ok(None);
+                };
+                all.extend(keys);
+            }
+            all.truncate(limit);
+            Ok(Some(all))
+        })
+    }
+
+    fn set_pinned<'a>(&'a self, key: &'a str, pinned: bool) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move { self.shard(key)?.set_pinned(key, pinned).await })
+    }
+
+    /// Healthy only if every shard is
+    fn health_check(&self) -> BoxFuture<'_, bool> {
+        Box::pin(async move {
+            join_all(self.shards.iter().map(|shard| shard.health_check()))
+                .await
+                .into_iter()
+                .all(|healthy| healthy)
+        })
+    }
+
+    /// Sum over all shards; `None` if any shard is unbounded
+    fn capacity(&self) -> Option<u64> {
+        self.shards.iter().map(|shard| shard.capacity()).sum()
+    }
+
+    /// Sum over all shards; `None` if any shard can't report it
+    fn entry_count(&self) -> Option<u64> {
+        self.shards.iter().map(|shard| shard.entry_count()).sum()
+    }
+
+    /// Sum over all shards; `None` if any shard can't estimate it
+    fn estimated_size_bytes(&self) -> Option<u64> {
+        self.shards
+            .iter()
+            .map(|shard| shard.estimated_size_bytes())
+            .sum()
+    }
+
+    /// Each shard frees its share of `bytes`, proportional to its size
+    fn evict_bytes(&self, bytes: u64) -> BoxFuture<'_, CacheResult<u64>> {
+        Box::pin(async move {
+            let sizes: Vec<u64> = self
+                .shards
+                .iter()
+                .map(|shard| shard.estimated_size_bytes().unwrap_or(0))
+                .collect();
+            let total: u64 = sizes.iter().sum();
+            let mut freed = 0;
+            for (shard, size) in self.shards.iter().zip(sizes) {
+                let share = if total == 0 {
+                    bytes.div_ceil(self.shards.len() as u64)
+                } else {
+                    u64::try_from(
+                        (u128::from(bytes) * u128::from(size)).div_ceil(u128::from(total)),
+                    )
+                    .unwrap_or(u64::MAX)
+                };
+                if share > 0 {
+                    freed += shard.evict_bytes(share).await?;
+                }
+            }
+            Ok(freed)
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        "Sharded"
+    }
+}
+
+impl<B: L2CacheBackend + ?Sized> L2CacheBackend for ShardedBackend<B> {
+    fn get_with_ttl<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> BoxFuture<'a, Option<(Bytes, Option<Duration>)>> {
+        Box::pin(async move { self.shard(key).ok()?.get_with_ttl(key).await })
+    }
+
+    /// One batched read per shard holding any of `keys`
+    fn get_many_with_ttl<'a>(
+        &'a self,
+        keys: &'a [String],
+    ) -> BoxFuture<'a, Vec<Option<ValueWithTtl>>> {
+        Box::pin(async move {
+            // Positions in `keys` and the keys themselves, per shard
+            let mut batches: Vec<(Vec<usize>, Vec<String>)> =
+                vec![(Vec::new(), Vec::new()); self.shards.len()];
+            for (position, key) in keys.iter().enumerate() {
+                if let Some((positions, shard_keys)) = batches.get_mut(self.shard_index(key)) {
+                    positions.push(position);
+                    shard_keys.push(key.clone());
+                }
+            }
+
+            let mut results = vec![None; keys.len()];
+            for (shard, (positions, shard_keys)) in self.shards.iter().zip(&batches) {
+                if shard_keys.is_empty() {
+                    continue;
+                }
+                let values = shard.get_many_with_ttl(shard_keys).await;
+                for (&position, value) in positions.iter().zip(values) {
+                    if let Some(slot) = results.get_mut(position) {
+                        *slot = value;
+                    }
+                }
+            }
+            results
+        })
+    }
+
+    fn age<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<Option<Duration>>> {
+        Box::pin(async move { self.shard(key)?.age(key).await })
+    }
+
+    fn next_version<'a>(
+        &'a self,
+        key: &'a str,
+        floor: u64,
+    ) -> BoxFuture<'a, CacheResult<Option<u64>>> {
+        Box::pin(async move { self.shard(key)?.next_version(key, floor).await })
+    }
+
+    /// Runs on the shard holding all of the transaction's keys
+    ///
+    /// Fails if the keys span several shards: there's no atomicity across shards.
+    fn execute_transaction<'a>(
+        &'a self,
+        transaction: &'a Transaction,
+    ) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move {
+            let mut shards = transaction
+                .checks()
+                .iter()
+                .map(|(key, _)| key.as_str())
+                .chain(transaction.writes().iter().map(TransactionWrite::key))
+                .map(|key| self.shard_index(key));
+            let shard = shards.next().unwrap_or(0);
+            if shards.any(|other| other != shard) {
+                return Err(CacheError::BackendError(
+                    "Transaction keys span several shards".to_string(),
+                ));
+            }
+            self.shards
+                .get(shard)
+                .ok_or_else(|| CacheError::InternalError("Shard index out of range".to_string()))?
+                .execute_transaction(transaction)
+                .await
+        })
+    }
+
+    /// Tags are kept on the key's shard
+    fn tag_key<'a>(&'a self, key: &'a str, tags: &'a [String]) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move { self.shard(key)?.tag_key(key, tags).await })
+    }
+
+    /// Union of the per-shard intersections (each key lives on one shard)
+    fn keys_with_all_tags<'a>(
+        &'a self,
+        tags: &'a [String],
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        Box::pin(async move {
+            let mut all = Vec::new();
+            let searches = self
+                .shards
+                .iter()
+                .map(|shard| shard.keys_with_all_tags(tags));
+            for keys in join_all(searches).await {
+                let Some(keys) = keys? else {
+                    return Ok(None);
+                };
+                all.extend(keys);
+            }
+            Ok(Some(all))
+        })
+    }
+
+    fn untag_keys<'a>(
+        &'a self,
+        keys: &'a [String],
+        tags: &'a [String],
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            for key in keys {
+                self.shard(key)?
+                    .untag_keys(std::slice::from_ref(key), tags)
+                    .await?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Each index is kept whole on the shard of its name
+    fn index_add<'a>(&'a self, index: &'a str, member: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move { self.shard(index)?.index_add(index, member).await })
+    }
+
+    fn index_remove<'a>(
+        &'a self,
+        index: &'a str,
+        member: &'a str,
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move { self.shard(index)?.index_remove(index, member).await })
+    }
+
+    fn index_drop<'a>(&'a self, index: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move { self.shard(index)?.index_drop(index).await })
+    }
+
+    fn index_members<'a>(
+        &'a self,
+        index: &'a str,
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        Box::pin(async move { self.shard(index)?.index_members(index).await })
+    }
+}
@@ -0,0 +1,107 @@
+//! Tiered backend - a whole `CacheManager` used as a single tier
+//!
+//! Lets multi-tier caches nest: e.g. a region-local L1+L2 cache can act as one
+//! remote tier of a larger composition via `CacheSystemBuilder::with_tier`.
+
+use crate::cache_manager::{CacheManager, CacheStrategy};
+use crate::error::CacheResult;
+use crate::traits::{CacheBackend, L2CacheBackend};
+use crate::transaction::Transaction;
+use bytes::Bytes;
+use futures_util::future::BoxFuture;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+
+/// Cache backend delegating to an inner `CacheManager`
+///
+/// Reads go through the inner manager's `get` (so its own promotion and
+/// statistics apply), writes through `set_with_strategy` with the given TTL,
+/// and removals through `invalidate` / `invalidate_pattern` (so inner
+/// invalidation broadcasts still fire). `get_with_ttl` reports the remaining
+/// TTL of the first inner tier holding the key.
+pub struct TieredBackend {
+    /// Inner cache acting as this tier
+    manager: Arc<CacheManager>,
+}
+
+impl TieredBackend {
+    /// Wrap `manager` as a single cache tier
+    #[must_use]
+    pub fn new(manager: Arc<CacheManager>) -> Self {
+        Self { manager }
+    }
+
+    /// The inner cache manager
+    #[must_use]
+    pub fn manager(&self) -> &Arc<CacheManager> {
+        &self.manager
+    }
+}
+
+impl CacheBackend for TieredBackend {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<Bytes>> {
+        Box::pin(async move {
+            match self.manager.get(key).await {
+                Ok(value) => value,
+                Err(e) => {
+                    warn!("Tiered backend get failed for key '{}': {}", key, e);
+                    None
+                }
+            }
+        })
+    }
+
+    fn set_with_ttl<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            self.manager
+                .set_with_strategy(key, value, CacheStrategy::Custom(ttl))
+                .await
+        })
+    }
+
+    fn remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move { self.manager.invalidate(key).await })
+    }
+
+    fn batch_remove<'a>(&'a self, keys: &'a [String]) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+            self.manager.invalidate_many(&keys).await
+        })
+    }
+
+    fn remove_pattern<'a>(&'a self, pattern: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move { self.manager.invalidate_pattern(pattern).await })
+    }
+
+    fn health_check(&self) -> BoxFuture<'_, bool> {
+        Box::pin(async move { self.manager.tiers_healthy().await })
+    }
+
+    fn name(&self) -> &'static str {
+        "Tiered"
+    }
+}
+
+impl L2CacheBackend for TieredBackend {
+    fn get_with_ttl<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> BoxFuture<'a, Option<(Bytes, Option<Duration>)>> {
+        Box::pin(async move { self.manager.peek_with_ttl(key).await })
+    }
+
+    /// Runs on the inner manager's lowest tier
+    fn execute_transaction<'a>(
+        &'a self,
+        transaction: &'a Transaction,
+    ) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(self.manager.execute_transaction(transaction.clone()))
+    }
+}
@@ -50,8 +50,13 @@
 #[cfg(feature = "moka")]
 #[cfg_attr(docsrs, doc(cfg(feature = "moka")))]
 use crate::backends::MokaCacheConfig;
+use crate::serialization::ValueCompression;
+use crate::spawner::Spawner;
 use crate::traits::{CacheBackend, L2CacheBackend, StreamingBackend};
-use crate::{CacheManager, CacheSystem, CacheTier, TierConfig};
+use crate::{
+    AdaptiveTtl, CacheManager, CacheSerializer, CacheSystem, CacheTier, DeadlinePolicy,
+    L2MissPolicy, L2WriteFailurePolicy, StrategyDurations, TierConfig, UndecodablePolicy,
+};
 
 #[cfg(feature = "moka")]
 #[cfg_attr(docsrs, doc(cfg(feature = "moka")))]
@@ -59,8 +64,10 @@ use crate::L1Cache;
 #[cfg(feature = "redis")]
 #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
 use crate::L2Cache;
-use crate::error::CacheResult;
+use crate::error::{CacheError, CacheResult};
+use std::any::Any;
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::info;
 
 /// Builder for constructing `CacheSystem` with custom backends
@@ -145,6 +152,8 @@ pub struct CacheSystemBuilder {
     // Legacy 2-tier configuration (v0.1.0 - v0.4.x)
     l1_backend: Option<Arc<dyn CacheBackend>>,
     l2_backend: Option<Arc<dyn L2CacheBackend>>,
+    // Same L1 backend with its concrete type, for `CacheSystem::l1_as()`
+    l1_concrete: Option<Arc<dyn Any + Send + Sync>>,
 
     streaming_backend: Option<Arc<dyn StreamingBackend>>,
     #[cfg(feature = "moka")]
@@ -153,6 +162,108 @@ pub struct CacheSystemBuilder {
 
     // Multi-tier configuration (v0.5.0+)
     tiers: Vec<(Arc<dyn L2CacheBackend>, TierConfig)>,
+
+    // Options applied to the built `CacheManager`
+    manager_options: ManagerOptions,
+}
+
+/// Manager-level options collected by the builder
+#[derive(Default, Clone)]
+#[allow(clippy::struct_excessive_bools)]
+struct ManagerOptions {
+    codec_guard: bool,
+    l2_concurrency_limit: Option<usize>,
+    async_promotion: bool,
+    async_promotion_capacity: Option<usize>,
+    promotion_dedup: Option<bool>,
+    striped_counters: bool,
+    spawner: Option<Arc<dyn Spawner>>,
+    l2_write_failure_policy: L2WriteFailurePolicy,
+    l2_miss_policy: L2MissPolicy,
+    l2_miss_sample_rate: Option<f64>,
+    reject_null_values: bool,
+    large_value_threshold: usize,
+    in_flight_wait_timeout: Duration,
+    coherence_sample_rate: f64,
+    adaptive_ttl: Option<AdaptiveTtl>,
+    sliding_expiration: Duration,
+    sliding_expiration_limit: Option<Duration>,
+    global_max_bytes: u64,
+    deadline_policy: DeadlinePolicy,
+    undecodable_policy: UndecodablePolicy,
+    strategy_durations: StrategyDurations,
+    serializer: Option<CacheSerializer>,
+    fallback_codecs: Vec<CacheSerializer>,
+    value_compression: Option<ValueCompression>,
+    key_prefix: Option<String>,
+    durable_tier: Option<Arc<dyn L2CacheBackend>>,
+}
+
+impl ManagerOptions {
+    /// Apply the options to a freshly constructed `CacheManager`
+    fn apply(self, manager: CacheManager) -> CacheManager {
+        // Added first so tier-wide options below cover it
+        let manager = match self.durable_tier {
+            Some(backend) => manager.with_durable_tier(backend),
+            None => manager,
+        };
+        let mut manager = manager
+            .with_codec_guard(self.codec_guard)
+            .with_striped_counters(self.striped_counters)
+            .with_l2_write_failure_policy(self.l2_write_failure_policy)
+            .with_l2_miss_policy(self.l2_miss_policy)
+            .with_reject_null_values(self.reject_null_values)
+            .with_large_value_threshold(self.large_value_threshold)
+            .with_in_flight_wait_timeout(self.in_flight_wait_timeout)
+            .with_coherence_sampling(self.coherence_sample_rate)
+            .with_global_max_bytes(self.global_max_bytes)
+            .with_deadline_policy(self.deadline_policy)
+            .with_undecodable_policy(self.undecodable_policy)
+            .with_strategy_durations(self.strategy_durations);
+        if let Some(serializer) = self.serializer {
+            manager.set_serializer(serializer);
+        }
+        let manager = manager.with_fallback_codecs(self.fallback_codecs);
+        let manager = match self.value_compression {
+            Some(compression) => manager.with_value_compression(compression),
+            None => manager,
+        };
+        let manager = match self.adaptive_ttl {
+            Some(config) => manager.with_adaptive_ttl(config),
+            None => manager,
+        };
+        let manager = manager.with_sliding_expiration(self.sliding_expiration);
+        let manager = match self.sliding_expiration_limit {
+            Some(max_lifetime) => manager.with_sliding_expiration_limit(max_lifetime),
+            None => manager,
+        };
+        let manager = match self.l2_miss_sample_rate {
+            Some(rate) => manager.with_l2_miss_sampling(rate),
+            None => manager,
+        };
+        let manager = match self.key_prefix {
+            Some(prefix) => manager.with_key_prefix(prefix),
+            None => manager,
+        };
+        let manager = match self.l2_concurrency_limit {
+            Some(limit) => manager.with_l2_concurrency_limit(limit),
+            None => manager,
+        };
+        let manager = match self.spawner {
+            Some(spawner) => manager.with_spawner(spawner),
+            None => manager,
+        };
+        let manager = match self.promotion_dedup {
+            Some(enabled) => manager.with_promotion_dedup(enabled),
+            None => manager,
+        };
+        match self.async_promotion_capacity {
+            Some(capacity) if self.async_promotion => {
+                manager.with_async_promotion_capacity(capacity)
+            }
+            _ => manager.with_async_promotion(self.async_promotion),
+        }
+    }
 }
 
 impl CacheSystemBuilder {
@@ -165,12 +276,14 @@ impl CacheSystemBuilder {
         Self {
             l1_backend: None,
             l2_backend: None,
+            l1_concrete: None,
 
             streaming_backend: None,
             #[cfg(feature = "moka")]
             #[cfg_attr(docsrs, doc(cfg(feature = "moka")))]
             moka_config: None,
             tiers: Vec::new(),
+            manager_options: ManagerOptions::default(),
         }
     }
 
@@ -213,6 +326,37 @@ impl CacheSystemBuilder {
     #[must_use]
     pub fn with_l1(mut self, backend: Arc<dyn CacheBackend>) -> Self {
         self.l1_backend = Some(backend);
+        self.l1_concrete = None;
+        self
+    }
+
+    /// Configure a custom L1 backend, keeping access to its concrete type
+    ///
+    /// Like `.with_l1()`, but the built system returns the backend from
+    /// `CacheSystem::l1_as::<B>()`, so backend-specific methods stay reachable.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn example() -> anyhow::Result<()> {
+    /// use std::sync::Arc;
+    /// use multi_tier_cache::{CacheSystemBuilder, DashMapCache};
+    ///
+    /// let cache = CacheSystemBuilder::new()
+    ///     .with_concrete_l1(Arc::new(DashMapCache::new()))
+    ///     .build()
+    ///     .await?;
+    ///
+    /// if let Some(l1) = cache.l1_as::<DashMapCache>() {
+    ///     l1.cleanup_expired();
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_concrete_l1<B: CacheBackend + 'static>(mut self, backend: Arc<B>) -> Self {
+        self.l1_backend = Some(Arc::clone(&backend) as Arc<dyn CacheBackend>);
+        self.l1_concrete = Some(backend);
         self
     }
 
@@ -402,11 +546,16 @@ impl CacheSystemBuilder {
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use std::sync::Arc;
-    /// use multi_tier_cache::CacheSystemBuilder;
+    /// use multi_tier_cache::{CacheSystemBuilder, L1Cache, L2Cache, MokaCacheConfig, TierConfig};
     ///
+    /// let l1 = Arc::new(L1Cache::new(MokaCacheConfig::default())?);
+    /// let l2 = Arc::new(L2Cache::new().await?);
     /// let rocksdb = Arc::new(RocksDBCache);
     ///
+    /// // Lower tiers need explicit upper tiers; `validate()` rejects configs without an L1
     /// let cache = CacheSystemBuilder::new()
+    ///     .with_tier(l1, TierConfig::as_l1())
+    ///     .with_tier(l2, TierConfig::as_l2())
     ///     .with_l3(rocksdb)
     ///     .build()
     ///     .await?;
@@ -450,11 +599,16 @@ impl CacheSystemBuilder {
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use std::sync::Arc;
-    /// use multi_tier_cache::CacheSystemBuilder;
+    /// use multi_tier_cache::{CacheSystemBuilder, L1Cache, L2Cache, MokaCacheConfig, TierConfig};
     ///
+    /// let l1 = Arc::new(L1Cache::new(MokaCacheConfig::default())?);
+    /// let l2 = Arc::new(L2Cache::new().await?);
     /// let s3_cache = Arc::new(S3Cache);
     ///
+    /// // Lower tiers need explicit upper tiers; `validate()` rejects configs without an L1
     /// let cache = CacheSystemBuilder::new()
+    ///     .with_tier(l1, TierConfig::as_l1())
+    ///     .with_tier(l2, TierConfig::as_l2())
     ///     .with_l4(s3_cache)
     ///     .build()
     ///     .await?;
@@ -467,6 +621,266 @@ impl CacheSystemBuilder {
         self
     }
 
+    /// Add a slower durable store below the other tiers
+    ///
+    /// Works with the default Moka + Redis pair, `.with_l1()` / `.with_l2()`
+    /// and `.with_tier()` alike: the store becomes the lowest tier, and hits
+    /// there are promoted back to every upper tier. See
+    /// `CacheManager::with_durable_tier()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use std::sync::Arc;
+    /// # use multi_tier_cache::DashMapCache;
+    /// # async fn example() -> anyhow::Result<()> {
+    /// use multi_tier_cache::CacheSystemBuilder;
+    ///
+    /// // L1 Moka, L2 Redis, L3 durable store (e.g. a DynamoDB-backed `L2CacheBackend`)
+    /// # let dynamodb = Arc::new(DashMapCache::new());
+    /// let cache = CacheSystemBuilder::new()
+    ///     .with_durable_tier(dynamodb)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_durable_tier(mut self, backend: Arc<dyn L2CacheBackend>) -> Self {
+        self.manager_options.durable_tier = Some(backend);
+        self
+    }
+
+    /// Enable the codec guard for typed values
+    ///
+    /// Typed values are prefixed with a short codec identifier, so reading a value
+    /// written with a different serializer is a logged cache miss rather than a
+    /// deserialization error. See `CacheManager::with_codec_guard()`.
+    #[must_use]
+    pub fn with_codec_guard(mut self, enabled: bool) -> Self {
+        self.manager_options.codec_guard = enabled;
+        self
+    }
+
+    /// Bound the number of concurrent L2 (and lower tier) operations
+    ///
+    /// Excess callers wait for a permit rather than piling onto the backend.
+    /// See `CacheManager::with_l2_concurrency_limit()`.
+    #[must_use]
+    pub fn with_l2_concurrency_limit(mut self, max_in_flight: usize) -> Self {
+        self.manager_options.l2_concurrency_limit = Some(max_in_flight);
+        self
+    }
+
+    /// Use striped request counters to reduce contention on many-core machines
+    ///
+    /// See `CacheManager::with_striped_counters()`.
+    #[must_use]
+    pub fn with_striped_counters(mut self, enabled: bool) -> Self {
+        self.manager_options.striped_counters = enabled;
+        self
+    }
+
+    /// Choose what happens to the L1 copy when a lower tier write fails
+    ///
+    /// See `CacheManager::with_l2_write_failure_policy()`.
+    #[must_use]
+    pub fn with_l2_write_failure_policy(mut self, policy: L2WriteFailurePolicy) -> Self {
+        self.manager_options.l2_write_failure_policy = policy;
+        self
+    }
+
+    /// Choose whether L1 or L2 is authoritative for key existence
+    ///
+    /// See `CacheManager::with_l2_miss_policy()`.
+    #[must_use]
+    pub fn with_l2_miss_policy(mut self, policy: L2MissPolicy) -> Self {
+        self.manager_options.l2_miss_policy = policy;
+        self
+    }
+
+    /// Confirm only a fraction of L1 hits in L2 under `L2MissPolicy::FlushL1`
+    ///
+    /// See `CacheManager::with_l2_miss_sampling()`.
+    #[must_use]
+    pub fn with_l2_miss_sampling(mut self, rate: f64) -> Self {
+        self.manager_options.l2_miss_sample_rate = Some(rate);
+        self
+    }
+
+    /// Skip caching values that serialize to JSON `null`
+    ///
+    /// See `CacheManager::with_reject_null_values()`.
+    #[must_use]
+    pub fn with_reject_null_values(mut self, enabled: bool) -> Self {
+        self.manager_options.reject_null_values = enabled;
+        self
+    }
+
+    /// Warn about stored values larger than `bytes` (disabled by default)
+    ///
+    /// See `CacheManager::with_large_value_threshold()`.
+    #[must_use]
+    pub fn with_large_value_threshold(mut self, bytes: usize) -> Self {
+        self.manager_options.large_value_threshold = bytes;
+        self
+    }
+
+    /// Limit how long a coalesced request waits for the in-flight computation
+    ///
+    /// See `CacheManager::with_in_flight_wait_timeout()`.
+    #[must_use]
+    pub fn with_in_flight_wait_timeout(mut self, timeout: Duration) -> Self {
+        self.manager_options.in_flight_wait_timeout = timeout;
+        self
+    }
+
+    /// Cross-check a fraction of L1 hits against L2
+    ///
+    /// See `CacheManager::with_coherence_sampling()`.
+    #[must_use]
+    pub fn with_coherence_sampling(mut self, rate: f64) -> Self {
+        self.manager_options.coherence_sample_rate = rate;
+        self
+    }
+
+    /// Keep frequently hit keys cached longer
+    ///
+    /// See `CacheManager::with_adaptive_ttl()`.
+    #[must_use]
+    pub fn with_adaptive_ttl(mut self, config: AdaptiveTtl) -> Self {
+        self.manager_options.adaptive_ttl = Some(config);
+        self
+    }
+
+    /// Reset the TTL of a key to `window` every time it is read
+    ///
+    /// See `CacheManager::with_sliding_expiration()`.
+    #[must_use]
+    pub fn with_sliding_expiration(mut self, window: Duration) -> Self {
+        self.manager_options.sliding_expiration = window;
+        self
+    }
+
+    /// Cap how long reads keep a key alive under sliding expiration
+    ///
+    /// See `CacheManager::with_sliding_expiration_limit()`.
+    #[must_use]
+    pub fn with_sliding_expiration_limit(mut self, max_lifetime: Duration) -> Self {
+        self.manager_options.sliding_expiration_limit = Some(max_lifetime);
+        self
+    }
+
+    /// Cap the memory held by all in-memory tiers together
+    ///
+    /// See `CacheManager::with_global_max_bytes()`.
+    #[must_use]
+    pub fn with_global_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.manager_options.global_max_bytes = max_bytes;
+        self
+    }
+
+    /// Choose what deadline-aware operations return past the deadline
+    ///
+    /// See `CacheManager::with_deadline_policy()`.
+    #[must_use]
+    pub fn with_deadline_policy(mut self, policy: DeadlinePolicy) -> Self {
+        self.manager_options.deadline_policy = policy;
+        self
+    }
+
+    /// Choose how typed reads handle cached values that fail to deserialize
+    ///
+    /// See `CacheManager::with_undecodable_policy()`.
+    #[must_use]
+    pub fn with_undecodable_policy(mut self, policy: UndecodablePolicy) -> Self {
+        self.manager_options.undecodable_policy = policy;
+        self
+    }
+
+    /// Override the TTLs of the named strategies
+    ///
+    /// See `CacheManager::with_strategy_durations()`.
+    #[must_use]
+    pub fn with_strategy_durations(mut self, durations: StrategyDurations) -> Self {
+        self.manager_options.strategy_durations = durations;
+        self
+    }
+
+    /// Serialize typed values with `serializer` instead of JSON
+    ///
+    /// See `CacheManager::set_serializer()`.
+    #[must_use]
+    pub fn with_serializer(mut self, serializer: CacheSerializer) -> Self {
+        self.manager_options.serializer = Some(serializer);
+        self
+    }
+
+    /// Also read typed values written with any of `codecs`
+    ///
+    /// See `CacheManager::with_fallback_codecs()`.
+    #[must_use]
+    pub fn with_fallback_codecs(mut self, codecs: Vec<CacheSerializer>) -> Self {
+        self.manager_options.fallback_codecs = codecs;
+        self
+    }
+
+    /// Compress typed values with `compression`
+    ///
+    /// See `CacheManager::with_value_compression()`.
+    #[must_use]
+    pub fn with_value_compression(mut self, compression: ValueCompression) -> Self {
+        self.manager_options.value_compression = Some(compression);
+        self
+    }
+
+    /// Store every key under `prefix`
+    ///
+    /// See `CacheManager::with_key_prefix()`.
+    #[must_use]
+    pub fn with_key_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.manager_options.key_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Run background tasks on a custom `Spawner` instead of `tokio::spawn`
+    ///
+    /// See `CacheManager::with_spawner()`.
+    #[must_use]
+    pub fn with_spawner(mut self, spawner: Arc<dyn Spawner>) -> Self {
+        self.manager_options.spawner = Some(spawner);
+        self
+    }
+
+    /// Promote values to upper tiers from a background task
+    ///
+    /// Keeps `get` latency independent of upper tier write latency.
+    /// See `CacheManager::with_async_promotion()`.
+    #[must_use]
+    pub fn with_async_promotion(mut self, enabled: bool) -> Self {
+        self.manager_options.async_promotion = enabled;
+        self
+    }
+
+    /// Enable async promotion with a custom queue capacity
+    ///
+    /// See `CacheManager::with_async_promotion_capacity()`.
+    #[must_use]
+    pub fn with_async_promotion_capacity(mut self, capacity: usize) -> Self {
+        self.manager_options.async_promotion = true;
+        self.manager_options.async_promotion_capacity = Some(capacity);
+        self
+    }
+
+    /// Allow only one promotion per key at a time (enabled by default)
+    ///
+    /// See `CacheManager::with_promotion_dedup()`.
+    #[must_use]
+    pub fn with_promotion_dedup(mut self, enabled: bool) -> Self {
+        self.manager_options.promotion_dedup = Some(enabled);
+        self
+    }
+
     /// Build the `CacheSystem` with configured or default backends
     ///
     /// If no custom backends were provided via `.with_l1()` or `.with_l2()`,
@@ -504,6 +918,7 @@ impl CacheSystemBuilder {
     /// Returns an error if the default backends cannot be initialized.
     pub async fn build(self) -> CacheResult<CacheSystem> {
         info!("Building Multi-Tier Cache System");
+        self.validate()?;
 
         if !self.tiers.is_empty() {
             self.build_multi_tier()
@@ -514,6 +929,69 @@ impl CacheSystemBuilder {
         }
     }
 
+    /// Check the configuration for conflicting or ignored settings
+    ///
+    /// Called automatically by `build()`; call it directly to fail fast (e.g. at
+    /// startup) without connecting to any backend. Rejects:
+    /// - legacy `.with_l1()` / `.with_l2()` backends combined with `.with_tier()` tiers
+    /// - `.with_moka_config()` when the default Moka L1 isn't used
+    /// - a tier at level 0, or two tiers at the same level
+    /// - tiers without a level 1 tier (e.g. only `.with_l3()`)
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ConfigError` describing the first conflict found.
+    pub fn validate(&self) -> CacheResult<()> {
+        if !self.tiers.is_empty() && (self.l1_backend.is_some() || self.l2_backend.is_some()) {
+            return Err(CacheError::ConfigError(
+                "with_l1()/with_l2() can't be combined with with_tier(); add those backends as \
+                 tiers with TierConfig::as_l1()/as_l2() instead"
+                    .to_string(),
+            ));
+        }
+
+        #[cfg(feature = "moka")]
+        if self.moka_config.is_some() && (!self.tiers.is_empty() || self.l1_backend.is_some()) {
+            return Err(CacheError::ConfigError(
+                "with_moka_config() only applies to the default Moka L1, but a custom L1 \
+                 backend or tiers were configured"
+                    .to_string(),
+            ));
+        }
+
+        let mut levels: Vec<(usize, &str)> = self
+            .tiers
+            .iter()
+            .map(|(backend, config)| (config.tier_level, backend.name()))
+            .collect();
+        levels.sort_by_key(|(level, _)| *level);
+
+        if let Some((_, name)) = levels.iter().find(|(level, _)| *level == 0) {
+            return Err(CacheError::ConfigError(format!(
+                "Tier levels start at 1, but backend '{name}' is configured at level 0"
+            )));
+        }
+        for pair in levels.windows(2) {
+            if let [(level, first), (next_level, second)] = pair
+                && level == next_level
+            {
+                return Err(CacheError::ConfigError(format!(
+                    "Duplicate tier level L{level}: backends '{first}' and '{second}'"
+                )));
+            }
+        }
+        if let Some((lowest, name)) = levels.first()
+            && *lowest != 1
+        {
+            return Err(CacheError::ConfigError(format!(
+                "Tier configuration has no L1 tier (lowest is '{name}' at L{lowest}); \
+                 add one with with_tier(backend, TierConfig::as_l1())"
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Internal helper for multi-tier mode (v0.5.0+)
     fn build_multi_tier(self) -> CacheResult<CacheSystem> {
         info!(
@@ -536,14 +1014,13 @@ impl CacheSystemBuilder {
                     config.promotion_frequency,
                     config.ttl_scale,
                 )
+                .with_ttl_bounds(config.min_ttl, config.max_ttl)
             })
             .collect();
 
         // Create cache manager with multi-tier support
-        let cache_manager = Arc::new(CacheManager::new_with_tiers(
-            cache_tiers,
-            self.streaming_backend,
-        )?);
+        let manager = CacheManager::new_with_tiers(cache_tiers, self.streaming_backend)?;
+        let cache_manager = Arc::new(self.manager_options.apply(manager));
 
         info!("Multi-Tier Cache System built successfully");
         info!("Note: Using multi-tier mode - use cache_manager() for all operations");
@@ -556,6 +1033,7 @@ impl CacheSystemBuilder {
             #[cfg(feature = "redis")]
             #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
             l2_cache: None,
+            l1_concrete: None,
         })
     }
 
@@ -570,13 +1048,14 @@ impl CacheSystemBuilder {
             let l2_cache: Arc<crate::L2Cache> = Arc::new(crate::L2Cache::new().await?);
 
             // Use legacy constructor that handles conversion to trait objects
-            let cache_manager =
-                Arc::new(CacheManager::new(l1_cache.clone(), l2_cache.clone()).await?);
+            let manager = CacheManager::new(l1_cache.clone(), l2_cache.clone()).await?;
+            let cache_manager = Arc::new(self.manager_options.apply(manager));
 
             info!("Multi-Tier Cache System built successfully");
 
             Ok(CacheSystem {
                 cache_manager,
+                l1_concrete: Some(Arc::clone(&l1_cache) as _),
                 #[cfg(feature = "moka")]
                 #[cfg_attr(docsrs, doc(cfg(feature = "moka")))]
                 l1_cache: Some(l1_cache),
@@ -597,21 +1076,26 @@ impl CacheSystemBuilder {
     async fn build_custom_2_tier(self) -> CacheResult<CacheSystem> {
         info!("Building with custom backends");
 
-        let l1_backend: Arc<dyn CacheBackend> = if let Some(backend) = self.l1_backend {
-            backend
-        } else {
-            #[cfg(feature = "moka")]
-            #[cfg_attr(docsrs, doc(cfg(feature = "moka")))]
-            {
-                Arc::new(L1Cache::new(self.moka_config.unwrap_or_default())?)
-            }
-            #[cfg(not(feature = "moka"))]
-            {
-                return Err(CacheError::ConfigError(
-                    "Moka feature not enabled. Provide a custom L1 backend.".to_string(),
-                ));
-            }
-        };
+        let (l1_backend, l1_concrete): (Arc<dyn CacheBackend>, _) =
+            if let Some(backend) = self.l1_backend {
+                (backend, self.l1_concrete)
+            } else {
+                #[cfg(feature = "moka")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "moka")))]
+                {
+                    let moka = Arc::new(L1Cache::new(self.moka_config.unwrap_or_default())?);
+                    (
+                        Arc::clone(&moka) as _,
+                        Some(moka as Arc<dyn Any + Send + Sync>),
+                    )
+                }
+                #[cfg(not(feature = "moka"))]
+                {
+                    return Err(CacheError::ConfigError(
+                        "Moka feature not enabled. Provide a custom L1 backend.".to_string(),
+                    ));
+                }
+            };
 
         let l2_backend: Arc<dyn L2CacheBackend> = if let Some(backend) = self.l2_backend {
             backend
@@ -632,11 +1116,8 @@ impl CacheSystemBuilder {
         let streaming_backend = self.streaming_backend;
 
         // Create cache manager with trait objects
-        let cache_manager = Arc::new(CacheManager::new_with_backends(
-            l1_backend,
-            l2_backend,
-            streaming_backend,
-        )?);
+        let manager = CacheManager::new_with_backends(l1_backend, l2_backend, streaming_backend)?;
+        let cache_manager = Arc::new(self.manager_options.apply(manager));
 
         info!("Multi-Tier Cache System built with custom backends");
         info!("Note: Using custom backends - use cache_manager() for all operations");
@@ -649,6 +1130,7 @@ impl CacheSystemBuilder {
             #[cfg(feature = "redis")]
             #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
             l2_cache: None,
+            l1_concrete,
         })
     }
 }
@@ -2,17 +2,21 @@
 //!
 //! Manages operations across L1 (Moka) and L2 (Redis) caches with intelligent fallback.
 
-use crate::error::CacheResult;
+use crate::error::{CacheError, CacheResult};
+use crate::key::CacheKey;
 use dashmap::DashMap;
 use rand::Rng;
+use std::collections::HashMap;
 use std::future::Future;
+use std::io::{Read, Write};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 #[cfg(feature = "redis")]
 #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
 use tokio::sync::Mutex;
 use tokio::sync::watch;
+use tokio::sync::{Semaphore, SemaphorePermit};
 use tracing::{debug, error, info, warn};
 
 #[cfg(feature = "moka")]
@@ -21,21 +25,37 @@ use crate::L1Cache;
 #[cfg(feature = "redis")]
 #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
 use crate::L2Cache;
+use crate::counter::{BucketCounters, Counter, KeyBuckets, StatEvent};
 #[cfg(feature = "redis")]
 #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
 use crate::invalidation::{
-    AtomicInvalidationStats, InvalidationConfig, InvalidationMessage, InvalidationPublisher,
-    InvalidationSubscriber,
+    AtomicInvalidationStats, InvalidationBatcher, InvalidationConfig, InvalidationMessage,
+    InvalidationPublisher, InvalidationSubscriber, KeyspaceSubscriber,
 };
-use crate::serialization::{CacheSerializer, JsonSerializer};
-use crate::traits::{CacheBackend, L2CacheBackend, StreamingBackend};
+use crate::promotion::{
+    DEFAULT_PROMOTION_QUEUE_CAPACITY, InFlightPromotions, PendingPromotion, PromotionClaim,
+    PromotionCounters, PromotionQueue, promote,
+};
+use crate::serialization::{CacheSerializer, CompressionMode, JsonSerializer, ValueCompression};
+use crate::spawner::{Spawner, TokioSpawner};
+use crate::traits::{
+    CacheBackend, KeyPage, KeyPredicate, L2CacheBackend, StreamingBackend, ValueWithTtl,
+};
+use crate::transaction::Transaction;
 use bytes::Bytes;
 use futures_util::future::BoxFuture;
+use futures_util::{Stream, StreamExt, stream};
 
 ///// Type alias for the in-flight requests map
 /// Stores a watch sender for each active key computation
 type InFlightMap = DashMap<String, Arc<watch::Sender<Option<CacheResult<Option<Bytes>>>>>>;
 
+/// Maps a key to its canonical spelling (see `CacheManager::with_key_canonicalizer`)
+type KeyCanonicalizer = dyn Fn(&str) -> String + Send + Sync;
+
+/// Decides whether a serialized value must not be cached (see `CacheManager::with_value_rejector`)
+type ValueRejector = dyn Fn(&[u8]) -> bool + Send + Sync;
+
 /// RAII Guard to ensure that keys are removed from `in_flight_requests` on cancellation/drop.
 struct RemoveInFlightGuard {
     map: Arc<InFlightMap>,
@@ -48,12 +68,32 @@ impl Drop for RemoveInFlightGuard {
     }
 }
 
+/// RAII Guard clearing `enforcing_memory_limit` when enforcement ends or is cancelled
+struct MemoryLimitGuard<'a>(&'a AtomicBool);
+
+impl Drop for MemoryLimitGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
 /// Represents the concurrency state for in-flight requests stampede protection.
 enum FlightState {
     Creator(Arc<watch::Sender<Option<CacheResult<Option<Bytes>>>>>),
     Waiter(watch::Receiver<Option<CacheResult<Option<Bytes>>>>),
 }
 
+/// A hit found by `CacheManager::read_tiers`
+struct TierRead {
+    value: Bytes,
+    /// Index in `tiers` of the tier that hit
+    tier_index: usize,
+    /// Remaining TTL in that tier (`None` if unknown)
+    ttl: Option<Duration>,
+    /// Whether the value was promoted (or queued for promotion) to the upper tiers
+    promoted: bool,
+}
+
 /// Cache strategies for different data types
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -84,6 +124,464 @@ impl CacheStrategy {
             Self::Custom(duration) => *duration,
         }
     }
+
+    /// Custom TTL of `secs` seconds
+    #[must_use]
+    pub const fn seconds(secs: u64) -> Self {
+        Self::Custom(Duration::from_secs(secs))
+    }
+}
+
+impl From<Duration> for CacheStrategy {
+    fn from(duration: Duration) -> Self {
+        Self::Custom(duration)
+    }
+}
+
+/// Bare integers are interpreted as a TTL in seconds
+impl From<u64> for CacheStrategy {
+    fn from(secs: u64) -> Self {
+        Self::seconds(secs)
+    }
+}
+
+/// TTLs a `CacheManager` uses for the named strategies
+///
+/// Defaults to the durations documented on `CacheStrategy`. `default` is also
+/// used wherever no TTL is known (e.g. promoting a value without one).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrategyDurations {
+    /// `CacheStrategy::RealTime`
+    pub real_time: Duration,
+    /// `CacheStrategy::ShortTerm`
+    pub short_term: Duration,
+    /// `CacheStrategy::MediumTerm`
+    pub medium_term: Duration,
+    /// `CacheStrategy::LongTerm`
+    pub long_term: Duration,
+    /// `CacheStrategy::Default`
+    pub default: Duration,
+}
+
+impl Default for StrategyDurations {
+    fn default() -> Self {
+        Self {
+            real_time: CacheStrategy::RealTime.to_duration(),
+            short_term: CacheStrategy::ShortTerm.to_duration(),
+            medium_term: CacheStrategy::MediumTerm.to_duration(),
+            long_term: CacheStrategy::LongTerm.to_duration(),
+            default: CacheStrategy::Default.to_duration(),
+        }
+    }
+}
+
+impl StrategyDurations {
+    /// TTL for `strategy` (`Custom` durations are returned unchanged)
+    #[must_use]
+    pub fn duration(&self, strategy: &CacheStrategy) -> Duration {
+        match strategy {
+            CacheStrategy::RealTime => self.real_time,
+            CacheStrategy::ShortTerm => self.short_term,
+            CacheStrategy::MediumTerm => self.medium_term,
+            CacheStrategy::LongTerm => self.long_term,
+            CacheStrategy::Custom(duration) => *duration,
+            CacheStrategy::Default => self.default,
+        }
+    }
+}
+
+/// Settings of the adaptive TTL mode (see `CacheManager::with_adaptive_ttl`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdaptiveTtl {
+    /// Estimated recent L1 hits for a key to count as hot
+    pub min_hits: u32,
+    /// Longest TTL an extension may give a key
+    pub max_ttl: Duration,
+    /// Counters of the frequency sketch (size it around the number of hot keys)
+    pub sketch_width: usize,
+}
+
+impl Default for AdaptiveTtl {
+    fn default() -> Self {
+        Self {
+            min_hits: 8,
+            max_ttl: Duration::from_hours(1),
+            sketch_width: 4096,
+        }
+    }
+}
+
+/// Adaptive TTL settings with the hit frequencies they act on
+struct AdaptiveTtlState {
+    config: AdaptiveTtl,
+    /// Decaying L1 hit counts per key
+    sketch: crate::backends::admission::FrequencySketch,
+}
+
+/// Longest a key is kept alive by sliding expiration, in windows (see
+/// `CacheManager::with_sliding_expiration`)
+const DEFAULT_SLIDING_LIFETIME_WINDOWS: u32 = 24;
+
+/// A sampling probability in 0.0..=1.0; non-finite rates disable sampling
+fn sample_rate(rate: f64) -> f64 {
+    if rate.is_finite() {
+        rate.clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Keys requested per L2 page by `CacheManager::scan`
+const SCAN_PAGE_SIZE: usize = 100;
+
+/// Sliding reads between sweeps of the sliding expiration bookkeeping
+const SLIDING_SWEEP_INTERVAL: u64 = 1024;
+
+/// Sliding expiration settings with the read times they act on
+struct SlidingExpiration {
+    /// TTL a read resets a key to
+    window: Duration,
+    /// Longest a key is kept alive by reads
+    max_lifetime: Duration,
+    /// First and last sliding read per key
+    reads: DashMap<String, (Instant, Instant)>,
+    /// Sliding reads so far (drives the sweeps)
+    touches: AtomicU64,
+}
+
+impl SlidingExpiration {
+    /// TTL to reset `key` to on a read, or `None` once it reached `max_lifetime`
+    fn next_ttl(&self, key: &str) -> Option<Duration> {
+        let now = Instant::now();
+        if self
+            .touches
+            .fetch_add(1, Ordering::Relaxed)
+            .is_multiple_of(SLIDING_SWEEP_INTERVAL)
+        {
+            // Keys not read for a whole window have expired since
+            self.reads
+                .retain(|_, (_, last)| now.duration_since(*last) <= self.window);
+        }
+
+        let mut reads = self.reads.entry(key.to_string()).or_insert((now, now));
+        let (first, last) = &mut *reads;
+        if now.duration_since(*last) > self.window {
+            // Expired and written again in between: a new lifetime
+            *first = now;
+        }
+        *last = now;
+        let remaining = self.max_lifetime.saturating_sub(now.duration_since(*first));
+        drop(reads);
+
+        if remaining.is_zero() {
+            self.reads.remove(key);
+            return None;
+        }
+        Some(self.window.min(remaining))
+    }
+}
+
+/// Where a value returned by `CacheManager::get_or_compute_with_source` came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheSource {
+    /// Found in the first tier
+    L1,
+    /// Found in a lower tier, identified by its tier level (2 = L2, 3 = L3, ...)
+    LowerTier(usize),
+    /// Computed by `compute_fn`, or by a concurrent caller computing the same key
+    Computed,
+}
+
+/// Set of tiers, by tier level, that a write goes to
+///
+/// See `CacheManager::get_or_compute_in_tiers`. Levels above 64 are only
+/// covered by `TierMask::ALL`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TierMask(u64);
+
+impl TierMask {
+    /// Every tier
+    pub const ALL: Self = Self(u64::MAX);
+    /// No tier
+    pub const NONE: Self = Self(0);
+    /// Only the first tier
+    pub const L1_ONLY: Self = Self::level(1);
+
+    /// Only tier `level` (1 = L1, 2 = L2, ...)
+    #[must_use]
+    pub const fn level(level: usize) -> Self {
+        Self::NONE.with_level(level)
+    }
+
+    /// This set plus tier `level`
+    #[must_use]
+    pub const fn with_level(self, level: usize) -> Self {
+        match Self::bit(level) {
+            Some(bit) => Self(self.0 | bit),
+            None => self,
+        }
+    }
+
+    /// Whether tier `level` is in the set
+    #[must_use]
+    pub const fn contains(self, level: usize) -> bool {
+        match Self::bit(level) {
+            Some(bit) => self.0 & bit != 0,
+            None => self.0 == Self::ALL.0,
+        }
+    }
+
+    const fn bit(level: usize) -> Option<u64> {
+        if level == 0 || level > 64 {
+            return None;
+        }
+        Some(1 << (level - 1))
+    }
+}
+
+/// A cached value with where it was found and how long it stays cached
+/// (see `CacheManager::get_batch_with_metadata`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheHit<T> {
+    /// The decoded value
+    pub value: T,
+    /// Remaining TTL in the tier it was found in (`None` if unknown)
+    pub ttl: Option<Duration>,
+    /// Tier the value was found in (never `Computed`)
+    pub source: CacheSource,
+}
+
+/// How a `CacheManager::get_tracked` read was served
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetOutcome {
+    /// Tier the value was found in (`None` = miss, never `Computed`)
+    pub served_by: Option<CacheSource>,
+    /// Whether the hit was promoted to the upper tiers (queued, with async promotion)
+    pub promoted: bool,
+    /// Remaining TTL in the tier the value was found in (`None` if a miss or unknown)
+    pub remaining_ttl: Option<Duration>,
+}
+
+/// A cached value whose deserialization is deferred (see `CacheManager::get_lazy`)
+///
+/// Holds the raw bytes read from the cache; nothing is decoded until
+/// `deserialize` is called.
+#[derive(Clone)]
+pub struct LazyValue<'a> {
+    manager: &'a CacheManager,
+    key: String,
+    bytes: Bytes,
+}
+
+impl std::fmt::Debug for LazyValue<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LazyValue")
+            .field("key", &self.key)
+            .field("len", &self.bytes.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl LazyValue<'_> {
+    /// Decode the value with the manager's serializer and codec settings
+    ///
+    /// Each call decodes the bytes again.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SerializationError` if the bytes can't be decoded into `T`
+    /// or were written with another codec.
+    pub fn deserialize<T: serde::de::DeserializeOwned>(&self) -> CacheResult<T> {
+        self.manager.decode_typed_result(&self.key, &self.bytes)
+    }
+
+    /// The key the value was read from
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// The raw cached bytes
+    #[must_use]
+    pub fn bytes(&self) -> &Bytes {
+        &self.bytes
+    }
+
+    /// Take the raw cached bytes
+    #[must_use]
+    pub fn into_bytes(self) -> Bytes {
+        self.bytes
+    }
+}
+
+/// Per-write options for `CacheManager::set_with_strategy_opts`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SetOpts {
+    /// Whether this value is compressed (see `CacheManager::with_value_compression`)
+    pub compression: CompressionMode,
+}
+
+impl SetOpts {
+    /// Override compression for this write
+    #[must_use]
+    pub fn with_compression(mut self, compression: CompressionMode) -> Self {
+        self.compression = compression;
+        self
+    }
+}
+
+/// Whether one tier holds a key (see `CacheManager::explain`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TierPresence {
+    /// Tier level (1 = L1, 2 = L2, ...)
+    pub tier_level: usize,
+    /// Whether the tier holds a live value for the key
+    pub present: bool,
+    /// Remaining TTL in this tier (`None` if absent or unknown)
+    pub ttl: Option<Duration>,
+}
+
+/// How a `get` would resolve a key (see `CacheManager::explain`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheExplain {
+    /// Key as stored in the tiers, after canonicalization and prefixing
+    pub storage_key: String,
+    /// Every tier, from L1 down
+    pub tiers: Vec<TierPresence>,
+    /// Tier a `get` would serve the value from (`None` = miss)
+    pub served_by: Option<CacheSource>,
+    /// Tier levels a `get` would promote the value into, nearest first
+    pub promotes_to: Vec<usize>,
+    /// Promotion happens on 1 in this many hits (1 = every hit)
+    pub promotion_frequency: usize,
+}
+
+/// Which tier decides whether a key exists when L1 and L2 disagree
+///
+/// An L1 copy can outlive the L2 value it came from (e.g. L2 evicted or expired
+/// it earlier, or another instance deleted it without invalidation).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum L2MissPolicy {
+    /// Serve L1 hits without consulting L2
+    #[default]
+    TrustL1,
+    /// Confirm L1 hits in L2 and flush the L1 copy if L2 no longer has it
+    ///
+    /// Every checked hit costs an L2 round trip, so by default L1 hits are
+    /// only as fast as L2 reads. Use `CacheManager::with_l2_miss_sampling` to
+    /// check a fraction of hits instead, at the cost of serving some flushed
+    /// keys from L1 until a later hit is checked.
+    FlushL1,
+}
+
+/// What `set_with_strategy` does with the L1 copy when a lower tier write fails
+///
+/// Other instances read from the shared lower tiers, so an L1 copy that only
+/// this instance holds is an inconsistency between instances.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum L2WriteFailurePolicy {
+    /// Keep the L1 copy with its full TTL
+    #[default]
+    KeepL1,
+    /// Remove the L1 copy again (the write fails if no other tier stored it)
+    EvictL1,
+    /// Rewrite the L1 copy with this TTL (capped at the strategy TTL) so it expires soon
+    ShortenL1Ttl(Duration),
+}
+
+/// What deadline-aware operations (`get_until`, `get_or_compute_until`) return
+/// once the caller's deadline has passed
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DeadlinePolicy {
+    /// Fail with `CacheError::Timeout`
+    #[default]
+    Error,
+    /// Report a miss (`Ok(None)`), letting the caller degrade gracefully
+    Miss,
+}
+
+/// Handling of cached values that the serializer can't decode into the
+/// requested type (e.g. written by another service in another format)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum UndecodablePolicy {
+    /// Fail the typed read with a `SerializationError`
+    #[default]
+    Error,
+    /// Log a warning and treat the value as a miss
+    Miss,
+    /// Like `Miss`, and also remove the key from every tier so it's recomputed
+    Evict,
+}
+
+/// Result of `CacheManager::get_if_newer`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConditionalResult<T> {
+    /// The cached version is newer than the client's: value and its version
+    Modified(T, u64),
+    /// The client's version is current
+    NotModified,
+    /// Nothing is cached under the key
+    Missing,
+}
+
+/// Check run on a cached value before a typed get-or-compute uses it
+type ValueCheck = fn(&CacheManager, &str, &[u8]) -> bool;
+
+/// Stored form of a value written by `set_versioned`
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Versioned<T> {
+    version: u64,
+    value: T,
+}
+
+/// Maximum number of buckets tracked by `CacheManager::with_stats_by_prefix`
+pub const MAX_STATS_BUCKETS: usize = 256;
+
+/// Bucket that collects statistics for keys beyond `MAX_STATS_BUCKETS` buckets
+pub const OVERFLOW_STATS_BUCKET: &str = "__overflow__";
+
+/// Longest TTL a tier stores a value for (10 years)
+///
+/// `ttl_scale` can push long TTLs past what backends accept (Redis rejects
+/// expiries that overflow its millisecond clock), so scaled TTLs are clamped.
+pub const MAX_TIER_TTL: Duration = Duration::from_hours(10 * 365 * 24);
+
+/// Scale `ttl` by a tier's `ttl_scale`, clamped to `MAX_TIER_TTL`
+///
+/// A NaN or negative scale leaves the TTL unscaled; an infinite one yields
+/// the maximum.
+fn scale_ttl(ttl: Duration, scale: f64) -> Duration {
+    if scale.is_nan() || scale < 0.0 {
+        return ttl.min(MAX_TIER_TTL);
+    }
+    Duration::try_from_secs_f64(ttl.as_secs_f64() * scale)
+        .map_or(MAX_TIER_TTL, |scaled| scaled.min(MAX_TIER_TTL))
+}
+
+/// Distribution of TTLs used for promotions
+///
+/// Promotions reuse the remaining TTL of the tier the value was found in, so a
+/// large share of short buckets indicates churny promotions. Each promotion
+/// into an upper tier is counted once, so `total()` matches
+/// `CacheManagerStats::promotions`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PromotionTtlHistogram {
+    /// Promotions with a TTL under 1 second
+    pub under_1s: u64,
+    /// Promotions with a TTL from 1 second up to (excluding) 10 seconds
+    pub from_1s_to_10s: u64,
+    /// Promotions with a TTL from 10 seconds up to (excluding) 60 seconds
+    pub from_10s_to_60s: u64,
+    /// Promotions with a TTL of 60 seconds or more
+    pub over_60s: u64,
+}
+
+impl PromotionTtlHistogram {
+    /// Total number of promotions recorded
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.under_1s + self.from_1s_to_10s + self.from_10s_to_60s + self.over_60s
+    }
 }
 
 /// Statistics for a single cache tier
@@ -95,6 +593,10 @@ pub struct TierStats {
     pub hits: AtomicU64,
     /// Backend name for identification
     pub backend_name: String,
+    /// Value bytes read from this tier (filled in by `get_tier_stats`)
+    bytes_read: u64,
+    /// Value bytes written to this tier (filled in by `get_tier_stats`)
+    bytes_written: u64,
 }
 
 impl Clone for TierStats {
@@ -103,6 +605,8 @@ impl Clone for TierStats {
             tier_level: self.tier_level,
             hits: AtomicU64::new(self.hits.load(Ordering::Relaxed)),
             backend_name: self.backend_name.clone(),
+            bytes_read: self.bytes_read,
+            bytes_written: self.bytes_written,
         }
     }
 }
@@ -113,6 +617,8 @@ impl TierStats {
             tier_level,
             hits: AtomicU64::new(0),
             backend_name,
+            bytes_read: 0,
+            bytes_written: 0,
         }
     }
 
@@ -120,6 +626,49 @@ impl TierStats {
     pub fn hit_count(&self) -> u64 {
         self.hits.load(Ordering::Relaxed)
     }
+
+    /// Total size of values read from this tier, in bytes
+    #[must_use]
+    pub fn bytes_read(&self) -> u64 {
+        self.bytes_read
+    }
+
+    /// Total size of values written to this tier, in bytes
+    #[must_use]
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes_written
+    }
+}
+
+/// Counters for `compute_fn` calls made on a cache miss
+#[derive(Debug, Default)]
+struct ComputeCounters {
+    /// `compute_fn` calls
+    computes: AtomicU64,
+    /// Total time spent in `compute_fn`, in nanoseconds
+    compute_nanos: AtomicU64,
+    /// Callers that received a value computed by a concurrent caller
+    coalesced: AtomicU64,
+    /// Lookups that took an entry in the in-flight request map
+    in_flight_lookups: AtomicU64,
+}
+
+/// Counters for stream operations made through the manager
+#[derive(Debug, Default)]
+struct StreamCounters {
+    /// Entries published
+    published: AtomicU64,
+    /// Entries delivered to consumer group reads
+    delivered: AtomicU64,
+    /// Entry IDs acknowledged
+    acked: AtomicU64,
+}
+
+/// Byte counters of a tier, shared by all clones of the tier
+#[derive(Debug, Default)]
+struct TierIoCounters {
+    bytes_read: AtomicU64,
+    bytes_written: AtomicU64,
 }
 
 /// A single cache tier in the multi-tier architecture
@@ -135,8 +684,38 @@ pub struct CacheTier {
     pub promotion_frequency: usize,
     /// TTL multiplier for this tier (e.g., L2 might store for 2x L1 TTL)
     pub ttl_scale: f64,
+    /// Shortest TTL this tier stores a value for (applied after scaling)
+    pub min_ttl: Option<Duration>,
+    /// Longest TTL this tier stores a value for (applied after scaling)
+    pub max_ttl: Option<Duration>,
     /// Statistics for this tier
     pub stats: TierStats,
+    /// Optional bound on in-flight operations (shared across limited tiers)
+    concurrency_limit: Option<ConcurrencyLimit>,
+    /// Bytes read from and written to this tier
+    io: Arc<TierIoCounters>,
+}
+
+/// Semaphore bounding the number of in-flight backend operations
+#[derive(Debug, Clone)]
+struct ConcurrencyLimit {
+    semaphore: Arc<Semaphore>,
+    max_in_flight: usize,
+}
+
+impl ConcurrencyLimit {
+    fn new(max_in_flight: usize) -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(max_in_flight)),
+            max_in_flight,
+        }
+    }
+
+    /// Number of permits currently held
+    fn in_use(&self) -> usize {
+        self.max_in_flight
+            .saturating_sub(self.semaphore.available_permits())
+    }
 }
 
 impl CacheTier {
@@ -155,30 +734,133 @@ impl CacheTier {
             promotion_enabled,
             promotion_frequency,
             ttl_scale,
+            min_ttl: None,
+            max_ttl: None,
             stats: TierStats::new(tier_level, backend_name),
+            concurrency_limit: None,
+            io: Arc::new(TierIoCounters::default()),
+        }
+    }
+
+    /// Clamp this tier's scaled TTLs into `min..=max`
+    ///
+    /// See `TierConfig::with_ttl_bounds()`.
+    #[must_use]
+    pub fn with_ttl_bounds(mut self, min: Option<Duration>, max: Option<Duration>) -> Self {
+        self.min_ttl = min;
+        self.max_ttl = max;
+        self
+    }
+
+    /// TTL this tier stores a value for: `ttl` scaled, then clamped to the bounds
+    fn tier_ttl(&self, ttl: Duration) -> Duration {
+        let ttl = scale_ttl(ttl, self.ttl_scale);
+        let ttl = self.max_ttl.map_or(ttl, |max| ttl.min(max));
+        let ttl = self.min_ttl.map_or(ttl, |min| ttl.max(min));
+        ttl.min(MAX_TIER_TTL)
+    }
+
+    /// Wait for a permit if this tier's concurrency is limited
+    async fn acquire_permit(&self) -> Option<SemaphorePermit<'_>> {
+        match &self.concurrency_limit {
+            // The semaphore is never closed, so acquire only fails if it were
+            Some(limit) => limit.semaphore.acquire().await.ok(),
+            None => None,
         }
     }
 
     /// Get value with TTL from this tier
     async fn get_with_ttl(&self, key: &str) -> Option<(Bytes, Option<Duration>)> {
-        self.backend.get_with_ttl(key).await
+        let _permit = self.acquire_permit().await;
+        let result = self.backend.get_with_ttl(key).await;
+        if let Some((value, _)) = &result {
+            self.record_read(value);
+        }
+        result
+    }
+
+    /// Get several values with TTLs from this tier in one batch
+    async fn get_many_with_ttl(&self, keys: &[String]) -> Vec<Option<ValueWithTtl>> {
+        let _permit = self.acquire_permit().await;
+        let results = self.backend.get_many_with_ttl(keys).await;
+        for (value, _) in results.iter().flatten() {
+            self.record_read(value);
+        }
+        results
     }
 
     /// Set value with TTL in this tier
-    async fn set_with_ttl(&self, key: &str, value: Bytes, ttl: Duration) -> CacheResult<()> {
-        let scaled_ttl = Duration::from_secs_f64(ttl.as_secs_f64() * self.ttl_scale);
-        self.backend.set_with_ttl(key, value, scaled_ttl).await
+    pub(crate) async fn set_with_ttl(
+        &self,
+        key: &str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> CacheResult<()> {
+        let scaled_ttl = self.tier_ttl(ttl);
+        let _permit = self.acquire_permit().await;
+        let len = value.len() as u64;
+        self.backend.set_with_ttl(key, value, scaled_ttl).await?;
+        self.io.bytes_written.fetch_add(len, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Reset the TTL of a key in this tier
+    async fn touch(&self, key: &str, ttl: Duration) -> CacheResult<bool> {
+        let scaled_ttl = self.tier_ttl(ttl);
+        let _permit = self.acquire_permit().await;
+        self.backend.touch(key, scaled_ttl).await
+    }
+
+    /// Replace value in this tier only if the key exists
+    async fn set_if_present(&self, key: &str, value: Bytes, ttl: Duration) -> CacheResult<bool> {
+        let scaled_ttl = self.tier_ttl(ttl);
+        let _permit = self.acquire_permit().await;
+        let len = value.len() as u64;
+        let replaced = self.backend.set_if_present(key, value, scaled_ttl).await?;
+        if replaced {
+            self.io.bytes_written.fetch_add(len, Ordering::Relaxed);
+        }
+        Ok(replaced)
     }
 
     /// Remove value from this tier
-    async fn remove(&self, key: &str) -> CacheResult<()> {
+    pub(crate) async fn remove(&self, key: &str) -> CacheResult<()> {
+        let _permit = self.acquire_permit().await;
         self.backend.remove(key).await
     }
 
+    /// Read and remove a value from this tier in one step
+    pub(crate) async fn get_and_remove(&self, key: &str) -> CacheResult<Option<Bytes>> {
+        let _permit = self.acquire_permit().await;
+        self.backend.get_and_remove(key).await
+    }
+
+    /// Remove several values from this tier in one backend call
+    pub(crate) async fn batch_remove(&self, keys: &[String]) -> CacheResult<()> {
+        let _permit = self.acquire_permit().await;
+        self.backend.batch_remove(keys).await
+    }
+
     /// Record a cache hit for this tier
     fn record_hit(&self) {
         self.stats.hits.fetch_add(1, Ordering::Relaxed);
     }
+
+    /// Count a value read from this tier
+    fn record_read(&self, value: &Bytes) {
+        self.io
+            .bytes_read
+            .fetch_add(value.len() as u64, Ordering::Relaxed);
+    }
+
+    /// Tier statistics including the current byte counters
+    fn stats_snapshot(&self) -> TierStats {
+        TierStats {
+            bytes_read: self.io.bytes_read.load(Ordering::Relaxed),
+            bytes_written: self.io.bytes_written.load(Ordering::Relaxed),
+            ..self.stats.clone()
+        }
+    }
 }
 
 /// Configuration for a cache tier (used in builder pattern)
@@ -192,6 +874,10 @@ pub struct TierConfig {
     pub promotion_frequency: usize,
     /// TTL scale factor (1.0 = same as base TTL)
     pub ttl_scale: f64,
+    /// Shortest TTL stored in this tier, after scaling (`None` = unbounded)
+    pub min_ttl: Option<Duration>,
+    /// Longest TTL stored in this tier, after scaling (`None` = unbounded)
+    pub max_ttl: Option<Duration>,
 }
 
 impl TierConfig {
@@ -203,6 +889,8 @@ impl TierConfig {
             promotion_enabled: true,
             promotion_frequency: 10,
             ttl_scale: 1.0,
+            min_ttl: None,
+            max_ttl: None,
         }
     }
 
@@ -214,6 +902,8 @@ impl TierConfig {
             promotion_enabled: false, // L1 is already top tier
             promotion_frequency: 1,   // Doesn't matter but use 1
             ttl_scale: 1.0,
+            min_ttl: None,
+            max_ttl: None,
         }
     }
 
@@ -225,6 +915,8 @@ impl TierConfig {
             promotion_enabled: true,
             promotion_frequency: 10,
             ttl_scale: 1.0,
+            min_ttl: None,
+            max_ttl: None,
         }
     }
 
@@ -236,6 +928,8 @@ impl TierConfig {
             promotion_enabled: true,
             promotion_frequency: 10,
             ttl_scale: 2.0, // Keep data 2x longer
+            min_ttl: None,
+            max_ttl: None,
         }
     }
 
@@ -247,6 +941,8 @@ impl TierConfig {
             promotion_enabled: true,
             promotion_frequency: 10,
             ttl_scale: 8.0, // Keep data 8x longer
+            min_ttl: None,
+            max_ttl: None,
         }
     }
 
@@ -271,6 +967,18 @@ impl TierConfig {
         self
     }
 
+    /// Clamp the tier's TTLs into `min..=max` after `ttl_scale` is applied
+    ///
+    /// Hard bounds per tier regardless of strategy, e.g. "L1 entries never
+    /// live longer than 60s" or "L3 entries live at least 1h". If `min`
+    /// exceeds `max`, `min` wins.
+    #[must_use]
+    pub fn with_ttl_bounds(mut self, min: Option<Duration>, max: Option<Duration>) -> Self {
+        self.min_ttl = min;
+        self.max_ttl = max;
+        self
+    }
+
     /// Set tier level
     #[must_use]
     pub fn with_level(mut self, level: usize) -> Self {
@@ -286,14 +994,25 @@ pub struct CacheManager {
     /// Optional streaming backend
     streaming_backend: Option<Arc<dyn StreamingBackend>>,
     /// Statistics
-    total_requests: AtomicU64,
-    l1_hits: AtomicU64,
-    l2_hits: AtomicU64,
-    misses: AtomicU64,
+    total_requests: Counter,
+    l1_hits: Counter,
+    l2_hits: Counter,
+    misses: Counter,
+    sets: Counter,
+    /// Per-bucket statistics (`None` = disabled)
+    key_buckets: Option<KeyBuckets>,
     /// In-flight requests map (Broadcaster integration will replace this in Step 4)
     in_flight_requests: Arc<InFlightMap>,
+    /// Longest a coalesced request waits for the in-flight computation (`None` = no limit)
+    in_flight_wait_timeout: Option<Duration>,
     /// Pluggable serializer
     serializer: Arc<CacheSerializer>,
+    /// Serializers tried, in order, on cached values the primary can't read
+    fallback_codecs: Arc<[CacheSerializer]>,
+    /// Compression of typed values (`None` = stored as serialized)
+    value_compression: Option<ValueCompression>,
+    /// Tag typed values with the serializer's codec ID and reject mismatches
+    codec_guard: bool,
     /// Invalidation publisher
     #[cfg(feature = "redis")]
     #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
@@ -302,12 +1021,72 @@ pub struct CacheManager {
     #[cfg(feature = "redis")]
     #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
     invalidation_subscriber: Option<Arc<InvalidationSubscriber>>,
+    /// Keyspace notification subscriber evicting L1 entries
+    #[cfg(feature = "redis")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
+    keyspace_subscriber: Option<Arc<KeyspaceSubscriber>>,
     /// Invalidation statistics
     #[cfg(feature = "redis")]
     #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
     invalidation_stats: Arc<AtomicInvalidationStats>,
-    /// Number of promotions performed
-    promotions: AtomicUsize,
+    /// Coalesces `Remove` broadcasts (`None` = publish each immediately)
+    #[cfg(feature = "redis")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
+    invalidation_batcher: Option<Arc<InvalidationBatcher>>,
+    /// Promotion counters (shared with the async promotion worker)
+    promotion_counters: Arc<PromotionCounters>,
+    /// Background promotion queue (`None` = promote inline)
+    promotion_queue: Option<Arc<PromotionQueue>>,
+    /// Keys being promoted (`None` = promotions aren't deduplicated)
+    in_flight_promotions: Option<Arc<InFlightPromotions>>,
+    /// Spawner for background tasks
+    spawner: Arc<dyn Spawner>,
+    /// Maps equivalent key spellings to one canonical key
+    key_canonicalizer: Option<Arc<KeyCanonicalizer>>,
+    /// Also store values under the canonical key
+    canonical_storage: bool,
+    /// Handling of the L1 copy when a lower tier write fails
+    l2_write_failure_policy: L2WriteFailurePolicy,
+    /// Authoritative tier for key existence
+    l2_miss_policy: L2MissPolicy,
+    /// Fraction of L1 hits `FlushL1` confirms in L2
+    l2_miss_sample_rate: f64,
+    /// Values that are never written (`None` = cache everything)
+    value_rejector: Option<Arc<ValueRejector>>,
+    /// Writes skipped by the value rejector
+    rejected_nulls: AtomicU64,
+    /// Size above which stored values are logged (`None` = disabled)
+    large_value_threshold: Option<usize>,
+    /// Stored values above `large_value_threshold`
+    large_values: AtomicU64,
+    /// Fraction of L1 hits cross-checked against L2 (0 = off)
+    coherence_sample_rate: f64,
+    /// Sampled L1 hits that L2 didn't confirm
+    stale_l1_hits: AtomicU64,
+    /// Extends the TTL of frequently hit keys (`None` = off)
+    adaptive_ttl: Option<AdaptiveTtlState>,
+    /// TTL extensions made by the adaptive TTL mode
+    ttl_extensions: AtomicU64,
+    /// Resets the TTL of keys on every read (`None` = off)
+    sliding_expiration: Option<SlidingExpiration>,
+    /// Cap on the bytes held by all in-memory tiers together (`None` = off)
+    global_max_bytes: Option<u64>,
+    /// Set while a write enforces `global_max_bytes`
+    enforcing_memory_limit: AtomicBool,
+    /// Bytes evicted to stay under `global_max_bytes`
+    memory_evicted_bytes: AtomicU64,
+    /// Compute timings and coalesced waiters
+    compute_counters: ComputeCounters,
+    /// Stream operation counters (see `get_stream_stats`)
+    stream_counters: StreamCounters,
+    /// Last version handed out by `set_versioned`
+    last_version: AtomicU64,
+    /// Result of deadline-aware operations past their deadline
+    deadline_policy: DeadlinePolicy,
+    /// Handling of cached values that fail to deserialize
+    undecodable_policy: UndecodablePolicy,
+    /// TTLs of the named strategies
+    strategy_durations: StrategyDurations,
 }
 
 impl CacheManager {
@@ -351,23 +1130,7 @@ impl CacheManager {
             CacheTier::new(l2_cache, 2, true, 10, 1.0),
         ];
 
-        Ok(Self {
-            tiers,
-            streaming_backend,
-            total_requests: AtomicU64::new(0),
-            l1_hits: AtomicU64::new(0),
-            l2_hits: AtomicU64::new(0),
-            misses: AtomicU64::new(0),
-            promotions: AtomicUsize::new(0),
-            in_flight_requests: Arc::new(DashMap::new()),
-            serializer: Arc::new(CacheSerializer::Json(JsonSerializer)),
-            #[cfg(feature = "redis")]
-            invalidation_publisher: None,
-            #[cfg(feature = "redis")]
-            invalidation_subscriber: None,
-            #[cfg(feature = "redis")]
-            invalidation_stats: Arc::new(AtomicInvalidationStats::default()),
-        })
+        Ok(Self::from_tiers(tiers, streaming_backend))
     }
 
     /// Create new cache manager with default backends (backward compatible)
@@ -400,23 +1163,7 @@ impl CacheManager {
             CacheTier::new(l2_cache as Arc<dyn L2CacheBackend>, 2, true, 10, 1.0),
         ];
 
-        Ok(Self {
-            tiers,
-            streaming_backend,
-            total_requests: AtomicU64::new(0),
-            l1_hits: AtomicU64::new(0),
-            l2_hits: AtomicU64::new(0),
-            misses: AtomicU64::new(0),
-            promotions: AtomicUsize::new(0),
-            in_flight_requests: Arc::new(DashMap::new()),
-            serializer: Arc::new(CacheSerializer::Json(JsonSerializer)),
-            #[cfg(feature = "redis")]
-            invalidation_publisher: None,
-            #[cfg(feature = "redis")]
-            invalidation_subscriber: None,
-            #[cfg(feature = "redis")]
-            invalidation_stats: Arc::new(AtomicInvalidationStats::default()),
-        })
+        Ok(Self::from_tiers(tiers, streaming_backend))
     }
 
     /// Create new cache manager with invalidation support
@@ -473,40 +1220,33 @@ impl CacheManager {
         };
 
         // Create publisher
-        let (invalidation_publisher, invalidation_subscriber) = {
+        let (invalidation_publisher, invalidation_subscriber, invalidation_stats) = {
             let client = redis::Client::open(redis_url)?;
             let conn_manager = redis::aio::ConnectionManager::new(client).await?;
             let publisher = InvalidationPublisher::new(conn_manager, config.clone());
 
-            // Create subscriber
-            let subscriber = InvalidationSubscriber::new(redis_url, config.clone())?;
+            // Create subscriber (ignores messages published by this instance)
+            let subscriber = InvalidationSubscriber::new(redis_url, config.clone())?
+                .with_instance_id(publisher.instance_id());
+
+            // Share stats with the subscriber so received/ignored counts are visible
+            let stats = subscriber.stats_handle();
             (
                 Some(Arc::new(Mutex::new(publisher))),
                 Some(Arc::new(subscriber)),
+                stats,
             )
         };
 
-        let invalidation_stats = Arc::new(AtomicInvalidationStats::default());
-
         let tiers = vec![
             CacheTier::new(l1_cache as Arc<dyn L2CacheBackend>, 1, false, 1, 1.0),
             CacheTier::new(l2_cache as Arc<dyn L2CacheBackend>, 2, true, 10, 1.0),
         ];
 
-        let manager = Self {
-            tiers,
-            streaming_backend,
-            total_requests: AtomicU64::new(0),
-            l1_hits: AtomicU64::new(0),
-            l2_hits: AtomicU64::new(0),
-            misses: AtomicU64::new(0),
-            promotions: AtomicUsize::new(0),
-            in_flight_requests: Arc::new(DashMap::new()),
-            serializer: Arc::new(CacheSerializer::Json(JsonSerializer)),
-            invalidation_publisher,
-            invalidation_subscriber,
-            invalidation_stats,
-        };
+        let mut manager = Self::from_tiers(tiers, streaming_backend);
+        manager.invalidation_publisher = invalidation_publisher;
+        manager.invalidation_subscriber = invalidation_subscriber;
+        manager.invalidation_stats = invalidation_stats;
 
         // Start subscriber with handler
         manager.start_invalidation_subscriber();
@@ -575,23 +1315,67 @@ impl CacheManager {
             }
         }
 
-        Ok(Self {
+        Ok(Self::from_tiers(tiers, streaming_backend))
+    }
+
+    /// Shared field initialization for all constructors
+    fn from_tiers(
+        tiers: Vec<CacheTier>,
+        streaming_backend: Option<Arc<dyn StreamingBackend>>,
+    ) -> Self {
+        Self {
             tiers,
             streaming_backend,
-            total_requests: AtomicU64::new(0),
-            l1_hits: AtomicU64::new(0),
-            l2_hits: AtomicU64::new(0),
-            misses: AtomicU64::new(0),
-            promotions: AtomicUsize::new(0),
+            total_requests: Counter::default(),
+            l1_hits: Counter::default(),
+            l2_hits: Counter::default(),
+            misses: Counter::default(),
+            sets: Counter::default(),
+            key_buckets: None,
+            promotion_counters: Arc::new(PromotionCounters::default()),
+            promotion_queue: None,
+            in_flight_promotions: Some(Arc::new(InFlightPromotions::new())),
+            spawner: Arc::new(TokioSpawner),
+            key_canonicalizer: None,
+            canonical_storage: false,
+            l2_write_failure_policy: L2WriteFailurePolicy::default(),
+            l2_miss_policy: L2MissPolicy::default(),
+            l2_miss_sample_rate: 1.0,
+            value_rejector: None,
+            rejected_nulls: AtomicU64::new(0),
+            large_value_threshold: None,
+            large_values: AtomicU64::new(0),
+            coherence_sample_rate: 0.0,
+            stale_l1_hits: AtomicU64::new(0),
+            adaptive_ttl: None,
+            ttl_extensions: AtomicU64::new(0),
+            sliding_expiration: None,
+            global_max_bytes: None,
+            enforcing_memory_limit: AtomicBool::new(false),
+            memory_evicted_bytes: AtomicU64::new(0),
+            compute_counters: ComputeCounters::default(),
+            stream_counters: StreamCounters::default(),
+            last_version: AtomicU64::new(0),
+            deadline_policy: DeadlinePolicy::default(),
+            undecodable_policy: UndecodablePolicy::default(),
+            strategy_durations: StrategyDurations::default(),
             in_flight_requests: Arc::new(DashMap::new()),
+            in_flight_wait_timeout: None,
             serializer: Arc::new(CacheSerializer::Json(JsonSerializer)),
+            fallback_codecs: Arc::new([]),
+            value_compression: None,
+            codec_guard: false,
             #[cfg(feature = "redis")]
             invalidation_publisher: None,
             #[cfg(feature = "redis")]
             invalidation_subscriber: None,
             #[cfg(feature = "redis")]
+            keyspace_subscriber: None,
+            #[cfg(feature = "redis")]
             invalidation_stats: Arc::new(AtomicInvalidationStats::default()),
-        })
+            #[cfg(feature = "redis")]
+            invalidation_batcher: None,
+        }
     }
 
     /// Set a custom serializer for the cache manager
@@ -600,652 +1384,3722 @@ impl CacheManager {
         self.serializer = Arc::new(serializer);
     }
 
-    /// Start the invalidation subscriber background task
-    #[cfg(feature = "redis")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
-    fn start_invalidation_subscriber(&self) {
-        #[cfg(feature = "redis")]
-        if let Some(subscriber) = &self.invalidation_subscriber {
-            let tiers = self.tiers.clone();
+    /// Enable or disable the codec guard for typed values
+    ///
+    /// When enabled, values written through the typed API (`get_or_compute_typed`,
+    /// `get_many_each`) are prefixed with a short codec identifier. Typed reads of a
+    /// value written with a different serializer (or without the guard) log a warning
+    /// and are treated as a cache miss instead of failing deserialization.
+    ///
+    /// Services sharing an L2 should enable the guard on every instance.
+    #[must_use]
+    pub fn with_codec_guard(mut self, enabled: bool) -> Self {
+        self.codec_guard = enabled;
+        self
+    }
 
-            subscriber.start(move |msg: crate::invalidation::InvalidationMessage| {
-                let tiers = tiers.clone();
-                async move {
-                    for tier in &tiers {
-                        match &msg {
-                            InvalidationMessage::Remove { key } => {
-                                tier.backend.remove(key).await.ok();
-                            }
-                            InvalidationMessage::Update {
-                                key,
-                                value,
-                                ttl_secs,
-                            } => {
-                                if let Some(secs) = ttl_secs {
-                                    tier.backend
-                                        .set_with_ttl(
-                                            key,
-                                            value.clone(),
-                                            Duration::from_secs(*secs),
-                                        )
-                                        .await
-                                        .ok();
-                                } else {
-                                    tier.backend.set(key, value.clone()).await.ok();
-                                }
-                            }
-                            InvalidationMessage::RemovePattern { pattern } => {
-                                if let Err(e) = tier.backend.remove_pattern(pattern).await {
-                                    warn!(
-                                        "Failed to remove pattern '{}' from L{}: {}",
-                                        pattern, tier.tier_level, e
-                                    );
-                                }
-                            }
-                            InvalidationMessage::RemoveBulk { keys } => {
-                                for key in keys {
-                                    if let Err(e) = tier.backend.remove(key).await {
-                                        warn!(
-                                            "Failed to remove '{}' from L{}: {}",
-                                            key, tier.tier_level, e
-                                        );
-                                    }
-                                }
-                            }
-                        }
-                    }
-                    Ok(())
-                }
-            });
+    /// Read typed values with `codecs` when the primary serializer can't
+    ///
+    /// For codec migrations during rolling deploys: a cached value the primary
+    /// serializer can't decode (or, with the codec guard, that carries another
+    /// codec's tag) is tried with each fallback in order before it counts as
+    /// undecodable. Writes always use the primary serializer, so old values are
+    /// replaced as they expire.
+    #[must_use]
+    pub fn with_fallback_codecs(mut self, codecs: Vec<CacheSerializer>) -> Self {
+        self.fallback_codecs = codecs.into();
+        self
+    }
 
-            info!("Invalidation subscriber started across all tiers");
+    /// Compress typed values with `compression`
+    ///
+    /// Values of at least the compression's minimum size are compressed before
+    /// they are stored; `set_with_strategy_opts` can force or skip compression
+    /// for a single write. Stored values gain a one-byte header, so values
+    /// cached before compression was enabled can't be read and count as
+    /// undecodable. With an `Encrypted` serializer values are compressed
+    /// before they are encrypted.
+    #[must_use]
+    pub fn with_value_compression(mut self, compression: ValueCompression) -> Self {
+        self.value_compression = Some(compression);
+        self
+    }
+
+    /// MIME type of typed values as returned by the raw `get`
+    ///
+    /// For HTTP handlers serving cached bytes directly. With the codec guard or
+    /// value compression enabled the bytes carry a header, so this is
+    /// `application/octet-stream`.
+    #[must_use]
+    pub fn content_type(&self) -> &'static str {
+        if self.codec_guard || self.value_compression.is_some() {
+            return "application/octet-stream";
         }
+        self.serializer.content_type()
     }
 
-    /// Get value from cache using multi-tier architecture (v0.5.0+)
+    /// Bound the number of concurrent L2 operations
     ///
-    /// This method iterates through all configured tiers and automatically promotes
-    /// to upper tiers on cache hit.
-    async fn get_multi_tier(&self, key: &str) -> CacheResult<Option<Bytes>> {
-        self.get_multi_tier_from(key, 0).await
+    /// Reads, writes and removals on every tier below L1 share a semaphore with
+    /// `max_in_flight` permits; excess callers wait for a permit instead of piling
+    /// more commands onto the backend (e.g. Redis). Permits currently held are
+    /// reported in `CacheManagerStats::l2_permits_in_use`. A limit of 0 is treated as 1.
+    #[must_use]
+    pub fn with_l2_concurrency_limit(mut self, max_in_flight: usize) -> Self {
+        let limit = ConcurrencyLimit::new(max_in_flight.max(1));
+        for tier in self.tiers.iter_mut().skip(1) {
+            tier.concurrency_limit = Some(limit.clone());
+        }
+        self
     }
 
-    /// Get value from cache starting from a specific tier index
-    async fn get_multi_tier_from(
-        &self,
-        key: &str,
-        start_index: usize,
-    ) -> CacheResult<Option<Bytes>> {
-        // Try each tier sequentially (sorted by tier_level) starting from start_index
-        for (tier_index, tier) in self.tiers.iter().enumerate().skip(start_index) {
-            if let Some((value, ttl)) = tier.get_with_ttl(key).await {
-                // Cache hit!
-                tier.record_hit();
-                if tier.tier_level == 1 {
-                    self.l1_hits.fetch_add(1, Ordering::Relaxed);
-                } else if tier.tier_level == 2 {
-                    self.l2_hits.fetch_add(1, Ordering::Relaxed);
-                }
+    /// Add a slower durable store as the lowest tier
+    ///
+    /// For cascades like "Moka, then Redis, then `DynamoDB`": a key missing
+    /// from every faster tier is read from `backend` before it's computed. The
+    /// tier sits one level below the current lowest tier and promotes every
+    /// hit, so a value found there is written back to all upper tiers and the
+    /// next read is served from L1. Writes reach it like any other tier, with
+    /// a 2x TTL scale (as `TierConfig::as_l3()`). It shares the concurrency
+    /// limit of the other lower tiers, if one is set.
+    ///
+    /// For another promotion frequency or TTL scale, add the store with
+    /// `CacheSystemBuilder::with_tier()` instead.
+    #[must_use]
+    pub fn with_durable_tier(mut self, backend: Arc<dyn L2CacheBackend>) -> Self {
+        let level = self.tiers.last().map_or(1, |tier| tier.tier_level + 1);
+        info!(level, backend = %backend.name(), "Adding durable tier");
+        let mut tier = CacheTier::new(backend, level, true, 1, 2.0);
+        tier.concurrency_limit = self
+            .tiers
+            .get(1)
+            .and_then(|lower| lower.concurrency_limit.clone());
+        self.tiers.push(tier);
+        self
+    }
 
-                // Promote to all upper tiers (if promotion enabled)
-                if tier.promotion_enabled && tier_index > 0 {
-                    // Probabilistic Promotion Check
-                    let should_promote = if tier.promotion_frequency <= 1 {
-                        true
-                    } else {
-                        rand::thread_rng().gen_ratio(
-                            1,
-                            u32::try_from(tier.promotion_frequency).unwrap_or(u32::MAX),
-                        )
-                    };
+    /// Choose what happens to the L1 copy when a lower tier write fails
+    ///
+    /// By default (`KeepL1`) `set_with_strategy` keeps the L1 copy and returns
+    /// `Ok`, so this instance serves a value other instances never see in L2.
+    /// `EvictL1` rolls the L1 write back; `ShortenL1Ttl` keeps it only briefly
+    /// so the inconsistency heals on its own.
+    #[must_use]
+    pub fn with_l2_write_failure_policy(mut self, policy: L2WriteFailurePolicy) -> Self {
+        self.l2_write_failure_policy = policy;
+        self
+    }
 
-                    if should_promote {
-                        let promotion_ttl =
-                            ttl.unwrap_or_else(|| CacheStrategy::Default.to_duration());
-
-                        // Promote to all tiers above this one
-                        for upper_tier in self.tiers.iter().take(tier_index).rev() {
-                            if let Err(e) = upper_tier
-                                .set_with_ttl(key, value.clone(), promotion_ttl)
-                                .await
-                            {
-                                warn!(
-                                    "Failed to promote '{}' from L{} to L{}: {}",
-                                    key, tier.tier_level, upper_tier.tier_level, e
-                                );
-                            } else {
-                                self.promotions.fetch_add(1, Ordering::Relaxed);
-                                debug!(
-                                    "Promoted '{}' from L{} to L{} (TTL: {:?})",
-                                    key, tier.tier_level, upper_tier.tier_level, promotion_ttl
-                                );
-                            }
-                        }
-                    } else {
-                        debug!(
-                            "Probabilistic skip promotion for '{}' from L{} (N={})",
-                            key, tier.tier_level, tier.promotion_frequency
-                        );
-                    }
-                }
+    /// Choose whether L1 or L2 is authoritative for key existence
+    ///
+    /// With the default `TrustL1`, an L1 copy is served until its own TTL runs
+    /// out even if L2 no longer holds the key. `FlushL1` makes L2 authoritative:
+    /// every L1 hit is confirmed in L2, and on an L2 miss the L1 copy is evicted
+    /// and the lookup continues as a miss. This trades L1 hit latency for
+    /// consistency, so reserve it for caches where stale existence matters,
+    /// or sample the checks with `with_l2_miss_sampling`.
+    #[must_use]
+    pub fn with_l2_miss_policy(mut self, policy: L2MissPolicy) -> Self {
+        self.l2_miss_policy = policy;
+        self
+    }
 
-                return Ok(Some(value));
-            }
-        }
+    /// Confirm only a fraction of L1 hits in L2 under `L2MissPolicy::FlushL1`
+    ///
+    /// Each L1 hit is checked with probability `rate` (0.0 to 1.0, clamped;
+    /// NaN and infinities count as 0.0), bounding the extra L2 load to `rate`
+    /// reads per hit. A key L2 no longer has is then served from L1 for about
+    /// `1 / rate` more hits before it is flushed. Defaults to `1.0` (check every hit); ignored with `TrustL1`.
+    #[must_use]
+    pub fn with_l2_miss_sampling(mut self, rate: f64) -> Self {
+        self.l2_miss_sample_rate = sample_rate(rate);
+        self
+    }
 
-        // Cache miss across all tiers
-        Ok(None)
+    /// Choose what `get_until` / `get_or_compute_until` return past the deadline
+    ///
+    /// `Error` (the default) fails with `CacheError::Timeout`; `Miss` returns
+    /// `Ok(None)` so callers can fall back without matching on the error.
+    #[must_use]
+    pub fn with_deadline_policy(mut self, policy: DeadlinePolicy) -> Self {
+        self.deadline_policy = policy;
+        self
     }
 
-    /// Get value from cache (L1 first, then L2 fallback with promotion)
+    /// Choose how typed reads handle cached values that fail to deserialize
     ///
-    /// This method now includes built-in Cache Stampede protection when cache misses occur.
-    /// Multiple concurrent requests for the same missing key will be coalesced to prevent
-    /// unnecessary duplicate work on external data sources.
+    /// `Error` (the default) fails with a `SerializationError`. `Miss` logs a
+    /// warning and reports a miss, so `get_or_compute_typed` and friends
+    /// compute the value and overwrite the cached one. `Evict` additionally
+    /// removes the key from every tier on a plain typed read (`get_typed`).
+    /// With `Miss` / `Evict`, typed get-or-compute calls decode cached values
+    /// twice (once to check them, once to return them).
+    #[must_use]
+    pub fn with_undecodable_policy(mut self, policy: UndecodablePolicy) -> Self {
+        self.undecodable_policy = policy;
+        self
+    }
+
+    /// Override the TTLs of the named strategies (`ShortTerm`, `LongTerm`, ...)
     ///
-    /// Supports both legacy 2-tier mode and new multi-tier mode (v0.5.0+).
+    /// Applies to every write that takes a `CacheStrategy` and to the fallback
+    /// TTL (`default`). `CacheStrategy::to_duration` keeps returning the
+    /// built-in values.
+    #[must_use]
+    pub fn with_strategy_durations(mut self, durations: StrategyDurations) -> Self {
+        self.strategy_durations = durations;
+        self
+    }
+
+    /// Skip caching values that serialize to JSON `null`
     ///
-    /// # Arguments
-    /// * `key` - Cache key to retrieve
+    /// A compute function returning `null` usually means a data bug, and a
+    /// cached `null` then looks like a valid hit. With this enabled,
+    /// `set_with_strategy` and `update_cache` return `Ok` without writing such
+    /// values, counting them in `CacheManagerStats::rejected_nulls`.
+    /// Disabled by default. Replaces any `with_value_rejector` predicate.
+    #[must_use]
+    pub fn with_reject_null_values(mut self, enabled: bool) -> Self {
+        self.value_rejector = if enabled {
+            Some(Arc::new(is_json_null))
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Skip caching values matching a custom predicate
     ///
-    /// # Returns
-    /// * `Ok(Some(value))` - Cache hit, value found in any tier
-    /// * `Ok(None)` - Cache miss, value not found in any cache
-    /// * `Err(error)` - Cache operation failed
-    /// # Errors
+    /// Like `with_reject_null_values`, but `reject` decides from the serialized
+    /// value (without the codec tag) whether it must not be cached.
+    #[must_use]
+    pub fn with_value_rejector<F>(mut self, reject: F) -> Self
+    where
+        F: Fn(&[u8]) -> bool + Send + Sync + 'static,
+    {
+        self.value_rejector = Some(Arc::new(reject));
+        self
+    }
+
+    /// Whether `value` must not be cached; counts the rejection
+    fn reject_value(&self, key: &str, value: &[u8]) -> bool {
+        let Some(reject) = &self.value_rejector else {
+            return false;
+        };
+        let payload = if self.codec_guard {
+            self.serializer.untag(value).unwrap_or(value)
+        } else {
+            value
+        };
+        let payload = match &self.value_compression {
+            Some(compression) => compression
+                .decompress(payload)
+                .unwrap_or(std::borrow::Cow::Borrowed(payload)),
+            None => std::borrow::Cow::Borrowed(payload),
+        };
+        if !reject(&payload) {
+            return false;
+        }
+        self.rejected_nulls.fetch_add(1, Ordering::Relaxed);
+        warn!("Not caching rejected value for key '{}'", key);
+        true
+    }
+
+    /// Limit how long a coalesced request waits for the in-flight computation
     ///
-    /// Returns an error if cache operation fails.
+    /// Concurrent misses on a key wait for the single request computing it. If
+    /// that computation hangs, so does every waiter. With a timeout, a waiter
+    /// that has waited `timeout` logs a warning and proceeds on its own: `get`
+    /// reads the lower tiers itself and `get_or_compute_with` runs its own
+    /// `compute_fn`, so the data source may see duplicate work. No limit by
+    /// default; a zero `timeout` removes it again.
+    #[must_use]
+    pub fn with_in_flight_wait_timeout(mut self, timeout: Duration) -> Self {
+        self.in_flight_wait_timeout = (!timeout.is_zero()).then_some(timeout);
+        self
+    }
+
+    /// Keep frequently hit keys cached longer
     ///
-    /// # Panics
+    /// L1 hits are counted per key in a decaying frequency sketch. When a key
+    /// with at least `min_hits` recent hits is read and its remaining TTL is at
+    /// most half of `max_ttl`, the value is rewritten to every tier with double
+    /// the remaining TTL (capped at `max_ttl`). Keys read rarely keep the TTL
+    /// they were written with and expire on schedule. Extensions are counted in
+    /// `CacheManagerStats::ttl_extensions`. Off by default.
+    #[must_use]
+    pub fn with_adaptive_ttl(mut self, config: AdaptiveTtl) -> Self {
+        self.adaptive_ttl = Some(AdaptiveTtlState {
+            config,
+            sketch: crate::backends::admission::FrequencySketch::new(config.sketch_width),
+        });
+        self
+    }
+
+    /// Reset the TTL of a key to `window` every time it is read
     ///
-    /// Panics if tiers are not initialized in multi-tier mode (should not happen if constructed correctly).
-    pub async fn get(&self, key: &str) -> CacheResult<Option<Bytes>> {
-        self.total_requests.fetch_add(1, Ordering::Relaxed);
+    /// Sliding expiration for session-style data: a hit in any tier touches the
+    /// key in every tier (Redis `PEXPIRE`, an in-place expiry update in memory),
+    /// so a key expires `window` after its last read instead of after its write.
+    /// Promotions triggered by the read use `window` as well. To keep a
+    /// constantly read key from living forever, reads extend it to at most 24
+    /// windows after the first sliding read; change the cap with
+    /// `with_sliding_expiration_limit`. A zero `window` turns it off again.
+    #[must_use]
+    pub fn with_sliding_expiration(mut self, window: Duration) -> Self {
+        self.sliding_expiration = (!window.is_zero()).then(|| SlidingExpiration {
+            window,
+            max_lifetime: window.saturating_mul(DEFAULT_SLIDING_LIFETIME_WINDOWS),
+            reads: DashMap::new(),
+            touches: AtomicU64::new(0),
+        });
+        self
+    }
 
-        // Fast path for L1 (first tier) - no locking needed
-        if let Some(tier1) = self.tiers.first()
-            && let Some((value, _ttl)) = tier1.get_with_ttl(key).await
+    /// Cap how long reads keep a key alive under sliding expiration
+    ///
+    /// Counted from the first sliding read of the key; once reached, reads stop
+    /// extending it and the key expires at the end of its current window. Has no
+    /// effect unless `with_sliding_expiration` was called first.
+    #[must_use]
+    pub fn with_sliding_expiration_limit(mut self, max_lifetime: Duration) -> Self {
+        if let Some(sliding) = &mut self.sliding_expiration {
+            sliding.max_lifetime = max_lifetime;
+        }
+        self
+    }
+
+    /// Cap the memory held by all in-memory tiers together
+    ///
+    /// Each tier's own capacity bounds only that tier, so an all-in-memory
+    /// multi-tier setup can still outgrow the process. With a cap, every write
+    /// sums `CacheBackend::estimated_size_bytes` over the tiers that report it
+    /// (Redis and Memcached don't) and, when the total exceeds `max_bytes`,
+    /// evicts the excess from the coldest tier first: the last in-memory tier,
+    /// then the one above it, up to L1. Evicted bytes are counted in
+    /// `CacheManagerStats::memory_evicted_bytes`. Sizes are estimates of keys
+    /// plus values, not allocator usage; `DashMapCache` and `MokaCache` keep
+    /// them as running counters, so the check doesn't scan the tiers. Zero
+    /// turns the cap off.
+    #[must_use]
+    pub fn with_global_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.global_max_bytes = (max_bytes > 0).then_some(max_bytes);
+        self
+    }
+
+    /// Estimated bytes held by all in-memory tiers together
+    ///
+    /// Sums `CacheBackend::estimated_size_bytes` over the tiers that report
+    /// it; see `with_global_max_bytes`.
+    #[must_use]
+    pub fn estimated_memory_bytes(&self) -> u64 {
+        self.tiers
+            .iter()
+            .filter_map(|tier| tier.backend.estimated_size_bytes())
+            .sum()
+    }
+
+    /// Evict from the coldest in-memory tiers until under `global_max_bytes`
+    ///
+    /// Skipped while another write is already enforcing the cap.
+    async fn enforce_memory_limit(&self) {
+        let Some(max_bytes) = self.global_max_bytes else {
+            return;
+        };
+        if self.estimated_memory_bytes() <= max_bytes
+            || self.enforcing_memory_limit.swap(true, Ordering::Acquire)
         {
-            tier1.record_hit();
-            // Update legacy stats for backward compatibility
-            self.l1_hits.fetch_add(1, Ordering::Relaxed);
-            return Ok(Some(value));
+            return;
         }
+        let _guard = MemoryLimitGuard(&self.enforcing_memory_limit);
 
-        let key_owned = key.to_string();
-        let flight_state = match self.in_flight_requests.entry(key_owned.clone()) {
-            dashmap::mapref::entry::Entry::Occupied(entry) => {
-                FlightState::Waiter(entry.get().subscribe())
+        let mut excess = self.estimated_memory_bytes().saturating_sub(max_bytes);
+        for tier in self.tiers.iter().rev() {
+            if excess == 0 {
+                break;
             }
-            dashmap::mapref::entry::Entry::Vacant(entry) => {
-                let (tx, _) = watch::channel(None);
-                let tx = Arc::new(tx);
-                entry.insert(tx.clone());
-                FlightState::Creator(tx)
+            if tier
+                .backend
+                .estimated_size_bytes()
+                .is_none_or(|size| size == 0)
+            {
+                continue;
             }
-        };
-
-        match flight_state {
-            FlightState::Waiter(mut rx) => {
-                // Wait for creator to finish
-                if rx.borrow().is_none() {
-                    while rx.changed().await.is_ok() {
-                        if rx.borrow().is_some() {
-                            break;
-                        }
-                    }
-                }
-                // Return result if it exists, otherwise fall through to re-compute
-                if let Some(res) = rx.borrow().clone() {
-                    return res;
+            match tier.backend.evict_bytes(excess).await {
+                Ok(freed) => {
+                    debug!(
+                        "[Cache] Evicted {} bytes from L{} (global memory cap)",
+                        freed, tier.tier_level
+                    );
+                    self.memory_evicted_bytes
+                        .fetch_add(freed, Ordering::Relaxed);
+                    excess = excess.saturating_sub(freed);
                 }
+                Err(e) => warn!(
+                    "Failed to evict from L{} for the global memory cap: {}",
+                    tier.tier_level, e
+                ),
             }
-            FlightState::Creator(tx) => {
-                // Creator - guard key removal on drop/cancellation
-                let _guard = RemoveInFlightGuard {
-                    map: Arc::clone(&self.in_flight_requests),
-                    key: key_owned,
-                };
-
-                // Double-check L1 after acquiring lock (or if we are the first to compute)
-                if let Some(tier1) = self.tiers.first()
-                    && let Some((value, _ttl)) = tier1.get_with_ttl(key).await
-                {
-                    tier1.record_hit();
-                    self.l1_hits.fetch_add(1, Ordering::Relaxed);
-                    let _ = tx.send(Some(Ok(Some(value.clone())))); // Notify any waiting subscribers
-                    return Ok(Some(value));
-                }
+        }
+    }
 
-                // Check remaining tiers with promotion (start from tier index 1: L2)
-                let result = self.get_multi_tier_from(key, 1).await;
+    /// Cross-check a fraction of L1 hits against L2 to measure coherence
+    ///
+    /// A diagnostic for invalidation lag: each L1 hit is sampled with
+    /// probability `rate` (0.0 to 1.0, clamped; NaN and infinities count as
+    /// 0.0), and L2 is read for the same key. If L2 no longer has the key or
+    /// holds a different value, the hit counts as stale in `CacheManagerStats::stale_l1_hits`. The L1 value is
+    /// still returned. Every sample costs an L2 read, so keep the rate low in
+    /// production (e.g. `0.01`). Off (`0.0`) by default.
+    #[must_use]
+    pub fn with_coherence_sampling(mut self, rate: f64) -> Self {
+        self.coherence_sample_rate = sample_rate(rate);
+        self
+    }
 
-                match &result {
-                    Ok(Some(val)) => {
-                        // Hit in L2+ tier - update legacy stats
-                        if self.tiers.len() >= 2 {
-                            self.l2_hits.fetch_add(1, Ordering::Relaxed);
-                        }
-                        let _ = tx.send(Some(Ok(Some(val.clone()))));
-                    }
-                    Ok(None) => {
-                        self.misses.fetch_add(1, Ordering::Relaxed);
-                        let _ = tx.send(Some(Ok(None)));
-                    }
-                    Err(e) => {
-                        let _ = tx.send(Some(Err(e.clone())));
-                    }
-                }
+    /// Warn about stored values larger than `bytes`
+    ///
+    /// Purely observational: after `set_with_strategy` stores a value whose
+    /// serialized size exceeds `bytes`, a warning with the key is logged and
+    /// `CacheManagerStats::large_values` is incremented. The value is still
+    /// cached. Helps spot accidentally huge entries before they strain Redis.
+    /// Disabled by default; `0` disables it again.
+    #[must_use]
+    pub fn with_large_value_threshold(mut self, bytes: usize) -> Self {
+        self.large_value_threshold = (bytes > 0).then_some(bytes);
+        self
+    }
 
-                return result;
-            }
+    /// Log and count a stored value above the large value threshold
+    fn audit_value_size(&self, key: &str, len: usize) {
+        #[cfg(feature = "metrics-facade")]
+        crate::metrics_facade::record_value_size(len);
+        if let Some(threshold) = self.large_value_threshold
+            && len > threshold
+        {
+            self.large_values.fetch_add(1, Ordering::Relaxed);
+            warn!(
+                "Cached large value for key '{}': {} bytes (threshold {})",
+                key, len, threshold
+            );
         }
+    }
 
-        // If waiter fell through (creator dropped without sending), do direct fallback query
-        let result = self.get_multi_tier_from(key, 1).await;
-        if let Ok(Some(_)) = result {
-            if self.tiers.len() >= 2 {
-                self.l2_hits.fetch_add(1, Ordering::Relaxed);
-            }
-        } else if let Ok(None) = result {
-            self.misses.fetch_add(1, Ordering::Relaxed);
-        }
-        result
+    /// Use striped counters for request statistics
+    ///
+    /// Every `get` increments shared counters (`total_requests`, `l1_hits`, ...).
+    /// On many-core machines under heavy load, the cache line holding a single
+    /// atomic bounces between cores. Striped counters spread increments over
+    /// per-thread slots and sum them in `get_stats()`, which is then slightly
+    /// more expensive and not an exact point-in-time snapshot.
+    ///
+    /// Switching resets the request counters, so call this before serving traffic.
+    #[must_use]
+    pub fn with_striped_counters(mut self, enabled: bool) -> Self {
+        self.total_requests = Counter::new(enabled);
+        self.l1_hits = Counter::new(enabled);
+        self.l2_hits = Counter::new(enabled);
+        self.misses = Counter::new(enabled);
+        self.sets = Counter::new(enabled);
+        self
     }
 
-    /// Get a value from cache and deserialize it (Type-Safe Version)
+    /// Keep statistics per key bucket (e.g. per tenant prefix)
     ///
-    /// # Errors
+    /// `extract` maps a key to its bucket name, or `None` to leave the key out of
+    /// bucket statistics. It runs on every recorded request, hit, miss and set, so
+    /// keep it cheap. At most `MAX_STATS_BUCKETS` buckets are tracked; keys mapping
+    /// to further buckets are counted under `OVERFLOW_STATS_BUCKET`.
+    /// Read the counters with `stats_by_bucket()`.
     ///
-    /// Returns a `SerializationError` if deserialization fails, or a `BackendError` if the cache retrieval fails.
-    pub async fn get_typed<T>(&self, key: &str) -> CacheResult<Option<T>>
+    /// # Example
+    /// ```rust,no_run
+    /// # use multi_tier_cache::CacheManager;
+    /// # fn example(cache_manager: CacheManager) -> CacheManager {
+    /// // "tenant-a:user:1" -> "tenant-a"
+    /// cache_manager.with_stats_by_prefix(|key| key.split(':').next().map(str::to_string))
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_stats_by_prefix<F>(mut self, extract: F) -> Self
     where
-        T: serde::de::DeserializeOwned,
+        F: Fn(&str) -> Option<String> + Send + Sync + 'static,
     {
-        if let Some(bytes) = self.get(key).await? {
-            return Ok(Some(self.serializer.deserialize::<T>(&bytes)?));
+        self.key_buckets = Some(KeyBuckets::new(Box::new(extract), MAX_STATS_BUCKETS));
+        self
+    }
+
+    /// Record a statistics event globally and in the key's bucket
+    fn record_stat(&self, key: &str, event: StatEvent) {
+        let counter = match event {
+            StatEvent::Request => &self.total_requests,
+            StatEvent::L1Hit => &self.l1_hits,
+            StatEvent::L2Hit => &self.l2_hits,
+            StatEvent::Miss => &self.misses,
+            StatEvent::Set => &self.sets,
+        };
+        counter.increment();
+        if let Some(buckets) = &self.key_buckets {
+            buckets.record(key, event);
         }
-        Ok(None)
+        #[cfg(feature = "metrics-facade")]
+        crate::metrics_facade::record_event(event);
+    }
+
+    /// Coalesce concurrent requests for equivalent keys
+    ///
+    /// `canonicalize` maps a key to a canonical spelling (e.g. sorting query
+    /// parameters), and must be idempotent. Keys with the same canonical form share
+    /// one in-flight lookup / compute in `get`, `get_or_compute_*` and `entry`.
+    ///
+    /// # Coalescing vs storage
+    ///
+    /// By default the canonical key is used **only for coalescing**: values are
+    /// still stored and looked up under the key exactly as the caller spelled it.
+    /// When two spellings race, one computes and stores under its own spelling;
+    /// the other receives that value but nothing is stored under its spelling, so
+    /// a later request with it still misses.
+    ///
+    /// With `with_canonical_storage(true)` the canonical key is also used for
+    /// storage, lookups, invalidation and broadcasts, so all spellings share one
+    /// cache entry. Keys already cached under other spellings become unreachable
+    /// through the manager when switching storage modes.
+    #[must_use]
+    pub fn with_key_canonicalizer<F>(mut self, canonicalize: F) -> Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        self.key_canonicalizer = Some(Arc::new(canonicalize));
+        self
+    }
+
+    /// Store every key under `prefix` (e.g. `"myapp:"`)
+    ///
+    /// Lets several applications share one Redis without key collisions. Keys
+    /// that already start with the prefix are left as is. Implemented as a key
+    /// canonicalizer with canonical storage, so it replaces any canonicalizer
+    /// set before (and vice versa). Patterns passed to `invalidate_pattern` must
+    /// include the prefix. With cross-instance invalidation, build the
+    /// `InvalidationConfig` with `InvalidationConfig::with_key_prefix` to
+    /// broadcast on a channel of this namespace only.
+    #[must_use]
+    pub fn with_key_prefix(self, prefix: impl Into<String>) -> Self {
+        let prefix = prefix.into();
+        self.with_key_canonicalizer(move |key| {
+            if key.starts_with(&prefix) {
+                key.to_string()
+            } else {
+                format!("{prefix}{key}")
+            }
+        })
+        .with_canonical_storage(true)
+    }
+
+    /// Store values under the canonical key as well (see `with_key_canonicalizer`)
+    ///
+    /// Has no effect without a key canonicalizer.
+    #[must_use]
+    pub fn with_canonical_storage(mut self, enabled: bool) -> Self {
+        self.canonical_storage = enabled;
+        self
+    }
+
+    /// Key used for storage: canonical if canonical storage is enabled
+    fn storage_key<'k>(&self, key: &'k str) -> std::borrow::Cow<'k, str> {
+        match &self.key_canonicalizer {
+            Some(canonicalize) if self.canonical_storage => {
+                std::borrow::Cow::Owned(canonicalize(key))
+            }
+            _ => std::borrow::Cow::Borrowed(key),
+        }
+    }
+
+    /// Key used for in-flight coalescing: always canonical if a canonicalizer is set
+    fn flight_key(&self, key: &str) -> String {
+        self.key_canonicalizer
+            .as_ref()
+            .map_or_else(|| key.to_string(), |canonicalize| canonicalize(key))
+    }
+
+    /// Run background tasks on a custom `Spawner` instead of `tokio::spawn`
+    ///
+    /// Applies to the async promotion worker and the invalidation and keyspace
+    /// subscribers. A subscriber that is already running (started by
+    /// `new_with_invalidation` or `with_keyspace_invalidation`) is shut down and
+    /// restarted on `spawner`.
+    #[must_use]
+    pub fn with_spawner(mut self, spawner: Arc<dyn Spawner>) -> Self {
+        self.spawner = spawner;
+        #[cfg(feature = "redis")]
+        if let Some(subscriber) = &self.invalidation_subscriber {
+            subscriber.shutdown();
+            self.start_invalidation_subscriber();
+        }
+        #[cfg(feature = "redis")]
+        if let Some(subscriber) = &self.keyspace_subscriber {
+            subscriber.shutdown();
+            self.start_keyspace_subscriber();
+        }
+        self
+    }
+
+    /// Apply promotions from lower tiers in a background task
+    ///
+    /// By default a hit in L2 (or below) writes the value into every upper tier
+    /// before `get` returns, so read latency includes the L1 write. When enabled,
+    /// promotions are pushed onto a bounded queue (default capacity 1024) and applied
+    /// by a background task instead. Under pressure the oldest pending promotion is
+    /// discarded; see `CacheManagerStats::async_promotions_dropped`.
+    ///
+    /// A value may be read from L2 again until its queued promotion lands.
+    /// Requires a Tokio runtime when the first promotion is queued.
+    ///
+    /// # Read-your-writes
+    ///
+    /// A queued promotion holds the value as it was read, so applied late it
+    /// could overwrite a newer write. To prevent that, writes and removals
+    /// through this manager are kept in a short-lived write log: promotions of
+    /// a key written since are skipped (or rolled back if the write raced the
+    /// promotion), and promotions older than half a second are dropped. A `get`
+    /// after `set_with_strategy` on the same manager therefore sees the new
+    /// value. This holds within one `CacheManager` only; other instances may
+    /// serve their previous copy until they receive the invalidation.
+    ///
+    /// Dropped old promotions are counted in
+    /// `CacheManagerStats::async_promotions_expired`. A steadily growing count
+    /// means the worker can't keep up, and those keys keep being read from
+    /// the lower tier until a later promotion lands.
+    #[must_use]
+    pub fn with_async_promotion(mut self, enabled: bool) -> Self {
+        if enabled {
+            return self.with_async_promotion_capacity(DEFAULT_PROMOTION_QUEUE_CAPACITY);
+        }
+        self.close_promotion_queue();
+        self.promotion_queue = None;
+        self
+    }
+
+    /// Enable async promotion with a custom queue capacity (0 is treated as 1)
+    ///
+    /// See `with_async_promotion`.
+    #[must_use]
+    pub fn with_async_promotion_capacity(mut self, capacity: usize) -> Self {
+        self.close_promotion_queue();
+        self.promotion_queue = Some(Arc::new(PromotionQueue::new(capacity)));
+        self
+    }
+
+    /// Allow only one promotion per key at a time (enabled by default)
+    ///
+    /// Many concurrent hits on a key that only a lower tier holds would each
+    /// write it to every upper tier before the first promotion lands. With
+    /// deduplication, hits arriving while that key's promotion is running (or
+    /// queued, with async promotion) return the value without promoting it
+    /// again; see `CacheManagerStats::deduplicated_promotions`.
+    #[must_use]
+    pub fn with_promotion_dedup(mut self, enabled: bool) -> Self {
+        self.in_flight_promotions = enabled.then(|| Arc::new(InFlightPromotions::new()));
+        self
+    }
+
+    /// Apply all writes that background tasks still hold
+    ///
+    /// Queued async promotions (see `with_async_promotion`) are written to
+    /// their tiers and batched invalidations (see
+    /// `with_invalidation_batch_window`) are published, waiting for any the
+    /// workers are applying at that moment. Call it before a critical
+    /// read-after-write or before shutting down. Returns immediately if
+    /// nothing is queued.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if publishing the pending invalidations fails.
+    pub async fn flush_pending_writes(&self) -> CacheResult<()> {
+        if let Some(queue) = &self.promotion_queue {
+            queue.flush(&self.tiers, &self.promotion_counters).await;
+        }
+        #[cfg(feature = "redis")]
+        if let (Some(batcher), Some(publisher)) =
+            (&self.invalidation_batcher, &self.invalidation_publisher)
+        {
+            batcher.flush(publisher, &self.invalidation_stats).await?;
+        }
+        Ok(())
+    }
+
+    /// Flush pending writes and stop all background tasks
+    ///
+    /// Runs `flush_pending_writes`, then stops the async promotion worker, the
+    /// invalidation batcher and the invalidation and keyspace subscribers. The
+    /// manager keeps serving reads and writes afterwards, but nothing runs in
+    /// the background anymore: async promotions are no longer applied and other
+    /// instances' invalidations are no longer received. Meant to be called once,
+    /// when the service stops (see `CacheSystem::run_until`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if publishing the pending invalidations fails; the
+    /// background tasks are stopped regardless.
+    pub async fn shutdown(&self) -> CacheResult<()> {
+        let flushed = self.flush_pending_writes().await;
+        self.close_promotion_queue();
+        #[cfg(feature = "redis")]
+        {
+            self.close_invalidation_batcher();
+            if let Some(subscriber) = &self.invalidation_subscriber {
+                subscriber.shutdown();
+            }
+            if let Some(subscriber) = &self.keyspace_subscriber {
+                subscriber.shutdown();
+            }
+        }
+        info!("Cache manager shut down");
+        flushed
+    }
+
+    /// Note a write or removal of `key` so queued promotions don't overwrite it
+    fn log_write(&self, key: &str) {
+        if let Some(queue) = &self.promotion_queue {
+            queue.record_write(key);
+        }
+    }
+
+    /// Stop the background promotion worker, if any, after it drains the queue
+    fn close_promotion_queue(&self) {
+        if let Some(queue) = &self.promotion_queue {
+            queue.close();
+        }
+    }
+
+    /// Batch invalidation broadcasts published within `window` of each other
+    ///
+    /// Keys are still removed locally right away, but instead of publishing one
+    /// `Remove` message per `invalidate` (or `take`), keys invalidated within
+    /// `window` are published together as a single `RemoveBulk` message. Other
+    /// instances drop the keys up to `window` later, and publish failures are
+    /// logged instead of returned. A zero window publishes each key immediately
+    /// (the default); without cross-instance invalidation this has no effect.
+    ///
+    /// Requires a Tokio runtime when the first invalidation is batched.
+    #[must_use]
+    pub fn with_invalidation_batch_window(self, window: Duration) -> Self {
+        #[cfg(feature = "redis")]
+        {
+            let mut manager = self;
+            manager.close_invalidation_batcher();
+            manager.invalidation_batcher =
+                (!window.is_zero()).then(|| Arc::new(InvalidationBatcher::new(window)));
+            manager
+        }
+        #[cfg(not(feature = "redis"))]
+        {
+            let _ = window;
+            self
+        }
+    }
+
+    /// Evict L1 entries when Redis reports their keys deleted, expired or evicted
+    ///
+    /// Starts `subscriber` (see `KeyspaceSubscriber`) and removes the key of
+    /// each event from L1. This covers changes that bypass this crate, such as
+    /// another service deleting keys, which `new_with_invalidation` can't see.
+    /// Only L1 is touched: the event already describes the Redis tier. The
+    /// subscriber is stopped when the manager is dropped.
+    ///
+    /// Keys are matched as stored, so with a `with_key_prefix` or
+    /// `with_key_canonicalizer` the events must name the stored keys.
+    #[cfg(feature = "redis")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
+    #[must_use]
+    pub fn with_keyspace_invalidation(mut self, subscriber: KeyspaceSubscriber) -> Self {
+        if let Some(previous) = self.keyspace_subscriber.take() {
+            previous.shutdown();
+        }
+        self.keyspace_subscriber = Some(Arc::new(subscriber));
+        self.start_keyspace_subscriber();
+        self
+    }
+
+    /// Start the keyspace subscriber background task
+    #[cfg(feature = "redis")]
+    fn start_keyspace_subscriber(&self) {
+        let (Some(subscriber), Some(l1)) = (&self.keyspace_subscriber, self.tiers.first()) else {
+            return;
+        };
+        let l1 = Arc::clone(&l1.backend);
+        subscriber.start_with(self.spawner.as_ref(), move |key: String| {
+            let l1 = Arc::clone(&l1);
+            async move { l1.remove(&key).await }
+        });
+        info!("Keyspace subscriber started for L1");
+    }
+
+    /// Publish pending batched invalidations, if any, and stop the batcher
+    #[cfg(feature = "redis")]
+    fn close_invalidation_batcher(&self) {
+        if let Some(batcher) = &self.invalidation_batcher {
+            batcher.close();
+        }
+    }
+
+    /// Serialize a typed value, adding the codec tag if the guard is enabled
+    fn encode_value<T: serde::Serialize>(&self, value: &T) -> CacheResult<Bytes> {
+        self.encode_value_with(value, CompressionMode::Auto)
+    }
+
+    /// `encode_value` for computed typed values, rejecting ones that can't be read back
+    ///
+    /// Codecs that aren't self-describing (Bincode) write values such as
+    /// `serde_json::Value` that never deserialize again; caching them would
+    /// make every later read fail, so they are decoded once here and the
+    /// error is returned instead.
+    fn encode_typed_value<T>(&self, key: &str, value: &T) -> CacheResult<Bytes>
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let bytes = self.encode_value(value)?;
+        if !self.serializer.supports_self_describing() {
+            self.decode_typed_result::<T>(key, &bytes)?;
+        }
+        Ok(bytes)
+    }
+
+    /// `encode_value`, compressing as `compression` asks
+    fn encode_value_with<T: serde::Serialize>(
+        &self,
+        value: &T,
+        compression: CompressionMode,
+    ) -> CacheResult<Bytes> {
+        let bytes = match &self.value_compression {
+            Some(value_compression) => {
+                self.serialize_compressed(value, value_compression, compression)?
+            }
+            None if compression == CompressionMode::Always => {
+                return Err(CacheError::ConfigError(
+                    "Compression was forced, but no value compression is configured".to_string(),
+                ));
+            }
+            None => self.serializer.serialize(value)?,
+        };
+        if self.codec_guard {
+            return Ok(self.serializer.tag(&bytes));
+        }
+        Ok(bytes)
+    }
+
+    /// Serialize and compress a typed value
+    ///
+    /// With an encrypting serializer the plaintext is compressed before it is
+    /// encrypted, since ciphertext doesn't compress.
+    fn serialize_compressed<T: serde::Serialize>(
+        &self,
+        value: &T,
+        value_compression: &ValueCompression,
+        compression: CompressionMode,
+    ) -> CacheResult<Bytes> {
+        #[cfg(feature = "encryption")]
+        if let CacheSerializer::Encrypted(encrypting) = self.serializer.as_ref() {
+            let plaintext = encrypting.inner().serialize(value)?;
+            return encrypting.encrypt(&value_compression.compress(&plaintext, compression)?);
+        }
+        value_compression.compress(&self.serializer.serialize(value)?, compression)
+    }
+
+    /// Whether typed lookups must check cached values before using them
+    /// (codec guard, or decryption with an encrypting serializer)
+    fn check_typed_values(&self) -> bool {
+        #[cfg(feature = "encryption")]
+        if matches!(self.serializer.as_ref(), CacheSerializer::Encrypted(_)) {
+            return true;
+        }
+        self.codec_guard
+    }
+
+    /// Check typed get-or-compute calls run on cached values before using them
+    ///
+    /// `None` if cached values are used as they are.
+    fn typed_value_check<T: serde::de::DeserializeOwned>(&self) -> Option<ValueCheck> {
+        if self.undecodable_policy != UndecodablePolicy::Error {
+            return Some(Self::typed_value_decodable::<T>);
+        }
+        self.check_typed_values()
+            .then_some(Self::typed_value_readable as ValueCheck)
+    }
+
+    /// Whether a cached value decodes into `T` (failures are logged)
+    fn typed_value_decodable<T: serde::de::DeserializeOwned>(
+        &self,
+        key: &str,
+        value: &[u8],
+    ) -> bool {
+        match self.decode_value::<T>(key, value) {
+            Ok(decoded) => decoded.is_some(),
+            Err(e) => {
+                warn!(key = %key, error = %e, "Cached value can't be decoded, treating as miss");
+                false
+            }
+        }
+    }
+
+    /// Whether a cached value passes the codec guard and decrypts
+    fn typed_value_readable(&self, key: &str, value: &[u8]) -> bool {
+        if self
+            .read_codecs()
+            .any(|serializer| self.codec_payload(serializer, value).is_some())
+        {
+            return true;
+        }
+        warn!(
+            key = %key,
+            codec = %self.serializer.name(),
+            "Cached value was written with a different codec or key, treating as miss"
+        );
+        false
+    }
+
+    /// The primary serializer, then the fallback codecs
+    fn read_codecs(&self) -> impl Iterator<Item = &CacheSerializer> {
+        std::iter::once(self.serializer.as_ref()).chain(self.fallback_codecs.iter())
+    }
+
+    /// Payload of a cached value for `serializer`, with the serializer to decode it
+    ///
+    /// Strips the codec tag (if the guard is enabled), decrypts and
+    /// decompresses. `None` if the value carries another codec's tag or fails
+    /// decompression or decryption.
+    fn codec_payload<'a>(
+        &self,
+        serializer: &'a CacheSerializer,
+        bytes: &'a [u8],
+    ) -> Option<(std::borrow::Cow<'a, [u8]>, &'a CacheSerializer)> {
+        let payload = if self.codec_guard {
+            serializer.untag(bytes)?
+        } else {
+            bytes
+        };
+        // Values are compressed before encryption, so decrypt first
+        #[cfg(feature = "encryption")]
+        if let CacheSerializer::Encrypted(encrypting) = serializer {
+            let plaintext = encrypting.decrypt(payload)?;
+            let plaintext = match &self.value_compression {
+                Some(compression) => compression.decompress(&plaintext)?.into_owned(),
+                None => plaintext,
+            };
+            return Some((std::borrow::Cow::Owned(plaintext), encrypting.inner()));
+        }
+        let payload = match &self.value_compression {
+            Some(compression) => compression.decompress(payload)?,
+            None => std::borrow::Cow::Borrowed(payload),
+        };
+        Some((payload, serializer))
+    }
+
+    /// Deserialize a typed value, returning `None` on a codec mismatch
+    ///
+    /// Fallback codecs are tried in order when the primary serializer can't
+    /// read the value; the primary's error is returned if none can.
+    fn decode_value<T: serde::de::DeserializeOwned>(
+        &self,
+        key: &str,
+        bytes: &[u8],
+    ) -> CacheResult<Option<T>> {
+        let mut first_error = None;
+        for (attempt, serializer) in self.read_codecs().enumerate() {
+            let Some((payload, decoder)) = self.codec_payload(serializer, bytes) else {
+                continue;
+            };
+            match decoder.deserialize::<T>(&payload) {
+                Ok(value) => {
+                    if attempt > 0 {
+                        debug!(key = %key, codec = %serializer.name(), "Read cached value with a fallback codec");
+                    }
+                    return Ok(Some(value));
+                }
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        if let Some(e) = first_error {
+            return Err(e);
+        }
+        warn!(
+            key = %key,
+            codec = %self.serializer.name(),
+            "Cached value was written with a different codec or key, treating as miss"
+        );
+        Ok(None)
+    }
+
+    /// `decode_value` for cache reads, applying the undecodable value policy
+    async fn decode_cached<T: serde::de::DeserializeOwned>(
+        &self,
+        key: &str,
+        bytes: &[u8],
+    ) -> CacheResult<Option<T>> {
+        match self.decode_value(key, bytes) {
+            Err(CacheError::SerializationError(e))
+                if self.undecodable_policy != UndecodablePolicy::Error =>
+            {
+                warn!(key = %key, error = %e, "Cached value can't be decoded, treating as miss");
+                if self.undecodable_policy == UndecodablePolicy::Evict {
+                    let storage_key = self.storage_key(key);
+                    for tier in &self.tiers {
+                        if let Err(e) = tier.remove(&storage_key).await {
+                            warn!(
+                                "Failed to evict undecodable '{}' from L{}: {}",
+                                key, tier.tier_level, e
+                            );
+                        }
+                    }
+                }
+                Ok(None)
+            }
+            result => result,
+        }
+    }
+
+    /// Start the invalidation subscriber background task
+    #[cfg(feature = "redis")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
+    fn start_invalidation_subscriber(&self) {
+        #[cfg(feature = "redis")]
+        if let Some(subscriber) = &self.invalidation_subscriber {
+            let tiers = self.tiers.clone();
+
+            subscriber.start_with(
+                self.spawner.as_ref(),
+                move |msg: crate::invalidation::InvalidationMessage| {
+                    let tiers = tiers.clone();
+                    async move {
+                        for tier in &tiers {
+                            match &msg {
+                                InvalidationMessage::Remove { key } => {
+                                    tier.backend.remove(key).await.ok();
+                                }
+                                InvalidationMessage::Update {
+                                    key,
+                                    value,
+                                    ttl_secs,
+                                } => {
+                                    if let Some(secs) = ttl_secs {
+                                        tier.backend
+                                            .set_with_ttl(
+                                                key,
+                                                value.clone(),
+                                                Duration::from_secs(*secs),
+                                            )
+                                            .await
+                                            .ok();
+                                    } else {
+                                        tier.backend.set(key, value.clone()).await.ok();
+                                    }
+                                }
+                                InvalidationMessage::RemovePattern { pattern } => {
+                                    if let Err(e) = tier.backend.remove_pattern(pattern).await {
+                                        warn!(
+                                            "Failed to remove pattern '{}' from L{}: {}",
+                                            pattern, tier.tier_level, e
+                                        );
+                                    }
+                                }
+                                InvalidationMessage::RemoveBulk { keys } => {
+                                    for key in keys {
+                                        if let Err(e) = tier.backend.remove(key).await {
+                                            warn!(
+                                                "Failed to remove '{}' from L{}: {}",
+                                                key, tier.tier_level, e
+                                            );
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        Ok(())
+                    }
+                },
+            );
+
+            info!("Invalidation subscriber started across all tiers");
+        }
+    }
+
+    /// Get value from cache using multi-tier architecture (v0.5.0+)
+    ///
+    /// This method iterates through all configured tiers and automatically promotes
+    /// to upper tiers on cache hit.
+    async fn get_multi_tier(&self, key: &str) -> CacheResult<Option<Bytes>> {
+        self.get_multi_tier_from(key, 0).await
+    }
+
+    /// Read `key` from L1, applying the `L2MissPolicy` to hits
+    async fn get_l1(&self, l1: &CacheTier, key: &str) -> Option<(Bytes, Option<Duration>)> {
+        let hit = l1.get_with_ttl(key).await?;
+        if self.l2_miss_policy == L2MissPolicy::FlushL1
+            && self.l2_miss_sample_rate > 0.0
+            && (self.l2_miss_sample_rate >= 1.0
+                || rand::thread_rng().gen_bool(self.l2_miss_sample_rate))
+            && let Some(l2) = self.tiers.get(1)
+            && l2.get_with_ttl(key).await.is_none()
+        {
+            debug!("Flushing '{}' from L1: no longer in L2", key);
+            if let Err(e) = l1.remove(key).await {
+                warn!("Failed to flush '{}' from L1: {}", key, e);
+            }
+            return None;
+        }
+        self.sample_coherence(key, &hit.0).await;
+        self.adapt_ttl(key, hit.1).await;
+        let ttl = self.slide_expiration(key).await.or(hit.1);
+        Some((hit.0, ttl))
+    }
+
+    /// Record an L1 hit and extend the TTL of `key` if it is hot
+    ///
+    /// Doubles the remaining TTL, up to `max_ttl`, once it has fallen to half
+    /// of `max_ttl` or below, so a hot key is extended at most once per halving.
+    /// Only the expiry is updated (`touch`), and only in tiers already holding
+    /// the key: a newer value in a lower tier is never overwritten with the
+    /// L1 copy, and tiers the key was kept out of stay without it.
+    async fn adapt_ttl(&self, key: &str, remaining: Option<Duration>) {
+        let Some(adaptive) = &self.adaptive_ttl else {
+            return;
+        };
+        adaptive.sketch.increment(key);
+        let Some(remaining) = remaining else {
+            return;
+        };
+        let max_ttl = adaptive.config.max_ttl;
+        if remaining > max_ttl / 2 || adaptive.sketch.estimate(key) < adaptive.config.min_hits {
+            return;
+        }
+
+        let extended = remaining.saturating_mul(2).min(max_ttl);
+        let mut touched = false;
+        for tier in &self.tiers {
+            match tier.touch(key, extended).await {
+                Ok(found) => touched |= found,
+                Err(e) => warn!(
+                    "Failed to extend TTL of '{}' in L{}: {}",
+                    key, tier.tier_level, e
+                ),
+            }
+        }
+        if !touched {
+            return;
+        }
+        self.ttl_extensions.fetch_add(1, Ordering::Relaxed);
+        debug!(key = %key, ttl = ?extended, "Extended TTL of hot key");
+    }
+
+    /// Reset the TTL of `key` in every tier after a read (sliding expiration)
+    ///
+    /// Returns the new TTL, or `None` if sliding expiration is off or the key
+    /// reached its maximum lifetime.
+    async fn slide_expiration(&self, key: &str) -> Option<Duration> {
+        let ttl = self.sliding_expiration.as_ref()?.next_ttl(key)?;
+        for tier in &self.tiers {
+            if let Err(e) = tier.touch(key, ttl).await {
+                warn!(
+                    "Failed to reset TTL of '{}' in L{}: {}",
+                    key, tier.tier_level, e
+                );
+            }
+        }
+        Some(ttl)
+    }
+
+    /// Cross-check a sampled L1 hit against L2, counting disagreements
+    async fn sample_coherence(&self, key: &str, value: &Bytes) {
+        if self.coherence_sample_rate <= 0.0 {
+            return;
+        }
+        let Some(l2) = self.tiers.get(1) else {
+            return;
+        };
+        if !rand::thread_rng().gen_bool(self.coherence_sample_rate) {
+            return;
+        }
+        // Straight to the backend so the check doesn't show in tier stats
+        let confirmed = l2
+            .backend
+            .get_with_ttl(key)
+            .await
+            .is_some_and(|(l2_value, _)| l2_value == value);
+        if !confirmed {
+            self.stale_l1_hits.fetch_add(1, Ordering::Relaxed);
+            debug!("Stale L1 hit for '{}': L2 has no or another value", key);
+        }
+    }
+
+    /// Get value from cache starting from a specific tier index
+    async fn get_multi_tier_from(
+        &self,
+        key: &str,
+        start_index: usize,
+    ) -> CacheResult<Option<Bytes>> {
+        Ok(self
+            .get_multi_tier_indexed(key, start_index)
+            .await?
+            .map(|(value, _)| value))
+    }
+
+    /// Like `get_multi_tier_from`, also returning the index of the tier that hit
+    async fn get_multi_tier_indexed(
+        &self,
+        key: &str,
+        start_index: usize,
+    ) -> CacheResult<Option<(Bytes, usize)>> {
+        Ok(self
+            .read_tiers(key, start_index)
+            .await?
+            .map(|read| (read.value, read.tier_index)))
+    }
+
+    /// Read `key` from the tiers starting at `start_index`, promoting hits
+    async fn read_tiers(&self, key: &str, start_index: usize) -> CacheResult<Option<TierRead>> {
+        // Try each tier sequentially (sorted by tier_level) starting from start_index
+        for (tier_index, tier) in self.tiers.iter().enumerate().skip(start_index) {
+            let hit = if tier_index == 0 {
+                self.get_l1(tier, key).await
+            } else {
+                tier.get_with_ttl(key).await
+            };
+            if let Some((value, ttl)) = hit {
+                // Cache hit!
+                tier.record_hit();
+                // L1 hits already slid in `get_l1`
+                let ttl = if tier_index > 0 {
+                    self.slide_expiration(key).await.or(ttl)
+                } else {
+                    ttl
+                };
+                if tier.tier_level == 1 {
+                    self.record_stat(key, StatEvent::L1Hit);
+                } else if tier.tier_level == 2 {
+                    self.record_stat(key, StatEvent::L2Hit);
+                }
+
+                // Promote to all upper tiers (if promotion enabled)
+                let mut promoted = false;
+                if tier.promotion_enabled && tier_index > 0 {
+                    // Probabilistic Promotion Check
+                    let should_promote = if tier.promotion_frequency <= 1 {
+                        true
+                    } else {
+                        rand::thread_rng().gen_ratio(
+                            1,
+                            u32::try_from(tier.promotion_frequency).unwrap_or(u32::MAX),
+                        )
+                    };
+
+                    let claim = should_promote
+                        .then(|| PromotionClaim::acquire(self.in_flight_promotions.as_ref(), key))
+                        .flatten();
+
+                    if let Some(claim) = claim {
+                        promoted = true;
+                        let promotion_ttl = ttl.unwrap_or(self.strategy_durations.default);
+
+                        if let Some(queue) = &self.promotion_queue {
+                            queue.push(
+                                PendingPromotion {
+                                    key: key.to_string(),
+                                    value: value.clone(),
+                                    ttl: promotion_ttl,
+                                    source_index: tier_index,
+                                    claim,
+                                    queued_at: Instant::now(),
+                                },
+                                &self.tiers,
+                                &self.promotion_counters,
+                                self.spawner.as_ref(),
+                            );
+                        } else {
+                            promote(
+                                &self.tiers,
+                                tier_index,
+                                key,
+                                &value,
+                                promotion_ttl,
+                                &self.promotion_counters,
+                            )
+                            .await;
+                            drop(claim);
+                        }
+                    } else if should_promote {
+                        self.promotion_counters
+                            .deduplicated
+                            .fetch_add(1, Ordering::Relaxed);
+                        debug!("Promotion of '{}' already in flight, skipping", key);
+                    } else {
+                        debug!(
+                            "Probabilistic skip promotion for '{}' from L{} (N={})",
+                            key, tier.tier_level, tier.promotion_frequency
+                        );
+                    }
+                }
+
+                return Ok(Some(TierRead {
+                    value,
+                    tier_index,
+                    ttl,
+                    promoted,
+                }));
+            }
+        }
+
+        // Cache miss across all tiers
+        Ok(None)
+    }
+
+    /// Get value from cache (L1 first, then L2 fallback with promotion)
+    ///
+    /// This method now includes built-in Cache Stampede protection when cache misses occur.
+    /// Multiple concurrent requests for the same missing key will be coalesced to prevent
+    /// unnecessary duplicate work on external data sources.
+    ///
+    /// An L1 hit returns from a lock-free fast path: it never touches the
+    /// in-flight request map (see `CacheManagerStats::in_flight_lookups`).
+    ///
+    /// Supports both legacy 2-tier mode and new multi-tier mode (v0.5.0+).
+    ///
+    /// # Arguments
+    /// * `key` - Cache key to retrieve
+    ///
+    /// # Returns
+    /// * `Ok(Some(value))` - Cache hit, value found in any tier
+    /// * `Ok(None)` - Cache miss, value not found in any cache
+    /// * `Err(error)` - Cache operation failed
+    /// # Errors
+    ///
+    /// Returns an error if cache operation fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if tiers are not initialized in multi-tier mode (should not happen if constructed correctly).
+    pub async fn get(&self, key: &(impl CacheKey + ?Sized)) -> CacheResult<Option<Bytes>> {
+        let key: &str = &key.cache_key();
+        #[cfg(feature = "metrics-facade")]
+        let _timer = crate::metrics_facade::OperationTimer::start("get");
+        let key: &str = &self.storage_key(key);
+        self.record_stat(key, StatEvent::Request);
+
+        // Fast path for L1 (first tier) - no locking needed
+        if let Some(tier1) = self.tiers.first()
+            && let Some((value, _ttl)) = self.get_l1(tier1, key).await
+        {
+            tier1.record_hit();
+            // Update legacy stats for backward compatibility
+            self.record_stat(key, StatEvent::L1Hit);
+            return Ok(Some(value));
+        }
+
+        let key_owned = self.flight_key(key);
+        self.compute_counters
+            .in_flight_lookups
+            .fetch_add(1, Ordering::Relaxed);
+        let flight_state = match self.in_flight_requests.entry(key_owned.clone()) {
+            dashmap::mapref::entry::Entry::Occupied(entry) => {
+                FlightState::Waiter(entry.get().subscribe())
+            }
+            dashmap::mapref::entry::Entry::Vacant(entry) => {
+                let (tx, _) = watch::channel(None);
+                let tx = Arc::new(tx);
+                entry.insert(tx.clone());
+                FlightState::Creator(tx)
+            }
+        };
+
+        match flight_state {
+            FlightState::Waiter(rx) => {
+                // Return result if it exists, otherwise fall through to re-compute
+                if let Some(res) = self.wait_for_flight(key, rx).await {
+                    return res;
+                }
+            }
+            FlightState::Creator(tx) => {
+                // Creator - guard key removal on drop/cancellation
+                let _guard = RemoveInFlightGuard {
+                    map: Arc::clone(&self.in_flight_requests),
+                    key: key_owned,
+                };
+
+                // Double-check L1 after acquiring lock (or if we are the first to compute)
+                if let Some(tier1) = self.tiers.first()
+                    && let Some((value, _ttl)) = self.get_l1(tier1, key).await
+                {
+                    tier1.record_hit();
+                    self.record_stat(key, StatEvent::L1Hit);
+                    let _ = tx.send(Some(Ok(Some(value.clone())))); // Notify any waiting subscribers
+                    return Ok(Some(value));
+                }
+
+                // Check remaining tiers with promotion (start from tier index 1: L2)
+                let result = self.get_multi_tier_from(key, 1).await;
+
+                match &result {
+                    Ok(Some(val)) => {
+                        // Hit in L2+ tier - update legacy stats
+                        if self.tiers.len() >= 2 {
+                            self.record_stat(key, StatEvent::L2Hit);
+                        }
+                        let _ = tx.send(Some(Ok(Some(val.clone()))));
+                    }
+                    Ok(None) => {
+                        self.record_stat(key, StatEvent::Miss);
+                        let _ = tx.send(Some(Ok(None)));
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Some(Err(e.clone())));
+                    }
+                }
+
+                return result;
+            }
+        }
+
+        // If waiter fell through (creator dropped without sending), do direct fallback query
+        let result = self.get_multi_tier_from(key, 1).await;
+        if let Ok(Some(_)) = result {
+            if self.tiers.len() >= 2 {
+                self.record_stat(key, StatEvent::L2Hit);
+            }
+        } else if let Ok(None) = result {
+            self.record_stat(key, StatEvent::Miss);
+        }
+        result
+    }
+
+    /// Wait for the in-flight computation behind `rx` and return its result
+    ///
+    /// `None` if the creator was dropped without sending or the in-flight
+    /// wait timeout elapsed first.
+    async fn wait_for_flight(
+        &self,
+        key: &str,
+        mut rx: watch::Receiver<Option<CacheResult<Option<Bytes>>>>,
+    ) -> Option<CacheResult<Option<Bytes>>> {
+        let wait = async {
+            if rx.borrow().is_none() {
+                while rx.changed().await.is_ok() {
+                    if rx.borrow().is_some() {
+                        break;
+                    }
+                }
+            }
+            rx.borrow().clone()
+        };
+        let Some(timeout) = self.in_flight_wait_timeout else {
+            return wait.await;
+        };
+        if let Ok(result) = tokio::time::timeout(timeout, wait).await {
+            result
+        } else {
+            warn!(
+                "Timed out after {:?} waiting for in-flight request for key '{}'; proceeding independently",
+                timeout, key
+            );
+            None
+        }
+    }
+
+    /// Deserialize the value returned by a typed get-or-compute
+    fn decode_typed_result<T: serde::de::DeserializeOwned>(
+        &self,
+        key: &str,
+        bytes: &[u8],
+    ) -> CacheResult<T> {
+        self.decode_value(key, bytes)?.ok_or_else(|| {
+            crate::error::CacheError::SerializationError(format!(
+                "Codec mismatch for key '{key}' (expected {})",
+                self.serializer.name()
+            ))
+        })
+    }
+
+    /// Get a value from cache and deserialize it (Type-Safe Version)
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SerializationError` if deserialization fails, or a `BackendError` if the cache retrieval fails.
+    pub async fn get_typed<T>(&self, key: &(impl CacheKey + ?Sized)) -> CacheResult<Option<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let key: &str = &key.cache_key();
+        if let Some(bytes) = self.get(key).await? {
+            return self.decode_cached(key, &bytes).await;
+        }
+        Ok(None)
+    }
+
+    /// Get a value whose deserialization is deferred until it is used
+    ///
+    /// Reads like `get` and returns the raw bytes in a `LazyValue`; call
+    /// `LazyValue::deserialize` once the value is actually needed. Useful when
+    /// fetching several candidate keys but using only one of them. Unlike
+    /// `get_typed`, an undecodable value is not handled by the
+    /// `UndecodablePolicy`; the error surfaces from `deserialize`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use multi_tier_cache::CacheManager;
+    /// # async fn example(cache_manager: &CacheManager) -> anyhow::Result<()> {
+    /// let mut first = None;
+    /// for key in ["banner:user:42", "banner:segment:7", "banner:default"] {
+    ///     if let Some(lazy) = cache_manager.get_lazy(key).await? {
+    ///         first = Some(lazy);
+    ///         break;
+    ///     }
+    /// }
+    /// let banner: Option<String> = first.map(|lazy| lazy.deserialize()).transpose()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// # Errors
+    ///
+    /// Returns an error if the cache read fails.
+    pub async fn get_lazy(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+    ) -> CacheResult<Option<LazyValue<'_>>> {
+        let key: &str = &key.cache_key();
+        Ok(self.get(key).await?.map(|bytes| LazyValue {
+            manager: self,
+            key: key.to_string(),
+            bytes,
+        }))
+    }
+
+    /// Get a typed value along with how the read was served
+    ///
+    /// Reports the tier that served the value, whether the hit was promoted to
+    /// the upper tiers and the remaining TTL, for tuning promotion. Reads and
+    /// promotes like `get` but without coalescing concurrent misses, so `get`
+    /// itself carries none of this bookkeeping.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache read or deserialization fails.
+    pub async fn get_tracked<T>(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+    ) -> CacheResult<(Option<T>, GetOutcome)>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let key: &str = &key.cache_key();
+        let storage_key = self.storage_key(key);
+        self.record_stat(&storage_key, StatEvent::Request);
+        let Some(read) = self.read_tiers(&storage_key, 0).await? else {
+            self.record_stat(&storage_key, StatEvent::Miss);
+            let outcome = GetOutcome {
+                served_by: None,
+                promoted: false,
+                remaining_ttl: None,
+            };
+            return Ok((None, outcome));
+        };
+        let outcome = GetOutcome {
+            served_by: Some(self.tier_source(read.tier_index)),
+            promoted: read.promoted,
+            remaining_ttl: read.ttl,
+        };
+        Ok((self.decode_cached(key, &read.value).await?, outcome))
+    }
+
+    /// Cache a typed value together with a new version
+    ///
+    /// Versions come from a per-key counter in L2 (Redis key
+    /// `mtc:version:<key>`, without TTL), so they strictly increase across
+    /// every instance sharing it regardless of clock skew. The counter is
+    /// advanced to at least the current time in microseconds since the Unix
+    /// epoch. Without an L2 tier, or if the L2 backend has no shared counters,
+    /// versions are taken from this manager's clock and are only ordered
+    /// within this process. Read it back with `get_if_newer` (or
+    /// `get_versioned`); `get_typed` doesn't understand the stored envelope.
+    /// Returns the new version.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization, advancing the L2 version counter or
+    /// every tier write fails.
+    pub async fn set_versioned<T: serde::Serialize>(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        value: &T,
+        strategy: impl Into<CacheStrategy>,
+    ) -> CacheResult<u64> {
+        let key: &str = &key.cache_key();
+        let version = self.next_version(key).await?;
+        let bytes = self.encode_value(&Versioned { version, value })?;
+        self.set_with_strategy(key, bytes, strategy).await?;
+        Ok(version)
+    }
+
+    /// Get a value written by `set_versioned` with its version
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache read or deserialization fails.
+    pub async fn get_versioned<T>(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+    ) -> CacheResult<Option<(T, u64)>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let key: &str = &key.cache_key();
+        let Some(bytes) = self.get(key).await? else {
+            return Ok(None);
+        };
+        Ok(self
+            .decode_cached::<Versioned<T>>(key, &bytes)
+            .await?
+            .map(|versioned| (versioned.value, versioned.version)))
+    }
+
+    /// Get a value written by `set_versioned` unless the client already has it
+    ///
+    /// For conditional responses (HTTP `ETag` / `If-None-Match`): returns
+    /// `NotModified` if the cached version is not newer than `client_version`,
+    /// otherwise the value and its version. Pass 0 if the client has nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache read or deserialization fails.
+    pub async fn get_if_newer<T>(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        client_version: u64,
+    ) -> CacheResult<ConditionalResult<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let key: &str = &key.cache_key();
+        Ok(match self.get_versioned(key).await? {
+            Some((value, version)) if version > client_version => {
+                ConditionalResult::Modified(value, version)
+            }
+            Some(_) => ConditionalResult::NotModified,
+            None => ConditionalResult::Missing,
+        })
+    }
+
+    /// Next version for `set_versioned`: the L2 counter of `key` if there is
+    /// one, otherwise the current time, strictly increasing within this manager
+    async fn next_version(&self, key: &str) -> CacheResult<u64> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| {
+                u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX)
+            });
+        if let Some(tier) = self.tiers.get(1) {
+            let storage_key = self.storage_key(key);
+            if let Some(version) = tier.backend.next_version(&storage_key, now).await? {
+                return Ok(version);
+            }
+        }
+        let previous = self
+            .last_version
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |last| {
+                Some(now.max(last.saturating_add(1)))
+            })
+            .unwrap_or(0);
+        Ok(now.max(previous.saturating_add(1)))
+    }
+
+    /// Get typed values with their remaining TTL and source tier, for inspection
+    ///
+    /// Meant for admin views listing cached entries. Each tier is read once
+    /// for all keys not found in the tiers above it (Redis pipelines the
+    /// `GET` / `TTL` pairs), so the call costs one round trip per tier. It is
+    /// read-only: nothing is promoted or computed and request statistics are
+    /// untouched. Results are in the order of `keys`, `None` for misses.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a cached value can't be deserialized (see
+    /// `with_undecodable_policy`).
+    pub async fn get_batch_with_metadata<T>(
+        &self,
+        keys: &[&str],
+    ) -> CacheResult<Vec<Option<CacheHit<T>>>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let storage_keys: Vec<String> = keys
+            .iter()
+            .map(|key| self.storage_key(key).into_owned())
+            .collect();
+        let found = self.read_tiers_batched(&storage_keys).await;
+
+        let mut hits = Vec::with_capacity(keys.len());
+        for (key, entry) in keys.iter().zip(found) {
+            let hit = match entry {
+                Some((bytes, ttl, tier_index)) => {
+                    self.decode_cached::<T>(key, &bytes)
+                        .await?
+                        .map(|value| CacheHit {
+                            value,
+                            ttl,
+                            source: self.tier_source(tier_index),
+                        })
+                }
+                None => None,
+            };
+            hits.push(hit);
+        }
+        Ok(hits)
+    }
+
+    /// Get several typed values, each with its own result
+    ///
+    /// Each tier is read once for the keys not found in the tiers above it, as in
+    /// `get_batch_with_metadata`. Failures are isolated per key: a value that
+    /// can't be deserialized yields an `Err` in its own slot while the other keys
+    /// still return. A batched tier read that fails reports its keys as misses,
+    /// so they are looked up in the next tier. Hits from lower tiers are
+    /// promoted as in `get`. Results are in the order of `keys`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use multi_tier_cache::CacheManager;
+    /// # async fn example(cache_manager: &CacheManager) {
+    /// for (key, result) in ["user:1", "user:2"]
+    ///     .iter()
+    ///     .zip(cache_manager.get_many::<String>(&["user:1", "user:2"]).await)
+    /// {
+    ///     match result {
+    ///         Ok(Some(name)) => println!("{key}: {name}"),
+    ///         Ok(None) => println!("{key}: not cached"),
+    ///         Err(e) => eprintln!("{key}: {e}"),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_many<T>(&self, keys: &[&str]) -> Vec<CacheResult<Option<T>>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let storage_keys: Vec<String> = keys
+            .iter()
+            .map(|key| self.storage_key(key).into_owned())
+            .collect();
+        let found = self.read_tiers_batched(&storage_keys).await;
+
+        let mut results = Vec::with_capacity(keys.len());
+        for ((key, storage_key), entry) in keys.iter().zip(&storage_keys).zip(found) {
+            self.record_stat(storage_key, StatEvent::Request);
+            let Some((bytes, ttl, tier_index)) = entry else {
+                self.record_stat(storage_key, StatEvent::Miss);
+                results.push(Ok(None));
+                continue;
+            };
+            if let Some(tier) = self.tiers.get(tier_index) {
+                tier.record_hit();
+                if tier.tier_level == 1 {
+                    self.record_stat(storage_key, StatEvent::L1Hit);
+                } else if tier.tier_level == 2 {
+                    self.record_stat(storage_key, StatEvent::L2Hit);
+                }
+                if tier.promotion_enabled && tier_index > 0 {
+                    promote(
+                        &self.tiers,
+                        tier_index,
+                        storage_key,
+                        &bytes,
+                        ttl.unwrap_or(self.strategy_durations.default),
+                        &self.promotion_counters,
+                    )
+                    .await;
+                }
+            }
+            results.push(self.decode_cached::<T>(key, &bytes).await);
+        }
+        results
+    }
+
+    /// Read `storage_keys` tier by tier, one batch per tier for the keys still missing
+    ///
+    /// Returns the value, remaining TTL and tier index of each key, in order.
+    async fn read_tiers_batched(
+        &self,
+        storage_keys: &[String],
+    ) -> Vec<Option<(Bytes, Option<Duration>, usize)>> {
+        let mut found: Vec<Option<(Bytes, Option<Duration>, usize)>> =
+            vec![None; storage_keys.len()];
+        let mut pending: Vec<usize> = (0..storage_keys.len()).collect();
+
+        for (tier_index, tier) in self.tiers.iter().enumerate() {
+            if pending.is_empty() {
+                break;
+            }
+            let batch: Vec<String> = pending
+                .iter()
+                .filter_map(|&i| storage_keys.get(i).cloned())
+                .collect();
+            let values = tier.get_many_with_ttl(&batch).await;
+            let mut still_pending = Vec::new();
+            for (&i, value) in pending.iter().zip(values) {
+                match (value, found.get_mut(i)) {
+                    (Some((bytes, ttl)), Some(slot)) => *slot = Some((bytes, ttl, tier_index)),
+                    _ => still_pending.push(i),
+                }
+            }
+            pending = still_pending;
+        }
+        found
+    }
+
+    /// Read and remove a typed value in one step ("consume once")
+    ///
+    /// Intended for one-time tokens, job claims and similar values that only one
+    /// consumer may receive. Upper tiers (L1, ...) are cleared first, then the value
+    /// is claimed from the last tier with `CacheBackend::get_and_remove()`. The
+    /// shared last tier is the only source of truth: a copy left in an upper tier
+    /// is dropped but never returned, since another instance may already have
+    /// taken the value. If invalidation is enabled, other instances are told to
+    /// drop their copies; a failed broadcast is logged and doesn't lose the
+    /// claimed value.
+    ///
+    /// # Atomicity
+    ///
+    /// Only one caller can take a value if the last tier's backend implements
+    /// `get_and_remove` atomically: Redis (`GETDEL`, or a Lua script on servers
+    /// before 6.2), `DashMap`, Moka and `QuickCache` do. Memcached and custom backends
+    /// without an override fall back to a racy `get` + `remove`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the last tier fails, or the value can't be deserialized.
+    pub async fn take<T>(&self, key: &(impl CacheKey + ?Sized)) -> CacheResult<Option<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let key: &str = &key.cache_key();
+        let key: &str = &self.storage_key(key);
+        let Some((last_tier, upper_tiers)) = self.tiers.split_last() else {
+            return Ok(None);
+        };
+        self.log_write(key);
+
+        // Drop local copies so this instance can't serve the value again
+        for tier in upper_tiers {
+            if let Err(e) = tier.remove(key).await {
+                warn!(
+                    "Failed to remove '{}' from L{}: {}",
+                    key, tier.tier_level, e
+                );
+            }
+        }
+
+        let Some(bytes) = last_tier.get_and_remove(key).await? else {
+            return Ok(None);
+        };
+        last_tier.record_read(&bytes);
+        debug!("Took '{}' from L{}", key, last_tier.tier_level);
+
+        if let Err(e) = self.broadcast_remove(key).await {
+            warn!("Failed to broadcast removal of taken key '{}': {}", key, e);
+        }
+        self.decode_value(key, &bytes)
     }
 
     /// Set value with specific cache strategy (all tiers)
     ///
-    /// Supports both legacy 2-tier mode and new multi-tier mode (v0.5.0+).
-    /// In multi-tier mode, stores to ALL tiers with their respective TTL scaling.
+    /// Supports both legacy 2-tier mode and new multi-tier mode (v0.5.0+).
+    /// In multi-tier mode, stores to ALL tiers with their respective TTL scaling.
+    /// # Errors
+    ///
+    /// Returns an error if cache set operation fails.
+    pub async fn set_with_strategy(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        value: Bytes,
+        strategy: impl Into<CacheStrategy>,
+    ) -> CacheResult<()> {
+        let key: &str = &key.cache_key();
+        self.store_in_tiers(key, value, strategy.into(), TierMask::ALL, false)
+            .await
+    }
+
+    /// Store a typed value with per-write options
+    ///
+    /// Writes like `set_with_strategy`, serializing `value` with the
+    /// configured serializer. `opts` overrides settings for this write only,
+    /// e.g. forcing compression of an image below the compression threshold,
+    /// or skipping it for an already-compressed payload.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use multi_tier_cache::{CacheManager, CacheStrategy, SetOpts};
+    /// # use multi_tier_cache::serialization::CompressionMode;
+    /// # async fn example(cache_manager: &CacheManager, gzipped: Vec<u8>) -> anyhow::Result<()> {
+    /// cache_manager
+    ///     .set_with_strategy_opts(
+    ///         "report:2024",
+    ///         &gzipped,
+    ///         CacheStrategy::LongTerm,
+    ///         SetOpts::default().with_compression(CompressionMode::Never),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// # Errors
+    ///
+    /// Returns an error if serialization or the write fails, or if `opts`
+    /// forces compression and no value compression is configured.
+    pub async fn set_with_strategy_opts<T: serde::Serialize>(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        value: &T,
+        strategy: impl Into<CacheStrategy>,
+        opts: SetOpts,
+    ) -> CacheResult<()> {
+        let key: &str = &key.cache_key();
+        let bytes = self.encode_value_with(value, opts.compression)?;
+        self.set_with_strategy(key, bytes, strategy).await
+    }
+
+    /// Set value with specific cache strategy, failing if any tier fails
+    ///
+    /// `set_with_strategy` succeeds as long as one tier stored the value, so a
+    /// failed L2 write is only logged. This variant returns the tier's error
+    /// instead, for writes that must reach the shared tiers. The
+    /// `L2WriteFailurePolicy` is still applied to the L1 copy before the error
+    /// is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails on any tier.
+    pub async fn set_with_strategy_strict(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        value: Bytes,
+        strategy: impl Into<CacheStrategy>,
+    ) -> CacheResult<()> {
+        let key: &str = &key.cache_key();
+        self.store_in_tiers(key, value, strategy.into(), TierMask::ALL, true)
+            .await
+    }
+
+    /// Write `value` to the tiers in `tiers`; with `strict`, any tier failure is an error
+    async fn store_in_tiers(
+        &self,
+        key: &str,
+        value: Bytes,
+        strategy: CacheStrategy,
+        tiers: TierMask,
+        strict: bool,
+    ) -> CacheResult<()> {
+        #[cfg(feature = "metrics-facade")]
+        let _timer = crate::metrics_facade::OperationTimer::start("set");
+        let key: &str = &self.storage_key(key);
+        if self.reject_value(key, &value) {
+            return Ok(());
+        }
+        self.log_write(key);
+        let ttl = self.strategy_durations.duration(&strategy);
+        let value_len = value.len();
+
+        let mut attempted = 0;
+        let mut success_count = 0;
+        let mut last_error = None;
+        let mut l1_stored = false;
+        let mut lower_tier_failed = false;
+
+        for (index, tier) in self.tiers.iter().enumerate() {
+            if !tiers.contains(tier.tier_level) {
+                continue;
+            }
+            attempted += 1;
+            match tier.set_with_ttl(key, value.clone(), ttl).await {
+                Ok(()) => {
+                    success_count += 1;
+                    l1_stored |= index == 0;
+                }
+                Err(e) => {
+                    error!(
+                        "L{} cache set failed for key '{}': {}",
+                        tier.tier_level, key, e
+                    );
+                    lower_tier_failed |= index > 0;
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        if l1_stored
+            && lower_tier_failed
+            && let Some(l1) = self.tiers.first()
+        {
+            match self.l2_write_failure_policy {
+                L2WriteFailurePolicy::KeepL1 => {}
+                L2WriteFailurePolicy::EvictL1 => match l1.remove(key).await {
+                    Ok(()) => {
+                        success_count -= 1;
+                        warn!("Evicted '{}' from L1 after a lower tier write failed", key);
+                    }
+                    Err(e) => warn!("Failed to evict '{}' from L1: {}", key, e),
+                },
+                L2WriteFailurePolicy::ShortenL1Ttl(short_ttl) => {
+                    let short_ttl = short_ttl.min(ttl);
+                    match l1.set_with_ttl(key, value, short_ttl).await {
+                        Ok(()) => warn!(
+                            "Shortened L1 TTL of '{}' to {:?} after a lower tier write failed",
+                            key, short_ttl
+                        ),
+                        Err(e) => warn!("Failed to shorten L1 TTL of '{}': {}", key, e),
+                    }
+                }
+            }
+        }
+
+        if strict && let Some(e) = last_error {
+            return Err(e);
+        }
+
+        if attempted == 0 {
+            debug!("[Cache] No selected tier for '{}', nothing stored", key);
+            return Ok(());
+        }
+
+        if success_count > 0 {
+            self.record_stat(key, StatEvent::Set);
+            self.audit_value_size(key, value_len);
+            self.enforce_memory_limit().await;
+            debug!(
+                "[Cache] Stored '{}' in {}/{} tiers (base TTL: {:?})",
+                key, success_count, attempted, ttl
+            );
+            return Ok(());
+        }
+
+        Err(last_error.unwrap_or_else(|| {
+            crate::error::CacheError::InternalError("All tiers failed".to_string())
+        }))
+    }
+
+    /// Set a value and wait until L2 has replicated it
+    ///
+    /// Writes like `set_with_strategy_strict`, so a failed L2 write is an error
+    /// even when L1 kept the value, then asks the L2 backend to confirm the
+    /// write reached at least `replicas` replicas (Redis `WAIT`). Only Redis
+    /// supports this; the wait adds up to `timeout` of latency per call, so
+    /// reserve it for writes that must survive a primary failover.
+    ///
+    /// Manual test against a primary with one replica:
+    ///
+    /// ```text
+    /// redis-server --port 6379 &
+    /// redis-server --port 6380 --replicaof 127.0.0.1 6379 &
+    /// # set_durable(key, value, strategy, 1, 100ms) -> Ok
+    /// redis-cli -p 6380 shutdown nosave
+    /// # set_durable(key, value, strategy, 1, 100ms) -> Err after ~100ms
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails, there is no L2 tier, the L2
+    /// backend can't wait for replicas, or fewer than `replicas` replicas
+    /// acknowledged the write within `timeout`. The value stays cached in the
+    /// latter cases.
+    pub async fn set_durable(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        value: Bytes,
+        strategy: impl Into<CacheStrategy>,
+        replicas: usize,
+        timeout: Duration,
+    ) -> CacheResult<()> {
+        let key: &str = &key.cache_key();
+        let Some(l2) = self.tiers.get(1) else {
+            return Err(CacheError::ConfigError(
+                "set_durable requires an L2 tier".to_string(),
+            ));
+        };
+        self.set_with_strategy_strict(key, value, strategy).await?;
+
+        let acknowledged = l2.backend.wait_for_replicas(replicas, timeout).await?;
+        if acknowledged < replicas {
+            return Err(CacheError::BackendError(format!(
+                "Only {acknowledged}/{replicas} replicas acknowledged '{key}' within {timeout:?}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Apply `transaction` atomically on the lowest tier
+    ///
+    /// All writes land together or not at all, and preconditions added with
+    /// `Transaction::expect` are checked first (`Ok(false)`: one didn't hold,
+    /// nothing was written). Keys and TTLs go through the same key
+    /// canonicalization and tier TTL limits as other writes; values are
+    /// stored as given, like `set_with_strategy`.
+    ///
+    /// Only the lowest tier (L2 in a 2-tier setup) runs the transaction, and
+    /// it must support them: this is Redis-only (`RedisCache`, or a sharded
+    /// backend when all keys live on one shard). Upper tiers of this instance
+    /// are kept consistent by dropping every written key from them afterwards,
+    /// so the next read fetches the committed value. Copies in other
+    /// instances' L1 aren't invalidated (an invalidation broadcast would also
+    /// delete the committed L2 values) and expire with their TTL.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use multi_tier_cache::{Bytes, CacheManager, Transaction};
+    /// # use std::time::Duration;
+    /// # async fn example(cache_manager: &CacheManager) -> anyhow::Result<()> {
+    /// let ttl = Duration::from_secs(300);
+    /// let tx = Transaction::new()
+    ///     .expect("seat:12", None)
+    ///     .set("seat:12", Bytes::from("alice"), ttl)
+    ///     .set("booking:alice", Bytes::from("12"), ttl);
+    /// let booked = cache_manager.execute_transaction(tx).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no tier, the lowest tier doesn't support
+    /// transactions, or the transaction fails. A failed transaction may have
+    /// been applied if the connection dropped, so written keys are dropped
+    /// from the upper tiers in that case too.
+    pub async fn execute_transaction(&self, transaction: Transaction) -> CacheResult<bool> {
+        let Some(last_tier) = self.tiers.last() else {
+            return Err(CacheError::ConfigError(
+                "Transactions require a cache tier".to_string(),
+            ));
+        };
+        let transaction = transaction.map(
+            |key| self.storage_key(key).into_owned(),
+            |ttl| last_tier.tier_ttl(ttl),
+        );
+        self.run_transaction(&transaction).await
+    }
+
+    /// Atomically swap the cached values of `key_a` and `key_b`
+    ///
+    /// Each value keeps its remaining TTL; a missing key makes the other one
+    /// missing too. Returns `false` without changing anything if either key
+    /// was written between reading and swapping, so callers can retry. Same
+    /// backend requirements and L1 handling as `execute_transaction()`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `execute_transaction()`.
+    pub async fn swap(&self, key_a: &str, key_b: &str) -> CacheResult<bool> {
+        let Some(last_tier) = self.tiers.last() else {
+            return Err(CacheError::ConfigError(
+                "Transactions require a cache tier".to_string(),
+            ));
+        };
+        let key_a = self.storage_key(key_a).into_owned();
+        let key_b = self.storage_key(key_b).into_owned();
+        let value_a = last_tier.backend.get_with_ttl(&key_a).await;
+        let value_b = last_tier.backend.get_with_ttl(&key_b).await;
+
+        let transaction = Transaction::new()
+            .expect(&key_a, value_a.as_ref().map(|(value, _)| value.clone()))
+            .expect(&key_b, value_b.as_ref().map(|(value, _)| value.clone()));
+        let transaction = match value_b {
+            Some((value, ttl)) => transaction.set(&key_a, value, ttl.unwrap_or(MAX_TIER_TTL)),
+            None => transaction.remove(&key_a),
+        };
+        let transaction = match value_a {
+            Some((value, ttl)) => transaction.set(&key_b, value, ttl.unwrap_or(MAX_TIER_TTL)),
+            None => transaction.remove(&key_b),
+        };
+        self.run_transaction(&transaction).await
+    }
+
+    /// Run a transaction (with storage keys) on the lowest tier, then drop
+    /// its written keys from the upper tiers
+    async fn run_transaction(&self, transaction: &Transaction) -> CacheResult<bool> {
+        let Some((last_tier, upper_tiers)) = self.tiers.split_last() else {
+            return Err(CacheError::ConfigError(
+                "Transactions require a cache tier".to_string(),
+            ));
+        };
+        for write in transaction.writes() {
+            self.log_write(write.key());
+        }
+
+        let result = last_tier.backend.execute_transaction(transaction).await;
+        if !matches!(result, Ok(false)) {
+            for tier in upper_tiers {
+                for write in transaction.writes() {
+                    if let Err(e) = tier.remove(write.key()).await {
+                        warn!(
+                            "Failed to remove '{}' from L{} after transaction: {}",
+                            write.key(),
+                            tier.tier_level,
+                            e
+                        );
+                    }
+                }
+            }
+        }
+        debug!(
+            writes = transaction.writes().len(),
+            "[Cache] Transaction result: {:?}", result
+        );
+        result
+    }
+
+    /// Replace the cached value of `key` only if it already exists
+    ///
+    /// The lowest tier (L2 in a 2-tier setup) decides whether the key exists,
+    /// atomically where the backend supports it (Redis `SET ... XX`, in-memory
+    /// backends lock the entry). Upper tiers are only written if the value was
+    /// replaced, so a key that is no longer cached is never re-created.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or the lowest tier's write fails.
+    pub async fn set_if_present<T: serde::Serialize>(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        value: &T,
+        ttl: Duration,
+    ) -> CacheResult<bool> {
+        let key: &str = &key.cache_key();
+        let key: &str = &self.storage_key(key);
+        let bytes = self.encode_value(value)?;
+        if self.reject_value(key, &bytes) {
+            return Ok(false);
+        }
+        let Some((last_tier, upper_tiers)) = self.tiers.split_last() else {
+            return Ok(false);
+        };
+        self.log_write(key);
+
+        if !last_tier.set_if_present(key, bytes.clone(), ttl).await? {
+            debug!(
+                "Skipped set of '{}': not present in L{}",
+                key, last_tier.tier_level
+            );
+            return Ok(false);
+        }
+        for tier in upper_tiers {
+            if let Err(e) = tier.set_with_ttl(key, bytes.clone(), ttl).await {
+                // Don't leave the old value behind in this tier
+                warn!("Failed to update '{}' in L{}: {}", key, tier.tier_level, e);
+                let _ = tier.remove(key).await;
+            }
+        }
+        self.record_stat(key, StatEvent::Set);
+        Ok(true)
+    }
+
+    /// Like `get`, but gives up once `deadline` passes
+    ///
+    /// Lets a caller with a per-request budget bound a slow L2 instead of
+    /// relying on a fixed backend timeout. If the deadline has already passed
+    /// no tier is queried. What's returned on expiry depends on the
+    /// `DeadlinePolicy` (see `with_deadline_policy`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CacheError::Timeout` past the deadline (with the default
+    /// policy), or an error if a cache operation fails.
+    pub async fn get_until(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        deadline: tokio::time::Instant,
+    ) -> CacheResult<Option<Bytes>> {
+        let key: &str = &key.cache_key();
+        Ok(self.until(key, deadline, self.get(key)).await?.flatten())
+    }
+
+    /// Like `get_or_compute_with`, but gives up once `deadline` passes
+    ///
+    /// The deadline covers the lower tier lookups and `compute_fn`. A compute
+    /// cut short by the deadline is dropped (so nothing is cached); concurrent
+    /// callers waiting on it recompute. Returns `Ok(None)` past the deadline
+    /// with `DeadlinePolicy::Miss`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CacheError::Timeout` past the deadline (with the default
+    /// policy), or an error if `compute_fn` or a cache operation fails.
+    pub async fn get_or_compute_until<F, Fut>(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        strategy: impl Into<CacheStrategy>,
+        deadline: tokio::time::Instant,
+        compute_fn: F,
+    ) -> CacheResult<Option<Bytes>>
+    where
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = CacheResult<Bytes>> + Send,
+    {
+        let key: &str = &key.cache_key();
+        let lookup = self.get_or_compute_with(key, strategy, compute_fn);
+        self.until(key, deadline, lookup).await
+    }
+
+    /// Run `operation` unless `deadline` passes first, applying the deadline policy
+    async fn until<T>(
+        &self,
+        key: &str,
+        deadline: tokio::time::Instant,
+        operation: impl Future<Output = CacheResult<T>>,
+    ) -> CacheResult<Option<T>> {
+        // Checked up front: in-memory tiers complete without yielding, so
+        // `timeout_at` alone would never fire for them
+        let result = if tokio::time::Instant::now() >= deadline {
+            None
+        } else {
+            tokio::time::timeout_at(deadline, operation).await.ok()
+        };
+        if let Some(value) = result {
+            return value.map(Some);
+        }
+
+        debug!("Deadline exceeded for key '{}'", key);
+        match self.deadline_policy {
+            DeadlinePolicy::Error => Err(CacheError::Timeout(format!(
+                "deadline exceeded for key '{key}'"
+            ))),
+            DeadlinePolicy::Miss => Ok(None),
+        }
+    }
+
+    /// Get or compute value with Cache Stampede protection across L1+L2+Compute
+    ///
+    /// This method provides comprehensive Cache Stampede protection:
+    /// 1. Check L1, then L2; a hit returns without touching the in-flight
+    ///    request map
+    /// 2. On a miss, coalesce concurrent callers on the same key and re-check
+    /// 3. Compute fresh data with protection against concurrent computations
+    ///
+    /// # Fairness
+    ///
+    /// Coalesced requests don't queue on a lock: they all subscribe to one
+    /// broadcast channel of the in-flight request and are woken together when
+    /// its result is sent. No waiter can be starved by others, so a waiter's
+    /// latency is the remaining compute time plus wake-up overhead (bounded
+    /// further by `with_in_flight_wait_timeout`).
+    ///
+    /// # Arguments
+    /// * `key` - Cache key
+    /// * `strategy` - Cache strategy for TTL and storage behavior
+    /// * `compute_fn` - Async function to compute the value if not in any cache
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use multi_tier_cache::{CacheManager, CacheStrategy, L1Cache, L2Cache, MokaCacheConfig};
+    /// # use std::sync::Arc;
+    /// # let l1 = Arc::new(L1Cache::new(MokaCacheConfig::default())?);
+    /// # let l2 = Arc::new(L2Cache::new().await?);
+    /// # let cache_manager = CacheManager::new(l1, l2).await?;
+    /// # async fn fetch_data_from_api() -> Result<bytes::Bytes, multi_tier_cache::error::CacheError> { Ok(bytes::Bytes::new()) }
+    /// let api_data = cache_manager.get_or_compute_with(
+    ///     "api_response",
+    ///     CacheStrategy::RealTime,
+    ///     || async {
+    ///         fetch_data_from_api().await
+    ///     }
+    /// ).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(dead_code)]
+    /// # Errors
+    ///
+    /// Returns an error if compute function fails or cache operations fail.
+    pub async fn get_or_compute_with<F, Fut>(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        strategy: impl Into<CacheStrategy>,
+        compute_fn: F,
+    ) -> CacheResult<Bytes>
+    where
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = CacheResult<Bytes>> + Send,
+    {
+        let key: &str = &key.cache_key();
+        self.get_or_compute_checked(key, strategy.into(), compute_fn, None)
+            .await
+    }
+
+    /// Like `get_or_compute_with`, but stores a computed value only in `tiers`
+    ///
+    /// For values that are cheap to recompute but large: `TierMask::L1_ONLY`
+    /// keeps them in memory and out of Redis. Lookups still check every tier,
+    /// and a value later found in a lower tier is promoted as usual.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if compute function fails or cache operations fail.
+    pub async fn get_or_compute_in_tiers<F, Fut>(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        strategy: impl Into<CacheStrategy>,
+        tiers: TierMask,
+        compute_fn: F,
+    ) -> CacheResult<Bytes>
+    where
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = CacheResult<Bytes>> + Send,
+    {
+        let key: &str = &key.cache_key();
+        let strategy = strategy.into();
+        self.get_or_compute_sourced(
+            key,
+            tiers,
+            || async move { Ok((compute_fn().await?, strategy)) },
+            None,
+        )
+        .await
+        .map(|(value, _)| value)
+    }
+
+    /// Stampede-protected get-or-compute shared by the raw and typed APIs
+    ///
+    /// Cached values failing `check_value` (the codec guard, decryption or
+    /// decoding) are treated as misses (and overwritten by the computed value).
+    async fn get_or_compute_checked<F, Fut>(
+        &self,
+        key: &str,
+        strategy: CacheStrategy,
+        compute_fn: F,
+        check_value: Option<ValueCheck>,
+    ) -> CacheResult<Bytes>
+    where
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = CacheResult<Bytes>> + Send,
+    {
+        self.get_or_compute_sourced(
+            key,
+            TierMask::ALL,
+            || async move { Ok((compute_fn().await?, strategy)) },
+            check_value,
+        )
+        .await
+        .map(|(value, _)| value)
+    }
+
+    /// `get_or_compute_checked`, also reporting where the value came from
+    ///
+    /// `compute_fn` returns the value together with the strategy to cache it
+    /// under; a computed value is stored in the tiers selected by `tiers`.
+    async fn get_or_compute_sourced<F, Fut>(
+        &self,
+        key: &str,
+        tiers: TierMask,
+        compute_fn: F,
+        check_value: Option<ValueCheck>,
+    ) -> CacheResult<(Bytes, CacheSource)>
+    where
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = CacheResult<(Bytes, CacheStrategy)>> + Send,
+    {
+        let key: &str = &self.storage_key(key);
+        self.record_stat(key, StatEvent::Request);
+
+        // 1. Try tiers sequentially first
+        if let Some((value, tier_index)) = self.get_multi_tier_indexed(key, 0).await?
+            && check_value.is_none_or(|check| check(self, key, &value))
+        {
+            return Ok((value, self.tier_source(tier_index)));
+        }
+
+        let key_owned = self.flight_key(key);
+        self.compute_counters
+            .in_flight_lookups
+            .fetch_add(1, Ordering::Relaxed);
+        let flight_state = match self.in_flight_requests.entry(key_owned.clone()) {
+            dashmap::mapref::entry::Entry::Occupied(entry) => {
+                FlightState::Waiter(entry.get().subscribe())
+            }
+            dashmap::mapref::entry::Entry::Vacant(entry) => {
+                let (tx, _) = watch::channel(None);
+                let tx = Arc::new(tx);
+                entry.insert(tx.clone());
+                FlightState::Creator(tx)
+            }
+        };
+
+        match flight_state {
+            FlightState::Waiter(rx) => {
+                // Return result if it exists, otherwise fall through to re-compute
+                if let Some(res) = self.wait_for_flight(key, rx).await {
+                    match res {
+                        Ok(Some(bytes)) => {
+                            self.compute_counters
+                                .coalesced
+                                .fetch_add(1, Ordering::Relaxed);
+                            return Ok((bytes, CacheSource::Computed));
+                        }
+                        Ok(None) => {} // Miss, fall through to re-compute
+                        Err(e) => return Err(e),
+                    }
+                }
+            }
+            FlightState::Creator(tx) => {
+                // Guard key removal on drop/cancellation
+                let _guard = RemoveInFlightGuard {
+                    map: Arc::clone(&self.in_flight_requests),
+                    key: key_owned,
+                };
+
+                // 3. Re-check cache after receiving/creating broadcaster (double-check pattern)
+                if let Some((value, tier_index)) = self.get_multi_tier_indexed(key, 0).await?
+                    && check_value.is_none_or(|check| check(self, key, &value))
+                {
+                    let _ = tx.send(Some(Ok(Some(value.clone()))));
+                    return Ok((value, self.tier_source(tier_index)));
+                }
+
+                // 4. Miss - compute fresh data
+                debug!(
+                    "Computing fresh data for key: '{}' (Stampede protected)",
+                    key
+                );
+
+                let result = self.timed_compute(compute_fn()).await;
+
+                match result {
+                    Ok((value, strategy)) => {
+                        let _ = self
+                            .store_in_tiers(key, value.clone(), strategy, tiers, false)
+                            .await;
+                        let _ = tx.send(Some(Ok(Some(value.clone()))));
+                        return Ok((value, CacheSource::Computed));
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Some(Err(e.clone())));
+                        return Err(e);
+                    }
+                }
+            }
+        }
+
+        // If waiter fell through (creator dropped or miss without sending), do direct compute
+        debug!(
+            "Computing fresh data for key: '{}' (Stampede fallback)",
+            key
+        );
+        let (value, strategy) = self.timed_compute(compute_fn()).await?;
+        let _ = self
+            .store_in_tiers(key, value.clone(), strategy, tiers, false)
+            .await;
+        Ok((value, CacheSource::Computed))
+    }
+
+    /// Await a `compute_fn` future, recording its duration
+    async fn timed_compute<Fut: Future>(&self, compute: Fut) -> Fut::Output {
+        let start = Instant::now();
+        let result = compute.await;
+        #[cfg(feature = "metrics-facade")]
+        crate::metrics_facade::record_compute(start.elapsed());
+        let nanos = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        self.compute_counters
+            .computes
+            .fetch_add(1, Ordering::Relaxed);
+        self.compute_counters
+            .compute_nanos
+            .fetch_add(nanos, Ordering::Relaxed);
+        result
+    }
+
+    /// Source reported for a hit in `self.tiers[tier_index]`
+    fn tier_source(&self, tier_index: usize) -> CacheSource {
+        match self.tiers.get(tier_index) {
+            Some(tier) if tier_index > 0 => CacheSource::LowerTier(tier.tier_level),
+            _ => CacheSource::L1,
+        }
+    }
+
+    /// Entry-style access to a single key
+    ///
+    /// Mirrors `std::collections::hash_map::Entry`: chain `and_modify` to update an
+    /// existing value, and finish with `or_insert` / `or_insert_with` to supply a
+    /// value for a missing key.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use multi_tier_cache::{CacheManager, CacheStrategy};
+    /// # use bytes::Bytes;
+    /// # async fn example(cache_manager: &CacheManager) -> anyhow::Result<()> {
+    /// let visits = cache_manager
+    ///     .entry("visits:home")
+    ///     .and_modify(|current| {
+    ///         let count: u64 = std::str::from_utf8(&current).ok()
+    ///             .and_then(|s| s.parse().ok())
+    ///             .unwrap_or(0);
+    ///         Bytes::from((count + 1).to_string())
+    ///     })
+    ///     .or_insert(CacheStrategy::LongTerm, Bytes::from("1"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn entry<'a>(&'a self, key: &'a str) -> Entry<'a> {
+        Entry {
+            manager: self,
+            key,
+            modify: None,
+        }
+    }
+
+    /// Take the in-flight lock for `key`, waiting for any current holder to finish
+    async fn lock_in_flight(
+        &self,
+        key: &str,
+    ) -> (
+        Arc<watch::Sender<Option<CacheResult<Option<Bytes>>>>>,
+        RemoveInFlightGuard,
+    ) {
+        let key = self.flight_key(key);
+        loop {
+            self.compute_counters
+                .in_flight_lookups
+                .fetch_add(1, Ordering::Relaxed);
+            let mut rx = match self.in_flight_requests.entry(key.clone()) {
+                dashmap::mapref::entry::Entry::Occupied(entry) => entry.get().subscribe(),
+                dashmap::mapref::entry::Entry::Vacant(entry) => {
+                    let (tx, _) = watch::channel(None);
+                    let tx = Arc::new(tx);
+                    entry.insert(Arc::clone(&tx));
+                    let guard = RemoveInFlightGuard {
+                        map: Arc::clone(&self.in_flight_requests),
+                        key,
+                    };
+                    return (tx, guard);
+                }
+            };
+
+            if rx.borrow().is_some() {
+                // Holder has finished but not released the key yet
+                tokio::task::yield_now().await;
+                continue;
+            }
+            while rx.changed().await.is_ok() {
+                if rx.borrow().is_some() {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Get or compute typed value with Cache Stampede protection (Type-Safe Version)
+    ///
+    /// This method provides the same functionality as `get_or_compute_with()` but with
+    /// **type-safe** automatic serialization/deserialization. Perfect for database queries,
+    /// API calls, or any computation that returns structured data.
+    ///
+    /// # Type Safety
+    ///
+    /// - Returns your actual type `T` instead of `serde_json::Value`
+    /// - Compiler enforces Serialize + `DeserializeOwned` bounds
+    /// - No manual JSON conversion needed
+    ///
+    /// # Cache Flow
+    ///
+    /// 1. Check L1 cache → deserialize if found
+    /// 2. Check L2 cache → deserialize + promote to L1 if found
+    /// 3. Execute `compute_fn` → serialize → store in L1+L2
+    /// 4. Full stampede protection (only ONE request computes)
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Cache key
+    /// * `strategy` - Cache strategy for TTL
+    /// * `compute_fn` - Async function returning `Result<T>`
+    ///
+    /// # Example - Database Query
+    ///
+    /// ```no_run
+    /// # use multi_tier_cache::{CacheManager, CacheStrategy, L1Cache, L2Cache, MokaCacheConfig};
+    /// # use std::sync::Arc;
+    /// # use serde::{Serialize, Deserialize};
+    /// # async fn example() -> anyhow::Result<()> {
+    /// # let l1 = Arc::new(L1Cache::new(MokaCacheConfig::default())?);
+    /// # let l2 = Arc::new(L2Cache::new().await?);
+    /// # let cache_manager = CacheManager::new(l1, l2);
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct User {
+    ///     id: i64,
+    ///     name: String,
+    /// }
+    ///
+    /// // Type-safe database caching (example - requires sqlx)
+    /// // let user: User = cache_manager.get_or_compute_typed(
+    /// //     "user:123",
+    /// //     CacheStrategy::MediumTerm,
+    /// //     || async {
+    /// //         sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = $1")
+    /// //             .bind(123)
+    /// //             .fetch_one(&pool)
+    /// //             .await
+    /// //     }
+    /// // ).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Example - API Call
+    ///
+    /// ```no_run
+    /// # use multi_tier_cache::{CacheManager, CacheStrategy, L1Cache, L2Cache, MokaCacheConfig};
+    /// # use std::sync::Arc;
+    /// # use serde::{Serialize, Deserialize};
+    /// # async fn example() -> anyhow::Result<()> {
+    /// # let l1 = Arc::new(L1Cache::new(MokaCacheConfig::default())?);
+    /// # let l2 = Arc::new(L2Cache::new().await?);
+    /// # let cache_manager = CacheManager::new(l1, l2);
+    /// #[derive(Serialize, Deserialize)]
+    /// struct ApiResponse {
+    ///     data: String,
+    ///     timestamp: i64,
+    /// }
+    ///
+    /// // API call caching (example - requires reqwest)
+    /// // let response: ApiResponse = cache_manager.get_or_compute_typed(
+    /// //     "api:endpoint",
+    /// //     CacheStrategy::RealTime,
+    /// //     || async {
+    /// //         reqwest::get("https://api.example.com/data")
+    /// //             .await?
+    /// //             .json::<ApiResponse>()
+    /// //             .await
+    /// //     }
+    /// // ).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Performance
+    ///
+    /// - L1 hit: <1ms + deserialization (~10-50μs for small structs)
+    /// - L2 hit: 2-5ms + deserialization + L1 promotion
+    /// - Compute: Your function time + serialization + L1+L2 storage
+    /// - Stampede protection: 99.6% latency reduction under high concurrency
+    ///
     /// # Errors
     ///
-    /// Returns an error if cache set operation fails.
-    pub async fn set_with_strategy(
+    /// Returns error if:
+    /// - Compute function fails
+    /// - Serialization fails (invalid type for JSON)
+    /// - Deserialization fails (cache data doesn't match type T)
+    /// - Cache operations fail (Redis connection issues)
+    #[allow(clippy::too_many_lines)]
+    pub async fn get_or_compute_typed<T, F, Fut>(
         &self,
-        key: &str,
-        value: Bytes,
-        strategy: CacheStrategy,
-    ) -> CacheResult<()> {
-        let ttl = strategy.to_duration();
+        key: &(impl CacheKey + ?Sized),
+        strategy: impl Into<CacheStrategy>,
+        compute_fn: F,
+    ) -> CacheResult<T>
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned + Send + Sync + 'static,
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = CacheResult<T>> + Send,
+    {
+        let key: &str = &key.cache_key();
+        // 1. Try to get typed from cache first
+        if let Some(value) = self.get_typed::<T>(key).await? {
+            return Ok(value);
+        }
 
-        let mut success_count = 0;
-        let mut last_error = None;
+        // 2. Use get_or_compute_with to handle stampede protection
+        let bytes_result = self
+            .get_or_compute_checked(
+                key,
+                strategy.into(),
+                || async move {
+                    let val = compute_fn().await?;
+                    self.encode_typed_value(key, &val)
+                },
+                self.typed_value_check::<T>(),
+            )
+            .await?;
 
-        for tier in &self.tiers {
-            match tier.set_with_ttl(key, value.clone(), ttl).await {
-                Ok(()) => {
-                    success_count += 1;
-                }
-                Err(e) => {
-                    error!(
-                        "L{} cache set failed for key '{}': {}",
-                        tier.tier_level, key, e
-                    );
-                    last_error = Some(e);
-                }
+        // 3. Deserialize result
+        self.decode_typed_result(key, &bytes_result)
+    }
+
+    /// Typed get-or-compute that degrades to `fallback` instead of failing
+    ///
+    /// Behaves like `get_or_compute_typed()`, but never fails. If the cache
+    /// can't be read (e.g. a value that doesn't deserialize), the error is
+    /// logged and `compute_fn` still runs; its value is returned and written
+    /// back, logging a failed write. Only when `compute_fn` fails too (or the
+    /// get-or-compute fails after a successful read) is the failure logged and
+    /// `fallback` returned. The fallback is never cached, so the next call
+    /// tries to compute the real value again.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use multi_tier_cache::{CacheManager, CacheStrategy};
+    /// # async fn example(cache_manager: &CacheManager) {
+    /// // An empty list is better than an error page
+    /// let posts: Vec<String> = cache_manager
+    ///     .get_or_compute_or_default("posts:latest", CacheStrategy::ShortTerm, || async {
+    ///         Ok(vec!["hello".to_string()])
+    ///     }, Vec::new())
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn get_or_compute_or_default<T, F, Fut>(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        strategy: impl Into<CacheStrategy>,
+        compute_fn: F,
+        fallback: T,
+    ) -> T
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned + Send + Sync + 'static,
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = CacheResult<T>> + Send,
+    {
+        let key: &str = &key.cache_key();
+        let strategy = strategy.into();
+        match self.get_typed::<T>(key).await {
+            Ok(Some(value)) => return value,
+            Ok(None) => {}
+            Err(e) => {
+                warn!("Cache read for '{}' failed, computing instead: {}", key, e);
+                return match compute_fn().await {
+                    Ok(value) => {
+                        let stored = match self.encode_typed_value(key, &value) {
+                            Ok(bytes) => self.set_with_strategy(key, bytes, strategy).await,
+                            Err(e) => Err(e),
+                        };
+                        if let Err(e) = stored {
+                            warn!("Failed to cache computed value for '{}': {}", key, e);
+                        }
+                        value
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Serving fallback for '{}' after cache and compute failed: {}",
+                            key, e
+                        );
+                        fallback
+                    }
+                };
             }
         }
 
-        if success_count > 0 {
-            debug!(
-                "[Cache] Stored '{}' in {}/{} tiers (base TTL: {:?})",
+        let computed = self
+            .get_or_compute_checked(
                 key,
-                success_count,
-                self.tiers.len(),
-                ttl
-            );
-            return Ok(());
+                strategy,
+                || async move {
+                    let val = compute_fn().await?;
+                    self.encode_typed_value(key, &val)
+                },
+                self.typed_value_check::<T>(),
+            )
+            .await
+            .and_then(|bytes| self.decode_typed_result(key, &bytes));
+        match computed {
+            Ok(value) => value,
+            Err(e) => {
+                warn!("Serving fallback for '{}' after compute failed: {}", key, e);
+                fallback
+            }
         }
+    }
 
-        Err(last_error.unwrap_or_else(|| {
-            crate::error::CacheError::InternalError("All tiers failed".to_string())
-        }))
+    /// Typed get-or-compute that also reports where the value came from
+    ///
+    /// Behaves like `get_or_compute_typed()` (including stampede protection),
+    /// returning a `CacheSource` alongside the value for cache-effectiveness
+    /// metrics.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use multi_tier_cache::{CacheManager, CacheSource, CacheStrategy};
+    /// # async fn example(cache_manager: &CacheManager) -> anyhow::Result<()> {
+    /// let (price, source): (f64, CacheSource) = cache_manager
+    ///     .get_or_compute_with_source("price:btc", CacheStrategy::RealTime, || async {
+    ///         Ok(42_000.0)
+    ///     })
+    ///     .await?;
+    /// if source == CacheSource::Computed {
+    ///     tracing::info!("price:btc recomputed");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as `get_or_compute_typed()`.
+    pub async fn get_or_compute_with_source<T, F, Fut>(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        strategy: impl Into<CacheStrategy>,
+        compute_fn: F,
+    ) -> CacheResult<(T, CacheSource)>
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned + Send + Sync + 'static,
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = CacheResult<T>> + Send,
+    {
+        let key: &str = &key.cache_key();
+        let strategy = strategy.into();
+        let (bytes, source) = self
+            .get_or_compute_sourced(
+                key,
+                TierMask::ALL,
+                || async move {
+                    let val = compute_fn().await?;
+                    Ok((self.encode_typed_value(key, &val)?, strategy))
+                },
+                self.typed_value_check::<T>(),
+            )
+            .await?;
+
+        Ok((self.decode_typed_result(key, &bytes)?, source))
     }
 
-    /// Get or compute value with Cache Stampede protection across L1+L2+Compute
+    /// Typed get-or-compute where `compute_fn` decides the TTL
     ///
-    /// This method provides comprehensive Cache Stampede protection:
-    /// 1. Check L1 cache first (uses Moka's built-in coalescing)
-    /// 2. Check L2 cache with mutex-based coalescing
-    /// 3. Compute fresh data with protection against concurrent computations
+    /// For values whose freshness is only known once fetched (an upstream
+    /// `Cache-Control: max-age`, a token's expiry), `compute_fn` returns the
+    /// value together with how long to cache it. The value is stored in every
+    /// tier for that TTL (`CacheStrategy::Custom`); hits return the cached
+    /// value as usual. Stampede protection matches `get_or_compute_typed()`.
     ///
-    /// # Arguments
-    /// * `key` - Cache key
-    /// * `strategy` - Cache strategy for TTL and storage behavior
-    /// * `compute_fn` - Async function to compute the value if not in any cache
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use multi_tier_cache::CacheManager;
+    /// # use std::time::Duration;
+    /// # async fn example(cache_manager: &CacheManager) -> anyhow::Result<()> {
+    /// let token: String = cache_manager
+    ///     .get_or_compute_with_ttl("token:api", || async {
+    ///         // e.g. the `expires_in` of an OAuth response
+    ///         Ok(("secret".to_string(), Duration::from_hours(1)))
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as `get_or_compute_typed()`.
+    pub async fn get_or_compute_with_ttl<T, F, Fut>(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        compute_fn: F,
+    ) -> CacheResult<T>
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned + Send + Sync + 'static,
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = CacheResult<(T, Duration)>> + Send,
+    {
+        let key: &str = &key.cache_key();
+        if let Some(value) = self.get_typed::<T>(key).await? {
+            return Ok(value);
+        }
+
+        let (bytes, _) = self
+            .get_or_compute_sourced(
+                key,
+                TierMask::ALL,
+                || async move {
+                    let (val, ttl) = compute_fn().await?;
+                    Ok((
+                        self.encode_typed_value(key, &val)?,
+                        CacheStrategy::Custom(ttl),
+                    ))
+                },
+                self.typed_value_check::<T>(),
+            )
+            .await?;
+
+        self.decode_typed_result(key, &bytes)
+    }
+
+    /// Get or compute many typed values, with per-key Cache Stampede protection
+    ///
+    /// Each key is resolved independently through `get_or_compute_with()`, so every
+    /// missing key is guarded by its own in-flight lock. Concurrent callers with
+    /// overlapping (but not identical) key sets coalesce on the keys they share
+    /// instead of serializing on the whole batch.
+    ///
+    /// `per_key_compute` is called once per missing key with the key as argument.
+    /// Results are returned in the same order as `keys`.
     ///
     /// # Example
-    /// ```rust,no_run
-    /// # #[tokio::main]
-    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///
+    /// ```no_run
     /// # use multi_tier_cache::{CacheManager, CacheStrategy, L1Cache, L2Cache, MokaCacheConfig};
     /// # use std::sync::Arc;
+    /// # async fn example() -> anyhow::Result<()> {
     /// # let l1 = Arc::new(L1Cache::new(MokaCacheConfig::default())?);
     /// # let l2 = Arc::new(L2Cache::new().await?);
     /// # let cache_manager = CacheManager::new(l1, l2).await?;
-    /// # async fn fetch_data_from_api() -> Result<bytes::Bytes, multi_tier_cache::error::CacheError> { Ok(bytes::Bytes::new()) }
-    /// let api_data = cache_manager.get_or_compute_with(
-    ///     "api_response",
-    ///     CacheStrategy::RealTime,
-    ///     || async {
-    ///         fetch_data_from_api().await
-    ///     }
-    /// ).await?;
+    /// let names: Vec<String> = cache_manager
+    ///     .get_many_each(&["user:1", "user:2"], CacheStrategy::MediumTerm, |key| async move {
+    ///         Ok(format!("name for {key}"))
+    ///     })
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    #[allow(dead_code)]
+    ///
     /// # Errors
     ///
-    /// Returns an error if compute function fails or cache operations fail.
-    pub async fn get_or_compute_with<F, Fut>(
+    /// Returns the first error from any key: compute failure, serialization or
+    /// deserialization failure, or a cache backend error.
+    pub async fn get_many_each<T, F, Fut>(
         &self,
-        key: &str,
-        strategy: CacheStrategy,
-        compute_fn: F,
-    ) -> CacheResult<Bytes>
+        keys: &[&str],
+        strategy: impl Into<CacheStrategy>,
+        per_key_compute: F,
+    ) -> CacheResult<Vec<T>>
     where
-        F: FnOnce() -> Fut + Send,
-        Fut: Future<Output = CacheResult<Bytes>> + Send,
+        T: serde::Serialize + serde::de::DeserializeOwned + Send + Sync + 'static,
+        F: Fn(String) -> Fut + Send + Sync,
+        Fut: Future<Output = CacheResult<T>> + Send,
     {
-        self.total_requests.fetch_add(1, Ordering::Relaxed);
+        let strategy = strategy.into();
+        let compute = &per_key_compute;
+        let lookups = keys.iter().map(|&key| {
+            let strategy = strategy.clone();
+            async move {
+                let bytes = self
+                    .get_or_compute_checked(
+                        key,
+                        strategy,
+                        || async move {
+                            let value = compute(key.to_string()).await?;
+                            self.encode_typed_value(key, &value)
+                        },
+                        self.typed_value_check::<T>(),
+                    )
+                    .await?;
+                self.decode_typed_result(key, &bytes)
+            }
+        });
+
+        futures_util::future::try_join_all(lookups).await
+    }
+
+    /// Get comprehensive cache statistics
+    ///
+    /// In multi-tier mode, aggregates statistics from all tiers.
+    /// In legacy mode, returns L1 and L2 stats.
+    #[allow(dead_code)]
+    pub fn get_stats(&self) -> CacheManagerStats {
+        CacheManagerStats {
+            promotions: self.promotion_counters.promotions.load(Ordering::Relaxed),
+            async_promotions_dropped: self
+                .promotion_counters
+                .async_dropped
+                .load(Ordering::Relaxed),
+            async_promotions_expired: self
+                .promotion_counters
+                .async_expired
+                .load(Ordering::Relaxed),
+            deduplicated_promotions: self.promotion_counters.deduplicated.load(Ordering::Relaxed),
+            rejected_nulls: self.rejected_nulls.load(Ordering::Relaxed),
+            large_values: self.large_values.load(Ordering::Relaxed),
+            stale_l1_hits: self.stale_l1_hits.load(Ordering::Relaxed),
+            ttl_extensions: self.ttl_extensions.load(Ordering::Relaxed),
+            memory_evicted_bytes: self.memory_evicted_bytes.load(Ordering::Relaxed),
+            computes: self.compute_counters.computes.load(Ordering::Relaxed),
+            total_compute_time: Duration::from_nanos(
+                self.compute_counters.compute_nanos.load(Ordering::Relaxed),
+            ),
+            coalesced_waiters: self.compute_counters.coalesced.load(Ordering::Relaxed),
+            in_flight_lookups: self
+                .compute_counters
+                .in_flight_lookups
+                .load(Ordering::Relaxed),
+            in_flight_requests: self.in_flight_requests.len(),
+            l2_permits_in_use: self
+                .tiers
+                .get(1)
+                .and_then(|tier| tier.concurrency_limit.as_ref())
+                .map_or(0, ConcurrencyLimit::in_use),
+            l2_bytes_read: self
+                .tiers
+                .get(1)
+                .map_or(0, |tier| tier.io.bytes_read.load(Ordering::Relaxed)),
+            l2_bytes_written: self
+                .tiers
+                .get(1)
+                .map_or(0, |tier| tier.io.bytes_written.load(Ordering::Relaxed)),
+            ..CacheManagerStats::from_counts(
+                self.total_requests.sum(),
+                self.l1_hits.sum(),
+                self.l2_hits.sum(),
+                self.misses.sum(),
+                self.sets.sum(),
+            )
+        }
+    }
+
+    /// Get statistics per key bucket (see `with_stats_by_prefix`)
+    ///
+    /// Only the request, hit, miss and set counters (and rates) are tracked per
+    /// bucket; manager-wide fields such as `promotions` are 0. Empty if bucket
+    /// statistics are disabled.
+    #[must_use]
+    pub fn stats_by_bucket(&self) -> HashMap<String, CacheManagerStats> {
+        let mut stats = HashMap::new();
+        if let Some(buckets) = &self.key_buckets {
+            buckets.for_each(|bucket, counters: &BucketCounters| {
+                stats.insert(
+                    bucket.to_string(),
+                    CacheManagerStats::from_counts(
+                        counters.requests.load(Ordering::Relaxed),
+                        counters.l1_hits.load(Ordering::Relaxed),
+                        counters.l2_hits.load(Ordering::Relaxed),
+                        counters.misses.load(Ordering::Relaxed),
+                        counters.sets.load(Ordering::Relaxed),
+                    ),
+                );
+            });
+        }
+        stats
+    }
+
+    /// Whether the manager runs more tiers than the standard L1 + L2 pair
+    ///
+    /// Managers from `new()` / `new_with_backends()` are 2-tier; ones from
+    /// `new_with_tiers()` (or a builder with extra tiers) usually aren't.
+    #[must_use]
+    pub fn is_multi_tier(&self) -> bool {
+        self.tiers.len() > 2
+    }
+
+    /// Number of configured tiers
+    #[must_use]
+    pub fn tier_count(&self) -> usize {
+        self.tiers.len()
+    }
+
+    /// Levels of the configured tiers, fastest first
+    #[must_use]
+    pub fn tier_levels(&self) -> Vec<usize> {
+        self.tiers.iter().map(|tier| tier.tier_level).collect()
+    }
+
+    /// Get per-tier statistics (v0.5.0+)
+    ///
+    /// Returns statistics for each tier, fastest first. Use `is_multi_tier()`
+    /// to tell a 2-tier manager from a multi-tier one.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use multi_tier_cache::{CacheManager, L1Cache, L2Cache, MokaCacheConfig};
+    /// # use std::sync::Arc;
+    /// # let l1 = Arc::new(L1Cache::new(MokaCacheConfig::default())?);
+    /// # let l2 = Arc::new(L2Cache::new().await?);
+    /// # let cache_manager = CacheManager::new(l1, l2).await?;
+    /// let tier_stats = cache_manager.get_tier_stats();
+    /// for stats in tier_stats {
+    ///     println!("L{}: {} hits ({})",
+    ///              stats.tier_level,
+    ///              stats.hit_count(),
+    ///              stats.backend_name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_tier_stats(&self) -> Vec<TierStats> {
+        self.tiers.iter().map(CacheTier::stats_snapshot).collect()
+    }
 
-        // 1. Try tiers sequentially first
-        if let Some(value) = self.get_multi_tier(key).await? {
-            return Ok(value);
+    /// Get the distribution of TTLs used for promotions
+    ///
+    /// Useful for spotting promotions of values that are about to expire anyway.
+    #[must_use]
+    pub fn promotion_ttl_histogram(&self) -> PromotionTtlHistogram {
+        self.promotion_counters.ttls.snapshot()
+    }
+
+    /// Get server information from the L2 backend (e.g. Redis `INFO memory` / `INFO stats`)
+    ///
+    /// Returns `None` if there is no L2 tier or the L2 backend doesn't expose
+    /// server info.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the L2 backend fails to report its server info.
+    pub async fn l2_server_info(&self) -> CacheResult<Option<HashMap<String, String>>> {
+        match self.tiers.get(1) {
+            Some(tier) => tier.backend.server_info().await,
+            None => Ok(None),
         }
+    }
 
-        let key_owned = key.to_string();
-        let flight_state = match self.in_flight_requests.entry(key_owned.clone()) {
-            dashmap::mapref::entry::Entry::Occupied(entry) => {
-                FlightState::Waiter(entry.get().subscribe())
-            }
-            dashmap::mapref::entry::Entry::Vacant(entry) => {
-                let (tx, _) = watch::channel(None);
-                let tx = Arc::new(tx);
-                entry.insert(tx.clone());
-                FlightState::Creator(tx)
-            }
+    /// Copy L1-only entries down to L2 (the inverse of promotion)
+    ///
+    /// For each key present in L1 but missing from L2, writes the L1 value to L2
+    /// with L1's remaining TTL (scaled by L2's `ttl_scale`, as in promotion), so
+    /// other instances reading the shared L2 see values this instance only
+    /// managed to store locally (e.g. after a failed L2 write). Keys missing
+    /// from L1, or already in L2, are left alone.
+    ///
+    /// Returns the number of keys written to L2.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first L2 write error; keys before it have been reconciled.
+    pub async fn reconcile_to_l2(&self, keys: &[&str]) -> CacheResult<usize> {
+        let (Some(l1), Some(l2)) = (self.tiers.first(), self.tiers.get(1)) else {
+            return Ok(0);
         };
 
-        match flight_state {
-            FlightState::Waiter(mut rx) => {
-                // Wait for creator to finish
-                if rx.borrow().is_none() {
-                    while rx.changed().await.is_ok() {
-                        if rx.borrow().is_some() {
-                            break;
-                        }
-                    }
-                }
-                // Return result if it exists, otherwise fall through to re-compute
-                if let Some(res) = rx.borrow().clone() {
-                    match res {
-                        Ok(Some(bytes)) => return Ok(bytes),
-                        Ok(None) => {} // Miss, fall through to re-compute
-                        Err(e) => return Err(e),
-                    }
-                }
+        let mut written = 0;
+        for &key in keys {
+            let key: &str = &self.storage_key(key);
+            let Some((value, ttl)) = l1.get_with_ttl(key).await else {
+                continue;
+            };
+            if l2.get_with_ttl(key).await.is_some() {
+                continue;
             }
-            FlightState::Creator(tx) => {
-                // Guard key removal on drop/cancellation
-                let _guard = RemoveInFlightGuard {
-                    map: Arc::clone(&self.in_flight_requests),
-                    key: key_owned,
-                };
-
-                // 3. Re-check cache after receiving/creating broadcaster (double-check pattern)
-                if let Some(value) = self.get_multi_tier(key).await? {
-                    let _ = tx.send(Some(Ok(Some(value.clone()))));
-                    return Ok(value);
-                }
 
-                // 4. Miss - compute fresh data
-                debug!(
-                    "Computing fresh data for key: '{}' (Stampede protected)",
-                    key
-                );
+            let ttl = ttl.unwrap_or(self.strategy_durations.default);
+            l2.set_with_ttl(key, value, ttl).await?;
+            written += 1;
+            debug!("Reconciled '{}' from L1 to L2 (TTL: {:?})", key, ttl);
+        }
 
-                let result = compute_fn().await;
+        Ok(written)
+    }
 
-                match &result {
-                    Ok(value) => {
-                        let _ = self.set_with_strategy(key, value.clone(), strategy).await;
-                        let _ = tx.send(Some(Ok(Some(value.clone()))));
-                    }
-                    Err(e) => {
-                        let _ = tx.send(Some(Err(e.clone())));
-                    }
-                }
+    /// Populate L1 from L2 entries matching `pattern`
+    ///
+    /// Meant for startup: an instance with a cold L1 over a populated shared L2
+    /// would otherwise pay L2 latency on every first request. Keys are
+    /// discovered with the L2 backend's key search (Redis `SCAN MATCH`) and
+    /// matched as stored (i.e. namespaced); at most `max_keys` are warmed so a
+    /// broad pattern can't pull the whole keyspace. Values are written to L1
+    /// with their remaining L2 TTL, scaled like a promotion.
+    ///
+    /// Returns the number of keys warmed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no L2 tier, the L2 backend can't search
+    /// keys, or the scan or an L1 write fails.
+    pub async fn warm_l1_from_l2(&self, pattern: &str, max_keys: usize) -> CacheResult<usize> {
+        let (Some(l1), Some(l2)) = (self.tiers.first(), self.tiers.get(1)) else {
+            return Err(CacheError::ConfigError(
+                "warm_l1_from_l2 requires an L2 tier".to_string(),
+            ));
+        };
+        let Some(keys) = l2.backend.keys_matching(pattern, max_keys).await? else {
+            return Err(CacheError::BackendError(format!(
+                "{} backend does not support key search",
+                l2.backend.name()
+            )));
+        };
 
-                return result;
-            }
+        let mut warmed = 0;
+        for key in keys.iter().take(max_keys) {
+            // Keys can expire between the scan and the read
+            let Some((value, ttl)) = l2.get_with_ttl(key).await else {
+                continue;
+            };
+            let ttl = ttl.unwrap_or(self.strategy_durations.default);
+            l1.set_with_ttl(key, value, ttl).await?;
+            warmed += 1;
         }
 
-        // If waiter fell through (creator dropped or miss without sending), do direct compute
-        debug!(
-            "Computing fresh data for key: '{}' (Stampede fallback)",
-            key
-        );
-        let result = compute_fn().await;
-        if let Ok(value) = &result {
-            let _ = self.set_with_strategy(key, value.clone(), strategy).await;
-        }
-        result
+        info!(pattern = %pattern, count = warmed, "Warmed L1 from L2");
+        Ok(warmed)
     }
 
-    /// Get or compute typed value with Cache Stampede protection (Type-Safe Version)
+    /// Stream L2 entries matching `pattern` with their deserialized values
     ///
-    /// This method provides the same functionality as `get_or_compute_with()` but with
-    /// **type-safe** automatic serialization/deserialization. Perfect for database queries,
-    /// API calls, or any computation that returns structured data.
+    /// Meant for admin and debug tooling. Keys are discovered page by page
+    /// with the L2 backend's `scan_keys_page` (Redis `SCAN MATCH` with
+    /// `COUNT` 100) as the stream is polled, so the keyspace is never listed at
+    /// once; they are matched and returned as stored (i.e. namespaced), and
+    /// each value is read from L2 before the next one is yielded. Entries that expired since the scan, or don't deserialize as
+    /// `T`, are skipped.
     ///
-    /// # Type Safety
+    /// This reads L2 only (L1 may hold newer values that haven't been written
+    /// through) and is not a consistent snapshot: keys written during the scan
+    /// may or may not appear, and a value may be newer than the scan.
     ///
-    /// - Returns your actual type `T` instead of `serde_json::Value`
-    /// - Compiler enforces Serialize + `DeserializeOwned` bounds
-    /// - No manual JSON conversion needed
+    /// # Errors
     ///
-    /// # Cache Flow
+    /// Yields a single error if there is no L2 tier, the L2 backend can't
+    /// search keys, or the scan fails.
+    pub fn scan<'a, T>(
+        &'a self,
+        pattern: &'a str,
+    ) -> impl Stream<Item = CacheResult<(String, T)>> + Send + 'a
+    where
+        T: serde::de::DeserializeOwned + Send + 'a,
+    {
+        // `None` once the last page was fetched or a fetch failed
+        let pages = stream::unfold(Some(0_u64), move |cursor| async move {
+            let cursor = cursor?;
+            let page = async {
+                let Some(l2) = self.tiers.get(1) else {
+                    return Err(CacheError::ConfigError(
+                        "scan requires an L2 tier".to_string(),
+                    ));
+                };
+                let Some((next, keys)) = l2
+                    .backend
+                    .scan_keys_page(pattern, cursor, SCAN_PAGE_SIZE)
+                    .await?
+                else {
+                    return Err(CacheError::BackendError(format!(
+                        "{} backend does not support key search",
+                        l2.backend.name()
+                    )));
+                };
+                debug!(pattern = %pattern, cursor, count = keys.len(), "Scanned a page of L2 keys");
+                Ok((l2, next, keys))
+            }
+            .await;
+            Some(match page {
+                Ok((l2, next, keys)) => (Ok((l2, keys)), (next != 0).then_some(next)),
+                Err(e) => (Err(e), None),
+            })
+        });
+
+        pages.flat_map(move |scanned| match scanned {
+            Ok((l2, keys)) => stream::iter(keys)
+                .filter_map(move |key| async move {
+                    // Keys can expire between the scan and the read
+                    let (value, _ttl) = l2.get_with_ttl(&key).await?;
+                    match self.decode_value::<T>(&key, &value) {
+                        Ok(Some(value)) => Some(Ok((key, value))),
+                        Ok(None) => None,
+                        Err(e) => {
+                            debug!(key = %key, error = %e, "Skipping undecodable entry in scan");
+                            None
+                        }
+                    }
+                })
+                .left_stream(),
+            Err(e) => stream::iter([Err(e)]).right_stream(),
+        })
+    }
+
+    /// Write every live L1 entry (key, value, remaining TTL) to `writer`
     ///
-    /// 1. Check L1 cache → deserialize if found
-    /// 2. Check L2 cache → deserialize + promote to L1 if found
-    /// 3. Execute `compute_fn` → serialize → store in L1+L2
-    /// 4. Full stampede protection (only ONE request computes)
+    /// Lets a restarting instance skip the cold start: dump before shutdown,
+    /// then [`restore_l1`](Self::restore_l1) on boot. The dump records the
+    /// wall-clock time it was taken, so TTLs keep counting down while the
+    /// process is down. Keys are written as stored (i.e. namespaced).
     ///
-    /// # Arguments
+    /// Returns the number of entries written.
     ///
-    /// * `key` - Cache key
-    /// * `strategy` - Cache strategy for TTL
-    /// * `compute_fn` - Async function returning `Result<T>`
+    /// # Errors
     ///
-    /// # Example - Database Query
+    /// Returns an error if the L1 backend can't enumerate its keys (see
+    /// [`CacheBackend::keys`]) or if writing to `writer` fails.
+    pub async fn dump_l1(&self, mut writer: impl Write) -> CacheResult<usize> {
+        let Some(l1) = self.tiers.first() else {
+            return Ok(0);
+        };
+        let Some(keys) = l1.backend.keys().await? else {
+            return Err(CacheError::BackendError(format!(
+                "{} backend does not support key iteration",
+                l1.backend.name()
+            )));
+        };
+
+        let dumped_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        writer.write_all(L1_DUMP_MAGIC)?;
+        write_u64(&mut writer, u64::try_from(dumped_at.as_millis())?)?;
+
+        let mut written = 0;
+        for key in keys {
+            // Entries can expire or be evicted between listing and reading
+            let Some((value, ttl)) = l1.get_with_ttl(&key).await else {
+                continue;
+            };
+            let ttl_ms = match ttl {
+                Some(ttl) => u64::try_from(ttl.as_millis())?,
+                None => L1_DUMP_NO_TTL,
+            };
+            write_bytes(&mut writer, key.as_bytes())?;
+            write_bytes(&mut writer, &value)?;
+            write_u64(&mut writer, ttl_ms)?;
+            written += 1;
+        }
+        writer.flush()?;
+
+        debug!("Dumped {} L1 entries", written);
+        Ok(written)
+    }
+
+    /// Load entries written by [`dump_l1`](Self::dump_l1) back into L1
     ///
-    /// ```no_run
-    /// # use multi_tier_cache::{CacheManager, CacheStrategy, L1Cache, L2Cache, MokaCacheConfig};
-    /// # use std::sync::Arc;
-    /// # use serde::{Serialize, Deserialize};
-    /// # async fn example() -> anyhow::Result<()> {
-    /// # let l1 = Arc::new(L1Cache::new(MokaCacheConfig::default())?);
-    /// # let l2 = Arc::new(L2Cache::new().await?);
-    /// # let cache_manager = CacheManager::new(l1, l2);
+    /// Each entry is stored with its remaining TTL minus the time elapsed since
+    /// the dump; entries that expired in the meantime are skipped. Entries
+    /// dumped without a TTL get the default strategy TTL.
     ///
-    /// #[derive(Serialize, Deserialize)]
-    /// struct User {
-    ///     id: i64,
-    ///     name: String,
-    /// }
+    /// Returns the number of entries restored.
     ///
-    /// // Type-safe database caching (example - requires sqlx)
-    /// // let user: User = cache_manager.get_or_compute_typed(
-    /// //     "user:123",
-    /// //     CacheStrategy::MediumTerm,
-    /// //     || async {
-    /// //         sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = $1")
-    /// //             .bind(123)
-    /// //             .fetch_one(&pool)
-    /// //             .await
-    /// //     }
-    /// // ).await?;
-    /// # Ok(())
-    /// # }
-    /// ```
+    /// # Errors
     ///
-    /// # Example - API Call
+    /// Returns an error if `reader` fails, the dump is malformed, or an L1
+    /// write fails (entries before it have been restored).
+    pub async fn restore_l1(&self, mut reader: impl Read) -> CacheResult<usize> {
+        let Some(l1) = self.tiers.first() else {
+            return Ok(0);
+        };
+
+        let mut magic = [0u8; L1_DUMP_MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        if &magic != L1_DUMP_MAGIC {
+            return Err(CacheError::SerializationError(
+                "Not an L1 dump (bad header)".to_string(),
+            ));
+        }
+        let dumped_at = UNIX_EPOCH + Duration::from_millis(read_u64(&mut reader)?);
+        let elapsed = SystemTime::now()
+            .duration_since(dumped_at)
+            .unwrap_or_default();
+
+        let mut restored = 0;
+        while let Some(key) = read_bytes(&mut reader, true)? {
+            let key = String::from_utf8(key)
+                .map_err(|e| CacheError::SerializationError(e.to_string()))?;
+            let value = read_bytes(&mut reader, false)?.unwrap_or_default();
+            let ttl = match read_u64(&mut reader)? {
+                L1_DUMP_NO_TTL => self.strategy_durations.default,
+                ttl_ms => match Duration::from_millis(ttl_ms).checked_sub(elapsed) {
+                    Some(ttl) if !ttl.is_zero() => ttl,
+                    _ => continue,
+                },
+            };
+            // Bypass the tier's ttl_scale: the dumped TTL is already scaled
+            l1.backend
+                .set_with_ttl(&key, Bytes::from(value), ttl)
+                .await?;
+            restored += 1;
+        }
+
+        debug!("Restored {} L1 entries", restored);
+        Ok(restored)
+    }
+
+    /// Value and remaining TTL from the first tier holding `key`
     ///
-    /// ```no_run
-    /// # use multi_tier_cache::{CacheManager, CacheStrategy, L1Cache, L2Cache, MokaCacheConfig};
-    /// # use std::sync::Arc;
-    /// # use serde::{Serialize, Deserialize};
-    /// # async fn example() -> anyhow::Result<()> {
-    /// # let l1 = Arc::new(L1Cache::new(MokaCacheConfig::default())?);
-    /// # let l2 = Arc::new(L2Cache::new().await?);
-    /// # let cache_manager = CacheManager::new(l1, l2);
-    /// #[derive(Serialize, Deserialize)]
-    /// struct ApiResponse {
-    ///     data: String,
-    ///     timestamp: i64,
-    /// }
+    /// Unlike `get`, this records no statistics and promotes nothing.
+    pub(crate) async fn peek_with_ttl(&self, key: &str) -> Option<(Bytes, Option<Duration>)> {
+        let key: &str = &self.storage_key(key);
+        for tier in &self.tiers {
+            if let Some(found) = tier.get_with_ttl(key).await {
+                return Some(found);
+            }
+        }
+        None
+    }
+
+    /// Whether every tier's backend reports healthy
+    pub(crate) async fn tiers_healthy(&self) -> bool {
+        for tier in &self.tiers {
+            if !tier.backend.health_check().await {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Write a probe value through the manager and read it back
     ///
-    /// // API call caching (example - requires reqwest)
-    /// // let response: ApiResponse = cache_manager.get_or_compute_typed(
-    /// //     "api:endpoint",
-    /// //     CacheStrategy::RealTime,
-    /// //     || async {
-    /// //         reqwest::get("https://api.example.com/data")
-    /// //             .await?
-    /// //             .json::<ApiResponse>()
-    /// //             .await
-    /// //     }
-    /// // ).await?;
+    /// Unlike the per-backend `health_check` pings, this exercises the whole
+    /// path a real value takes: it stores a uniquely keyed typed value with
+    /// `set_with_strategy`, reads it back with `get`, checks the bytes and the
+    /// decoded value match, then drops the L1 copy and reads it again to
+    /// exercise the lower tiers and promotion. The probe key is removed from
+    /// every tier afterwards (without an invalidation broadcast), even if a
+    /// step failed. The reads count towards the request statistics.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use multi_tier_cache::CacheManager;
+    /// # async fn example(cache_manager: &CacheManager) -> anyhow::Result<()> {
+    /// // At startup, fail fast on a misconfigured codec or tier
+    /// cache_manager.self_test().await?;
     /// # Ok(())
     /// # }
     /// ```
+    /// # Errors
     ///
-    /// # Performance
+    /// Returns an `InternalError` naming the step that failed (write, read,
+    /// comparison, decoding, lower tier read or cleanup).
+    pub async fn self_test(&self) -> CacheResult<()> {
+        let token = uuid::Uuid::new_v4();
+        let key = format!("__mtc_self_test:{token}");
+        let probe = format!("multi-tier-cache self-test {token}");
+
+        let result = self.self_test_round_trip(&key, &probe).await;
+
+        let storage_key = self.storage_key(&key);
+        let mut cleanup = Ok(());
+        for tier in &self.tiers {
+            if let Err(e) = tier.remove(&storage_key).await {
+                cleanup = Err(CacheError::InternalError(format!(
+                    "Self-test cleanup of '{key}' failed in L{}: {e}",
+                    tier.tier_level
+                )));
+            }
+        }
+
+        result.and(cleanup)?;
+        debug!(tiers = self.tiers.len(), "Cache self-test passed");
+        Ok(())
+    }
+
+    /// The write and read steps of `self_test`
+    async fn self_test_round_trip(&self, key: &str, probe: &String) -> CacheResult<()> {
+        let failed = |step: &str, detail: String| {
+            CacheError::InternalError(format!("Self-test {step} of '{key}' failed: {detail}"))
+        };
+
+        let bytes = self
+            .encode_value(probe)
+            .map_err(|e| failed("encoding", e.to_string()))?;
+        self.set_with_strategy(
+            key,
+            bytes.clone(),
+            CacheStrategy::Custom(Duration::from_mins(1)),
+        )
+        .await
+        .map_err(|e| failed("write", e.to_string()))?;
+
+        let check_read = |step: &str, read: Option<Bytes>| -> CacheResult<()> {
+            let read = read.ok_or_else(|| failed(step, "value not found".to_string()))?;
+            if read != bytes {
+                return Err(failed(
+                    step,
+                    format!("read {} bytes, wrote {}", read.len(), bytes.len()),
+                ));
+            }
+            match self.decode_value::<String>(key, &read) {
+                Ok(Some(decoded)) if decoded == *probe => Ok(()),
+                Ok(Some(_)) => Err(failed(step, "decoded a different value".to_string())),
+                Ok(None) => Err(failed(step, "codec mismatch".to_string())),
+                Err(e) => Err(failed(step, format!("decoding failed: {e}"))),
+            }
+        };
+
+        let read = self
+            .get(key)
+            .await
+            .map_err(|e| failed("read", e.to_string()))?;
+        check_read("read", read)?;
+
+        // Served from below L1, promoting it again
+        if let [l1, _, ..] = self.tiers.as_slice() {
+            l1.remove(&self.storage_key(key))
+                .await
+                .map_err(|e| failed("L1 removal", e.to_string()))?;
+            let read = self
+                .get(key)
+                .await
+                .map_err(|e| failed("lower tier read", e.to_string()))?;
+            check_read("lower tier read", read)?;
+        }
+        Ok(())
+    }
+
+    /// Age of the L2 copy of `key` (time since it was written)
     ///
-    /// - L1 hit: <1ms + deserialization (~10-50μs for small structs)
-    /// - L2 hit: 2-5ms + deserialization + L1 promotion
-    /// - Compute: Your function time + serialization + L1+L2 storage
-    /// - Stampede protection: 99.6% latency reduction under high concurrency
+    /// Combined with the remaining TTL this shows where an entry is in its
+    /// lifecycle. Accuracy depends on the backend: `DashMapCache` records the
+    /// write time exactly, while `RedisCache` approximates it with
+    /// `OBJECT IDLETIME`, which reads also reset (free, but a lower bound with
+    /// one second resolution). Storing a timestamp in every value would be
+    /// exact but change the stored format shared with other instances.
+    ///
+    /// Returns `None` if the key is missing, there is no L2 tier, or the backend
+    /// doesn't track age.
     ///
     /// # Errors
     ///
-    /// Returns error if:
-    /// - Compute function fails
-    /// - Serialization fails (invalid type for JSON)
-    /// - Deserialization fails (cache data doesn't match type T)
-    /// - Cache operations fail (Redis connection issues)
-    #[allow(clippy::too_many_lines)]
-    pub async fn get_or_compute_typed<T, F, Fut>(
-        &self,
-        key: &str,
-        strategy: CacheStrategy,
-        compute_fn: F,
-    ) -> CacheResult<T>
-    where
-        T: serde::Serialize + serde::de::DeserializeOwned + Send + Sync + 'static,
-        F: FnOnce() -> Fut + Send,
-        Fut: Future<Output = CacheResult<T>> + Send,
-    {
-        // 1. Try to get typed from cache first
-        if let Some(value) = self.get_typed::<T>(key).await? {
-            return Ok(value);
+    /// Returns an error if the L2 backend fails to report the age.
+    pub async fn age(&self, key: &(impl CacheKey + ?Sized)) -> CacheResult<Option<Duration>> {
+        let key: &str = &key.cache_key();
+        let key: &str = &self.storage_key(key);
+        match self.tiers.get(1) {
+            Some(tier) => tier.backend.age(key).await,
+            None => Ok(None),
         }
+    }
 
-        // 2. Use get_or_compute_with to handle stampede protection
-        let serializer = self.serializer.clone();
-        let bytes_result = self
-            .get_or_compute_with(key, strategy, || async move {
-                let val = compute_fn().await?;
-                serializer.serialize(&val)
-            })
-            .await?;
+    /// Pin `key` in L1 so it is never evicted to make room for other entries
+    ///
+    /// Meant for a few always-needed keys (configuration, feature flags). A
+    /// pinned entry still expires with its TTL, and the pin covers values
+    /// written to the key later. Support depends on the L1 backend: Moka keeps
+    /// pinned entries outside its capacity bound, `DashMap` never evicts for
+    /// capacity anyway, and other backends ignore the pin.
+    ///
+    /// Returns whether the L1 backend honors the pin.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the L1 backend fails to update the pin.
+    pub async fn pin(&self, key: &(impl CacheKey + ?Sized)) -> CacheResult<bool> {
+        let key: &str = &key.cache_key();
+        self.set_l1_pinned(key, true).await
+    }
 
-        // 3. Deserialize result
-        self.serializer.deserialize::<T>(&bytes_result)
+    /// Undo `pin`, making `key` evictable again
+    ///
+    /// Returns whether the L1 backend supports pinning.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the L1 backend fails to update the pin.
+    pub async fn unpin(&self, key: &(impl CacheKey + ?Sized)) -> CacheResult<bool> {
+        let key: &str = &key.cache_key();
+        self.set_l1_pinned(key, false).await
     }
 
-    /// Get comprehensive cache statistics
+    /// Fraction of L1's capacity in use (0.0 = empty, 1.0 = full)
     ///
-    /// In multi-tier mode, aggregates statistics from all tiers.
-    /// In legacy mode, returns L1 and L2 stats.
-    #[allow(dead_code)]
-    pub fn get_stats(&self) -> CacheManagerStats {
-        let total_reqs = self.total_requests.load(Ordering::Relaxed);
-        let l1_hits = self.l1_hits.load(Ordering::Relaxed);
-        let l2_hits = self.l2_hits.load(Ordering::Relaxed);
-        let misses = self.misses.load(Ordering::Relaxed);
+    /// Feeds scaling alerts such as "L1 is 95% full". `None` if L1 is
+    /// unbounded or can't report its size (see `CacheBackend::capacity` and
+    /// `CacheBackend::entry_count`). With Moka the count trails recent writes
+    /// until its next maintenance run.
+    #[must_use]
+    pub fn l1_fill_ratio(&self) -> Option<f64> {
+        let l1 = self.tiers.first()?;
+        let capacity = l1.backend.capacity().filter(|&capacity| capacity > 0)?;
+        let entries = l1.backend.entry_count()?;
+        #[allow(clippy::cast_precision_loss)]
+        Some(entries as f64 / capacity as f64)
+    }
 
-        CacheManagerStats {
-            total_requests: total_reqs,
-            l1_hits,
-            l2_hits,
-            total_hits: l1_hits + l2_hits,
-            misses,
-            hit_rate: if total_reqs > 0 {
-                #[allow(clippy::cast_precision_loss)]
-                {
-                    ((l1_hits + l2_hits) as f64 / total_reqs as f64) * 100.0
-                }
-            } else {
-                0.0
-            },
-            l1_hit_rate: if total_reqs > 0 {
-                #[allow(clippy::cast_precision_loss)]
-                {
-                    (l1_hits as f64 / total_reqs as f64) * 100.0
+    /// Describe how a `get` of `key` would be resolved, without resolving it
+    ///
+    /// Reports which tiers hold the key and their remaining TTLs, the tier a
+    /// `get` would serve from (honoring `L2MissPolicy`) and the tiers it would
+    /// promote into. Read-only: nothing is promoted, evicted or counted in the
+    /// manager's statistics, which makes it safe to call while diagnosing
+    /// "why is this key stale" reports. Backends may still count the reads in
+    /// their own counters.
+    pub async fn explain(&self, key: &(impl CacheKey + ?Sized)) -> CacheExplain {
+        let key: &str = &key.cache_key();
+        let key: &str = &self.storage_key(key);
+        let mut tiers = Vec::with_capacity(self.tiers.len());
+        for tier in &self.tiers {
+            let hit = tier.backend.get_with_ttl(key).await;
+            tiers.push(TierPresence {
+                tier_level: tier.tier_level,
+                present: hit.is_some(),
+                ttl: hit.and_then(|(_, ttl)| ttl),
+            });
+        }
+
+        // FlushL1 drops an L1 copy that L2 no longer has
+        let l1_flushed = self.l2_miss_policy == L2MissPolicy::FlushL1
+            && tiers.get(1).is_some_and(|l2| !l2.present);
+        let served_index = tiers
+            .iter()
+            .enumerate()
+            .position(|(index, tier)| tier.present && !(index == 0 && l1_flushed));
+
+        let (served_by, promotes_to, promotion_frequency) =
+            match served_index.and_then(|index| Some((index, self.tiers.get(index)?))) {
+                Some((0, _)) => (Some(CacheSource::L1), Vec::new(), 1),
+                Some((index, tier)) => {
+                    let promotes_to = if tier.promotion_enabled {
+                        self.tiers
+                            .iter()
+                            .take(index)
+                            .rev()
+                            .map(|upper| upper.tier_level)
+                            .collect()
+                    } else {
+                        Vec::new()
+                    };
+                    (
+                        Some(CacheSource::LowerTier(tier.tier_level)),
+                        promotes_to,
+                        tier.promotion_frequency.max(1),
+                    )
                 }
-            } else {
-                0.0
-            },
-            promotions: self.promotions.load(Ordering::Relaxed),
-            in_flight_requests: self.in_flight_requests.len(),
+                None => (None, Vec::new(), 1),
+            };
+
+        CacheExplain {
+            storage_key: key.to_string(),
+            tiers,
+            served_by,
+            promotes_to,
+            promotion_frequency,
         }
     }
 
-    /// Get per-tier statistics (v0.5.0+)
-    ///
-    /// Returns statistics for each tier if multi-tier mode is enabled.
-    /// Returns None if using legacy 2-tier mode.
-    ///
-    /// # Example
-    /// ```rust,no_run
-    /// # #[tokio::main]
-    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// # use multi_tier_cache::{CacheManager, L1Cache, L2Cache, MokaCacheConfig};
-    /// # use std::sync::Arc;
-    /// # let l1 = Arc::new(L1Cache::new(MokaCacheConfig::default())?);
-    /// # let l2 = Arc::new(L2Cache::new().await?);
-    /// # let cache_manager = CacheManager::new(l1, l2).await?;
-    /// let tier_stats = cache_manager.get_tier_stats();
-    /// for stats in tier_stats {
-    ///     println!("L{}: {} hits ({})",
-    ///              stats.tier_level,
-    ///              stats.hit_count(),
-    ///              stats.backend_name);
-    /// }
-    /// # Ok(())
-    /// # }
-    /// ```
-    pub fn get_tier_stats(&self) -> Vec<TierStats> {
-        self.tiers.iter().map(|tier| tier.stats.clone()).collect()
+    async fn set_l1_pinned(&self, key: &str, pinned: bool) -> CacheResult<bool> {
+        let key: &str = &self.storage_key(key);
+        match self.tiers.first() {
+            Some(l1) => l1.backend.set_pinned(key, pinned).await,
+            None => Ok(false),
+        }
+    }
+}
+
+/// Whether a serialized value is JSON `null` (surrounding whitespace allowed)
+fn is_json_null(value: &[u8]) -> bool {
+    value.trim_ascii() == b"null"
+}
+
+/// Header identifying an L1 dump (format version in the last byte)
+const L1_DUMP_MAGIC: &[u8; 8] = b"MTCL1DM1";
+
+/// TTL marker for entries whose backend didn't report a remaining TTL
+const L1_DUMP_NO_TTL: u64 = u64::MAX;
+
+fn write_u64(writer: &mut impl Write, value: u64) -> CacheResult<()> {
+    Ok(writer.write_all(&value.to_le_bytes())?)
+}
+
+fn write_bytes(writer: &mut impl Write, bytes: &[u8]) -> CacheResult<()> {
+    writer.write_all(&u32::try_from(bytes.len())?.to_le_bytes())?;
+    Ok(writer.write_all(bytes)?)
+}
+
+fn read_u64(reader: &mut impl Read) -> CacheResult<u64> {
+    let mut buf = [0u8; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+/// Read a length-prefixed byte string; `None` on a clean end of stream if
+/// `eof_ok` (i.e. between entries)
+fn read_bytes(reader: &mut impl Read, eof_ok: bool) -> CacheResult<Option<Vec<u8>>> {
+    let mut len = [0u8; 4];
+    match reader.read_exact(&mut len) {
+        Ok(()) => {}
+        Err(e) if eof_ok && e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
     }
+    let mut bytes = vec![0u8; u32::from_le_bytes(len) as usize];
+    reader.read_exact(&mut bytes)?;
+    Ok(Some(bytes))
 }
 
 /// Proxy wrapper to allow using `CacheBackend` where `DynL2CacheBackend` is expected
@@ -1270,14 +5124,80 @@ impl CacheBackend for ProxyL1ToL2 {
         self.0.remove(key)
     }
 
+    fn batch_remove<'a>(&'a self, keys: &'a [String]) -> BoxFuture<'a, CacheResult<()>> {
+        self.0.batch_remove(keys)
+    }
+
+    fn get_and_remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<Option<Bytes>>> {
+        self.0.get_and_remove(key)
+    }
+
+    fn set_if_present<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> BoxFuture<'a, CacheResult<bool>> {
+        self.0.set_if_present(key, value, ttl)
+    }
+
+    fn touch<'a>(&'a self, key: &'a str, ttl: Duration) -> BoxFuture<'a, CacheResult<bool>> {
+        self.0.touch(key, ttl)
+    }
+
     fn remove_pattern<'a>(&'a self, pattern: &'a str) -> BoxFuture<'a, CacheResult<()>> {
         self.0.remove_pattern(pattern)
     }
 
+    fn remove_if(&self, predicate: KeyPredicate) -> BoxFuture<'_, CacheResult<()>> {
+        self.0.remove_if(predicate)
+    }
+
+    fn keys(&self) -> BoxFuture<'_, CacheResult<Option<Vec<String>>>> {
+        self.0.keys()
+    }
+
+    fn keys_matching<'a>(
+        &'a self,
+        pattern: &'a str,
+        limit: usize,
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        self.0.keys_matching(pattern, limit)
+    }
+
+    fn scan_keys_page<'a>(
+        &'a self,
+        pattern: &'a str,
+        cursor: u64,
+        count: usize,
+    ) -> BoxFuture<'a, CacheResult<Option<KeyPage>>> {
+        self.0.scan_keys_page(pattern, cursor, count)
+    }
+
+    fn set_pinned<'a>(&'a self, key: &'a str, pinned: bool) -> BoxFuture<'a, CacheResult<bool>> {
+        self.0.set_pinned(key, pinned)
+    }
+
     fn health_check(&self) -> BoxFuture<'_, bool> {
         self.0.health_check()
     }
 
+    fn capacity(&self) -> Option<u64> {
+        self.0.capacity()
+    }
+
+    fn entry_count(&self) -> Option<u64> {
+        self.0.entry_count()
+    }
+
+    fn estimated_size_bytes(&self) -> Option<u64> {
+        self.0.estimated_size_bytes()
+    }
+
+    fn evict_bytes(&self, bytes: u64) -> BoxFuture<'_, CacheResult<u64>> {
+        self.0.evict_bytes(bytes)
+    }
+
     fn name(&self) -> &'static str {
         self.0.name()
     }
@@ -1314,62 +5234,293 @@ impl CacheManager {
         maxlen: Option<usize>,
     ) -> CacheResult<String> {
         match &self.streaming_backend {
-            Some(backend) => backend.stream_add(stream_key, fields, maxlen).await,
+            Some(backend) => {
+                let id = backend.stream_add(stream_key, fields, maxlen).await?;
+                self.stream_counters
+                    .published
+                    .fetch_add(1, Ordering::Relaxed);
+                Ok(id)
+            }
+            None => Err(crate::error::CacheError::ConfigError(
+                "Streaming backend not configured".to_string(),
+            )),
+        }
+    }
+
+    /// Publish several entries to a stream in one round-trip
+    ///
+    /// Redis pipelines one `XADD` per entry, instead of the N round-trips of
+    /// calling `publish_to_stream` in a loop. The batch is not atomic: other
+    /// writers' entries may be interleaved with it.
+    ///
+    /// # Arguments
+    /// * `stream_key` - Name of the stream
+    /// * `entries` - Field-value pairs of each entry, in publishing order
+    /// * `maxlen` - Optional max length for stream trimming
+    ///
+    /// # Returns
+    /// The entry IDs generated by Redis, in the order of `entries`
+    ///
+    /// # Errors
+    /// Returns error if streaming backend is not configured or the publish fails
+    pub async fn publish_to_stream_batch(
+        &self,
+        stream_key: &str,
+        entries: Vec<Vec<(String, String)>>,
+        maxlen: Option<usize>,
+    ) -> CacheResult<Vec<String>> {
+        match &self.streaming_backend {
+            Some(backend) => {
+                let ids = backend
+                    .stream_add_batch(stream_key, entries, maxlen)
+                    .await?;
+                self.stream_counters
+                    .published
+                    .fetch_add(ids.len() as u64, Ordering::Relaxed);
+                Ok(ids)
+            }
+            None => Err(crate::error::CacheError::ConfigError(
+                "Streaming backend not configured".to_string(),
+            )),
+        }
+    }
+
+    /// Read latest entries from Redis Stream
+    ///
+    /// # Arguments
+    /// * `stream_key` - Name of the stream
+    /// * `count` - Number of latest entries to retrieve
+    ///
+    /// # Returns
+    /// Vector of (`entry_id`, fields) tuples (newest first)
+    ///
+    /// # Errors
+    /// Returns error if streaming backend is not configured
+    pub async fn read_stream_latest(
+        &self,
+        stream_key: &str,
+        count: usize,
+    ) -> CacheResult<Vec<(String, Vec<(String, String)>)>> {
+        match &self.streaming_backend {
+            Some(backend) => backend.stream_read_latest(stream_key, count).await,
+            None => Err(crate::error::CacheError::ConfigError(
+                "Streaming backend not configured".to_string(),
+            )),
+        }
+    }
+
+    /// Read from Redis Stream with optional blocking
+    ///
+    /// # Arguments
+    /// * `stream_key` - Name of the stream
+    /// * `last_id` - Last ID seen ("0" for start, "$" for new only)
+    /// * `count` - Max entries to retrieve
+    /// * `block_ms` - Optional blocking timeout in ms
+    ///
+    /// # Returns
+    /// Vector of (`entry_id`, fields) tuples
+    ///
+    /// # Errors
+    /// Returns error if streaming backend is not configured
+    pub async fn read_stream(
+        &self,
+        stream_key: &str,
+        last_id: &str,
+        count: usize,
+        block_ms: Option<usize>,
+    ) -> CacheResult<Vec<(String, Vec<(String, String)>)>> {
+        match &self.streaming_backend {
+            Some(backend) => {
+                backend
+                    .stream_read(stream_key, last_id, count, block_ms)
+                    .await
+            }
+            None => Err(crate::error::CacheError::ConfigError(
+                "Streaming backend not configured".to_string(),
+            )),
+        }
+    }
+
+    /// Create a consumer group for a stream (creating the stream if needed)
+    ///
+    /// # Arguments
+    /// * `stream_key` - Name of the stream
+    /// * `group` - Consumer group name
+    /// * `start_id` - First entry the group delivers ("0" for all, "$" for new only)
+    ///
+    /// # Errors
+    /// Returns error if streaming backend is not configured or the group exists
+    pub async fn create_stream_group(
+        &self,
+        stream_key: &str,
+        group: &str,
+        start_id: &str,
+    ) -> CacheResult<()> {
+        match &self.streaming_backend {
+            Some(backend) => {
+                backend
+                    .stream_create_group(stream_key, group, start_id)
+                    .await
+            }
+            None => Err(crate::error::CacheError::ConfigError(
+                "Streaming backend not configured".to_string(),
+            )),
+        }
+    }
+
+    /// Read new entries from a stream as a member of a consumer group
+    ///
+    /// Delivered entries stay pending for the group until acknowledged with
+    /// `ack_stream_entries` (see `stream_pending_count`).
+    ///
+    /// # Arguments
+    /// * `stream_key` - Name of the stream
+    /// * `group` - Consumer group name
+    /// * `consumer` - Consumer name within the group
+    /// * `count` - Max entries to retrieve
+    /// * `block_ms` - Optional blocking timeout in ms
+    ///
+    /// # Returns
+    /// Vector of (`entry_id`, fields) tuples
+    ///
+    /// # Errors
+    /// Returns error if streaming backend is not configured or the read fails
+    pub async fn read_stream_group(
+        &self,
+        stream_key: &str,
+        group: &str,
+        consumer: &str,
+        count: usize,
+        block_ms: Option<usize>,
+    ) -> CacheResult<Vec<(String, Vec<(String, String)>)>> {
+        match &self.streaming_backend {
+            Some(backend) => {
+                let entries = backend
+                    .stream_read_group(stream_key, group, consumer, count, block_ms)
+                    .await?;
+                self.stream_counters
+                    .delivered
+                    .fetch_add(entries.len() as u64, Ordering::Relaxed);
+                Ok(entries)
+            }
+            None => Err(crate::error::CacheError::ConfigError(
+                "Streaming backend not configured".to_string(),
+            )),
+        }
+    }
+
+    /// Acknowledge processed entries of a consumer group
+    ///
+    /// # Arguments
+    /// * `stream_key` - Name of the stream
+    /// * `group` - Consumer group name
+    /// * `ids` - Entry IDs to acknowledge
+    ///
+    /// # Errors
+    /// Returns error if streaming backend is not configured or the ack fails
+    pub async fn ack_stream_entries(
+        &self,
+        stream_key: &str,
+        group: &str,
+        ids: &[String],
+    ) -> CacheResult<()> {
+        match &self.streaming_backend {
+            Some(backend) => {
+                backend.stream_ack(stream_key, group, ids).await?;
+                self.stream_counters
+                    .acked
+                    .fetch_add(ids.len() as u64, Ordering::Relaxed);
+                Ok(())
+            }
+            None => Err(crate::error::CacheError::ConfigError(
+                "Streaming backend not configured".to_string(),
+            )),
+        }
+    }
+
+    /// Number of entries delivered to `group` but not yet acknowledged
+    ///
+    /// This is the group's backlog across all consumers and instances (Redis
+    /// `XPENDING` summary); a steadily growing count means consumers fall
+    /// behind or crash before acknowledging.
+    ///
+    /// # Errors
+    /// Returns error if streaming backend is not configured or doesn't support it
+    pub async fn stream_pending_count(&self, group: &str, stream_key: &str) -> CacheResult<usize> {
+        match &self.streaming_backend {
+            Some(backend) => backend.stream_pending_count(group, stream_key).await,
             None => Err(crate::error::CacheError::ConfigError(
                 "Streaming backend not configured".to_string(),
             )),
         }
     }
 
-    /// Read latest entries from Redis Stream
+    /// Delete specific entries from a stream by ID
     ///
-    /// # Arguments
-    /// * `stream_key` - Name of the stream
-    /// * `count` - Number of latest entries to retrieve
+    /// Unlike the `maxlen` trimming of `publish_to_stream`, which only drops the
+    /// oldest entries, this removes exactly the given entries (Redis `XDEL`),
+    /// e.g. one user's events for a GDPR deletion request. Consumer groups keep
+    /// deleted entries in their pending lists until acknowledged.
     ///
     /// # Returns
-    /// Vector of (`entry_id`, fields) tuples (newest first)
+    /// Number of entries deleted; IDs that don't exist are skipped
     ///
     /// # Errors
-    /// Returns error if streaming backend is not configured
-    pub async fn read_stream_latest(
-        &self,
-        stream_key: &str,
-        count: usize,
-    ) -> CacheResult<Vec<(String, Vec<(String, String)>)>> {
+    /// Returns error if streaming backend is not configured or the delete fails
+    pub async fn stream_delete(&self, stream_key: &str, ids: &[&str]) -> CacheResult<usize> {
         match &self.streaming_backend {
-            Some(backend) => backend.stream_read_latest(stream_key, count).await,
+            Some(backend) => backend.stream_delete(stream_key, ids).await,
             None => Err(crate::error::CacheError::ConfigError(
                 "Streaming backend not configured".to_string(),
             )),
         }
     }
 
-    /// Read from Redis Stream with optional blocking
+    /// Stream operations made through this manager
+    ///
+    /// Counts are local to this instance; use `stream_pending_count` for the
+    /// backlog of a consumer group as a whole.
+    pub fn get_stream_stats(&self) -> StreamStats {
+        StreamStats {
+            entries_published: self.stream_counters.published.load(Ordering::Relaxed),
+            entries_delivered: self.stream_counters.delivered.load(Ordering::Relaxed),
+            entries_acked: self.stream_counters.acked.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Reclaim stream entries left pending by stalled consumers of a group
     ///
     /// # Arguments
+    /// * `group` - Consumer group name
+    /// * `consumer` - Consumer that takes ownership of the reclaimed entries
     /// * `stream_key` - Name of the stream
-    /// * `last_id` - Last ID seen ("0" for start, "$" for new only)
-    /// * `count` - Max entries to retrieve
-    /// * `block_ms` - Optional blocking timeout in ms
+    /// * `min_idle_ms` - Only claim entries pending for at least this long
+    /// * `start_id` - ID to start scanning the pending list from ("0-0" for start)
+    /// * `count` - Max entries to claim
     ///
     /// # Returns
-    /// Vector of (`entry_id`, fields) tuples
+    /// Vector of (`entry_id`, fields) tuples now owned by `consumer`
     ///
     /// # Errors
-    /// Returns error if streaming backend is not configured
-    pub async fn read_stream(
+    /// Returns error if streaming backend is not configured or the claim fails
+    pub async fn stream_autoclaim(
         &self,
+        group: &str,
+        consumer: &str,
         stream_key: &str,
-        last_id: &str,
+        min_idle_ms: u64,
+        start_id: &str,
         count: usize,
-        block_ms: Option<usize>,
     ) -> CacheResult<Vec<(String, Vec<(String, String)>)>> {
         match &self.streaming_backend {
             Some(backend) => {
-                backend
-                    .stream_read(stream_key, last_id, count, block_ms)
-                    .await
+                let claimed = backend
+                    .stream_autoclaim(group, consumer, stream_key, min_idle_ms, start_id, count)
+                    .await?;
+                self.stream_counters
+                    .delivered
+                    .fetch_add(claimed.len() as u64, Ordering::Relaxed);
+                Ok(claimed)
             }
             None => Err(crate::error::CacheError::ConfigError(
                 "Streaming backend not configured".to_string(),
@@ -1401,7 +5552,10 @@ impl CacheManager {
     /// # Errors
     ///
     /// Returns an error if invalidation fails.
-    pub async fn invalidate(&self, key: &str) -> CacheResult<()> {
+    pub async fn invalidate(&self, key: &(impl CacheKey + ?Sized)) -> CacheResult<()> {
+        let key: &str = &key.cache_key();
+        let key: &str = &self.storage_key(key);
+        self.log_write(key);
         // Remove from ALL tiers
         for tier in &self.tiers {
             if let Err(e) = tier.remove(key).await {
@@ -1413,9 +5567,28 @@ impl CacheManager {
         }
 
         // Broadcast to other instances
+        self.broadcast_remove(key).await?;
+
+        debug!("Invalidated '{}' across all instances", key);
+        Ok(())
+    }
+
+    /// Publish a `Remove` message for `key` if invalidation is enabled
+    ///
+    /// With a batch window the key is queued for the next `RemoveBulk` instead.
+    async fn broadcast_remove(&self, key: &str) -> CacheResult<()> {
         #[cfg(feature = "redis")]
         {
             if let Some(publisher) = &self.invalidation_publisher {
+                if let Some(batcher) = &self.invalidation_batcher {
+                    batcher.push(
+                        key,
+                        publisher,
+                        &self.invalidation_stats,
+                        self.spawner.as_ref(),
+                    );
+                    return Ok(());
+                }
                 let mut pub_lock: tokio::sync::MutexGuard<
                     '_,
                     crate::invalidation::InvalidationPublisher,
@@ -1427,8 +5600,35 @@ impl CacheManager {
                     .fetch_add(1, Ordering::Relaxed);
             }
         }
+        #[cfg(not(feature = "redis"))]
+        let _ = key;
+        Ok(())
+    }
 
-        debug!("Invalidated '{}' across all instances", key);
+    /// Invalidate several keys across all tiers and instances
+    ///
+    /// Each tier removes the keys with a single `batch_remove` call (one `DEL`
+    /// on Redis) instead of one removal per key, and other instances receive a
+    /// single `RemoveBulk` message.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use multi_tier_cache::CacheManager;
+    /// # async fn example(cache_manager: &CacheManager) -> anyhow::Result<()> {
+    /// cache_manager.invalidate_many(&["user:1", "user:2", "user:3"]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// # Errors
+    ///
+    /// Returns an error if publishing the broadcast fails.
+    pub async fn invalidate_many(&self, keys: &[&str]) -> CacheResult<()> {
+        let keys: Vec<String> = keys
+            .iter()
+            .map(|key| self.storage_key(key).into_owned())
+            .collect();
+        self.invalidate_stored_keys(&keys).await?;
+        debug!(count = keys.len(), "Invalidated keys across all instances");
         Ok(())
     }
 
@@ -1461,11 +5661,17 @@ impl CacheManager {
     /// Returns an error if cache update fails.
     pub async fn update_cache(
         &self,
-        key: &str,
+        key: &(impl CacheKey + ?Sized),
         value: Bytes,
         ttl: Option<Duration>,
     ) -> CacheResult<()> {
-        let ttl = ttl.unwrap_or_else(|| CacheStrategy::Default.to_duration());
+        let key: &str = &key.cache_key();
+        let key: &str = &self.storage_key(key);
+        if self.reject_value(key, &value) {
+            return Ok(());
+        }
+        let ttl = ttl.unwrap_or(self.strategy_durations.default);
+        self.log_write(key);
 
         // Update ALL tiers
         for tier in &self.tiers {
@@ -1539,6 +5745,275 @@ impl CacheManager {
         Ok(())
     }
 
+    /// Invalidate all keys matching any of `patterns`
+    ///
+    /// Like calling `invalidate_pattern` for each pattern, but every tier is
+    /// searched for all patterns concurrently, keys matched by several patterns
+    /// are removed once, and other instances receive a single `RemoveBulk`
+    /// message listing the keys. Tiers that can't enumerate their keys fall back
+    /// to `remove_pattern`, so their matches are removed but not counted.
+    ///
+    /// Returns the number of distinct keys removed.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use multi_tier_cache::CacheManager;
+    /// # async fn example(cache_manager: &CacheManager) -> anyhow::Result<()> {
+    /// let removed = cache_manager
+    ///     .invalidate_patterns(&["user:42:*", "session:42:*"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// # Errors
+    ///
+    /// Returns an error if searching a tier or publishing the broadcast fails.
+    pub async fn invalidate_patterns(&self, patterns: &[&str]) -> CacheResult<usize> {
+        debug!(patterns = ?patterns, "Invalidating patterns across all tiers");
+
+        // 1. Search every tier for every pattern at once
+        let searches = self.tiers.iter().flat_map(|tier| {
+            patterns.iter().map(move |pattern| async move {
+                (
+                    tier,
+                    *pattern,
+                    tier.backend.keys_matching(pattern, usize::MAX).await,
+                )
+            })
+        });
+        let mut keys = std::collections::BTreeSet::new();
+        for (tier, pattern, found) in futures_util::future::join_all(searches).await {
+            match found? {
+                Some(found) => keys.extend(found),
+                None => tier.backend.remove_pattern(pattern).await?,
+            }
+        }
+
+        // 2. Remove each distinct key once, with one broadcast
+        let keys: Vec<String> = keys.into_iter().collect();
+        self.invalidate_stored_keys(&keys).await?;
+
+        debug!(removed = keys.len(), "Invalidated keys matching patterns");
+        Ok(keys.len())
+    }
+
+    /// Remove storage keys from every tier and publish one `RemoveBulk` for them
+    async fn invalidate_stored_keys(&self, keys: &[String]) -> CacheResult<()> {
+        for key in keys {
+            self.log_write(key);
+        }
+        if !keys.is_empty() {
+            for tier in &self.tiers {
+                if let Err(e) = tier.batch_remove(keys).await {
+                    warn!(
+                        "Failed to remove {} keys from L{}: {}",
+                        keys.len(),
+                        tier.tier_level,
+                        e
+                    );
+                }
+            }
+        }
+
+        #[cfg(feature = "redis")]
+        if !keys.is_empty()
+            && let Some(publisher) = &self.invalidation_publisher
+        {
+            let msg = InvalidationMessage::remove_bulk(keys.to_vec());
+            publisher.lock().await.publish(&msg).await?;
+            self.invalidation_stats
+                .messages_sent
+                .fetch_add(1, Ordering::Relaxed);
+        }
+        Ok(())
+    }
+
+    /// Store a value and tag it, for later invalidation with `invalidate_tags_all`
+    ///
+    /// Writes like `set_with_strategy`, then adds the key to the L2 tag set of
+    /// each of `tags` (Redis sets `mtc:tag:<tag>`), so every instance sharing
+    /// L2 sees the tags.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails, there is no L2 tier, or the L2
+    /// backend doesn't support tags.
+    pub async fn set_with_tags(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        value: Bytes,
+        strategy: impl Into<CacheStrategy>,
+        tags: &[&str],
+    ) -> CacheResult<()> {
+        let key: &str = &key.cache_key();
+        self.set_with_strategy(key, value, strategy).await?;
+        let tags: Vec<String> = tags.iter().map(ToString::to_string).collect();
+        self.tag_tier()?
+            .backend
+            .tag_key(&self.storage_key(key), &tags)
+            .await
+    }
+
+    /// Invalidate the keys tagged with every one of `tags`
+    ///
+    /// Only keys in the intersection of the tag sets (Redis `SINTER`) are
+    /// removed: with tags `user:42` and `orders`, a key tagged only `user:42`
+    /// stays. Pass a single tag to invalidate all keys carrying it. Removed keys
+    /// leave these tag sets and other instances receive one `RemoveBulk`.
+    ///
+    /// Returns the number of keys invalidated, counting keys that had
+    /// already expired but were still listed under the tags.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no L2 tier, the L2 backend doesn't support
+    /// tags, or reading the tag sets or publishing the broadcast fails.
+    pub async fn invalidate_tags_all(&self, tags: &[&str]) -> CacheResult<usize> {
+        let tier = self.tag_tier()?;
+        let tags: Vec<String> = tags.iter().map(ToString::to_string).collect();
+        let keys = tier
+            .backend
+            .keys_with_all_tags(&tags)
+            .await?
+            .ok_or_else(|| {
+                CacheError::BackendError(format!(
+                    "{} backend does not support tags",
+                    tier.backend.name()
+                ))
+            })?;
+
+        self.invalidate_stored_keys(&keys).await?;
+        tier.backend.untag_keys(&keys, &tags).await?;
+
+        debug!(tags = ?tags, removed = keys.len(), "Invalidated keys with all tags");
+        Ok(keys.len())
+    }
+
+    /// Store a typed value and add `member` to the secondary index `index`
+    ///
+    /// Writes like `set_with_strategy`, then adds `member` (typically the key,
+    /// or an ID within it) to the L2 index (Redis sorted set
+    /// `mtc:index:<index>`), so related keys such as "all keys of user 42" can
+    /// be listed with `get_index_members` instead of a `SCAN`. Unlike tag sets,
+    /// index members are kept in lexicographic order.
+    ///
+    /// Index members are not removed when the key expires or is invalidated:
+    /// invalidate with `invalidate_indexed` instead, or clean up with
+    /// `remove_index_member` / `drop_index`. Otherwise `get_index_members`
+    /// keeps listing members whose keys are gone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or the write fails, there is no L2
+    /// tier, or the L2 backend doesn't support secondary indexes.
+    pub async fn set_indexed<T: serde::Serialize>(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        value: &T,
+        strategy: impl Into<CacheStrategy>,
+        index: &str,
+        member: &str,
+    ) -> CacheResult<()> {
+        let key: &str = &key.cache_key();
+        let bytes = self.encode_value(value)?;
+        self.set_with_strategy(key, bytes, strategy).await?;
+        self.tag_tier()?.backend.index_add(index, member).await
+    }
+
+    /// Members of the secondary index `index`, in lexicographic order
+    ///
+    /// An index nothing was added to has no members.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no L2 tier, the L2 backend doesn't support
+    /// secondary indexes, or reading the index fails.
+    pub async fn get_index_members(&self, index: &str) -> CacheResult<Vec<String>> {
+        let tier = self.tag_tier()?;
+        tier.backend.index_members(index).await?.ok_or_else(|| {
+            CacheError::BackendError(format!(
+                "{} backend does not support secondary indexes",
+                tier.backend.name()
+            ))
+        })
+    }
+
+    /// Remove `member` from the secondary index `index`
+    ///
+    /// Removing a member that isn't in the index is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no L2 tier, the L2 backend doesn't support
+    /// secondary indexes, or the removal fails.
+    pub async fn remove_index_member(&self, index: &str, member: &str) -> CacheResult<()> {
+        self.tag_tier()?.backend.index_remove(index, member).await
+    }
+
+    /// Drop the secondary index `index` with all its members
+    ///
+    /// The indexed keys themselves are left cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no L2 tier, the L2 backend doesn't support
+    /// secondary indexes, or the removal fails.
+    pub async fn drop_index(&self, index: &str) -> CacheResult<()> {
+        self.tag_tier()?.backend.index_drop(index).await
+    }
+
+    /// Invalidate a key written by `set_indexed` and remove it from its index
+    ///
+    /// Invalidates `key` like `invalidate`, then removes `member` from
+    /// `index`, so `get_index_members` no longer lists it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the invalidation fails, there is no L2 tier, the L2
+    /// backend doesn't support secondary indexes, or the removal fails.
+    pub async fn invalidate_indexed(
+        &self,
+        key: &(impl CacheKey + ?Sized),
+        index: &str,
+        member: &str,
+    ) -> CacheResult<()> {
+        self.invalidate(key).await?;
+        self.remove_index_member(index, member).await
+    }
+
+    /// The tier keeping tag sets and secondary indexes (L2)
+    fn tag_tier(&self) -> CacheResult<&CacheTier> {
+        self.tiers.get(1).ok_or_else(|| {
+            CacheError::ConfigError("Tags and indexes require an L2 tier".to_string())
+        })
+    }
+
+    /// Drop every L1 entry whose key matches `predicate`
+    ///
+    /// Local only: L2 and other instances are untouched, and no keys are
+    /// scanned in Redis. Meant for clearing a tenant's hot entries after it
+    /// was deleted, e.g. `|key| key.starts_with("tenant:42:")`. The predicate
+    /// sees keys as stored (i.e. namespaced). Moka and `DashMap` evaluate it in
+    /// one pass over the store; other backends list and remove their keys.
+    ///
+    /// To reach other instances as well, use `invalidate_pattern`: remote
+    /// subscribers apply the broadcast `RemovePattern` the same way.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the L1 backend can't enumerate or remove its keys.
+    pub async fn invalidate_l1_if<F>(&self, predicate: F) -> CacheResult<()>
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        let Some(l1) = self.tiers.first() else {
+            return Ok(());
+        };
+        l1.backend.remove_if(Arc::new(predicate)).await?;
+        debug!(tier = %l1.tier_level, "Invalidated L1 entries matching predicate");
+        Ok(())
+    }
+
     /// Set value with automatic broadcast to all instances
     ///
     /// This is a write-through operation that updates the cache and
@@ -1565,12 +6040,15 @@ impl CacheManager {
     /// Returns an error if cache set or broadcast fails.
     pub async fn set_with_broadcast(
         &self,
-        key: &str,
+        key: &(impl CacheKey + ?Sized),
         value: Bytes,
-        strategy: CacheStrategy,
+        strategy: impl Into<CacheStrategy>,
     ) -> CacheResult<()> {
+        let key: &str = &key.cache_key();
+        let key: &str = &self.storage_key(key);
+        let strategy = strategy.into();
         #[cfg(feature = "redis")]
-        let ttl = strategy.to_duration();
+        let ttl = self.strategy_durations.duration(&strategy);
 
         // Set in local caches
         self.set_with_strategy(key, value.clone(), strategy).await?;
@@ -1605,6 +6083,142 @@ impl CacheManager {
     }
 }
 
+/// Entry-style handle for a single cache key, created by `CacheManager::entry`
+///
+/// Nothing is read or written until `or_insert` or `or_insert_with` is awaited.
+pub struct Entry<'a> {
+    manager: &'a CacheManager,
+    key: &'a str,
+    modify: Option<Box<dyn FnOnce(Bytes) -> Bytes + Send + 'a>>,
+}
+
+impl<'a> Entry<'a> {
+    /// The key this entry refers to
+    #[must_use]
+    pub fn key(&self) -> &'a str {
+        self.key
+    }
+
+    /// Update the cached value in place if the key is present
+    ///
+    /// The read-modify-write runs under the key's in-flight lock, so it is
+    /// serialized with other entry updates and stampede-protected computations
+    /// on this instance (not across instances). The new value is written to all
+    /// tiers with `update_cache`, so other instances are notified when
+    /// invalidation is enabled.
+    #[must_use]
+    pub fn and_modify<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(Bytes) -> Bytes + Send + 'a,
+    {
+        self.modify = Some(Box::new(f));
+        self
+    }
+
+    /// Return the cached value, or store and return `default` if the key is missing
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if cache operations fail.
+    pub async fn or_insert(
+        self,
+        strategy: impl Into<CacheStrategy>,
+        default: Bytes,
+    ) -> CacheResult<Bytes> {
+        self.or_insert_with(strategy, || async move { Ok(default) })
+            .await
+    }
+
+    /// Return the cached value, or compute, store and return it if the key is missing
+    ///
+    /// Without `and_modify` this is `CacheManager::get_or_compute_with`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `compute_fn` or cache operations fail.
+    pub async fn or_insert_with<F, Fut>(
+        self,
+        strategy: impl Into<CacheStrategy>,
+        compute_fn: F,
+    ) -> CacheResult<Bytes>
+    where
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = CacheResult<Bytes>> + Send,
+    {
+        let Some(modify) = self.modify else {
+            return self
+                .manager
+                .get_or_compute_with(self.key, strategy, compute_fn)
+                .await;
+        };
+
+        let manager = self.manager;
+        let strategy = strategy.into();
+        let key: &str = &manager.storage_key(self.key);
+        manager.record_stat(key, StatEvent::Request);
+        let (tx, _guard) = manager.lock_in_flight(key).await;
+
+        let result = match manager.get_multi_tier(key).await {
+            Ok(Some(current)) => {
+                let value = modify(current);
+                manager
+                    .update_cache(
+                        key,
+                        value.clone(),
+                        Some(manager.strategy_durations.duration(&strategy)),
+                    )
+                    .await
+                    .map(|()| value)
+            }
+            Ok(None) => {
+                manager.record_stat(key, StatEvent::Miss);
+                match manager.timed_compute(compute_fn()).await {
+                    Ok(value) => manager
+                        .set_with_strategy(key, value.clone(), strategy)
+                        .await
+                        .map(|()| value),
+                    Err(e) => Err(e),
+                }
+            }
+            Err(e) => Err(e),
+        };
+
+        let _ = tx.send(Some(result.clone().map(Some)));
+        result
+    }
+}
+
+impl Drop for CacheManager {
+    fn drop(&mut self) {
+        self.close_promotion_queue();
+        #[cfg(feature = "redis")]
+        self.close_invalidation_batcher();
+        #[cfg(feature = "redis")]
+        if let Some(subscriber) = &self.keyspace_subscriber {
+            subscriber.shutdown();
+        }
+    }
+}
+
+/// Stream statistics of a `CacheManager` (see `CacheManager::get_stream_stats`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StreamStats {
+    /// Entries published with `publish_to_stream`
+    pub entries_published: u64,
+    /// Entries delivered by `read_stream_group` or claimed by `stream_autoclaim`
+    pub entries_delivered: u64,
+    /// Entry IDs acknowledged with `ack_stream_entries`
+    pub entries_acked: u64,
+}
+
+impl StreamStats {
+    /// Entries this instance read through a consumer group but hasn't acknowledged
+    #[must_use]
+    pub fn unacked(&self) -> u64 {
+        self.entries_delivered.saturating_sub(self.entries_acked)
+    }
+}
+
 /// Cache Manager statistics
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -1618,6 +6232,138 @@ pub struct CacheManagerStats {
     pub l1_hit_rate: f64,
     pub promotions: usize,
     pub in_flight_requests: usize,
+    /// L2 operations currently holding a concurrency permit (0 without a limit)
+    pub l2_permits_in_use: usize,
+    /// Queued promotions discarded because the async promotion queue was full
+    pub async_promotions_dropped: u64,
+    /// Queued promotions discarded because they waited in the queue over half a second
+    pub async_promotions_expired: u64,
+    /// Promotions skipped because the same key was already being promoted
+    pub deduplicated_promotions: u64,
+    /// Successful `set_with_strategy` writes (including computed values)
+    pub sets: u64,
+    /// Value bytes read from L2 (see `TierStats::bytes_read` for other tiers)
+    pub l2_bytes_read: u64,
+    /// Value bytes written to L2, including promotions into it
+    pub l2_bytes_written: u64,
+    /// Writes skipped because the value was rejected (JSON `null` or a custom predicate)
+    pub rejected_nulls: u64,
+    /// Stored values above the large value threshold (see `with_large_value_threshold`)
+    pub large_values: u64,
+    /// Sampled L1 hits whose value L2 no longer had or held differently
+    /// (see `with_coherence_sampling`)
+    pub stale_l1_hits: u64,
+    /// TTLs extended for frequently hit keys (see `with_adaptive_ttl`)
+    pub ttl_extensions: u64,
+    /// Bytes evicted to stay under the global memory cap (see `with_global_max_bytes`)
+    pub memory_evicted_bytes: u64,
+    /// `compute_fn` calls made on a miss (excludes coalesced waiters)
+    pub computes: u64,
+    /// Total time spent in `compute_fn` (see `avg_compute_duration`)
+    pub total_compute_time: Duration,
+    /// Callers served by another caller's in-flight compute instead of computing
+    pub coalesced_waiters: u64,
+    /// Lookups that went through the in-flight request map used for
+    /// coalescing; L1 hits take a fast path that never touches it
+    pub in_flight_lookups: u64,
+}
+
+impl CacheManagerStats {
+    /// Stats from request counters, with rates derived and other fields zeroed
+    fn from_counts(
+        total_requests: u64,
+        l1_hits: u64,
+        l2_hits: u64,
+        misses: u64,
+        sets: u64,
+    ) -> Self {
+        let rate = |hits: u64| {
+            if total_requests > 0 {
+                #[allow(clippy::cast_precision_loss)]
+                {
+                    (hits as f64 / total_requests as f64) * 100.0
+                }
+            } else {
+                0.0
+            }
+        };
+
+        Self {
+            total_requests,
+            l1_hits,
+            l2_hits,
+            total_hits: l1_hits + l2_hits,
+            misses,
+            hit_rate: rate(l1_hits + l2_hits),
+            l1_hit_rate: rate(l1_hits),
+            promotions: 0,
+            in_flight_requests: 0,
+            l2_permits_in_use: 0,
+            async_promotions_dropped: 0,
+            async_promotions_expired: 0,
+            deduplicated_promotions: 0,
+            sets,
+            l2_bytes_read: 0,
+            l2_bytes_written: 0,
+            rejected_nulls: 0,
+            large_values: 0,
+            stale_l1_hits: 0,
+            ttl_extensions: 0,
+            memory_evicted_bytes: 0,
+            computes: 0,
+            total_compute_time: Duration::ZERO,
+            coalesced_waiters: 0,
+            in_flight_lookups: 0,
+        }
+    }
+
+    /// Combine the stats of two managers, e.g. one per shard
+    ///
+    /// Counters (and the point-in-time gauges `in_flight_requests` and
+    /// `l2_permits_in_use`) are summed. `hit_rate` and `l1_hit_rate` are
+    /// recomputed from the summed counts, so they are weighted by each side's
+    /// `total_requests` rather than averaged.
+    #[must_use]
+    pub fn merge(&self, other: &Self) -> Self {
+        Self {
+            promotions: self.promotions + other.promotions,
+            in_flight_requests: self.in_flight_requests + other.in_flight_requests,
+            l2_permits_in_use: self.l2_permits_in_use + other.l2_permits_in_use,
+            async_promotions_dropped: self.async_promotions_dropped
+                + other.async_promotions_dropped,
+            async_promotions_expired: self.async_promotions_expired
+                + other.async_promotions_expired,
+            deduplicated_promotions: self.deduplicated_promotions + other.deduplicated_promotions,
+            l2_bytes_read: self.l2_bytes_read + other.l2_bytes_read,
+            l2_bytes_written: self.l2_bytes_written + other.l2_bytes_written,
+            rejected_nulls: self.rejected_nulls + other.rejected_nulls,
+            large_values: self.large_values + other.large_values,
+            stale_l1_hits: self.stale_l1_hits + other.stale_l1_hits,
+            ttl_extensions: self.ttl_extensions + other.ttl_extensions,
+            memory_evicted_bytes: self.memory_evicted_bytes + other.memory_evicted_bytes,
+            computes: self.computes + other.computes,
+            total_compute_time: self.total_compute_time + other.total_compute_time,
+            coalesced_waiters: self.coalesced_waiters + other.coalesced_waiters,
+            in_flight_lookups: self.in_flight_lookups + other.in_flight_lookups,
+            ..Self::from_counts(
+                self.total_requests + other.total_requests,
+                self.l1_hits + other.l1_hits,
+                self.l2_hits + other.l2_hits,
+                self.misses + other.misses,
+                self.sets + other.sets,
+            )
+        }
+    }
+
+    /// Average time spent in `compute_fn` per computed miss
+    ///
+    /// Compare with L2 latency to judge how much work the cache saves.
+    /// Returns `None` before the first compute.
+    #[must_use]
+    pub fn avg_compute_duration(&self) -> Option<Duration> {
+        let computes = u32::try_from(self.computes).unwrap_or(u32::MAX);
+        self.total_compute_time.checked_div(computes)
+    }
 }
 
 #[cfg(test)]
@@ -1634,7 +6380,7 @@ mod tests {
             Arc::new(ProxyL1ToL2(l2)) as Arc<dyn L2CacheBackend>,
             None,
         )
-        .unwrap();
+        .unwrap_or_else(|_| panic!("Failed to create manager"));
 
         let key = "cancellation_test_key";
         let manager_clone = Arc::new(manager);
@@ -1661,4 +6407,65 @@ mod tests {
             "Key was not cleaned up after cancellation"
         );
     }
+
+    #[tokio::test]
+    async fn test_cache_strategy_conversions() {
+        assert_eq!(
+            CacheStrategy::seconds(30).to_duration(),
+            Duration::from_secs(30)
+        );
+        assert_eq!(
+            CacheStrategy::from(Duration::from_millis(1500)).to_duration(),
+            Duration::from_millis(1500)
+        );
+        assert_eq!(
+            CacheStrategy::from(45).to_duration(),
+            Duration::from_secs(45)
+        );
+
+        // Bare durations and seconds are accepted wherever a strategy is
+        let manager = CacheManager::new_with_backends(
+            Arc::new(crate::backends::DashMapCache::new()),
+            Arc::new(crate::backends::DashMapCache::new()),
+            None,
+        )
+        .unwrap_or_else(|_| panic!("Failed to create manager"));
+        manager
+            .set_with_strategy("duration_key", Bytes::from("a"), Duration::from_mins(1))
+            .await
+            .unwrap_or_else(|_| panic!("Failed to set with Duration"));
+        let value = manager
+            .get_or_compute_with("seconds_key", 60, || async { Ok(Bytes::from("b")) })
+            .await
+            .unwrap_or_else(|_| panic!("Failed to compute with seconds"));
+        assert_eq!(value, Bytes::from("b"));
+        assert_eq!(
+            manager.get("duration_key").await.unwrap_or(None),
+            Some(Bytes::from("a"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scaled_ttl_is_clamped() {
+        let day = Duration::from_hours(24);
+        assert_eq!(scale_ttl(day, 2.0), day * 2);
+        assert_eq!(scale_ttl(Duration::MAX, 8.0), MAX_TIER_TTL);
+        assert_eq!(scale_ttl(day, f64::INFINITY), MAX_TIER_TTL);
+        assert_eq!(scale_ttl(day, f64::MAX), MAX_TIER_TTL);
+        assert_eq!(scale_ttl(day, f64::NAN), day);
+        assert_eq!(scale_ttl(day, -1.0), day);
+        assert_eq!(scale_ttl(Duration::MAX, f64::NAN), MAX_TIER_TTL);
+
+        // An L4-style tier stores an extreme TTL without panicking
+        let backend = Arc::new(crate::backends::DashMapCache::new());
+        let tier = CacheTier::new(backend.clone(), 4, false, 1, 8.0);
+        tier.set_with_ttl("huge_ttl", Bytes::from("v"), Duration::MAX)
+            .await
+            .unwrap_or_else(|_| panic!("Failed to set with huge TTL"));
+        let (_, ttl) = backend
+            .get_with_ttl("huge_ttl")
+            .await
+            .unwrap_or_else(|| panic!("Value missing"));
+        assert!(ttl.is_some_and(|ttl| ttl <= MAX_TIER_TTL));
+    }
 }
@@ -0,0 +1,261 @@
+//! Declarative cache configuration
+//!
+//! `CacheConfig` deserializes from any serde format (YAML, TOML, JSON, ...) and
+//! maps onto `CacheSystemBuilder` calls, so a deployment can describe its cache
+//! in a config file instead of code. Build it with `CacheSystem::from_config()`
+//! or, for an already-parsed document, `CacheSystem::from_config_value()`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use multi_tier_cache::CacheSystem;
+//!
+//! # async fn example() -> anyhow::Result<()> {
+//! let cache = CacheSystem::from_config_value(serde_json::json!({
+//!     "codec": "json",
+//!     "redis_url": "redis://127.0.0.1:6379",
+//!     "key_prefix": "myapp:",
+//!     "strategies": { "short_term_secs": 120 },
+//!     "tiers": [
+//!         { "backend": "moka", "capacity": 10000 },
+//!         { "backend": "redis", "ttl_scale": 2.0 }
+//!     ]
+//! }))
+//! .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::backends::DashMapCache;
+#[cfg(feature = "quick_cache")]
+use crate::backends::QuickCacheBackend;
+#[cfg(feature = "redis")]
+use crate::backends::RedisCache;
+#[cfg(feature = "moka")]
+use crate::backends::{MokaCache, MokaCacheConfig};
+use crate::error::CacheResult;
+use crate::traits::L2CacheBackend;
+use crate::{CacheSerializer, CacheSystem, CacheSystemBuilder, StrategyDurations, TierConfig};
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Cache system configuration
+///
+/// Every field is optional; an empty document builds the default Moka + Redis
+/// system. Unknown fields are rejected so typos don't go unnoticed.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheConfig {
+    /// Serializer for typed values
+    pub codec: CodecKind,
+    /// Tag typed values with the codec (see `CacheManager::with_codec_guard()`)
+    pub codec_guard: bool,
+    /// Redis URL for Redis tiers (falls back to `REDIS_URL`)
+    pub redis_url: Option<String>,
+    /// Prefix for every key (see `CacheManager::with_key_prefix()`)
+    pub key_prefix: Option<String>,
+    /// TTLs of the named strategies
+    pub strategies: StrategyConfig,
+    /// Tiers, fastest first; empty means the default Moka L1 + Redis L2
+    pub tiers: Vec<TierDefinition>,
+}
+
+/// Serializer selection (`"json"`, `"raw"`, `"bincode"`, `"msgpack"`)
+///
+/// Binary codecs are only available with their crate feature enabled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CodecKind {
+    /// `JsonSerializer`
+    #[default]
+    Json,
+    /// `RawSerializer`
+    Raw,
+    /// `BincodeSerializer`
+    #[cfg(feature = "bincode")]
+    Bincode,
+    /// `MsgPackSerializer`
+    #[cfg(feature = "msgpack")]
+    MsgPack,
+}
+
+impl CodecKind {
+    /// The serializer this codec names
+    #[must_use]
+    pub fn serializer(self) -> CacheSerializer {
+        match self {
+            Self::Json => CacheSerializer::Json(crate::JsonSerializer),
+            Self::Raw => CacheSerializer::Raw(crate::serialization::RawSerializer),
+            #[cfg(feature = "bincode")]
+            Self::Bincode => CacheSerializer::Bincode(crate::serialization::BincodeSerializer),
+            #[cfg(feature = "msgpack")]
+            Self::MsgPack => CacheSerializer::MsgPack(crate::serialization::MsgPackSerializer),
+        }
+    }
+}
+
+/// Strategy TTL overrides in seconds; unset strategies keep their default
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StrategyConfig {
+    /// `CacheStrategy::RealTime`
+    pub real_time_secs: Option<u64>,
+    /// `CacheStrategy::ShortTerm`
+    pub short_term_secs: Option<u64>,
+    /// `CacheStrategy::MediumTerm`
+    pub medium_term_secs: Option<u64>,
+    /// `CacheStrategy::LongTerm`
+    pub long_term_secs: Option<u64>,
+    /// `CacheStrategy::Default` and the fallback TTL
+    pub default_secs: Option<u64>,
+}
+
+impl StrategyConfig {
+    /// Resolve the overrides against the built-in durations
+    #[must_use]
+    pub fn durations(&self) -> StrategyDurations {
+        let defaults = StrategyDurations::default();
+        let secs =
+            |value: Option<u64>, default: Duration| value.map_or(default, Duration::from_secs);
+        StrategyDurations {
+            real_time: secs(self.real_time_secs, defaults.real_time),
+            short_term: secs(self.short_term_secs, defaults.short_term),
+            medium_term: secs(self.medium_term_secs, defaults.medium_term),
+            long_term: secs(self.long_term_secs, defaults.long_term),
+            default: secs(self.default_secs, defaults.default),
+        }
+    }
+}
+
+/// Backend of a configured tier
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TierBackendKind {
+    /// `DashMapCache`
+    DashMap,
+    /// `MokaCache`
+    #[cfg(feature = "moka")]
+    Moka,
+    /// `QuickCacheBackend`
+    #[cfg(feature = "quick_cache")]
+    QuickCache,
+    /// `RedisCache`, connected to `CacheConfig::redis_url`
+    #[cfg(feature = "redis")]
+    Redis,
+}
+
+/// One tier of a `CacheConfig`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TierDefinition {
+    /// Backend to create
+    pub backend: TierBackendKind,
+    /// Tier level (defaults to the position in `tiers`, starting at 1)
+    pub level: Option<usize>,
+    /// Maximum entries (Moka and `QuickCache` only, default 5000)
+    pub capacity: Option<u64>,
+    /// TTL multiplier (see `TierConfig::with_ttl_scale()`)
+    pub ttl_scale: Option<f64>,
+    /// Promote hits to upper tiers (see `TierConfig::with_promotion()`)
+    pub promotion: Option<bool>,
+    /// Promote with 1/N probability (see `TierConfig::with_promotion_frequency()`)
+    pub promotion_frequency: Option<usize>,
+}
+
+impl TierDefinition {
+    /// Tier settings: the `TierConfig::as_lN()` preset for `level`, then overrides
+    fn tier_config(&self, level: usize) -> TierConfig {
+        let mut config = match level {
+            1 => TierConfig::as_l1(),
+            2 => TierConfig::as_l2(),
+            3 => TierConfig::as_l3(),
+            4 => TierConfig::as_l4(),
+            _ => TierConfig::new(level),
+        };
+        if let Some(scale) = self.ttl_scale {
+            config = config.with_ttl_scale(scale);
+        }
+        if let Some(enabled) = self.promotion {
+            config = config.with_promotion(enabled);
+        }
+        if let Some(n) = self.promotion_frequency {
+            config = config.with_promotion_frequency(n);
+        }
+        config
+    }
+
+    /// Create the tier's backend
+    async fn create_backend(
+        &self,
+        redis_url: Option<&str>,
+    ) -> CacheResult<Arc<dyn L2CacheBackend>> {
+        #[cfg(not(feature = "redis"))]
+        let _ = redis_url;
+        Ok(match self.backend {
+            TierBackendKind::DashMap => Arc::new(DashMapCache::new()),
+            #[cfg(feature = "moka")]
+            TierBackendKind::Moka => {
+                let defaults = MokaCacheConfig::default();
+                Arc::new(MokaCache::new(MokaCacheConfig {
+                    max_capacity: self.capacity.unwrap_or(defaults.max_capacity),
+                    ..defaults
+                })?)
+            }
+            #[cfg(feature = "quick_cache")]
+            TierBackendKind::QuickCache => {
+                Arc::new(QuickCacheBackend::new(self.capacity.unwrap_or(5000))?)
+            }
+            #[cfg(feature = "redis")]
+            TierBackendKind::Redis => match redis_url {
+                Some(url) => Arc::new(RedisCache::with_url(url).await?),
+                None => Arc::new(RedisCache::new().await?),
+            },
+        })
+    }
+}
+
+impl CacheConfig {
+    /// Translate the configuration into builder calls
+    ///
+    /// Connects to Redis if a Redis tier is configured (or, without tiers, if
+    /// `redis_url` is set). Further builder options can be added before
+    /// `build()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a backend can't be created.
+    pub async fn into_builder(self) -> CacheResult<CacheSystemBuilder> {
+        let mut builder = CacheSystemBuilder::new()
+            .with_serializer(self.codec.serializer())
+            .with_codec_guard(self.codec_guard)
+            .with_strategy_durations(self.strategies.durations());
+        if let Some(prefix) = self.key_prefix {
+            builder = builder.with_key_prefix(prefix);
+        }
+
+        #[cfg(feature = "redis")]
+        if self.tiers.is_empty()
+            && let Some(url) = &self.redis_url
+        {
+            builder = builder.with_l2(Arc::new(RedisCache::with_url(url).await?));
+        }
+
+        for (index, tier) in self.tiers.iter().enumerate() {
+            let level = tier.level.unwrap_or(index + 1);
+            let backend = tier.create_backend(self.redis_url.as_deref()).await?;
+            builder = builder.with_tier(backend, tier.tier_config(level));
+        }
+        Ok(builder)
+    }
+
+    /// Build the configured `CacheSystem`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a backend can't be created or the tier layout is
+    /// invalid (see `CacheSystemBuilder::validate()`).
+    pub async fn build(self) -> CacheResult<CacheSystem> {
+        self.into_builder().await?.build().await
+    }
+}
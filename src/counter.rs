@@ -0,0 +1,200 @@
+//! Request counters backing `CacheManagerStats`
+//!
+//! A single atomic is exact and cheap at low concurrency, but on many-core
+//! machines every `get` bouncing the same cache line between cores becomes
+//! measurable. The striped variant spreads increments over cache-line padded
+//! slots (one per thread, modulo the stripe count) and sums them on read.
+
+use dashmap::DashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+/// Upper bound on the number of stripes per counter
+const MAX_STRIPES: usize = 64;
+
+/// Source of per-thread stripe indices
+static NEXT_STRIPE: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// Stripe index of the current thread, assigned round-robin on first use
+    static THREAD_STRIPE: usize = NEXT_STRIPE.fetch_add(1, Ordering::Relaxed);
+}
+
+/// An `AtomicU64` on its own cache line (128 bytes covers adjacent-line prefetch)
+#[derive(Debug, Default)]
+#[repr(align(128))]
+pub(crate) struct PaddedU64(AtomicU64);
+
+/// Monotonic counter, either a single atomic or striped across cache lines
+#[derive(Debug)]
+pub(crate) enum Counter {
+    Single(AtomicU64),
+    Striped(Box<[PaddedU64]>),
+}
+
+impl Default for Counter {
+    fn default() -> Self {
+        Self::Single(AtomicU64::new(0))
+    }
+}
+
+impl Counter {
+    /// Create a striped counter sized to the available parallelism
+    pub(crate) fn striped() -> Self {
+        let stripes = std::thread::available_parallelism()
+            .map_or(1, std::num::NonZeroUsize::get)
+            .next_power_of_two()
+            .min(MAX_STRIPES);
+        Self::Striped((0..stripes).map(|_| PaddedU64::default()).collect())
+    }
+
+    /// Create a counter of the requested kind
+    pub(crate) fn new(striped: bool) -> Self {
+        if striped {
+            Self::striped()
+        } else {
+            Self::default()
+        }
+    }
+
+    /// Add one to the counter
+    pub(crate) fn increment(&self) {
+        match self {
+            Self::Single(value) => {
+                value.fetch_add(1, Ordering::Relaxed);
+            }
+            Self::Striped(stripes) => {
+                let index = THREAD_STRIPE.with(|stripe| *stripe) % stripes.len();
+                if let Some(slot) = stripes.get(index) {
+                    slot.0.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+
+    /// Current total (not a consistent snapshot while increments are in flight)
+    pub(crate) fn sum(&self) -> u64 {
+        match self {
+            Self::Single(value) => value.load(Ordering::Relaxed),
+            Self::Striped(stripes) => stripes
+                .iter()
+                .map(|slot| slot.0.load(Ordering::Relaxed))
+                .sum(),
+        }
+    }
+}
+
+/// Statistic recorded for a single key operation
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum StatEvent {
+    Request,
+    L1Hit,
+    L2Hit,
+    Miss,
+    Set,
+}
+
+/// Key-to-bucket mapping used for per-prefix statistics
+pub(crate) type BucketExtractor = dyn Fn(&str) -> Option<String> + Send + Sync;
+
+/// Plain counters for one statistics bucket
+#[derive(Debug, Default)]
+pub(crate) struct BucketCounters {
+    pub(crate) requests: AtomicU64,
+    pub(crate) l1_hits: AtomicU64,
+    pub(crate) l2_hits: AtomicU64,
+    pub(crate) misses: AtomicU64,
+    pub(crate) sets: AtomicU64,
+}
+
+impl BucketCounters {
+    fn record(&self, event: StatEvent) {
+        let counter = match event {
+            StatEvent::Request => &self.requests,
+            StatEvent::L1Hit => &self.l1_hits,
+            StatEvent::L2Hit => &self.l2_hits,
+            StatEvent::Miss => &self.misses,
+            StatEvent::Set => &self.sets,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Per-bucket counters with a bounded number of buckets
+///
+/// Once `max_buckets` distinct buckets exist, events for new buckets are
+/// counted in `overflow` instead, so memory stays bounded with unbounded key spaces.
+pub(crate) struct KeyBuckets {
+    extract: Box<BucketExtractor>,
+    buckets: DashMap<String, Arc<BucketCounters>>,
+    overflow: Arc<BucketCounters>,
+    max_buckets: usize,
+}
+
+impl KeyBuckets {
+    pub(crate) fn new(extract: Box<BucketExtractor>, max_buckets: usize) -> Self {
+        Self {
+            extract,
+            buckets: DashMap::new(),
+            overflow: Arc::new(BucketCounters::default()),
+            max_buckets,
+        }
+    }
+
+    /// Record `event` in the bucket `key` maps to (keys mapping to `None` are skipped)
+    pub(crate) fn record(&self, key: &str, event: StatEvent) {
+        let Some(bucket) = (self.extract)(key) else {
+            return;
+        };
+
+        if let Some(counters) = self.buckets.get(&bucket) {
+            counters.record(event);
+            return;
+        }
+        if self.buckets.len() >= self.max_buckets {
+            self.overflow.record(event);
+            return;
+        }
+        self.buckets.entry(bucket).or_default().record(event);
+    }
+
+    /// Visit every bucket, followed by the overflow bucket if it was used
+    pub(crate) fn for_each(&self, mut f: impl FnMut(&str, &BucketCounters)) {
+        for entry in &self.buckets {
+            f(entry.key(), entry.value());
+        }
+        if self.overflow.requests.load(Ordering::Relaxed) > 0
+            || self.overflow.sets.load(Ordering::Relaxed) > 0
+        {
+            f(crate::cache_manager::OVERFLOW_STATS_BUCKET, &self.overflow);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Counter;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_striped_counter_sums_all_threads() {
+        let counter = Arc::new(Counter::striped());
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let counter = Arc::clone(&counter);
+                std::thread::spawn(move || {
+                    for _ in 0..1000 {
+                        counter.increment();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle
+                .join()
+                .unwrap_or_else(|_| panic!("Counter thread panicked"));
+        }
+
+        assert_eq!(counter.sum(), 8000);
+    }
+}
@@ -27,6 +27,9 @@ pub enum CacheError {
     /// Internal logic error or unexpected state
     #[error("Internal error: {0}")]
     InternalError(String),
+    /// Operation did not finish before the caller's deadline
+    #[error("Timeout: {0}")]
+    Timeout(String),
 }
 
 #[cfg(feature = "redis")]
@@ -49,6 +52,12 @@ impl From<rmp_serde::decode::Error> for CacheError {
     }
 }
 
+impl From<std::io::Error> for CacheError {
+    fn from(err: std::io::Error) -> Self {
+        Self::SerializationError(err.to_string())
+    }
+}
+
 impl From<std::num::TryFromIntError> for CacheError {
     fn from(err: std::num::TryFromIntError) -> Self {
         Self::ConfigError(err.to_string())
@@ -0,0 +1,234 @@
+//! Eviction notifications for in-memory backends
+//!
+//! `MokaCache` and `DashMapCache` accept an `EvictionListener` that is called
+//! whenever they drop an entry on their own (capacity eviction, TTL expiry),
+//! but not for explicit removals or overwrites.
+//!
+//! `EvictionStreamPublisher` is a ready-made listener that forwards the events
+//! to a stream (`key`, `cause`, `timestamp` fields) for audit and analytics
+//! pipelines. Events go through a bounded queue to a background task that
+//! appends them in batches, so the cache never waits on the stream; when the
+//! queue is full, events are dropped and counted. The stream itself is capped
+//! with `XADD MAXLEN`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use multi_tier_cache::eviction::{EvictionStreamConfig, EvictionStreamPublisher};
+//! use multi_tier_cache::{MokaCache, MokaCacheConfig, RedisStreams};
+//! use std::sync::Arc;
+//!
+//! # async fn example() -> anyhow::Result<()> {
+//! let streams = Arc::new(RedisStreams::new("redis://127.0.0.1:6379").await?);
+//! let publisher = EvictionStreamPublisher::start(streams, EvictionStreamConfig::default());
+//! let l1 = MokaCache::with_eviction_listener(MokaCacheConfig::default(), publisher.listener())?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::spawner::{Spawner, TokioSpawner};
+use crate::traits::StreamingBackend;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Task name of the eviction stream publisher
+const PUBLISHER_TASK_NAME: &str = "mtc-eviction-publisher";
+
+/// Most events appended to the stream in one batch
+const MAX_BATCH: usize = 128;
+
+/// Why a backend dropped an entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EvictionCause {
+    /// The entry's TTL elapsed
+    Expired,
+    /// The entry was evicted to make room (capacity or memory cap)
+    Size,
+}
+
+impl EvictionCause {
+    /// Name used in the `cause` stream field
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Expired => "expired",
+            Self::Size => "size",
+        }
+    }
+}
+
+/// An entry dropped by a backend
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvictionEvent {
+    /// Key of the dropped entry
+    pub key: String,
+    /// Why it was dropped
+    pub cause: EvictionCause,
+    /// When it was dropped
+    pub timestamp: SystemTime,
+}
+
+impl EvictionEvent {
+    /// Event for `key`, timestamped now
+    #[must_use]
+    pub fn new(key: impl Into<String>, cause: EvictionCause) -> Self {
+        Self {
+            key: key.into(),
+            cause,
+            timestamp: SystemTime::now(),
+        }
+    }
+
+    /// Stream fields: `key`, `cause` and `timestamp` (Unix milliseconds)
+    #[must_use]
+    pub fn fields(&self) -> Vec<(String, String)> {
+        let millis = self
+            .timestamp
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis());
+        vec![
+            ("key".to_string(), self.key.clone()),
+            ("cause".to_string(), self.cause.as_str().to_string()),
+            ("timestamp".to_string(), millis.to_string()),
+        ]
+    }
+}
+
+/// Callback invoked for each entry a backend evicts
+///
+/// Called synchronously from the backend's eviction path, so it must be cheap
+/// and must not block.
+pub type EvictionListener = Arc<dyn Fn(EvictionEvent) + Send + Sync>;
+
+/// Configuration for `EvictionStreamPublisher`
+#[derive(Debug, Clone)]
+pub struct EvictionStreamConfig {
+    /// Stream the events are appended to
+    pub stream_key: String,
+    /// Approximate cap on the stream length (`XADD MAXLEN`)
+    pub maxlen: usize,
+    /// Events buffered for the publishing task; further events are dropped
+    pub queue_capacity: usize,
+}
+
+impl Default for EvictionStreamConfig {
+    fn default() -> Self {
+        Self {
+            stream_key: "mtc:evictions".to_string(),
+            maxlen: 10_000,
+            queue_capacity: 1024,
+        }
+    }
+}
+
+/// Publish counters shared with the background task
+#[derive(Debug, Default)]
+struct PublisherCounters {
+    published: AtomicU64,
+    dropped: AtomicU64,
+    failed: AtomicU64,
+}
+
+/// Publishes eviction events to a stream from a background task
+///
+/// The task runs until the publisher and every listener it handed out are dropped.
+pub struct EvictionStreamPublisher {
+    sender: mpsc::Sender<EvictionEvent>,
+    counters: Arc<PublisherCounters>,
+}
+
+impl EvictionStreamPublisher {
+    /// Start publishing to `streams` on the current Tokio runtime
+    #[must_use]
+    pub fn start(streams: Arc<dyn StreamingBackend>, config: EvictionStreamConfig) -> Self {
+        Self::start_with(streams, config, &TokioSpawner)
+    }
+
+    /// Start publishing to `streams` on a custom `Spawner`
+    #[must_use]
+    pub fn start_with(
+        streams: Arc<dyn StreamingBackend>,
+        config: EvictionStreamConfig,
+        spawner: &dyn Spawner,
+    ) -> Self {
+        let (sender, receiver) = mpsc::channel(config.queue_capacity.max(1));
+        let counters = Arc::new(PublisherCounters::default());
+        spawner.spawn_named(
+            PUBLISHER_TASK_NAME,
+            Box::pin(publish_events(
+                streams,
+                config,
+                receiver,
+                Arc::clone(&counters),
+            )),
+        );
+        Self { sender, counters }
+    }
+
+    /// Listener to pass to a backend (`MokaCache::with_eviction_listener`,
+    /// `DashMapCache::with_eviction_listener`)
+    #[must_use]
+    pub fn listener(&self) -> EvictionListener {
+        let sender = self.sender.clone();
+        let counters = Arc::clone(&self.counters);
+        Arc::new(move |event| {
+            if sender.try_send(event).is_err() {
+                counters.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        })
+    }
+
+    /// Events appended to the stream
+    #[must_use]
+    pub fn published(&self) -> u64 {
+        self.counters.published.load(Ordering::Relaxed)
+    }
+
+    /// Events dropped because the queue was full
+    #[must_use]
+    pub fn dropped(&self) -> u64 {
+        self.counters.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Events lost because appending them to the stream failed
+    #[must_use]
+    pub fn failed(&self) -> u64 {
+        self.counters.failed.load(Ordering::Relaxed)
+    }
+}
+
+/// Drain the queue into the stream, batching whatever is already queued
+async fn publish_events(
+    streams: Arc<dyn StreamingBackend>,
+    config: EvictionStreamConfig,
+    mut receiver: mpsc::Receiver<EvictionEvent>,
+    counters: Arc<PublisherCounters>,
+) {
+    while let Some(first) = receiver.recv().await {
+        let mut batch = vec![first.fields()];
+        while batch.len() < MAX_BATCH
+            && let Ok(event) = receiver.try_recv()
+        {
+            batch.push(event.fields());
+        }
+
+        let count = batch.len() as u64;
+        match streams
+            .stream_add_batch(&config.stream_key, batch, Some(config.maxlen))
+            .await
+        {
+            Ok(_) => {
+                counters.published.fetch_add(count, Ordering::Relaxed);
+                debug!(count, stream = %config.stream_key, "Published eviction events");
+            }
+            Err(e) => {
+                counters.failed.fetch_add(count, Ordering::Relaxed);
+                warn!("Failed to publish {} eviction events: {}", count, e);
+            }
+        }
+    }
+    debug!("Eviction publisher stopped");
+}
@@ -4,14 +4,16 @@
 //! It supports both cache removal (invalidation) and cache updates (refresh).
 
 use crate::error::CacheResult;
+use crate::serialization::ByteTransform;
+use crate::spawner::{Spawner, spawn_named};
 use crate::traits::StreamingBackend;
 use bytes::Bytes;
-use futures_util::StreamExt;
+use futures_util::{Stream, StreamExt};
 use redis::AsyncCommands;
 use serde::{Deserialize, Serialize};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
-use tokio::sync::broadcast;
-use tracing::{error, info, warn};
+use tokio::sync::{Notify, broadcast, mpsc};
+use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 /// Invalidation message types sent across cache instances via Redis Pub/Sub
@@ -102,6 +104,143 @@ impl InvalidationMessage {
     }
 }
 
+/// Wire format for invalidation messages
+///
+/// Carries the ID of the publishing instance next to the message so that a
+/// subscriber can recognize (and skip) messages it published itself. The
+/// message fields are flattened, so the payload stays readable by
+/// `InvalidationMessage::from_json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct InvalidationEnvelope {
+    /// Instance ID of the publisher (absent for messages from older publishers)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<String>,
+    /// Publish time in microseconds since the Unix epoch (only with
+    /// `InvalidationConfig::track_propagation_latency`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published_at_us: Option<u64>,
+    /// Whether the value of an `Update` message is compressed (see
+    /// `InvalidationConfig::update_compression`)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub compressed: bool,
+    /// The invalidation message itself
+    #[serde(flatten)]
+    pub message: InvalidationMessage,
+}
+
+/// Current time in microseconds since the Unix epoch
+fn unix_micros() -> u64 {
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO);
+    u64::try_from(since_epoch.as_micros()).unwrap_or(u64::MAX)
+}
+
+impl InvalidationEnvelope {
+    /// Serialize to JSON for transmission
+    pub(crate) fn to_json(&self) -> CacheResult<String> {
+        serde_json::to_string(self).map_err(|e| {
+            crate::error::CacheError::SerializationError(format!(
+                "Failed to serialize invalidation message: {e}"
+            ))
+        })
+    }
+
+    /// Deserialize from JSON
+    pub(crate) fn from_json(json: &str) -> CacheResult<Self> {
+        serde_json::from_str(json).map_err(|e| {
+            crate::error::CacheError::SerializationError(format!(
+                "Failed to deserialize invalidation message: {e}"
+            ))
+        })
+    }
+
+    /// Wrap `message`, compressing the value of a large enough `Update`
+    pub(crate) fn compressing(
+        message: &InvalidationMessage,
+        compression: Option<&UpdateCompression>,
+    ) -> CacheResult<Self> {
+        let mut envelope = Self {
+            origin: None,
+            published_at_us: None,
+            compressed: false,
+            message: message.clone(),
+        };
+        if let (Some(compression), InvalidationMessage::Update { value, .. }) =
+            (compression, &mut envelope.message)
+            && value.len() >= compression.min_size
+        {
+            *value = Bytes::from(compression.transform.forward(value)?);
+            envelope.compressed = true;
+        }
+        Ok(envelope)
+    }
+
+    /// The message, with a compressed `Update` value restored
+    ///
+    /// Fails if the value is compressed and there is no `compression` to undo it.
+    pub(crate) fn into_message(
+        self,
+        compression: Option<&UpdateCompression>,
+    ) -> CacheResult<InvalidationMessage> {
+        let mut message = self.message;
+        if self.compressed
+            && let InvalidationMessage::Update { value, .. } = &mut message
+        {
+            let compression = compression.ok_or_else(|| {
+                crate::error::CacheError::InvalidationError(
+                    "Received a compressed Update without update_compression configured"
+                        .to_string(),
+                )
+            })?;
+            *value = Bytes::from(compression.transform.backward(value)?);
+        }
+        Ok(message)
+    }
+}
+
+/// Compression of `Update` message values (see `InvalidationConfig::update_compression`)
+///
+/// Uses a `ByteTransform`, like `CodecChain`. Publishers and subscribers of a
+/// channel must be configured with the same transform.
+///
+/// The crate ships no compressor: implement `ByteTransform` over a compression
+/// crate such as `zstd` or `lz4_flex`, with `forward` compressing and
+/// `backward` decompressing.
+#[derive(Clone)]
+pub struct UpdateCompression {
+    /// Compressor; `backward` must undo `forward`
+    transform: Arc<dyn ByteTransform>,
+    /// Values shorter than this are sent uncompressed
+    min_size: usize,
+}
+
+impl std::fmt::Debug for UpdateCompression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UpdateCompression")
+            .field("transform", &self.transform.name())
+            .field("min_size", &self.min_size)
+            .finish()
+    }
+}
+
+impl UpdateCompression {
+    /// Compress `Update` values of at least 1 KiB with `transform`
+    pub fn new(transform: impl ByteTransform + 'static) -> Self {
+        Self {
+            transform: Arc::new(transform),
+            min_size: 1024,
+        }
+    }
+
+    /// Only compress values of at least `min_size` bytes
+    #[must_use]
+    pub fn with_min_size(mut self, min_size: usize) -> Self {
+        self.min_size = min_size;
+        self
+    }
+}
+
 /// Helper module for Bytes serialization in JSON
 mod serde_bytes_wrapper {
     use bytes::Bytes;
@@ -178,6 +317,21 @@ pub struct InvalidationConfig {
 
     /// Maximum length of audit stream (older entries are trimmed)
     pub audit_stream_maxlen: Option<usize>,
+
+    /// Stamp published messages with the publish time so subscribers can
+    /// measure propagation latency (see `InvalidationStats::propagation_latency_avg`)
+    ///
+    /// Latency is computed against the subscriber's clock, so it is only as
+    /// accurate as the clock sync between instances; negative values caused by
+    /// skew count as zero.
+    pub track_propagation_latency: bool,
+
+    /// Compress the value of large `Update` messages before publishing
+    ///
+    /// Compressed messages carry a flag so subscribers know to decompress;
+    /// every subscriber of the channel needs the same compression configured.
+    /// Subscribers without it count such messages as processing errors.
+    pub update_compression: Option<UpdateCompression>,
 }
 
 impl Default for InvalidationConfig {
@@ -188,7 +342,33 @@ impl Default for InvalidationConfig {
             enable_audit_stream: false,
             audit_stream: "cache:invalidations".to_string(),
             audit_stream_maxlen: Some(10000),
+            track_propagation_latency: false,
+            update_compression: None,
+        }
+    }
+}
+
+impl InvalidationConfig {
+    /// Use the channel and audit stream of the namespace of `key_prefix`
+    ///
+    /// Appends the prefix (without its trailing `:`) to `channel` and
+    /// `audit_stream`, e.g. `cache:invalidate` becomes `cache:invalidate:orders`
+    /// for the prefix `orders:`. Pair it with `CacheManager::with_key_prefix`
+    /// using the same prefix.
+    ///
+    /// With one channel, every subscriber receives every service's messages and
+    /// discards most of them. Per-namespace channels only reach the instances
+    /// sharing the prefix, at the cost of one channel per namespace: instances
+    /// must agree on the prefix, and `invalidate` calls are never seen by other
+    /// namespaces, even for keys they happen to share.
+    #[must_use]
+    pub fn with_key_prefix(mut self, key_prefix: &str) -> Self {
+        let namespace = key_prefix.trim_end_matches(':');
+        if !namespace.is_empty() {
+            self.channel = format!("{}:{namespace}", self.channel);
+            self.audit_stream = format!("{}:{namespace}", self.audit_stream);
         }
+        self
     }
 }
 
@@ -196,13 +376,35 @@ impl Default for InvalidationConfig {
 pub struct InvalidationPublisher {
     connection: redis::aio::ConnectionManager,
     config: InvalidationConfig,
+    /// ID stamped on every published message (used for loopback suppression)
+    instance_id: String,
 }
 
 impl InvalidationPublisher {
-    /// Create a new publisher
+    /// Create a new publisher with a random instance ID
     #[must_use]
     pub fn new(connection: redis::aio::ConnectionManager, config: InvalidationConfig) -> Self {
-        Self { connection, config }
+        Self {
+            connection,
+            config,
+            instance_id: Uuid::new_v4().to_string(),
+        }
+    }
+
+    /// Set the instance ID stamped on published messages
+    ///
+    /// Use the same ID for the `InvalidationSubscriber` of this instance so it
+    /// ignores its own messages.
+    #[must_use]
+    pub fn with_instance_id(mut self, instance_id: impl Into<String>) -> Self {
+        self.instance_id = instance_id.into();
+        self
+    }
+
+    /// Instance ID stamped on published messages
+    #[must_use]
+    pub fn instance_id(&self) -> &str {
+        &self.instance_id
     }
 
     /// Publish an invalidation message to all subscribers
@@ -211,7 +413,11 @@ impl InvalidationPublisher {
     ///
     /// Returns an error if serialization or publishing fails.
     pub async fn publish(&mut self, message: &InvalidationMessage) -> CacheResult<()> {
-        let json = message.to_json()?;
+        let mut envelope =
+            InvalidationEnvelope::compressing(message, self.config.update_compression.as_ref())?;
+        envelope.origin = Some(self.instance_id.clone());
+        envelope.published_at_us = self.config.track_propagation_latency.then(unix_micros);
+        let json = envelope.to_json()?;
 
         // Publish to Pub/Sub channel
         let _: () = self
@@ -323,9 +529,24 @@ pub struct InvalidationStats {
 
     /// Number of failed message processing attempts
     pub processing_errors: u64,
+
+    /// Number of received messages skipped because this instance published them
+    pub self_messages_ignored: u64,
+
+    /// Number of timestamped messages received (including this instance's own)
+    pub propagation_samples: u64,
+
+    /// Fastest observed publish-to-receive latency
+    pub propagation_latency_min: Option<Duration>,
+
+    /// Slowest observed publish-to-receive latency
+    pub propagation_latency_max: Option<Duration>,
+
+    /// Mean publish-to-receive latency
+    pub propagation_latency_avg: Option<Duration>,
 }
 
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
 /// Thread-safe statistics for invalidation operations
 #[derive(Debug, Default)]
@@ -337,10 +558,33 @@ pub struct AtomicInvalidationStats {
     pub patterns_received: AtomicU64,
     pub bulk_removes_received: AtomicU64,
     pub processing_errors: AtomicU64,
+    pub self_messages_ignored: AtomicU64,
+    propagation: PropagationCounters,
+}
+
+/// Publish-to-receive latency aggregates, in microseconds
+#[derive(Debug)]
+struct PropagationCounters {
+    samples: AtomicU64,
+    total_us: AtomicU64,
+    min_us: AtomicU64,
+    max_us: AtomicU64,
+}
+
+impl Default for PropagationCounters {
+    fn default() -> Self {
+        Self {
+            samples: AtomicU64::new(0),
+            total_us: AtomicU64::new(0),
+            min_us: AtomicU64::new(u64::MAX),
+            max_us: AtomicU64::new(0),
+        }
+    }
 }
 
 impl AtomicInvalidationStats {
     pub fn snapshot(&self) -> InvalidationStats {
+        let samples = self.propagation.samples.load(Ordering::Relaxed);
         InvalidationStats {
             messages_sent: self.messages_sent.load(Ordering::Relaxed),
             messages_received: self.messages_received.load(Ordering::Relaxed),
@@ -349,11 +593,171 @@ impl AtomicInvalidationStats {
             patterns_received: self.patterns_received.load(Ordering::Relaxed),
             bulk_removes_received: self.bulk_removes_received.load(Ordering::Relaxed),
             processing_errors: self.processing_errors.load(Ordering::Relaxed),
+            self_messages_ignored: self.self_messages_ignored.load(Ordering::Relaxed),
+            propagation_samples: samples,
+            propagation_latency_min: (samples > 0)
+                .then(|| Duration::from_micros(self.propagation.min_us.load(Ordering::Relaxed))),
+            propagation_latency_max: (samples > 0)
+                .then(|| Duration::from_micros(self.propagation.max_us.load(Ordering::Relaxed))),
+            propagation_latency_avg: (samples > 0).then(|| {
+                Duration::from_micros(self.propagation.total_us.load(Ordering::Relaxed) / samples)
+            }),
         }
     }
+
+    /// Record the latency of a message published at `published_at_us`
+    ///
+    /// A publish time ahead of the local clock (clock skew) counts as zero.
+    pub(crate) fn record_propagation(&self, published_at_us: u64) {
+        let latency_us = unix_micros().saturating_sub(published_at_us);
+        let counters = &self.propagation;
+        counters.total_us.fetch_add(latency_us, Ordering::Relaxed);
+        counters.min_us.fetch_min(latency_us, Ordering::Relaxed);
+        counters.max_us.fetch_max(latency_us, Ordering::Relaxed);
+        counters.samples.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+use std::sync::{Arc, OnceLock};
+
+/// Task name of the Pub/Sub invalidation subscriber
+const SUBSCRIBER_TASK_NAME: &str = "mtc-invalidation-subscriber";
+
+/// Task name of the Streams-based invalidation subscriber
+const RELIABLE_SUBSCRIBER_TASK_NAME: &str = "mtc-reliable-invalidation-subscriber";
+
+/// Task name of the keyspace notification subscriber
+const KEYSPACE_SUBSCRIBER_TASK_NAME: &str = "mtc-keyspace-subscriber";
+
+/// Key events that remove a key (see `KeyspaceSubscriber::with_events`)
+pub const DEFAULT_KEYSPACE_EVENTS: &[&str] = &["del", "expired", "evicted"];
+
+/// Messages buffered between the subscriber task and an `into_stream` consumer
+const INVALIDATION_STREAM_BUFFER: usize = 256;
+
+/// Task name of the batched invalidation publisher
+const BATCHER_TASK_NAME: &str = "mtc-invalidation-batcher";
+
+/// Collects `Remove` broadcasts for a short window and publishes them together
+///
+/// Backs `CacheManager::with_invalidation_batch_window`. The first key queued
+/// opens a window; every key queued until it closes goes out in one
+/// `RemoveBulk` message (a plain `Remove` if it's the only key). The publishing
+/// task is spawned on first use.
+pub(crate) struct InvalidationBatcher {
+    window: Duration,
+    pending: std::sync::Mutex<Vec<String>>,
+    notify: Notify,
+    closed: AtomicBool,
+    worker: OnceLock<()>,
+    /// Held from taking a batch until it is published
+    publishing: tokio::sync::Mutex<()>,
 }
 
-use std::sync::Arc;
+impl InvalidationBatcher {
+    pub(crate) fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: std::sync::Mutex::new(Vec::new()),
+            notify: Notify::new(),
+            closed: AtomicBool::new(false),
+            worker: OnceLock::new(),
+            publishing: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Queue `key` for the next batched broadcast
+    pub(crate) fn push(
+        self: &Arc<Self>,
+        key: &str,
+        publisher: &Arc<tokio::sync::Mutex<InvalidationPublisher>>,
+        stats: &Arc<AtomicInvalidationStats>,
+        spawner: &dyn Spawner,
+    ) {
+        self.worker.get_or_init(|| {
+            spawner.spawn_named(
+                BATCHER_TASK_NAME,
+                Box::pin(Arc::clone(self).run(Arc::clone(publisher), Arc::clone(stats))),
+            );
+        });
+
+        self.pending
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(key.to_string());
+        self.notify.notify_one();
+    }
+
+    /// Publish any pending keys and stop the worker
+    pub(crate) fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.notify.notify_one();
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pending
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .is_empty()
+    }
+
+    /// Drain the pending keys into one message, `None` if there are none
+    fn take_batch(&self) -> Option<InvalidationMessage> {
+        let mut keys = std::mem::take(
+            &mut *self
+                .pending
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        );
+        let mut seen = std::collections::HashSet::new();
+        keys.retain(|key| seen.insert(key.clone()));
+        match keys.len() {
+            0 => None,
+            1 => keys.pop().map(InvalidationMessage::remove),
+            _ => Some(InvalidationMessage::remove_bulk(keys)),
+        }
+    }
+
+    /// Publish the pending keys now and wait for a batch the worker is publishing
+    pub(crate) async fn flush(
+        &self,
+        publisher: &tokio::sync::Mutex<InvalidationPublisher>,
+        stats: &AtomicInvalidationStats,
+    ) -> CacheResult<()> {
+        let _publishing = self.publishing.lock().await;
+        if let Some(message) = self.take_batch() {
+            publisher.lock().await.publish(&message).await?;
+            stats.messages_sent.fetch_add(1, Ordering::Relaxed);
+        }
+        Ok(())
+    }
+
+    async fn run(
+        self: Arc<Self>,
+        publisher: Arc<tokio::sync::Mutex<InvalidationPublisher>>,
+        stats: Arc<AtomicInvalidationStats>,
+    ) {
+        debug!(window = ?self.window, "Invalidation batcher started");
+        loop {
+            let closed = self.closed.load(Ordering::Acquire);
+            if !closed {
+                if self.is_empty() {
+                    self.notify.notified().await;
+                    continue;
+                }
+                tokio::time::sleep(self.window).await;
+            }
+
+            if let Err(e) = self.flush(&publisher, &stats).await {
+                warn!("Failed to publish batched invalidation: {}", e);
+            }
+            if closed {
+                break;
+            }
+        }
+        debug!("Invalidation batcher stopped");
+    }
+}
 
 /// Handle for subscribing to invalidation messages
 ///
@@ -368,6 +772,8 @@ pub struct InvalidationSubscriber {
     stats: Arc<AtomicInvalidationStats>,
     /// Shutdown signal sender
     shutdown_tx: broadcast::Sender<()>,
+    /// Messages published with this instance ID are ignored
+    instance_id: Option<String>,
 }
 
 impl InvalidationSubscriber {
@@ -393,15 +799,32 @@ impl InvalidationSubscriber {
             config,
             stats: Arc::new(AtomicInvalidationStats::default()),
             shutdown_tx,
+            instance_id: None,
         })
     }
 
+    /// Ignore messages published by the given instance
+    ///
+    /// Pass the `InvalidationPublisher::instance_id` of the same cache instance,
+    /// so updates it already applied locally are not re-applied when they loop
+    /// back through Pub/Sub.
+    #[must_use]
+    pub fn with_instance_id(mut self, instance_id: impl Into<String>) -> Self {
+        self.instance_id = Some(instance_id.into());
+        self
+    }
+
     /// Get a snapshot of current statistics
     #[must_use]
     pub fn stats(&self) -> InvalidationStats {
         self.stats.snapshot()
     }
 
+    /// Shared handle to the subscriber statistics
+    pub(crate) fn stats_handle(&self) -> Arc<AtomicInvalidationStats> {
+        Arc::clone(&self.stats)
+    }
+
     /// Start the subscriber background task
     ///
     /// # Arguments
@@ -410,16 +833,65 @@ impl InvalidationSubscriber {
     /// # Returns
     /// Join handle for the background task
     pub fn start<F, Fut>(&self, handler: F) -> tokio::task::JoinHandle<()>
+    where
+        F: Fn(InvalidationMessage) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = CacheResult<()>> + Send + 'static,
+    {
+        spawn_named(SUBSCRIBER_TASK_NAME, self.task(handler))
+    }
+
+    /// Start the subscriber background task on a custom `Spawner`
+    pub fn start_with<F, Fut>(&self, spawner: &dyn Spawner, handler: F)
+    where
+        F: Fn(InvalidationMessage) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = CacheResult<()>> + Send + 'static,
+    {
+        spawner.spawn_named(SUBSCRIBER_TASK_NAME, Box::pin(self.task(handler)));
+    }
+
+    /// Consume the subscriber and observe invalidation messages as a `Stream`
+    ///
+    /// Runs the same background task as `start` (including reconnects) and
+    /// forwards each parsed message through a bounded channel, so a slow
+    /// consumer applies backpressure to the subscription. Dropping the stream
+    /// shuts the task down. Messages published before the subscription is
+    /// established are not seen.
+    pub fn into_stream(self) -> impl Stream<Item = InvalidationMessage> + Send + 'static {
+        let (tx, rx) = mpsc::channel(INVALIDATION_STREAM_BUFFER);
+        spawn_named(
+            SUBSCRIBER_TASK_NAME,
+            self.task(move |message| {
+                let tx = tx.clone();
+                async move {
+                    tx.send(message).await.map_err(|_| {
+                        crate::error::CacheError::InvalidationError(
+                            "Invalidation stream receiver dropped".to_string(),
+                        )
+                    })
+                }
+            }),
+        );
+
+        // The subscriber travels with the stream; dropping it closes the shutdown channel
+        futures_util::stream::unfold((rx, self), |(mut rx, subscriber)| async move {
+            rx.recv().await.map(|message| (message, (rx, subscriber)))
+        })
+    }
+
+    /// Subscriber task: (re)connect and dispatch messages until shutdown
+    fn task<F, Fut>(&self, handler: F) -> impl std::future::Future<Output = ()> + Send + 'static
     where
         F: Fn(InvalidationMessage) -> Fut + Send + Sync + 'static,
         Fut: std::future::Future<Output = CacheResult<()>> + Send + 'static,
     {
         let client = self.client.clone();
         let channel = self.config.channel.clone();
+        let compression = self.config.update_compression.clone();
         let stats = Arc::clone(&self.stats);
+        let instance_id = self.instance_id.clone();
         let mut shutdown_rx = self.shutdown_tx.subscribe();
 
-        tokio::spawn(async move {
+        async move {
             let handler = Arc::new(handler);
 
             loop {
@@ -433,6 +905,8 @@ impl InvalidationSubscriber {
                 match Self::run_subscriber_loop(
                     &client,
                     &channel,
+                    instance_id.as_deref(),
+                    compression.as_ref(),
                     Arc::clone(&handler),
                     Arc::clone(&stats),
                     &mut shutdown_rx,
@@ -461,13 +935,15 @@ impl InvalidationSubscriber {
                     }
                 }
             }
-        })
+        }
     }
 
     /// Internal subscriber loop
     async fn run_subscriber_loop<F, Fut>(
         client: &redis::Client,
         channel: &str,
+        instance_id: Option<&str>,
+        compression: Option<&UpdateCompression>,
         handler: Arc<F>,
         stats: Arc<AtomicInvalidationStats>,
         shutdown_rx: &mut broadcast::Receiver<()>,
@@ -509,7 +985,7 @@ impl InvalidationSubscriber {
                             };
 
                             // Deserialize message
-                            let invalidation_msg = match InvalidationMessage::from_json(&payload) {
+                            let envelope = match InvalidationEnvelope::from_json(&payload) {
                                 Ok(m) => m,
                                 Err(e) => {
                                     warn!("Failed to deserialize invalidation message: {}", e);
@@ -518,6 +994,24 @@ impl InvalidationSubscriber {
                                 }
                             };
 
+                            if let Some(published_at_us) = envelope.published_at_us {
+                                stats.record_propagation(published_at_us);
+                            }
+
+                            // Skip messages this instance published itself (already applied locally)
+                            if instance_id.is_some() && envelope.origin.as_deref() == instance_id {
+                                stats.self_messages_ignored.fetch_add(1, Ordering::Relaxed);
+                                continue;
+                            }
+                            let invalidation_msg = match envelope.into_message(compression) {
+                                Ok(m) => m,
+                                Err(e) => {
+                                    warn!("Failed to decompress invalidation message: {}", e);
+                                    stats.processing_errors.fetch_add(1, Ordering::Relaxed);
+                                    continue;
+                                }
+                            };
+
                             // Update stats
                             stats.messages_received.fetch_add(1, Ordering::Relaxed);
                             match &invalidation_msg {
@@ -560,6 +1054,208 @@ impl InvalidationSubscriber {
     }
 }
 
+/// Subscriber to Redis keyspace notifications
+///
+/// Where `InvalidationSubscriber` listens to this crate's own Pub/Sub channel,
+/// `KeyspaceSubscriber` takes its events from Redis itself, so it also sees
+/// keys another service deleted or that Redis expired or evicted. The handler
+/// receives the affected key; `CacheManager::with_keyspace_invalidation` uses
+/// it to evict the key from L1.
+///
+/// Redis only publishes these events once enabled, e.g. for generic commands
+/// (`del`), expirations and evictions on key-event channels:
+///
+/// ```text
+/// redis-cli CONFIG SET notify-keyspace-events Egxe
+/// ```
+///
+/// Notifications are fire-and-forget: events published while the subscriber
+/// is disconnected are lost. In a cluster, each node publishes only the events
+/// of its own keys.
+pub struct KeyspaceSubscriber {
+    /// Redis client for creating Pub/Sub connections
+    client: redis::Client,
+    /// Database whose events are received
+    db: u32,
+    /// Key event names (`del`, `expired`, ...)
+    events: Vec<String>,
+    /// Statistics (`removes_received` counts handled events)
+    stats: Arc<AtomicInvalidationStats>,
+    /// Shutdown signal sender
+    shutdown_tx: broadcast::Sender<()>,
+}
+
+impl KeyspaceSubscriber {
+    /// Subscribe to the `DEFAULT_KEYSPACE_EVENTS` of database `db`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if Redis client creation fails.
+    pub fn new(redis_url: &str, db: u32) -> CacheResult<Self> {
+        let client = redis::Client::open(redis_url).map_err(|e| {
+            crate::error::CacheError::ConfigError(format!(
+                "Failed to create Redis client for keyspace subscriber: {e}"
+            ))
+        })?;
+
+        let (shutdown_tx, _) = broadcast::channel(1);
+
+        Ok(Self {
+            client,
+            db,
+            events: DEFAULT_KEYSPACE_EVENTS
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            stats: Arc::new(AtomicInvalidationStats::default()),
+            shutdown_tx,
+        })
+    }
+
+    /// Listen to these key events instead of the defaults
+    ///
+    /// Adding `set` catches keys overwritten by other services, but also
+    /// fires for this instance's own L2 writes, evicting the L1 copy it just
+    /// stored.
+    #[must_use]
+    pub fn with_events(mut self, events: &[&str]) -> Self {
+        self.events = events.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// Get a snapshot of current statistics
+    #[must_use]
+    pub fn stats(&self) -> InvalidationStats {
+        self.stats.snapshot()
+    }
+
+    /// Pub/Sub channels of the subscribed events
+    fn channels(&self) -> Vec<String> {
+        self.events
+            .iter()
+            .map(|event| format!("__keyevent@{}__:{event}", self.db))
+            .collect()
+    }
+
+    /// Start the subscriber background task
+    ///
+    /// `handler` is called with the key of each event.
+    pub fn start<F, Fut>(&self, handler: F) -> tokio::task::JoinHandle<()>
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = CacheResult<()>> + Send + 'static,
+    {
+        spawn_named(KEYSPACE_SUBSCRIBER_TASK_NAME, self.task(handler))
+    }
+
+    /// Start the subscriber background task on a custom `Spawner`
+    pub fn start_with<F, Fut>(&self, spawner: &dyn Spawner, handler: F)
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = CacheResult<()>> + Send + 'static,
+    {
+        spawner.spawn_named(KEYSPACE_SUBSCRIBER_TASK_NAME, Box::pin(self.task(handler)));
+    }
+
+    /// Subscriber task: (re)connect and dispatch events until shutdown
+    fn task<F, Fut>(&self, handler: F) -> impl std::future::Future<Output = ()> + Send + 'static
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = CacheResult<()>> + Send + 'static,
+    {
+        let client = self.client.clone();
+        let channels = self.channels();
+        let stats = Arc::clone(&self.stats);
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        async move {
+            loop {
+                if shutdown_rx.try_recv().is_ok() {
+                    break;
+                }
+                match Self::run_subscriber_loop(
+                    &client,
+                    &channels,
+                    &handler,
+                    &stats,
+                    &mut shutdown_rx,
+                )
+                .await
+                {
+                    Ok(()) => break,
+                    Err(e) => {
+                        error!("Keyspace subscriber error: {}. Reconnecting in 5s...", e);
+                        stats.processing_errors.fetch_add(1, Ordering::Relaxed);
+                        tokio::select! {
+                            () = tokio::time::sleep(Duration::from_secs(5)) => {},
+                            _ = shutdown_rx.recv() => break,
+                        }
+                    }
+                }
+            }
+            info!("Keyspace subscriber shutting down...");
+        }
+    }
+
+    /// Internal subscriber loop
+    async fn run_subscriber_loop<F, Fut>(
+        client: &redis::Client,
+        channels: &[String],
+        handler: &F,
+        stats: &AtomicInvalidationStats,
+        shutdown_rx: &mut broadcast::Receiver<()>,
+    ) -> CacheResult<()>
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = CacheResult<()>> + Send + 'static,
+    {
+        let mut pubsub = client.get_async_pubsub().await.map_err(|e| {
+            crate::error::CacheError::BackendError(format!("Failed to get pubsub connection: {e}"))
+        })?;
+        pubsub.subscribe(channels).await.map_err(|e| {
+            crate::error::CacheError::InvalidationError(format!(
+                "Failed to subscribe to keyspace events: {e}"
+            ))
+        })?;
+        info!(channels = ?channels, "Subscribed to keyspace notifications");
+
+        let mut stream = pubsub.on_message();
+        loop {
+            tokio::select! {
+                msg = stream.next() => {
+                    let Some(msg) = msg else {
+                        return Err(crate::error::CacheError::InvalidationError(
+                            "Keyspace notification stream ended".to_string(),
+                        ));
+                    };
+                    // The payload of a key-event notification is the key
+                    let key: String = match msg.get_payload() {
+                        Ok(key) => key,
+                        Err(e) => {
+                            warn!("Failed to get keyspace event payload: {}", e);
+                            stats.processing_errors.fetch_add(1, Ordering::Relaxed);
+                            continue;
+                        }
+                    };
+                    debug!(channel = %msg.get_channel_name(), key = %key, "Keyspace event");
+                    stats.messages_received.fetch_add(1, Ordering::Relaxed);
+                    stats.removes_received.fetch_add(1, Ordering::Relaxed);
+                    if let Err(e) = handler(key).await {
+                        error!("Keyspace event handler error: {}", e);
+                        stats.processing_errors.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+                _ = shutdown_rx.recv() => return Ok(()),
+            }
+        }
+    }
+
+    /// Signal the subscriber to shutdown
+    pub fn shutdown(&self) {
+        let _ = self.shutdown_tx.send(());
+    }
+}
+
 /// Reliable subscriber using Redis Streams and Consumer Groups
 pub struct ReliableStreamSubscriber {
     redis_url: String,
@@ -597,6 +1293,24 @@ impl ReliableStreamSubscriber {
     }
 
     pub fn start<F, Fut>(&self, handler: F) -> tokio::task::JoinHandle<()>
+    where
+        F: Fn(InvalidationMessage) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = CacheResult<()>> + Send + 'static,
+    {
+        spawn_named(RELIABLE_SUBSCRIBER_TASK_NAME, self.task(handler))
+    }
+
+    /// Start the subscriber background task on a custom `Spawner`
+    pub fn start_with<F, Fut>(&self, spawner: &dyn Spawner, handler: F)
+    where
+        F: Fn(InvalidationMessage) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = CacheResult<()>> + Send + 'static,
+    {
+        spawner.spawn_named(RELIABLE_SUBSCRIBER_TASK_NAME, Box::pin(self.task(handler)));
+    }
+
+    /// Subscriber task: consume the stream as part of the consumer group until shutdown
+    fn task<F, Fut>(&self, handler: F) -> impl std::future::Future<Output = ()> + Send + 'static
     where
         F: Fn(InvalidationMessage) -> Fut + Send + Sync + 'static,
         Fut: std::future::Future<Output = CacheResult<()>> + Send + 'static,
@@ -609,7 +1323,7 @@ impl ReliableStreamSubscriber {
         let mut shutdown_rx = self.shutdown_tx.subscribe();
         let redis_url = self.redis_url.clone();
 
-        tokio::spawn(async move {
+        async move {
             info!(
                 stream = %stream_key,
                 group = %group_name,
@@ -660,7 +1374,7 @@ impl ReliableStreamSubscriber {
                     );
                 }
             }
-        })
+        }
     }
 
     async fn run_reliable_loop<F, Fut>(
@@ -718,6 +1432,15 @@ impl ReliableStreamSubscriber {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::CacheError;
+    use run_length::RunLength;
+
+    mod run_length {
+        include!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/common/run_length.rs"
+        ));
+    }
 
     #[test]
     fn test_invalidation_message_serialization() -> CacheResult<()> {
@@ -734,7 +1457,7 @@ mod tests {
         let msg = InvalidationMessage::update(
             "test_key",
             Bytes::from("{\"value\": 123}"),
-            Some(Duration::from_secs(3600)),
+            Some(Duration::from_hours(1)),
         );
 
         if let InvalidationMessage::Update {
@@ -770,6 +1493,121 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_batcher_coalesces_keys() {
+        let batcher = InvalidationBatcher::new(Duration::from_millis(10));
+        assert!(batcher.take_batch().is_none());
+
+        let queue = |key: &str| {
+            batcher
+                .pending
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .push(key.to_string());
+        };
+        queue("a");
+        assert!(matches!(
+            batcher.take_batch(),
+            Some(InvalidationMessage::Remove { ref key }) if key == "a"
+        ));
+
+        for key in ["a", "b", "a", "c"] {
+            queue(key);
+        }
+        assert!(matches!(
+            batcher.take_batch(),
+            Some(InvalidationMessage::RemoveBulk { ref keys }) if keys == &["a", "b", "c"]
+        ));
+        assert!(batcher.is_empty());
+    }
+
+    #[test]
+    fn test_invalidation_envelope_origin() -> CacheResult<()> {
+        let envelope = InvalidationEnvelope {
+            origin: Some("instance-a".to_string()),
+            published_at_us: Some(1_700_000_000_000_000),
+            compressed: false,
+            message: InvalidationMessage::remove("test_key"),
+        };
+        let json = envelope.to_json()?;
+
+        let parsed = InvalidationEnvelope::from_json(&json)?;
+        assert_eq!(parsed.origin.as_deref(), Some("instance-a"));
+        assert_eq!(parsed.published_at_us, Some(1_700_000_000_000_000));
+        assert!(
+            matches!(parsed.message, InvalidationMessage::Remove { ref key } if key == "test_key")
+        );
+
+        // Plain messages remain readable in both directions
+        let plain = InvalidationMessage::from_json(&json)?;
+        assert!(matches!(plain, InvalidationMessage::Remove { ref key } if key == "test_key"));
+        let legacy = InvalidationEnvelope::from_json(&InvalidationMessage::remove("k").to_json()?)?;
+        assert!(legacy.origin.is_none());
+        assert!(legacy.published_at_us.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_compressed_update_round_trip() -> CacheResult<()> {
+        let compression = UpdateCompression::new(RunLength).with_min_size(64);
+        let value = Bytes::from(vec![b'x'; 100_000]);
+        let message =
+            InvalidationMessage::update("big", value.clone(), Some(Duration::from_mins(1)));
+
+        let json = InvalidationEnvelope::compressing(&message, Some(&compression))?.to_json()?;
+        assert!(
+            json.len() < 10_000,
+            "payload not compressed: {} bytes",
+            json.len()
+        );
+
+        let received = InvalidationEnvelope::from_json(&json)?;
+        assert!(received.compressed);
+        match received.into_message(Some(&compression))? {
+            InvalidationMessage::Update {
+                key,
+                value: received_value,
+                ttl_secs,
+            } => {
+                assert_eq!(key, "big");
+                assert_eq!(received_value, value);
+                assert_eq!(ttl_secs, Some(60));
+            }
+            other => panic!("Unexpected message: {other:?}"),
+        }
+
+        // Without compression configured, a compressed Update is rejected
+        let received = InvalidationEnvelope::from_json(&json)?;
+        assert!(received.into_message(None).is_err());
+
+        // Small values and other messages are sent as-is
+        let small = InvalidationMessage::update("small", Bytes::from("v"), None);
+        let envelope = InvalidationEnvelope::compressing(&small, Some(&compression))?;
+        assert!(!envelope.compressed);
+        assert!(!envelope.to_json()?.contains("compressed"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_propagation_latency_stats() {
+        let stats = AtomicInvalidationStats::default();
+        assert!(stats.snapshot().propagation_latency_avg.is_none());
+
+        let now = unix_micros();
+        stats.record_propagation(now.saturating_sub(2_000));
+        stats.record_propagation(now.saturating_sub(4_000));
+        // Publisher clock ahead of ours: clamped to zero
+        stats.record_propagation(now + 60_000_000);
+
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.propagation_samples, 3);
+        assert_eq!(snapshot.propagation_latency_min, Some(Duration::ZERO));
+        let max = snapshot.propagation_latency_max.unwrap_or_default();
+        assert!(max >= Duration::from_millis(4) && max < Duration::from_secs(1));
+        let avg = snapshot.propagation_latency_avg.unwrap_or_default();
+        assert!(avg >= Duration::from_millis(2) && avg <= max);
+    }
+
     #[test]
     fn test_invalidation_config_default() {
         let config = InvalidationConfig::default();
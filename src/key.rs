@@ -0,0 +1,151 @@
+//! Structured cache keys
+//!
+//! Keyed `CacheManager` methods accept any `CacheKey`, not just strings, so a
+//! `(tenant_id, user_id)` tuple can be passed directly instead of formatting
+//! it by hand at every call site. Keys are formatted deterministically, so
+//! every instance stores a given key under the same string:
+//!
+//! - strings are used as-is
+//! - integers, `bool`, `char` and `Uuid` use their `Display` form
+//! - tuples join their parts with `:` (`("acme", 42_u64)` is `"acme:42"`),
+//!   so glob patterns such as `"acme:*"` still match them in `scan_keys`,
+//!   `invalidate_pattern` and friends
+//! - any other `Display` type can be wrapped in `DisplayKey`
+//!
+//! Parts containing `:` make composite keys ambiguous (`("a:b", "c")` and
+//! `("a", "b:c")` are the same key); keep separators out of key parts.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use multi_tier_cache::{CacheManager, CacheStrategy};
+//!
+//! # async fn example(cache_manager: &CacheManager) -> anyhow::Result<()> {
+//! let key = ("acme", 42_u64);
+//! cache_manager
+//!     .set_with_strategy(&key, bytes::Bytes::from("alice"), CacheStrategy::ShortTerm)
+//!     .await?;
+//! assert!(cache_manager.get(&key).await?.is_some());
+//! assert!(cache_manager.get("acme:42").await?.is_some());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::borrow::Cow;
+use std::fmt::Display;
+
+/// Separator between the parts of a composite (tuple) key
+pub const KEY_SEPARATOR: char = ':';
+
+/// A value usable as a cache key
+///
+/// Implemented for strings, integers, `bool`, `char`, `Uuid`, references to
+/// keys, tuples of up to four keys and `DisplayKey`. Implement it for your own
+/// key types to control their string form.
+pub trait CacheKey: Sync {
+    /// The string the key is stored under
+    fn cache_key(&self) -> Cow<'_, str>;
+}
+
+impl CacheKey for str {
+    fn cache_key(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl CacheKey for String {
+    fn cache_key(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl CacheKey for Cow<'_, str> {
+    fn cache_key(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl<T: CacheKey + ?Sized> CacheKey for &T {
+    fn cache_key(&self) -> Cow<'_, str> {
+        (**self).cache_key()
+    }
+}
+
+/// `CacheKey` via the type's `Display` form
+macro_rules! display_cache_key {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl CacheKey for $ty {
+                fn cache_key(&self) -> Cow<'_, str> {
+                    Cow::Owned(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+display_cache_key!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    bool,
+    char,
+    uuid::Uuid,
+);
+
+/// `CacheKey` for tuples, joining the parts with `KEY_SEPARATOR`
+macro_rules! tuple_cache_key {
+    ($first:ident $(, $rest:ident)*) => {
+        impl<$first: CacheKey, $($rest: CacheKey),*> CacheKey for ($first, $($rest),*) {
+            #[allow(non_snake_case)]
+            fn cache_key(&self) -> Cow<'_, str> {
+                let ($first, $($rest),*) = self;
+                let mut key = $first.cache_key().into_owned();
+                $(
+                    key.push(KEY_SEPARATOR);
+                    key.push_str(&$rest.cache_key());
+                )*
+                Cow::Owned(key)
+            }
+        }
+    };
+}
+
+tuple_cache_key!(A, B);
+tuple_cache_key!(A, B, C);
+tuple_cache_key!(A, B, C, D);
+
+/// Key formatted with its `Display` implementation
+///
+/// For key types that already implement `Display` with a stable format:
+///
+/// ```rust
+/// use multi_tier_cache::{CacheKey, DisplayKey};
+///
+/// struct OrderId(u32);
+///
+/// impl std::fmt::Display for OrderId {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "order-{}", self.0)
+///     }
+/// }
+///
+/// assert_eq!(("eu", DisplayKey(OrderId(7))).cache_key(), "eu:order-7");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DisplayKey<T>(pub T);
+
+impl<T: Display + Sync> CacheKey for DisplayKey<T> {
+    fn cache_key(&self) -> Cow<'_, str> {
+        Cow::Owned(self.0.to_string())
+    }
+}
@@ -57,29 +57,40 @@
 pub mod backends;
 pub mod builder;
 pub mod cache_manager;
+pub mod config;
+mod counter;
 pub mod error;
+pub mod eviction;
 #[cfg(feature = "redis")]
 #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
 pub mod invalidation;
+pub mod key;
+#[cfg(feature = "metrics-facade")]
+#[cfg_attr(docsrs, doc(cfg(feature = "metrics-facade")))]
+pub mod metrics_facade;
+mod promotion;
 #[cfg(feature = "redis")]
 #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
 pub mod redis_streams;
 pub mod serialization;
+pub mod spawner;
 pub mod traits;
+pub mod transaction;
 
 pub use error::{CacheError, CacheResult};
 pub use serialization::{CacheSerializer, JsonSerializer};
+use std::any::Any;
 pub use std::sync::Arc;
 use tracing::{info, warn};
 
 // Re-export backend types (maintains backward compatibility)
-pub use backends::DashMapCache;
+pub use backends::{DashMapCache, TieredBackend};
 #[cfg(feature = "moka")]
 #[cfg_attr(docsrs, doc(cfg(feature = "moka")))]
 pub use backends::{L1Cache, MokaCache, MokaCacheConfig};
 #[cfg(feature = "redis")]
 #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
-pub use backends::{L2Cache, RedisCache};
+pub use backends::{L2Cache, RedisCache, RedisHashCache, RedisReconnectConfig};
 
 // Optional backends (feature-gated)
 #[cfg(feature = "backend-memcached")]
@@ -92,24 +103,51 @@ pub use backends::QuickCacheBackend;
 pub use builder::CacheSystemBuilder;
 pub use bytes::Bytes;
 pub use cache_manager::{
+    AdaptiveTtl,
+    CacheExplain,
+    CacheHit,
     CacheManager,
     CacheManagerStats,
+    CacheSource,
     CacheStrategy,
     CacheTier,
+    ConditionalResult,
+    DeadlinePolicy,
+    Entry,
+    GetOutcome,
+    L2MissPolicy,
+    L2WriteFailurePolicy,
+    LazyValue,
+    MAX_STATS_BUCKETS,
+    MAX_TIER_TTL,
+    OVERFLOW_STATS_BUCKET,
+    PromotionTtlHistogram,
+    SetOpts,
+    StrategyDurations,
+    StreamStats,
     // Multi-tier support (v0.5.0+)
     TierConfig,
+    TierMask,
+    TierPresence,
     TierStats,
+    UndecodablePolicy,
 };
+pub use config::CacheConfig;
 #[cfg(feature = "redis")]
 #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
 pub use invalidation::{
     InvalidationConfig, InvalidationMessage, InvalidationPublisher, InvalidationStats,
-    InvalidationSubscriber, ReliableStreamSubscriber,
+    InvalidationSubscriber, KeyspaceSubscriber, ReliableStreamSubscriber, UpdateCompression,
 };
+pub use key::{CacheKey, DisplayKey};
 #[cfg(feature = "redis")]
 #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
 pub use redis_streams::RedisStreams;
-pub use traits::{CacheBackend, L2CacheBackend, StreamingBackend};
+pub use spawner::{Spawner, TokioSpawner};
+pub use traits::{
+    CacheBackend, KeyPage, KeyPredicate, L2CacheBackend, StreamingBackend, ValueWithTtl,
+};
+pub use transaction::{Transaction, TransactionWrite};
 
 // Re-export backend types (maintains backward compatibility)
 
@@ -137,7 +175,8 @@ pub use traits::{CacheBackend, L2CacheBackend, StreamingBackend};
 /// # Note on `l1_cache` and `l2_cache` Fields
 ///
 /// When using multi-tier mode or custom backends, `l1_cache` and `l2_cache`
-/// may be `None`. Always use `cache_manager()` for cache operations.
+/// may be `None`. Always use `cache_manager()` for cache operations; for a
+/// custom L1's own methods, build with `with_concrete_l1()` and use `l1_as()`.
 #[derive(Clone)]
 pub struct CacheSystem {
     /// Unified cache manager (primary interface)
@@ -150,6 +189,8 @@ pub struct CacheSystem {
     #[cfg(feature = "redis")]
     #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
     pub l2_cache: Option<Arc<L2Cache>>,
+    /// Concrete L1 backend, if known (see `l1_as`)
+    l1_concrete: Option<Arc<dyn Any + Send + Sync>>,
 }
 
 impl CacheSystem {
@@ -195,6 +236,7 @@ impl CacheSystem {
 
         Ok(Self {
             cache_manager,
+            l1_concrete: Some(Arc::clone(&l1_cache) as _),
             l1_cache: Some(l1_cache),
             l2_cache: Some(l2_cache),
         })
@@ -239,11 +281,52 @@ impl CacheSystem {
 
         Ok(Self {
             cache_manager,
+            l1_concrete: Some(Arc::clone(&l1_cache) as _),
             l1_cache: Some(l1_cache),
             l2_cache: Some(l2_cache),
         })
     }
 
+    /// Build a cache system from a declarative `CacheConfig`
+    ///
+    /// See the `config` module for the supported settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a backend can't be created or the tier layout is
+    /// invalid.
+    pub async fn from_config(config: CacheConfig) -> CacheResult<Self> {
+        config.build().await
+    }
+
+    /// Build a cache system from a configuration document
+    ///
+    /// `value` is deserialized into a `CacheConfig`; parse YAML or TOML into a
+    /// `serde_json::Value` first, or deserialize `CacheConfig` directly and use
+    /// `from_config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ConfigError` if `value` is not a valid configuration, or an
+    /// error if the system can't be built.
+    pub async fn from_config_value(value: serde_json::Value) -> CacheResult<Self> {
+        let config: CacheConfig = serde_json::from_value(value)
+            .map_err(|e| CacheError::ConfigError(format!("Invalid cache config: {e}")))?;
+        Self::from_config(config).await
+    }
+
+    /// The L1 backend as its concrete type `B`
+    ///
+    /// Gives access to backend-specific operations (e.g.
+    /// `DashMapCache::cleanup_expired`) without keeping a separate handle.
+    /// Available for the default Moka L1 and for backends passed to
+    /// `CacheSystemBuilder::with_concrete_l1()`; `None` otherwise, or if `B`
+    /// is not the L1's type.
+    #[must_use]
+    pub fn l1_as<B: Any + Send + Sync>(&self) -> Option<Arc<B>> {
+        Arc::clone(self.l1_concrete.as_ref()?).downcast::<B>().ok()
+    }
+
     /// Perform health check on all cache tiers
     ///
     /// Returns `true` if at least L1 is operational.
@@ -300,8 +383,96 @@ impl CacheSystem {
     /// Get reference to cache manager (primary interface)
     ///
     /// Use this for all cache operations: get, set, streams, etc.
+    ///
+    /// # Sharing the manager
+    ///
+    /// `CacheManager` is not `Clone`; it is shared through this `Arc`. Pass
+    /// `&CacheManager` to code that only borrows it (no refcount traffic), and
+    /// `Arc::clone` the returned `Arc` for tasks that must own it. Don't wrap it
+    /// in another `Arc`: `Arc::new(cache.cache_manager().clone())` builds an
+    /// `Arc<Arc<CacheManager>>` that adds an indirection on every call.
+    ///
+    /// ```rust
+    /// use multi_tier_cache::{CacheManager, CacheStrategy, CacheSystemBuilder, DashMapCache, TierConfig};
+    /// use std::sync::Arc;
+    ///
+    /// async fn handle_request(manager: &CacheManager) -> multi_tier_cache::CacheResult<()> {
+    ///     manager.set_with_strategy("hits", "1".into(), CacheStrategy::ShortTerm).await
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> anyhow::Result<()> {
+    /// let cache = CacheSystemBuilder::new()
+    ///     .with_tier(Arc::new(DashMapCache::new()), TierConfig::as_l1())
+    ///     .with_tier(Arc::new(DashMapCache::new()), TierConfig::as_l2())
+    ///     .build()
+    ///     .await?;
+    ///
+    /// // Hot path: borrow
+    /// handle_request(cache.cache_manager()).await?;
+    ///
+    /// // Spawned task: own a clone of the same Arc
+    /// let manager = Arc::clone(cache.cache_manager());
+    /// tokio::spawn(async move { manager.get("hits").await }).await??;
+    /// # Ok(())
+    /// # }
+    /// ```
     #[must_use]
     pub fn cache_manager(&self) -> &Arc<CacheManager> {
         &self.cache_manager
     }
+    /// Flush pending writes and stop background tasks
+    ///
+    /// See `CacheManager::shutdown()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if publishing the pending invalidations fails.
+    pub async fn shutdown(&self) -> CacheResult<()> {
+        self.cache_manager.shutdown().await
+    }
+
+    /// Keep the cache running until `signal` completes, then shut it down
+    ///
+    /// Background tasks (invalidation subscribers, async promotions, batched
+    /// invalidations) run while waiting; afterwards pending writes are flushed
+    /// and the tasks stopped with `shutdown()`. Run it next to the HTTP server
+    /// of an axum or actix service, with the same shutdown signal.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use multi_tier_cache::CacheSystem;
+    ///
+    /// # async fn example() -> anyhow::Result<()> {
+    /// let cache = CacheSystem::new().await?;
+    /// let lifecycle = tokio::spawn({
+    ///     let cache = cache.clone();
+    ///     async move {
+    ///         cache
+    ///             .run_until(async {
+    ///                 let _ = tokio::signal::ctrl_c().await;
+    ///             })
+    ///             .await
+    ///     }
+    /// });
+    ///
+    /// // ... serve requests using `cache.cache_manager()` ...
+    ///
+    /// lifecycle.await??;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if publishing the pending invalidations fails.
+    pub async fn run_until(
+        &self,
+        signal: impl std::future::Future<Output = ()>,
+    ) -> CacheResult<()> {
+        signal.await;
+        info!("Shutdown signal received, stopping cache background tasks");
+        self.shutdown().await
+    }
 }
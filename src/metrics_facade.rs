@@ -0,0 +1,72 @@
+//! Cache metrics recorded through the `metrics` crate facade
+//!
+//! With the `metrics-facade` feature, the manager reports hits, misses,
+//! writes, operation latency and value sizes to whichever `metrics` recorder
+//! is installed, so any `metrics` exporter (Prometheus, `StatsD`,
+//! `OpenTelemetry`...) picks them up. Without a recorder the calls are no-ops.
+//!
+//! Counters mirror `CacheManagerStats` and are process-wide: several managers
+//! report to the same series.
+
+use crate::counter::StatEvent;
+use std::time::{Duration, Instant};
+
+/// Counter of lookups (`get` and `get_or_compute_*`)
+pub const REQUESTS: &str = "multi_tier_cache_requests_total";
+/// Counter of hits, labelled with the serving `tier` (`"1"`, `"2"`)
+pub const HITS: &str = "multi_tier_cache_hits_total";
+/// Counter of lookups no tier could serve
+pub const MISSES: &str = "multi_tier_cache_misses_total";
+/// Counter of successful writes
+pub const SETS: &str = "multi_tier_cache_sets_total";
+/// Histogram of operation latency in seconds, labelled with the `op` (`"get"`, `"set"`)
+pub const OPERATION_DURATION: &str = "multi_tier_cache_operation_duration_seconds";
+/// Histogram of `compute_fn` durations in seconds
+pub const COMPUTE_DURATION: &str = "multi_tier_cache_compute_duration_seconds";
+/// Histogram of the size in bytes of stored values
+pub const VALUE_SIZE: &str = "multi_tier_cache_value_size_bytes";
+
+/// Record a request statistic
+pub(crate) fn record_event(event: StatEvent) {
+    match event {
+        StatEvent::Request => metrics::counter!(REQUESTS).increment(1),
+        StatEvent::L1Hit => metrics::counter!(HITS, "tier" => "1").increment(1),
+        StatEvent::L2Hit => metrics::counter!(HITS, "tier" => "2").increment(1),
+        StatEvent::Miss => metrics::counter!(MISSES).increment(1),
+        StatEvent::Set => metrics::counter!(SETS).increment(1),
+    }
+}
+
+/// Record the duration of a `compute_fn` call
+pub(crate) fn record_compute(duration: Duration) {
+    metrics::histogram!(COMPUTE_DURATION).record(duration.as_secs_f64());
+}
+
+/// Record the size of a stored value
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn record_value_size(len: usize) {
+    metrics::histogram!(VALUE_SIZE).record(len as f64);
+}
+
+/// Records the latency of an operation when dropped
+pub(crate) struct OperationTimer {
+    op: &'static str,
+    start: Instant,
+}
+
+impl OperationTimer {
+    /// Start timing `op`
+    pub(crate) fn start(op: &'static str) -> Self {
+        Self {
+            op,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for OperationTimer {
+    fn drop(&mut self) {
+        metrics::histogram!(OPERATION_DURATION, "op" => self.op)
+            .record(self.start.elapsed().as_secs_f64());
+    }
+}
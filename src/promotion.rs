@@ -0,0 +1,319 @@
+//! Promotion of values from lower tiers to upper tiers
+//!
+//! Promotions normally run inline on the read path. With async promotion
+//! enabled (`CacheManager::with_async_promotion`), they are pushed onto a bounded
+//! queue and applied by a background task, so reads return right after the
+//! lower tier fetch.
+//!
+//! Either way, only one promotion per key is in flight at a time (see
+//! `CacheManager::with_promotion_dedup`): concurrent hits on the same lower
+//! tier key skip their promotion rather than repeat the upper tier writes.
+//!
+//! A queued promotion carries the value as it was read, so it must not land
+//! on top of a newer write. The queue keeps a short-lived log of the keys its
+//! manager wrote or removed, and the worker skips (or rolls back) promotions
+//! of those keys, keeping reads after a write on the same manager consistent.
+
+use crate::cache_manager::{CacheTier, PromotionTtlHistogram};
+use crate::spawner::Spawner;
+use bytes::Bytes;
+use dashmap::{DashMap, DashSet};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+use tracing::{debug, warn};
+
+/// Default capacity of the async promotion queue
+pub(crate) const DEFAULT_PROMOTION_QUEUE_CAPACITY: usize = 1024;
+
+/// How long a write stays in the write log
+const WRITE_LOG_RETENTION: Duration = Duration::from_secs(1);
+
+/// Queued promotions older than this are discarded
+///
+/// Half the write log retention: a promotion applied later could have been
+/// read before a write that has already left the log.
+const MAX_PROMOTION_AGE: Duration = Duration::from_millis(500);
+
+/// Writes logged between sweeps of expired write log entries
+const WRITE_LOG_SWEEP_INTERVAL: u64 = 1024;
+
+/// Promotion statistics, shared with the background worker
+#[derive(Debug, Default)]
+pub(crate) struct PromotionCounters {
+    /// Number of promotions performed (one per upper tier written)
+    pub(crate) promotions: AtomicUsize,
+    /// Bucketed TTLs of performed promotions
+    pub(crate) ttls: PromotionTtlCounters,
+    /// Queued promotions discarded because the queue was full
+    pub(crate) async_dropped: AtomicU64,
+    /// Queued promotions discarded for waiting longer than `MAX_PROMOTION_AGE`
+    pub(crate) async_expired: AtomicU64,
+    /// Promotions skipped because one for the same key was in flight
+    pub(crate) deduplicated: AtomicU64,
+}
+
+/// Thread-safe bucket counters backing `PromotionTtlHistogram`
+#[derive(Debug, Default)]
+pub(crate) struct PromotionTtlCounters {
+    under_1s: AtomicU64,
+    from_1s_to_10s: AtomicU64,
+    from_10s_to_60s: AtomicU64,
+    over_60s: AtomicU64,
+}
+
+impl PromotionTtlCounters {
+    fn record(&self, ttl: Duration) {
+        let bucket = match ttl.as_secs() {
+            0 => &self.under_1s,
+            1..10 => &self.from_1s_to_10s,
+            10..60 => &self.from_10s_to_60s,
+            _ => &self.over_60s,
+        };
+        bucket.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> PromotionTtlHistogram {
+        PromotionTtlHistogram {
+            under_1s: self.under_1s.load(Ordering::Relaxed),
+            from_1s_to_10s: self.from_1s_to_10s.load(Ordering::Relaxed),
+            from_10s_to_60s: self.from_10s_to_60s.load(Ordering::Relaxed),
+            over_60s: self.over_60s.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Write a value found at `tiers[source_index]` into all tiers above it
+pub(crate) async fn promote(
+    tiers: &[CacheTier],
+    source_index: usize,
+    key: &str,
+    value: &Bytes,
+    ttl: Duration,
+    counters: &PromotionCounters,
+) {
+    let source_level = tiers.get(source_index).map_or(0, |tier| tier.tier_level);
+
+    for upper_tier in tiers.iter().take(source_index).rev() {
+        if let Err(e) = upper_tier.set_with_ttl(key, value.clone(), ttl).await {
+            warn!(
+                "Failed to promote '{}' from L{} to L{}: {}",
+                key, source_level, upper_tier.tier_level, e
+            );
+        } else {
+            counters.promotions.fetch_add(1, Ordering::Relaxed);
+            counters.ttls.record(ttl);
+            debug!(
+                "Promoted '{}' from L{} to L{} (TTL: {:?})",
+                key, source_level, upper_tier.tier_level, ttl
+            );
+        }
+    }
+}
+
+/// Keys with a promotion in progress (running inline or queued)
+pub(crate) type InFlightPromotions = DashSet<String>;
+
+/// Exclusive right to promote a key, released on drop
+///
+/// Held for the duration of an inline promotion, or moved into the queued
+/// `PendingPromotion`, so it's released once the promotion is applied or
+/// dropped from a full queue.
+pub(crate) struct PromotionClaim {
+    in_flight: Option<Arc<InFlightPromotions>>,
+    key: String,
+}
+
+impl PromotionClaim {
+    /// Claim `key`, or `None` if a promotion of it is already in flight
+    ///
+    /// Without an in-flight set (deduplication disabled) every claim succeeds.
+    pub(crate) fn acquire(in_flight: Option<&Arc<InFlightPromotions>>, key: &str) -> Option<Self> {
+        if let Some(in_flight) = in_flight
+            && !in_flight.insert(key.to_string())
+        {
+            return None;
+        }
+        Some(Self {
+            in_flight: in_flight.cloned(),
+            key: key.to_string(),
+        })
+    }
+}
+
+impl Drop for PromotionClaim {
+    fn drop(&mut self) {
+        if let Some(in_flight) = &self.in_flight {
+            in_flight.remove(&self.key);
+        }
+    }
+}
+
+/// A promotion waiting to be applied by the background worker
+pub(crate) struct PendingPromotion {
+    pub(crate) key: String,
+    pub(crate) value: Bytes,
+    pub(crate) ttl: Duration,
+    pub(crate) source_index: usize,
+    pub(crate) claim: PromotionClaim,
+    pub(crate) queued_at: Instant,
+}
+
+/// Keys recently written or removed through the manager, with the time
+#[derive(Default)]
+struct WriteLog {
+    writes: DashMap<String, Instant>,
+    /// Writes logged so far (drives the sweeps)
+    logged: AtomicU64,
+}
+
+impl WriteLog {
+    fn record(&self, key: &str) {
+        let now = Instant::now();
+        if self
+            .logged
+            .fetch_add(1, Ordering::Relaxed)
+            .is_multiple_of(WRITE_LOG_SWEEP_INTERVAL)
+        {
+            self.writes
+                .retain(|_, at| now.duration_since(*at) <= WRITE_LOG_RETENTION);
+        }
+        self.writes.insert(key.to_string(), now);
+    }
+
+    /// Whether `key` was written within the retention period
+    fn contains(&self, key: &str) -> bool {
+        self.writes
+            .get(key)
+            .is_some_and(|at| at.elapsed() <= WRITE_LOG_RETENTION)
+    }
+}
+
+/// Bounded drop-oldest queue of promotions, drained by a background task
+pub(crate) struct PromotionQueue {
+    pending: Mutex<VecDeque<PendingPromotion>>,
+    capacity: usize,
+    notify: Notify,
+    closed: AtomicBool,
+    worker: OnceLock<()>,
+    /// Held while promotions are being applied, so a flush can wait them out
+    applying: tokio::sync::Mutex<()>,
+    /// Recent writes that pending promotions must not overwrite
+    write_log: WriteLog,
+}
+
+impl PromotionQueue {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            pending: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity: capacity.max(1),
+            notify: Notify::new(),
+            closed: AtomicBool::new(false),
+            worker: OnceLock::new(),
+            applying: tokio::sync::Mutex::new(()),
+            write_log: WriteLog::default(),
+        }
+    }
+
+    /// Log a write or removal of `key`, before it reaches the tiers
+    ///
+    /// Promotions of `key` read earlier are skipped from now on, and any being
+    /// applied right now is rolled back.
+    pub(crate) fn record_write(&self, key: &str) {
+        self.write_log.record(key);
+    }
+
+    /// Enqueue a promotion, discarding the oldest pending one if the queue is full
+    ///
+    /// The worker is spawned on first use so it sees the final tier configuration.
+    pub(crate) fn push(
+        self: &Arc<Self>,
+        promotion: PendingPromotion,
+        tiers: &[CacheTier],
+        counters: &Arc<PromotionCounters>,
+        spawner: &dyn Spawner,
+    ) {
+        self.worker.get_or_init(|| {
+            spawner.spawn_named(
+                "mtc-promotion-worker",
+                Box::pin(Arc::clone(self).run(tiers.to_vec(), Arc::clone(counters))),
+            );
+        });
+
+        {
+            let mut pending = self
+                .pending
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            if pending.len() >= self.capacity {
+                pending.pop_front();
+                counters.async_dropped.fetch_add(1, Ordering::Relaxed);
+            }
+            pending.push_back(promotion);
+        }
+        self.notify.notify_one();
+    }
+
+    /// Stop the worker once the remaining promotions are applied
+    pub(crate) fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.notify.notify_one();
+    }
+
+    /// Apply all pending promotions now and wait for any the worker is applying
+    pub(crate) async fn flush(&self, tiers: &[CacheTier], counters: &PromotionCounters) {
+        let _applying = self.applying.lock().await;
+        while let Some(p) = self.pop() {
+            self.apply(tiers, &p, counters).await;
+            drop(p.claim);
+        }
+    }
+
+    /// Apply one promotion unless it is stale or its key was written since
+    async fn apply(&self, tiers: &[CacheTier], p: &PendingPromotion, counters: &PromotionCounters) {
+        if p.queued_at.elapsed() > MAX_PROMOTION_AGE {
+            debug!("Dropping promotion of '{}' queued too long", p.key);
+            counters.async_expired.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        if self.write_log.contains(&p.key) {
+            debug!("Skipping stale promotion of '{}'", p.key);
+            return;
+        }
+        promote(tiers, p.source_index, &p.key, &p.value, p.ttl, counters).await;
+
+        // A write that started while promoting may have been overwritten
+        if self.write_log.contains(&p.key) {
+            debug!("Rolling back promotion of '{}' raced by a write", p.key);
+            for upper_tier in tiers.iter().take(p.source_index) {
+                if let Err(e) = upper_tier.remove(&p.key).await {
+                    warn!(
+                        "Failed to roll back promotion of '{}' in L{}: {}",
+                        p.key, upper_tier.tier_level, e
+                    );
+                }
+            }
+        }
+    }
+
+    fn pop(&self) -> Option<PendingPromotion> {
+        self.pending
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .pop_front()
+    }
+
+    async fn run(self: Arc<Self>, tiers: Vec<CacheTier>, counters: Arc<PromotionCounters>) {
+        debug!("Async promotion worker started");
+        loop {
+            self.flush(&tiers, &counters).await;
+            if self.closed.load(Ordering::Acquire) {
+                break;
+            }
+            self.notify.notified().await;
+        }
+        debug!("Async promotion worker stopped");
+    }
+}
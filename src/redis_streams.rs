@@ -3,6 +3,7 @@ use crate::traits::{StreamEntry, StreamingBackend};
 use futures_util::future::BoxFuture;
 use redis::AsyncCommands;
 use redis::aio::ConnectionManager;
+use redis::streams::StreamPendingReply;
 use tracing::debug;
 
 /// Represents a raw entry from a Redis stream: (ID, [ (Field, Value) ])
@@ -68,6 +69,40 @@ impl StreamingBackend for RedisStreams {
         })
     }
 
+    /// One pipelined `XADD` per entry, sent in a single round-trip
+    fn stream_add_batch<'a>(
+        &'a self,
+        stream_key: &'a str,
+        entries: Vec<Vec<(String, String)>>,
+        maxlen: Option<usize>,
+    ) -> BoxFuture<'a, CacheResult<Vec<String>>> {
+        Box::pin(async move {
+            if entries.is_empty() {
+                return Ok(Vec::new());
+            }
+            let mut pipe = redis::pipe();
+            for fields in entries {
+                let cmd = pipe.cmd("XADD").arg(stream_key);
+                if let Some(max) = maxlen {
+                    cmd.arg("MAXLEN").arg("~").arg(max);
+                }
+                cmd.arg("*");
+                for (field, value) in fields {
+                    cmd.arg(field).arg(value);
+                }
+            }
+
+            let mut conn = self.conn_manager.clone();
+            let ids: Vec<String> = pipe.query_async(&mut conn).await.map_err(|e| {
+                crate::error::CacheError::BackendError(format!(
+                    "Failed to batch add to Redis stream: {e}"
+                ))
+            })?;
+            debug!("[Stream] Pipelined {} XADDs to '{}'", ids.len(), stream_key);
+            Ok(ids)
+        })
+    }
+
     fn stream_read_latest<'a>(
         &'a self,
         stream_key: &'a str,
@@ -216,4 +251,92 @@ impl StreamingBackend for RedisStreams {
             Ok(())
         })
     }
+
+    fn stream_autoclaim<'a>(
+        &'a self,
+        group_name: &'a str,
+        consumer_name: &'a str,
+        stream_key: &'a str,
+        min_idle_ms: u64,
+        start_id: &'a str,
+        count: usize,
+    ) -> BoxFuture<'a, CacheResult<Vec<StreamEntry>>> {
+        Box::pin(async move {
+            let mut conn = self.conn_manager.clone();
+            // XAUTOCLAIM returns [next_start_id, entries] (plus deleted IDs on Redis 7+)
+            let reply: Vec<redis::Value> = redis::cmd("XAUTOCLAIM")
+                .arg(stream_key)
+                .arg(group_name)
+                .arg(consumer_name)
+                .arg(min_idle_ms)
+                .arg(start_id)
+                .arg("COUNT")
+                .arg(count)
+                .query_async(&mut conn)
+                .await
+                .map_err(|e| {
+                    crate::error::CacheError::BackendError(format!(
+                        "Failed to autoclaim Redis stream entries: {e}"
+                    ))
+                })?;
+
+            // Entries deleted while pending come back as nil on Redis 6.2
+            let entries: Vec<Option<RawStreamEntry>> = match reply.into_iter().nth(1) {
+                Some(value) => redis::from_redis_value(value).map_err(|e| {
+                    crate::error::CacheError::BackendError(format!(
+                        "Failed to parse XAUTOCLAIM reply: {e}"
+                    ))
+                })?,
+                None => Vec::new(),
+            };
+            let claimed: Vec<StreamEntry> = entries.into_iter().flatten().collect();
+
+            debug!(
+                "[Stream] XAUTOCLAIM moved {} entries from '{}' to consumer '{}'",
+                claimed.len(),
+                stream_key,
+                consumer_name
+            );
+            Ok(claimed)
+        })
+    }
+    fn stream_pending_count<'a>(
+        &'a self,
+        group_name: &'a str,
+        stream_key: &'a str,
+    ) -> BoxFuture<'a, CacheResult<usize>> {
+        Box::pin(async move {
+            let mut conn = self.conn_manager.clone();
+            let reply: StreamPendingReply =
+                conn.xpending(stream_key, group_name).await.map_err(|e| {
+                    crate::error::CacheError::BackendError(format!(
+                        "Failed to read Redis stream pending summary: {e}"
+                    ))
+                })?;
+            Ok(reply.count())
+        })
+    }
+
+    fn stream_delete<'a>(
+        &'a self,
+        stream_key: &'a str,
+        ids: &'a [&'a str],
+    ) -> BoxFuture<'a, CacheResult<usize>> {
+        Box::pin(async move {
+            if ids.is_empty() {
+                return Ok(0);
+            }
+            let mut conn = self.conn_manager.clone();
+            let deleted: usize = conn.xdel(stream_key, ids).await.map_err(|e| {
+                crate::error::CacheError::BackendError(format!(
+                    "Failed to delete Redis stream entries: {e}"
+                ))
+            })?;
+            debug!(
+                "[Stream] XDEL removed {} entries from '{}'",
+                deleted, stream_key
+            );
+            Ok(deleted)
+        })
+    }
 }
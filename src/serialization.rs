@@ -3,8 +3,35 @@ use bytes::Bytes;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use std::fmt::Debug;
+use std::sync::Arc;
 
 /// High-performance cache data serialization enum
+///
+/// # Schemaless values
+///
+/// `serde_json::Value` is supported as a typed value for ad-hoc maps:
+///
+/// - **Json**: full support.
+/// - **`MsgPack`**: full support. Integers, floats, strings, arrays, nested
+///   objects and `null` round-trip unchanged, except with the
+///   `json-arbitrary-precision` feature, which makes numbers read back as strings.
+/// - **Bincode**: not supported. Bincode is not self-describing, so values can be
+///   written but reading them back fails with a `SerializationError` naming the
+///   limitation. `CacheManager::get_or_compute_typed` and its variants decode
+///   computed values once with such codecs and return that error instead of
+///   caching them. Use `Json` or `MsgPack` for schemaless data.
+///
+/// The same applies to types using `#[serde(flatten)]` or `#[serde(untagged)]`
+/// enums, which need a self-describing format: Json and `MsgPack` round-trip
+/// them, Bincode fails with a `SerializationError` on write (flatten) or read
+/// (untagged), never with a wrong value. Check a codec with
+/// `supports_self_describing`, or test a sample value with `verify_round_trip`
+/// at startup.
+///
+/// # Byte values
+///
+/// `Json` writes byte values (`Bytes`, `Vec<u8>`) as an array of decimal
+/// numbers, up to four bytes per payload byte. Use `Raw` to store them as-is.
 #[derive(Debug, Clone)]
 pub enum CacheSerializer {
     /// Default JSON serializer
@@ -15,6 +42,13 @@ pub enum CacheSerializer {
     /// Binary serializer using `MessagePack`
     #[cfg(feature = "msgpack")]
     MsgPack(MsgPackSerializer),
+    /// Another serializer with AES-256-GCM encryption of its output
+    #[cfg(feature = "encryption")]
+    Encrypted(EncryptingSerializer),
+    /// Another serializer followed by a pipeline of byte transforms
+    Chain(CodecChain),
+    /// Byte values stored unencoded
+    Raw(RawSerializer),
 }
 
 impl Default for CacheSerializer {
@@ -36,6 +70,10 @@ impl CacheSerializer {
             Self::Bincode(_) => BincodeSerializer::serialize_internal(value),
             #[cfg(feature = "msgpack")]
             Self::MsgPack(_) => MsgPackSerializer::serialize_internal(value),
+            #[cfg(feature = "encryption")]
+            Self::Encrypted(encrypting) => encrypting.serialize_internal(value),
+            Self::Chain(chain) => chain.serialize_internal(value),
+            Self::Raw(_) => RawSerializer::serialize_internal(value),
         }
     }
 
@@ -51,6 +89,10 @@ impl CacheSerializer {
             Self::Bincode(_) => BincodeSerializer::deserialize_internal(bytes),
             #[cfg(feature = "msgpack")]
             Self::MsgPack(_) => MsgPackSerializer::deserialize_internal(bytes),
+            #[cfg(feature = "encryption")]
+            Self::Encrypted(encrypting) => encrypting.deserialize_internal(bytes),
+            Self::Chain(chain) => chain.deserialize_internal(bytes),
+            Self::Raw(_) => RawSerializer::deserialize_internal(bytes),
         }
     }
 
@@ -63,11 +105,124 @@ impl CacheSerializer {
             Self::Bincode(_) => "Bincode",
             #[cfg(feature = "msgpack")]
             Self::MsgPack(_) => "MsgPack",
+            #[cfg(feature = "encryption")]
+            Self::Encrypted(_) => "Encrypted",
+            Self::Chain(_) => "Chain",
+            Self::Raw(_) => "Raw",
         }
     }
+
+    /// Whether the format is self-describing
+    ///
+    /// Only self-describing formats can hold `serde_json::Value`,
+    /// `#[serde(flatten)]` fields and `#[serde(untagged)]` enums. Wrappers
+    /// (`Encrypted`, `Chain`) report their inner serializer's capability; `Raw`
+    /// stores bytes only and reports `false`.
+    #[must_use]
+    pub fn supports_self_describing(&self) -> bool {
+        match self {
+            Self::Json(_) => true,
+            #[cfg(feature = "bincode")]
+            Self::Bincode(_) => false,
+            #[cfg(feature = "msgpack")]
+            Self::MsgPack(_) => true,
+            #[cfg(feature = "encryption")]
+            Self::Encrypted(encrypting) => encrypting.inner.supports_self_describing(),
+            Self::Chain(chain) => chain.base.supports_self_describing(),
+            Self::Raw(_) => false,
+        }
+    }
+
+    /// MIME type of the serialized bytes, for an HTTP `Content-Type` header
+    ///
+    /// `Encrypted` and `Chain` output is opaque and reports
+    /// `application/octet-stream`, as do `Bincode` and `Raw`.
+    #[must_use]
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Json(_) => "application/json",
+            #[cfg(feature = "bincode")]
+            Self::Bincode(_) => "application/octet-stream",
+            #[cfg(feature = "msgpack")]
+            Self::MsgPack(_) => "application/msgpack",
+            #[cfg(feature = "encryption")]
+            Self::Encrypted(_) => "application/octet-stream",
+            Self::Chain(_) | Self::Raw(_) => "application/octet-stream",
+        }
+    }
+
+    /// Check that `sample` survives a serialize/deserialize round trip unchanged
+    ///
+    /// Run it at startup with a representative value of each cached type to
+    /// catch a codec that can't represent the type before values are cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SerializationError` if the round trip fails or yields a
+    /// different value.
+    pub fn verify_round_trip<T>(&self, sample: &T) -> CacheResult<()>
+    where
+        T: Serialize + DeserializeOwned + PartialEq,
+    {
+        let bytes = self.serialize(sample)?;
+        if self.deserialize::<T>(&bytes)? == *sample {
+            Ok(())
+        } else {
+            Err(crate::error::CacheError::SerializationError(format!(
+                "{} serializer does not round-trip `{}` unchanged",
+                self.name(),
+                std::any::type_name::<T>()
+            )))
+        }
+    }
+
+    /// Short codec identifier embedded in values when the codec guard is enabled
+    #[must_use]
+    pub fn codec_id(&self) -> u8 {
+        match self {
+            Self::Json(_) => b'J',
+            #[cfg(feature = "bincode")]
+            Self::Bincode(_) => b'B',
+            #[cfg(feature = "msgpack")]
+            Self::MsgPack(_) => b'M',
+            #[cfg(feature = "encryption")]
+            Self::Encrypted(_) => b'E',
+            Self::Chain(_) => b'C',
+            Self::Raw(_) => b'R',
+        }
+    }
+
+    /// Prefix serialized bytes with the codec tag (magic + codec id)
+    pub(crate) fn tag(&self, bytes: &[u8]) -> Bytes {
+        let mut tagged = Vec::with_capacity(CODEC_TAG_MAGIC.len() + 1 + bytes.len());
+        tagged.extend_from_slice(&CODEC_TAG_MAGIC);
+        tagged.push(self.codec_id());
+        tagged.extend_from_slice(bytes);
+        Bytes::from(tagged)
+    }
+
+    /// Strip the codec tag, returning `None` if it is missing or names another codec
+    pub(crate) fn untag<'a>(&self, bytes: &'a [u8]) -> Option<&'a [u8]> {
+        let payload = bytes.strip_prefix(&CODEC_TAG_MAGIC)?;
+        let (&codec_id, payload) = payload.split_first()?;
+        (codec_id == self.codec_id()).then_some(payload)
+    }
 }
 
+/// Magic bytes marking a codec-tagged value (never a valid JSON/`MessagePack` prefix)
+const CODEC_TAG_MAGIC: [u8; 2] = [0xC1, 0xCC];
+
 /// Default JSON serializer using `serde_json`
+///
+/// Byte values become arrays of numbers; see `RawSerializer` for binary payloads.
+///
+/// With the `json-arbitrary-precision` feature, `serde_json`'s
+/// `arbitrary_precision` is enabled: `serde_json::Number` (and so
+/// `serde_json::Value`) holds numbers as their decimal text instead of an
+/// `f64`, and high-precision decimals round-trip exactly. Typed `f64` fields
+/// still round to `f64`. The feature is process-wide: every `serde_json` user
+/// in the build is affected, and `serde_json::Value` numbers no longer encode
+/// as plain numbers in non-JSON codecs (`MsgPack` stores them as strings).
 #[derive(Debug, Default, Clone)]
 pub struct JsonSerializer;
 
@@ -94,12 +249,30 @@ impl BincodeSerializer {
     fn serialize_internal<T: Serialize>(value: &T) -> CacheResult<Bytes> {
         bincode::serialize(value)
             .map(Bytes::from)
-            .map_err(|e| crate::error::CacheError::SerializationError(e.to_string()))
+            .map_err(|e| match *e {
+                // What `#[serde(flatten)]` fields produce
+                bincode::ErrorKind::SequenceMustHaveLength => {
+                    crate::error::CacheError::SerializationError(
+                        "Bincode cannot serialize maps of unknown length such as \
+                     `#[serde(flatten)]` fields; use the Json or MsgPack serializer"
+                            .to_string(),
+                    )
+                }
+                other => crate::error::CacheError::SerializationError(other.to_string()),
+            })
     }
 
     fn deserialize_internal<T: DeserializeOwned>(bytes: &[u8]) -> CacheResult<T> {
-        bincode::deserialize(bytes)
-            .map_err(|e| crate::error::CacheError::SerializationError(e.to_string()))
+        bincode::deserialize(bytes).map_err(|e| match *e {
+            bincode::ErrorKind::DeserializeAnyNotSupported => {
+                crate::error::CacheError::SerializationError(
+                    "Bincode cannot deserialize schemaless types such as `serde_json::Value` \
+                     or `#[serde(untagged)]` enums; use the Json or MsgPack serializer"
+                        .to_string(),
+                )
+            }
+            other => crate::error::CacheError::SerializationError(other.to_string()),
+        })
     }
 }
 
@@ -121,3 +294,632 @@ impl MsgPackSerializer {
             .map_err(|e| crate::error::CacheError::SerializationError(e.to_string()))
     }
 }
+
+/// Serializer wrapper encrypting values with AES-256-GCM
+///
+/// The inner serializer's output is encrypted with a random 96-bit nonce per
+/// value, stored as `nonce || ciphertext || tag`. The GCM tag authenticates the
+/// value: a tampered (or foreign) value fails to decrypt, and typed reads
+/// through `CacheManager` treat it as a cache miss.
+///
+/// # Key rotation
+///
+/// Values are always encrypted with the current key. Keys added with
+/// `with_previous_key` are only tried for decryption, so values written before
+/// a rotation stay readable until they expire.
+///
+/// # Example
+///
+/// ```rust
+/// use multi_tier_cache::serialization::{EncryptingSerializer, JsonSerializer};
+/// use multi_tier_cache::CacheSerializer;
+///
+/// # fn example() -> multi_tier_cache::CacheResult<()> {
+/// let serializer = CacheSerializer::Encrypted(
+///     EncryptingSerializer::new(CacheSerializer::Json(JsonSerializer), &[7; 32])?
+///         .with_previous_key(&[6; 32])?,
+/// );
+/// let bytes = serializer.serialize(&"secret")?;
+/// assert_eq!(serializer.deserialize::<String>(&bytes)?, "secret");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "encryption")]
+#[derive(Clone)]
+pub struct EncryptingSerializer {
+    /// Serializer producing the plaintext
+    inner: Box<CacheSerializer>,
+    /// Current key first, then previous keys (decryption only)
+    keys: Vec<ring::aead::LessSafeKey>,
+    /// Nonce source
+    rng: ring::rand::SystemRandom,
+}
+
+#[cfg(feature = "encryption")]
+impl Debug for EncryptingSerializer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptingSerializer")
+            .field("inner", &self.inner)
+            .field("keys", &self.keys.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "encryption")]
+impl EncryptingSerializer {
+    /// Encrypt the output of `inner` with a 256-bit `key`
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ConfigError` if the key can't be loaded.
+    pub fn new(inner: CacheSerializer, key: &[u8; 32]) -> CacheResult<Self> {
+        Ok(Self {
+            inner: Box::new(inner),
+            keys: vec![Self::load_key(key)?],
+            rng: ring::rand::SystemRandom::new(),
+        })
+    }
+
+    /// Also accept values encrypted with a previous `key`
+    ///
+    /// Previous keys are tried in the order they were added.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ConfigError` if the key can't be loaded.
+    pub fn with_previous_key(mut self, key: &[u8; 32]) -> CacheResult<Self> {
+        self.keys.push(Self::load_key(key)?);
+        Ok(self)
+    }
+
+    /// The serializer producing the plaintext
+    #[must_use]
+    pub fn inner(&self) -> &CacheSerializer {
+        &self.inner
+    }
+
+    fn load_key(key: &[u8; 32]) -> CacheResult<ring::aead::LessSafeKey> {
+        ring::aead::UnboundKey::new(&ring::aead::AES_256_GCM, key)
+            .map(ring::aead::LessSafeKey::new)
+            .map_err(|_| {
+                crate::error::CacheError::ConfigError("Invalid AES-256-GCM key".to_string())
+            })
+    }
+
+    /// Encrypt `plaintext` with the current key
+    pub(crate) fn encrypt(&self, plaintext: &[u8]) -> CacheResult<Bytes> {
+        use ring::rand::SecureRandom;
+
+        let failed =
+            || crate::error::CacheError::SerializationError("Encryption failed".to_string());
+        let key = self.keys.first().ok_or_else(failed)?;
+        let mut nonce = [0u8; ring::aead::NONCE_LEN];
+        self.rng.fill(&mut nonce).map_err(|_| failed())?;
+
+        let mut in_out = plaintext.to_vec();
+        key.seal_in_place_append_tag(
+            ring::aead::Nonce::assume_unique_for_key(nonce),
+            ring::aead::Aad::empty(),
+            &mut in_out,
+        )
+        .map_err(|_| failed())?;
+
+        let mut sealed = Vec::with_capacity(nonce.len() + in_out.len());
+        sealed.extend_from_slice(&nonce);
+        sealed.extend_from_slice(&in_out);
+        Ok(Bytes::from(sealed))
+    }
+
+    /// Decrypt and authenticate `sealed`, trying each key; `None` if no key
+    /// opens it (wrong key, tampered or not encrypted)
+    pub(crate) fn decrypt(&self, sealed: &[u8]) -> Option<Vec<u8>> {
+        let (nonce, ciphertext) = sealed.split_at_checked(ring::aead::NONCE_LEN)?;
+        let nonce: [u8; ring::aead::NONCE_LEN] = nonce.try_into().ok()?;
+        self.keys.iter().find_map(|key| {
+            let mut in_out = ciphertext.to_vec();
+            let plaintext_len = key
+                .open_in_place(
+                    ring::aead::Nonce::assume_unique_for_key(nonce),
+                    ring::aead::Aad::empty(),
+                    &mut in_out,
+                )
+                .ok()?
+                .len();
+            in_out.truncate(plaintext_len);
+            Some(in_out)
+        })
+    }
+
+    fn serialize_internal<T: Serialize>(&self, value: &T) -> CacheResult<Bytes> {
+        self.encrypt(&self.inner.serialize(value)?)
+    }
+
+    fn deserialize_internal<T: DeserializeOwned>(&self, bytes: &[u8]) -> CacheResult<T> {
+        let plaintext = self.decrypt(bytes).ok_or_else(|| {
+            crate::error::CacheError::SerializationError(
+                "Decryption failed: wrong key or tampered value".to_string(),
+            )
+        })?;
+        self.inner.deserialize(&plaintext)
+    }
+}
+
+/// Reversible byte transform applied after serialization (compression,
+/// encryption, signing...)
+///
+/// `backward` must undo `forward`. Implementations fail with a
+/// `SerializationError` on input they can't undo (truncated, tampered or
+/// foreign bytes).
+pub trait ByteTransform: Send + Sync {
+    /// Transform name, for logs and `Debug` output
+    fn name(&self) -> &'static str;
+
+    /// Transform bytes on the way into the cache
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SerializationError` if the bytes can't be transformed.
+    fn forward(&self, bytes: &[u8]) -> CacheResult<Vec<u8>>;
+
+    /// Undo `forward` on the way out of the cache
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SerializationError` if the bytes can't be restored.
+    fn backward(&self, bytes: &[u8]) -> CacheResult<Vec<u8>>;
+}
+
+/// Serializer followed by an ordered pipeline of byte transforms
+///
+/// On write the base serializer runs first, then each transform's `forward`
+/// in the order added; on read the `backward` steps run in reverse order
+/// before the base deserializer. Order matters: compress before encrypting,
+/// since ciphertext doesn't compress.
+///
+/// # Example
+///
+/// ```rust
+/// use multi_tier_cache::serialization::{ByteTransform, CodecChain, JsonSerializer};
+/// use multi_tier_cache::{CacheResult, CacheSerializer};
+///
+/// struct Reverse;
+///
+/// impl ByteTransform for Reverse {
+///     fn name(&self) -> &'static str { "Reverse" }
+///     fn forward(&self, bytes: &[u8]) -> CacheResult<Vec<u8>> {
+///         Ok(bytes.iter().rev().copied().collect())
+///     }
+///     fn backward(&self, bytes: &[u8]) -> CacheResult<Vec<u8>> {
+///         self.forward(bytes)
+///     }
+/// }
+///
+/// # fn example() -> CacheResult<()> {
+/// let serializer = CacheSerializer::Chain(
+///     CodecChain::new(CacheSerializer::Json(JsonSerializer)).with_transform(Reverse),
+/// );
+/// let bytes = serializer.serialize(&"value")?;
+/// assert_eq!(&bytes[..], b"\"eulav\"");
+/// assert_eq!(serializer.deserialize::<String>(&bytes)?, "value");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CodecChain {
+    /// Serializer producing the bytes the transforms operate on
+    base: Box<CacheSerializer>,
+    /// Transforms in write order
+    transforms: Vec<Arc<dyn ByteTransform>>,
+}
+
+impl Debug for CodecChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let transforms: Vec<&str> = self.transforms.iter().map(|t| t.name()).collect();
+        f.debug_struct("CodecChain")
+            .field("base", &self.base)
+            .field("transforms", &transforms)
+            .finish()
+    }
+}
+
+impl CodecChain {
+    /// Chain without transforms, behaving like `base`
+    #[must_use]
+    pub fn new(base: CacheSerializer) -> Self {
+        Self {
+            base: Box::new(base),
+            transforms: Vec::new(),
+        }
+    }
+
+    /// Append `transform` to the end of the write pipeline
+    #[must_use]
+    pub fn with_transform(mut self, transform: impl ByteTransform + 'static) -> Self {
+        self.transforms.push(Arc::new(transform));
+        self
+    }
+
+    /// The serializer producing the untransformed bytes
+    #[must_use]
+    pub fn base(&self) -> &CacheSerializer {
+        &self.base
+    }
+
+    fn serialize_internal<T: Serialize>(&self, value: &T) -> CacheResult<Bytes> {
+        let mut bytes = self.base.serialize(value)?;
+        for transform in &self.transforms {
+            bytes = Bytes::from(transform.forward(&bytes)?);
+        }
+        Ok(bytes)
+    }
+
+    fn deserialize_internal<T: DeserializeOwned>(&self, bytes: &[u8]) -> CacheResult<T> {
+        let mut bytes = bytes.to_vec();
+        for transform in self.transforms.iter().rev() {
+            bytes = transform.backward(&bytes)?;
+        }
+        self.base.deserialize(&bytes)
+    }
+}
+
+/// Header byte of a value stored uncompressed
+const FRAME_RAW: u8 = 0;
+/// Header byte of a value stored compressed
+const FRAME_COMPRESSED: u8 = 1;
+
+/// When a typed write is compressed (see `ValueCompression`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CompressionMode {
+    /// Compress values of at least the configured minimum size
+    #[default]
+    Auto,
+    /// Compress regardless of size (e.g. large images)
+    Always,
+    /// Never compress (e.g. already-compressed payloads)
+    Never,
+}
+
+/// Compression of typed values (see `CacheManager::with_value_compression`)
+///
+/// Uses a `ByteTransform`, like `CodecChain`, but only for values of at least
+/// `min_size` bytes. Each stored value starts with a one-byte header recording
+/// whether it was compressed, so values written with and without compression
+/// can be read back alike. Every instance sharing the cache must use the same
+/// transform.
+///
+/// No compressor is built in; supply a `ByteTransform` wrapping a compression
+/// crate such as `zstd` or `lz4_flex` (`forward` compresses, `backward`
+/// decompresses).
+#[derive(Clone)]
+pub struct ValueCompression {
+    /// Compressor; `backward` must undo `forward`
+    transform: Arc<dyn ByteTransform>,
+    /// Values shorter than this are stored uncompressed in `Auto` mode
+    min_size: usize,
+}
+
+impl Debug for ValueCompression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValueCompression")
+            .field("transform", &self.transform.name())
+            .field("min_size", &self.min_size)
+            .finish()
+    }
+}
+
+impl ValueCompression {
+    /// Compress values of at least 1 KiB with `transform`
+    pub fn new(transform: impl ByteTransform + 'static) -> Self {
+        Self {
+            transform: Arc::new(transform),
+            min_size: 1024,
+        }
+    }
+
+    /// Only compress values of at least `min_size` bytes (in `Auto` mode)
+    #[must_use]
+    pub fn with_min_size(mut self, min_size: usize) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Add the header to serialized `bytes`, compressing them as `mode` asks
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SerializationError` if the transform fails.
+    pub fn compress(&self, bytes: &[u8], mode: CompressionMode) -> CacheResult<Bytes> {
+        let compress = match mode {
+            CompressionMode::Auto => bytes.len() >= self.min_size,
+            CompressionMode::Always => true,
+            CompressionMode::Never => false,
+        };
+        let (header, body) = if compress {
+            (
+                FRAME_COMPRESSED,
+                std::borrow::Cow::Owned(self.transform.forward(bytes)?),
+            )
+        } else {
+            (FRAME_RAW, std::borrow::Cow::Borrowed(bytes))
+        };
+        let mut framed = Vec::with_capacity(body.len() + 1);
+        framed.push(header);
+        framed.extend_from_slice(&body);
+        Ok(Bytes::from(framed))
+    }
+
+    /// Strip the header from stored `bytes`, decompressing them if needed
+    ///
+    /// `None` if the header is missing or unknown, or decompression fails.
+    #[must_use]
+    pub fn decompress<'a>(&self, bytes: &'a [u8]) -> Option<std::borrow::Cow<'a, [u8]>> {
+        match bytes.split_first()? {
+            (&FRAME_RAW, body) => Some(std::borrow::Cow::Borrowed(body)),
+            (&FRAME_COMPRESSED, body) => self
+                .transform
+                .backward(body)
+                .ok()
+                .map(std::borrow::Cow::Owned),
+            _ => None,
+        }
+    }
+
+    /// Whether stored `bytes` carry the compressed header
+    #[must_use]
+    pub fn is_compressed(bytes: &[u8]) -> bool {
+        bytes.first() == Some(&FRAME_COMPRESSED)
+    }
+}
+
+/// Serializer storing byte values as-is
+///
+/// For payloads that already are bytes (protobuf, images, compressed blobs),
+/// so a 1 KiB payload takes 1 KiB in every tier. `bytes::Bytes`,
+/// `serde_bytes::ByteBuf`, `Vec<u8>` and strings are written unchanged and
+/// read back without copying into an intermediate format. Any other type fails
+/// with a `SerializationError`.
+///
+/// # Example
+///
+/// ```rust
+/// use bytes::Bytes;
+/// use multi_tier_cache::serialization::RawSerializer;
+/// use multi_tier_cache::{CacheResult, CacheSerializer};
+///
+/// # fn example() -> CacheResult<()> {
+/// let serializer = CacheSerializer::Raw(RawSerializer);
+/// let payload = Bytes::from_static(&[0x08, 0x96, 0x01]);
+/// let stored = serializer.serialize(&payload)?;
+/// assert_eq!(stored, payload);
+/// assert_eq!(serializer.deserialize::<Bytes>(&stored)?, payload);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct RawSerializer;
+
+impl RawSerializer {
+    fn serialize_internal<T: Serialize>(value: &T) -> CacheResult<Bytes> {
+        value
+            .serialize(RawValue { in_seq: false })
+            .map(Bytes::from)
+            .map_err(|e| crate::error::CacheError::SerializationError(e.0))
+    }
+
+    fn deserialize_internal<T: DeserializeOwned>(bytes: &[u8]) -> CacheResult<T> {
+        T::deserialize(RawValueDeserializer(bytes.to_vec()))
+            .map_err(|e| crate::error::CacheError::SerializationError(e.0))
+    }
+}
+
+/// Error of the raw (de)serializer
+#[derive(Debug)]
+struct RawError(String);
+
+impl RawError {
+    fn unsupported() -> Self {
+        Self("Raw serializer only stores bytes and strings".to_string())
+    }
+}
+
+impl std::fmt::Display for RawError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for RawError {}
+
+impl serde::ser::Error for RawError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+impl serde::de::Error for RawError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+/// Serializer accepting byte strings, strings and sequences of `u8`
+struct RawValue {
+    /// Serializing an element of a sequence: only `u8` is accepted
+    in_seq: bool,
+}
+
+/// Reject every listed `Serializer` method
+macro_rules! reject {
+    ($($method:ident($($ty:ty),*)),* $(,)?) => {
+        $(fn $method(self, $(_: $ty),*) -> Result<Self::Ok, Self::Error> {
+            Err(RawError::unsupported())
+        })*
+    };
+}
+
+impl serde::Serializer for RawValue {
+    type Ok = Vec<u8>;
+    type Error = RawError;
+    type SerializeSeq = RawSeq;
+    type SerializeTuple = serde::ser::Impossible<Vec<u8>, RawError>;
+    type SerializeTupleStruct = serde::ser::Impossible<Vec<u8>, RawError>;
+    type SerializeTupleVariant = serde::ser::Impossible<Vec<u8>, RawError>;
+    type SerializeMap = serde::ser::Impossible<Vec<u8>, RawError>;
+    type SerializeStruct = serde::ser::Impossible<Vec<u8>, RawError>;
+    type SerializeStructVariant = serde::ser::Impossible<Vec<u8>, RawError>;
+
+    reject! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(&'static str),
+        serialize_unit_variant(&'static str, u32, &'static str),
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Vec<u8>, RawError> {
+        if self.in_seq {
+            return Ok(vec![v]);
+        }
+        Err(RawError::unsupported())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Vec<u8>, RawError> {
+        self.serialize_bytes(v.as_bytes())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Vec<u8>, RawError> {
+        if self.in_seq {
+            return Err(RawError::unsupported());
+        }
+        Ok(v.to_vec())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, _: &T) -> Result<Vec<u8>, RawError> {
+        Err(RawError::unsupported())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Vec<u8>, RawError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Vec<u8>, RawError> {
+        Err(RawError::unsupported())
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<RawSeq, RawError> {
+        if self.in_seq {
+            return Err(RawError::unsupported());
+        }
+        Ok(RawSeq(Vec::with_capacity(len.unwrap_or(0))))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, RawError> {
+        Err(RawError::unsupported())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, RawError> {
+        Err(RawError::unsupported())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, RawError> {
+        Err(RawError::unsupported())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, RawError> {
+        Err(RawError::unsupported())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, RawError> {
+        Err(RawError::unsupported())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, RawError> {
+        Err(RawError::unsupported())
+    }
+}
+
+/// Sequence of `u8` (e.g. a `Vec<u8>` without `serde_bytes`)
+struct RawSeq(Vec<u8>);
+
+impl serde::ser::SerializeSeq for RawSeq {
+    type Ok = Vec<u8>;
+    type Error = RawError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), RawError> {
+        self.0.extend(value.serialize(RawValue { in_seq: true })?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Vec<u8>, RawError> {
+        Ok(self.0)
+    }
+}
+
+/// Deserializer handing stored bytes to the visitor
+struct RawValueDeserializer(Vec<u8>);
+
+impl<'de> serde::Deserializer<'de> for RawValueDeserializer {
+    type Error = RawError;
+
+    fn deserialize_any<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, RawError> {
+        visitor.visit_byte_buf(self.0)
+    }
+
+    fn deserialize_seq<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, RawError> {
+        visitor.visit_seq(serde::de::value::SeqDeserializer::new(self.0.into_iter()))
+    }
+
+    fn deserialize_newtype_struct<V: serde::de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, RawError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct tuple tuple_struct map struct
+        enum identifier ignored_any
+    }
+}
@@ -0,0 +1,85 @@
+//! Task spawning abstraction for background work
+//!
+//! Background tasks (the invalidation subscriber, the async promotion worker)
+//! are handed to a `Spawner` instead of calling `tokio::spawn` directly, so they
+//! can run on a specific runtime handle, a `LocalSet`-backed executor, or be
+//! tracked by the application.
+//!
+//! The tasks still use Tokio timers and Redis' Tokio I/O, so whatever executor
+//! polls them must do so inside a Tokio runtime context.
+//!
+//! Background tasks carry names (`mtc-invalidation-subscriber`,
+//! `mtc-promotion-worker`, ...). `TokioSpawner` registers them with Tokio when
+//! built with `RUSTFLAGS="--cfg tokio_unstable"` and the `tokio-console`
+//! feature, so they are identifiable in tokio-console; otherwise names are ignored.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use futures_util::future::BoxFuture;
+//! use multi_tier_cache::Spawner;
+//!
+//! /// Spawns onto a dedicated runtime instead of the caller's
+//! struct DedicatedRuntime(tokio::runtime::Handle);
+//!
+//! impl Spawner for DedicatedRuntime {
+//!     fn spawn(&self, task: BoxFuture<'static, ()>) {
+//!         self.0.spawn(task);
+//!     }
+//! }
+//! ```
+
+use futures_util::future::BoxFuture;
+use std::future::Future;
+use tokio::task::JoinHandle;
+
+/// Runs background tasks for the cache
+pub trait Spawner: Send + Sync {
+    /// Start `task` in the background; it must be polled to completion
+    fn spawn(&self, task: BoxFuture<'static, ()>);
+
+    /// Start a named background task
+    ///
+    /// Default implementation ignores the name and calls `spawn`.
+    fn spawn_named(&self, name: &'static str, task: BoxFuture<'static, ()>) {
+        let _ = name;
+        self.spawn(task);
+    }
+}
+
+/// Default spawner: `tokio::spawn` on the current runtime
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TokioSpawner;
+
+impl Spawner for TokioSpawner {
+    fn spawn(&self, task: BoxFuture<'static, ()>) {
+        tokio::spawn(task);
+    }
+
+    fn spawn_named(&self, name: &'static str, task: BoxFuture<'static, ()>) {
+        spawn_named(name, task);
+    }
+}
+
+/// `tokio::spawn` that names the task for tokio-console when supported
+#[cfg(all(tokio_unstable, feature = "tokio-console"))]
+pub(crate) fn spawn_named<F>(name: &str, task: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    match tokio::task::Builder::new().name(name).spawn(task) {
+        Ok(handle) => handle,
+        Err(e) => panic!("Failed to spawn task '{name}': {e}"),
+    }
+}
+
+/// `tokio::spawn` that names the task for tokio-console when supported
+#[cfg(not(all(tokio_unstable, feature = "tokio-console")))]
+pub(crate) fn spawn_named<F>(_name: &str, task: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    tokio::spawn(task)
+}
@@ -40,9 +40,12 @@
 ///     fn name(&self) -> &'static str { "MyCache" }
 /// }
 /// ```
-use crate::error::CacheResult;
+use crate::error::{CacheError, CacheResult};
+use crate::transaction::Transaction;
 use bytes::Bytes;
 use futures_util::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Core cache backend trait for both L1 and L2 caches
@@ -119,6 +122,92 @@ pub trait CacheBackend: Send + Sync {
     /// * `Err(e)` - Cache operation failed
     fn remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<()>>;
 
+    /// Remove several keys at once
+    ///
+    /// Default implementation removes the keys one by one. Backends with a
+    /// multi-key delete override it (Redis issues a single `DEL`).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Keys removed (missing keys are ignored)
+    /// * `Err(e)` - A removal failed
+    fn batch_remove<'a>(&'a self, keys: &'a [String]) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            for key in keys {
+                self.remove(key).await?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Read and remove a value in one step ("consume once")
+    ///
+    /// The default implementation is a `get` followed by a `remove` and is **not
+    /// atomic**: two concurrent callers may both receive the value. Backends that
+    /// can do better override it (Redis uses `GETDEL`; `DashMap`, Moka and
+    /// `QuickCache` remove the entry and return it in a single map operation).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(value))` - Value was present and has been removed
+    /// * `Ok(None)` - Key not found
+    /// * `Err(e)` - Cache operation failed
+    fn get_and_remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, CacheResult<Option<Bytes>>> {
+        Box::pin(async move {
+            let value = self.get(key).await;
+            if value.is_some() {
+                self.remove(key).await?;
+            }
+            Ok(value)
+        })
+    }
+
+    /// Replace the value of `key` only if it already exists (like Redis `SET XX`)
+    ///
+    /// The default implementation is a `get` followed by a `set_with_ttl` and is
+    /// **not atomic**: the key may be removed or expire in between. Backends that
+    /// can do better override it (Redis uses `SET ... XX`; `DashMap`, Moka and
+    /// `QuickCache` check and replace the entry under its lock).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - Key existed and its value was replaced
+    /// * `Ok(false)` - Key not found, nothing written
+    /// * `Err(e)` - Cache operation failed
+    fn set_if_present<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move {
+            if self.get(key).await.is_none() {
+                return Ok(false);
+            }
+            self.set_with_ttl(key, value, ttl).await?;
+            Ok(true)
+        })
+    }
+
+    /// Reset the TTL of `key` to `ttl` without changing its value
+    ///
+    /// Default implementation reads the value and writes it back with
+    /// `set_if_present`; backends with a native expiry update override it.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - Key existed and its TTL was reset
+    /// * `Ok(false)` - Key not found, nothing changed
+    /// * `Err(e)` - Cache operation failed
+    fn touch<'a>(&'a self, key: &'a str, ttl: Duration) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move {
+            let Some(value) = self.get(key).await else {
+                return Ok(false);
+            };
+            self.set_if_present(key, value, ttl).await
+        })
+    }
+
     /// Check if cache backend is healthy
     ///
     /// This method should verify that the cache backend is operational.
@@ -144,12 +233,157 @@ pub trait CacheBackend: Send + Sync {
         Box::pin(async { Ok(()) })
     }
 
+    /// Remove every entry whose key matches `predicate`
+    ///
+    /// Default implementation lists `keys()` and removes the matches one by
+    /// one. In-memory backends override it with a single pass over their
+    /// store (Moka `invalidate_entries_if`, `DashMap` `retain`).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Matching entries removed
+    /// * `Err(e)` - The backend can't enumerate keys, or a removal failed
+    fn remove_if(&self, predicate: KeyPredicate) -> BoxFuture<'_, CacheResult<()>> {
+        Box::pin(async move {
+            let Some(keys) = self.keys().await? else {
+                return Err(CacheError::BackendError(format!(
+                    "{} backend does not support key iteration",
+                    self.name()
+                )));
+            };
+            for key in keys.iter().filter(|key| predicate(key)) {
+                self.remove(key).await?;
+            }
+            Ok(())
+        })
+    }
+
+    /// List the keys of all live (non-expired) entries
+    ///
+    /// Default implementation returns `None` for backends that can't enumerate keys.
+    fn keys(&self) -> BoxFuture<'_, CacheResult<Option<Vec<String>>>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    /// List up to `limit` keys of live entries matching a glob-style pattern
+    ///
+    /// Default implementation filters `keys()`; backends that can search
+    /// server-side (Redis `SCAN MATCH`) override it. `None` if the backend
+    /// can't enumerate keys.
+    fn keys_matching<'a>(
+        &'a self,
+        pattern: &'a str,
+        limit: usize,
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        Box::pin(async move {
+            Ok(self.keys().await?.map(|keys| {
+                keys.into_iter()
+                    .filter(|key| crate::backends::matches_pattern(key, pattern))
+                    .take(limit)
+                    .collect()
+            }))
+        })
+    }
+
+    /// List one page of keys of live entries matching a glob-style pattern
+    ///
+    /// Start with `cursor` 0 and pass the returned cursor back in until it is
+    /// 0 again, like Redis `SCAN`, which backs the Redis implementation
+    /// (`count` is its `COUNT` hint, so a page may hold more or fewer keys,
+    /// even none). Default implementation sorts `keys_matching` and returns
+    /// `count` of them from offset `cursor`, so every page lists the keys
+    /// again; keys removed during a scan can shift later ones past the cursor.
+    /// `None` if the backend can't enumerate keys.
+    fn scan_keys_page<'a>(
+        &'a self,
+        pattern: &'a str,
+        cursor: u64,
+        count: usize,
+    ) -> BoxFuture<'a, CacheResult<Option<KeyPage>>> {
+        Box::pin(async move {
+            let Some(mut keys) = self.keys_matching(pattern, usize::MAX).await? else {
+                return Ok(None);
+            };
+            keys.sort_unstable();
+            let total = keys.len();
+            let start = usize::try_from(cursor).unwrap_or(usize::MAX).min(total);
+            let end = start.saturating_add(count.max(1)).min(total);
+            let page = keys.drain(start..end).collect();
+            let next = if end < total {
+                u64::try_from(end).unwrap_or(0)
+            } else {
+                0
+            };
+            Ok(Some((next, page)))
+        })
+    }
+
+    /// Pin or unpin `key` so capacity-based eviction never removes it
+    ///
+    /// A pinned entry still expires with its TTL; it is only exempt from
+    /// eviction when the cache is full. The pin applies to the key, so a
+    /// value written later is pinned too. Moka keeps pinned entries outside
+    /// its bounded store; `DashMap` never evicts for capacity, so every entry
+    /// is effectively pinned. The default implementation doesn't support
+    /// pinning.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - The backend honors the pin
+    /// * `Ok(false)` - The backend doesn't support pinning, nothing changed
+    /// * `Err(e)` - Cache operation failed
+    fn set_pinned<'a>(&'a self, _key: &'a str, _pinned: bool) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async { Ok(false) })
+    }
+
+    /// Maximum number of entries the backend holds before evicting
+    ///
+    /// `None` for unbounded backends (`DashMap`, Redis) and backends that
+    /// don't know their capacity.
+    fn capacity(&self) -> Option<u64> {
+        None
+    }
+
+    /// Number of entries currently held
+    ///
+    /// May be approximate: Moka only updates its count during maintenance.
+    /// `None` if the backend can't report it cheaply.
+    fn entry_count(&self) -> Option<u64> {
+        None
+    }
+
+    /// Approximate bytes held in memory (keys plus values)
+    ///
+    /// Used to enforce `CacheManager::with_global_max_bytes`. `None` for
+    /// backends that don't hold values in this process (Redis, Memcached) or
+    /// can't estimate it.
+    fn estimated_size_bytes(&self) -> Option<u64> {
+        None
+    }
+
+    /// Evict entries until about `bytes` bytes are freed
+    ///
+    /// Returns the bytes actually freed, which may fall short if the backend
+    /// runs out of entries. Default implementation evicts nothing.
+    fn evict_bytes(&self, _bytes: u64) -> BoxFuture<'_, CacheResult<u64>> {
+        Box::pin(async { Ok(0) })
+    }
+
     /// Get the name of this cache backend
     fn name(&self) -> &'static str;
 }
 
 // (No longer needed since traits are now dyn-compatible)
 
+/// Predicate over stored keys (see `CacheBackend::remove_if`)
+pub type KeyPredicate = Arc<dyn Fn(&str) -> bool + Send + Sync>;
+
+/// A value with its remaining TTL (`None` if unknown or without expiry)
+pub type ValueWithTtl = (Bytes, Option<Duration>);
+
+/// A page of keys with the cursor of the next page (0 once the scan is done)
+pub type KeyPage = (u64, Vec<String>);
+
 /// Extended trait for L2 cache backends with TTL introspection
 ///
 /// This trait extends `CacheBackend` with the ability to retrieve both a value
@@ -191,6 +425,175 @@ pub trait L2CacheBackend: CacheBackend {
     /// Get value with its remaining TTL from L2 cache
     fn get_with_ttl<'a>(&'a self, key: &'a str)
     -> BoxFuture<'a, Option<(Bytes, Option<Duration>)>>;
+
+    /// Get several values with their remaining TTLs, in the order of `keys`
+    ///
+    /// Default implementation calls `get_with_ttl` per key; backends with
+    /// batched reads (Redis pipelining) override it to save round trips.
+    fn get_many_with_ttl<'a>(
+        &'a self,
+        keys: &'a [String],
+    ) -> BoxFuture<'a, Vec<Option<ValueWithTtl>>> {
+        Box::pin(async move {
+            let mut results = Vec::with_capacity(keys.len());
+            for key in keys {
+                results.push(self.get_with_ttl(key).await);
+            }
+            results
+        })
+    }
+
+    /// Get server-side information (memory usage, hit/miss counters, ...)
+    ///
+    /// Default implementation returns `None` for backends without server stats.
+    fn server_info(&self) -> BoxFuture<'_, CacheResult<Option<HashMap<String, String>>>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    /// Get the age of a cached value (time since it was written)
+    ///
+    /// Default implementation returns `None` for backends that don't track it.
+    fn age<'a>(&'a self, _key: &'a str) -> BoxFuture<'a, CacheResult<Option<Duration>>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    /// Advance the shared version counter of `key` and return the new version
+    ///
+    /// Atomically sets the counter to the larger of its current value plus one
+    /// (a missing counter counts as 0) and `floor`, so versions handed out to
+    /// every instance sharing the backend strictly increase. Counters have no
+    /// TTL. Default implementation returns `None` for backends without shared
+    /// counters.
+    fn next_version<'a>(
+        &'a self,
+        _key: &'a str,
+        _floor: u64,
+    ) -> BoxFuture<'a, CacheResult<Option<u64>>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    /// Block until previous writes reach `replicas` replicas or `timeout` passes
+    ///
+    /// Returns the number of replicas that acknowledged the writes.
+    /// Default implementation returns an error for backends without replication.
+    fn wait_for_replicas(
+        &self,
+        _replicas: usize,
+        _timeout: Duration,
+    ) -> BoxFuture<'_, CacheResult<usize>> {
+        Box::pin(async {
+            Err(CacheError::BackendError(
+                "wait_for_replicas is not supported by this backend".to_string(),
+            ))
+        })
+    }
+
+    /// Apply `transaction` atomically
+    ///
+    /// Returns `false` (having written nothing) if a precondition doesn't
+    /// hold. Default implementation returns an error for backends without
+    /// multi-key atomicity.
+    fn execute_transaction<'a>(
+        &'a self,
+        _transaction: &'a Transaction,
+    ) -> BoxFuture<'a, CacheResult<bool>> {
+        Box::pin(async move {
+            Err(CacheError::BackendError(format!(
+                "{} backend does not support transactions",
+                self.name()
+            )))
+        })
+    }
+
+    /// Add `key` to the tag set of each of `tags`
+    ///
+    /// Default implementation returns an error for backends without tag sets.
+    fn tag_key<'a>(&'a self, _key: &'a str, _tags: &'a [String]) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            Err(CacheError::BackendError(format!(
+                "{} backend does not support tags",
+                self.name()
+            )))
+        })
+    }
+
+    /// Keys tagged with every one of `tags` (the intersection of their tag sets)
+    ///
+    /// Empty `tags` match no keys. Default implementation returns `None` for
+    /// backends without tag sets.
+    fn keys_with_all_tags<'a>(
+        &'a self,
+        _tags: &'a [String],
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    /// Remove `keys` from the tag sets of `tags`
+    ///
+    /// Default implementation does nothing (no tag sets to clean up).
+    fn untag_keys<'a>(
+        &'a self,
+        _keys: &'a [String],
+        _tags: &'a [String],
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Add `member` to the secondary index `index`
+    ///
+    /// Default implementation returns an error for backends without indexes.
+    fn index_add<'a>(
+        &'a self,
+        _index: &'a str,
+        _member: &'a str,
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            Err(CacheError::BackendError(format!(
+                "{} backend does not support secondary indexes",
+                self.name()
+            )))
+        })
+    }
+
+    /// Remove `member` from the secondary index `index`
+    ///
+    /// Removing a member that isn't in the index is a no-op. Default
+    /// implementation returns an error for backends without indexes.
+    fn index_remove<'a>(
+        &'a self,
+        _index: &'a str,
+        _member: &'a str,
+    ) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            Err(CacheError::BackendError(format!(
+                "{} backend does not support secondary indexes",
+                self.name()
+            )))
+        })
+    }
+
+    /// Drop the secondary index `index` with all its members
+    ///
+    /// Default implementation returns an error for backends without indexes.
+    fn index_drop<'a>(&'a self, _index: &'a str) -> BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            Err(CacheError::BackendError(format!(
+                "{} backend does not support secondary indexes",
+                self.name()
+            )))
+        })
+    }
+
+    /// Members of the secondary index `index`, in lexicographic order
+    ///
+    /// An unknown index has no members. Default implementation returns `None`
+    /// for backends without indexes.
+    fn index_members<'a>(
+        &'a self,
+        _index: &'a str,
+    ) -> BoxFuture<'a, CacheResult<Option<Vec<String>>>> {
+        Box::pin(async { Ok(None) })
+    }
 }
 
 // (No longer needed since traits are now dyn-compatible)
@@ -279,6 +682,25 @@ pub trait StreamingBackend: Send + Sync {
         maxlen: Option<usize>,
     ) -> BoxFuture<'a, CacheResult<String>>;
 
+    /// Add several entries to a stream, returning their IDs in order
+    ///
+    /// Default implementation calls `stream_add` once per entry; backends that
+    /// can batch the writes into one round-trip should override it.
+    fn stream_add_batch<'a>(
+        &'a self,
+        stream_key: &'a str,
+        entries: Vec<Vec<(String, String)>>,
+        maxlen: Option<usize>,
+    ) -> BoxFuture<'a, CacheResult<Vec<String>>> {
+        Box::pin(async move {
+            let mut ids = Vec::with_capacity(entries.len());
+            for fields in entries {
+                ids.push(self.stream_add(stream_key, fields, maxlen).await?);
+            }
+            Ok(ids)
+        })
+    }
+
     /// Read the latest N entries from a stream (newest first)
     fn stream_read_latest<'a>(
         &'a self,
@@ -320,4 +742,57 @@ pub trait StreamingBackend: Send + Sync {
         group_name: &'a str,
         ids: &'a [String],
     ) -> BoxFuture<'a, CacheResult<()>>;
+
+    /// Claim entries left pending by other consumers of a group
+    ///
+    /// Entries idle for at least `min_idle_ms`, scanning from `start_id`, are
+    /// transferred to `consumer_name` and returned (at most `count`).
+    ///
+    /// Default implementation returns an error for backends without support.
+    fn stream_autoclaim<'a>(
+        &'a self,
+        _group_name: &'a str,
+        _consumer_name: &'a str,
+        _stream_key: &'a str,
+        _min_idle_ms: u64,
+        _start_id: &'a str,
+        _count: usize,
+    ) -> BoxFuture<'a, CacheResult<Vec<StreamEntry>>> {
+        Box::pin(async {
+            Err(CacheError::BackendError(
+                "stream_autoclaim is not supported by this backend".to_string(),
+            ))
+        })
+    }
+    /// Number of entries delivered to a consumer group but not yet acknowledged
+    ///
+    /// Default implementation returns an error for backends without support.
+    fn stream_pending_count<'a>(
+        &'a self,
+        _group_name: &'a str,
+        _stream_key: &'a str,
+    ) -> BoxFuture<'a, CacheResult<usize>> {
+        Box::pin(async {
+            Err(CacheError::BackendError(
+                "stream_pending_count is not supported by this backend".to_string(),
+            ))
+        })
+    }
+
+    /// Delete specific entries from a stream by ID
+    ///
+    /// Returns the number of entries deleted; IDs that don't exist are skipped.
+    ///
+    /// Default implementation returns an error for backends without support.
+    fn stream_delete<'a>(
+        &'a self,
+        _stream_key: &'a str,
+        _ids: &'a [&'a str],
+    ) -> BoxFuture<'a, CacheResult<usize>> {
+        Box::pin(async {
+            Err(CacheError::BackendError(
+                "stream_delete is not supported by this backend".to_string(),
+            ))
+        })
+    }
 }
@@ -0,0 +1,153 @@
+//! Multi-key transactions
+//!
+//! A `Transaction` groups writes to several keys that must be applied
+//! all-or-nothing, optionally guarded by preconditions on the current values
+//! (compare-and-set). Only backends with server-side atomicity can run one:
+//! Redis executes it with `MULTI`/`EXEC`, or a Lua script when it has
+//! preconditions. Other backends return an error from
+//! `L2CacheBackend::execute_transaction`.
+
+use bytes::Bytes;
+use std::time::Duration;
+
+/// A write applied by a `Transaction`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionWrite {
+    /// Store `value` under `key` for `ttl`
+    Set {
+        /// Key to write
+        key: String,
+        /// Value to store
+        value: Bytes,
+        /// Time to live
+        ttl: Duration,
+    },
+    /// Delete `key`
+    Remove {
+        /// Key to delete
+        key: String,
+    },
+}
+
+impl TransactionWrite {
+    /// Key this write affects
+    #[must_use]
+    pub fn key(&self) -> &str {
+        match self {
+            Self::Set { key, .. } | Self::Remove { key } => key,
+        }
+    }
+}
+
+/// Builder for an atomic group of writes
+///
+/// Preconditions added with `expect` are checked before any write; if one
+/// doesn't hold, nothing is written and the transaction reports `false`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use multi_tier_cache::{Bytes, RedisCache, Transaction};
+/// use std::time::Duration;
+///
+/// # async fn example(redis: &RedisCache) -> anyhow::Result<()> {
+/// let ttl = Duration::from_secs(300);
+/// let tx = Transaction::new()
+///     .expect("balance:a", Some(Bytes::from("10")))
+///     .expect("balance:b", Some(Bytes::from("5")))
+///     .set("balance:a", Bytes::from("7"), ttl)
+///     .set("balance:b", Bytes::from("8"), ttl);
+/// if !redis.transaction(&tx).await? {
+///     // A balance changed concurrently: nothing was written
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Transaction {
+    /// Expected current values (`None` = key must not exist)
+    checks: Vec<(String, Option<Bytes>)>,
+    /// Writes, in order
+    writes: Vec<TransactionWrite>,
+}
+
+impl Transaction {
+    /// Empty transaction
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only apply the writes if `key` currently holds `value` (`None`: is absent)
+    #[must_use]
+    pub fn expect(mut self, key: impl Into<String>, value: Option<Bytes>) -> Self {
+        self.checks.push((key.into(), value));
+        self
+    }
+
+    /// Store `value` under `key` for `ttl`
+    #[must_use]
+    pub fn set(mut self, key: impl Into<String>, value: Bytes, ttl: Duration) -> Self {
+        self.writes.push(TransactionWrite::Set {
+            key: key.into(),
+            value,
+            ttl,
+        });
+        self
+    }
+
+    /// Delete `key`
+    #[must_use]
+    pub fn remove(mut self, key: impl Into<String>) -> Self {
+        self.writes
+            .push(TransactionWrite::Remove { key: key.into() });
+        self
+    }
+
+    /// Preconditions, in the order they were added
+    #[must_use]
+    pub fn checks(&self) -> &[(String, Option<Bytes>)] {
+        &self.checks
+    }
+
+    /// Writes, in the order they were added
+    #[must_use]
+    pub fn writes(&self) -> &[TransactionWrite] {
+        &self.writes
+    }
+
+    /// Whether the transaction has no writes
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.writes.is_empty()
+    }
+
+    /// Rewrite every key with `key_fn` and every TTL with `ttl_fn`
+    pub(crate) fn map(
+        self,
+        key_fn: impl Fn(&str) -> String,
+        ttl_fn: impl Fn(Duration) -> Duration,
+    ) -> Self {
+        Self {
+            checks: self
+                .checks
+                .into_iter()
+                .map(|(key, value)| (key_fn(&key), value))
+                .collect(),
+            writes: self
+                .writes
+                .into_iter()
+                .map(|write| match write {
+                    TransactionWrite::Set { key, value, ttl } => TransactionWrite::Set {
+                        key: key_fn(&key),
+                        value,
+                        ttl: ttl_fn(ttl),
+                    },
+                    TransactionWrite::Remove { key } => {
+                        TransactionWrite::Remove { key: key_fn(&key) }
+                    }
+                })
+                .collect(),
+        }
+    }
+}
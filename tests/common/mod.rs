@@ -9,13 +9,16 @@
 
 use anyhow::Result;
 use multi_tier_cache::backends::MokaCacheConfig;
+use multi_tier_cache::serialization::ByteTransform;
 use multi_tier_cache::{
-    CacheManager, CacheSystem, CacheSystemBuilder, InvalidationConfig, L1Cache, L2Cache,
-    L2CacheBackend, TierConfig,
+    CacheError, CacheManager, CacheResult, CacheSystem, CacheSystemBuilder, CacheTier,
+    DashMapCache, InvalidationConfig, L1Cache, L2Cache, L2CacheBackend, TierConfig,
 };
 use std::sync::Arc;
 use std::sync::Once;
 
+pub mod run_length;
+
 static INIT: Once = Once::new();
 
 /// Initialize tracing for tests
@@ -32,6 +35,11 @@ pub fn redis_url() -> String {
     std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string())
 }
 
+/// Get Redis read replica URL from environment, falling back to the primary URL
+pub fn redis_replica_url() -> String {
+    std::env::var("REDIS_REPLICA_URL").unwrap_or_else(|_| redis_url())
+}
+
 /// Generate a unique test key prefix to avoid conflicts between tests
 pub fn test_key_prefix() -> String {
     use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -94,6 +102,43 @@ pub async fn setup_cache_with_invalidation() -> Result<Arc<CacheManager>> {
     Ok(Arc::new(manager))
 }
 
+/// In-memory L1 + L2 cache for tests that don't need a Redis server
+pub struct InMemoryCache {
+    pub manager: Arc<CacheManager>,
+    pub l1: Arc<DashMapCache>,
+    pub l2: Arc<DashMapCache>,
+}
+
+/// Initialize a 2-tier cache manager backed by two `DashMap` tiers
+///
+/// L2 promotes to L1 on every hit (N=1) so promotion behavior is deterministic.
+pub fn setup_in_memory_cache() -> Result<InMemoryCache> {
+    let l1 = Arc::new(DashMapCache::new());
+    let l2 = Arc::new(DashMapCache::new());
+    let tiers = vec![
+        CacheTier::new(Arc::clone(&l1) as Arc<dyn L2CacheBackend>, 1, false, 1, 1.0),
+        CacheTier::new(Arc::clone(&l2) as Arc<dyn L2CacheBackend>, 2, true, 1, 1.0),
+    ];
+    let manager = CacheManager::new_with_tiers(tiers, None)?;
+
+    Ok(InMemoryCache {
+        manager: Arc::new(manager),
+        l1,
+        l2,
+    })
+}
+
+/// Initialize a 2-tier cache manager with its own `DashMap` L1 over a shared L2
+///
+/// Use this to simulate several service instances sharing one distributed tier.
+pub fn in_memory_manager_with_l2(l2: Arc<DashMapCache>) -> Result<CacheManager> {
+    let tiers = vec![
+        CacheTier::new(Arc::new(DashMapCache::new()), 1, false, 1, 1.0),
+        CacheTier::new(l2, 2, true, 1, 1.0),
+    ];
+    Ok(CacheManager::new_with_tiers(tiers, None)?)
+}
+
 /// Cleanup test keys from Redis
 pub async fn cleanup_test_keys(prefix: &str) -> Result<()> {
     let _cache = setup_cache_system().await?;
@@ -0,0 +1,40 @@
+// Run-length `ByteTransform` shared by the integration tests and the crate's
+// unit tests. The crate `include!`s this file, so it takes the library types
+// from the enclosing module instead of naming the crate, and uses no inner
+// attributes or doc comments.
+
+use super::{ByteTransform, CacheError, CacheResult};
+
+/// Run-length encoding as (count, byte) pairs: a stand-in compressor
+pub struct RunLength;
+
+impl ByteTransform for RunLength {
+    fn name(&self) -> &'static str {
+        "RunLength"
+    }
+
+    fn forward(&self, bytes: &[u8]) -> CacheResult<Vec<u8>> {
+        let mut encoded = Vec::new();
+        for run in bytes.chunk_by(|a, b| a == b) {
+            for part in run.chunks(usize::from(u8::MAX)) {
+                if let Some(&byte) = part.first() {
+                    encoded.extend([u8::try_from(part.len()).unwrap_or(u8::MAX), byte]);
+                }
+            }
+        }
+        Ok(encoded)
+    }
+
+    fn backward(&self, bytes: &[u8]) -> CacheResult<Vec<u8>> {
+        let pairs = bytes.chunks_exact(2);
+        if !pairs.remainder().is_empty() {
+            return Err(CacheError::SerializationError("Truncated run".to_string()));
+        }
+        Ok(pairs
+            .flat_map(|pair| match pair {
+                [count, byte] => vec![*byte; usize::from(*count)],
+                _ => Vec::new(),
+            })
+            .collect())
+    }
+}
@@ -100,7 +100,7 @@ async fn test_l2_to_l1_promotion() {
         .l2_cache
         .as_ref()
         .unwrap_or_else(|| panic!("L2 cache missing"))
-        .set_with_ttl(&key, value.clone(), Duration::from_secs(300))
+        .set_with_ttl(&key, value.clone(), Duration::from_mins(5))
         .await
         .unwrap_or_else(|_| panic!("Failed to set L2"));
 
@@ -339,6 +339,38 @@ async fn test_health_check() {
     assert!(healthy, "Cache system should be healthy");
 }
 
+/// Test Redis server info exposure through the cache manager
+#[tokio::test]
+async fn test_l2_server_info() {
+    let cache = setup_cache_system()
+        .await
+        .unwrap_or_else(|_| panic!("Failed to setup cache"));
+
+    let info = cache
+        .cache_manager()
+        .l2_server_info()
+        .await
+        .unwrap_or_else(|_| panic!("Failed to get server info"))
+        .unwrap_or_else(|| panic!("Redis L2 should expose server info"));
+    assert!(
+        info.contains_key("used_memory"),
+        "Missing INFO memory fields"
+    );
+    assert!(
+        info.contains_key("keyspace_hits"),
+        "Missing INFO stats fields"
+    );
+
+    // Backends without server stats report None
+    let in_memory = setup_in_memory_cache().unwrap_or_else(|_| panic!("Failed to setup cache"));
+    let info = in_memory
+        .manager
+        .l2_server_info()
+        .await
+        .unwrap_or_else(|_| panic!("Failed to get server info"));
+    assert!(info.is_none());
+}
+
 /// Test different cache strategies
 #[tokio::test]
 async fn test_cache_strategies() {
@@ -351,7 +383,7 @@ async fn test_cache_strategies() {
         ("short", CacheStrategy::ShortTerm),
         ("medium", CacheStrategy::MediumTerm),
         ("long", CacheStrategy::LongTerm),
-        ("custom", CacheStrategy::Custom(Duration::from_secs(60))),
+        ("custom", CacheStrategy::Custom(Duration::from_mins(1))),
     ];
 
     for (name, strategy) in strategies {
@@ -430,3 +462,517 @@ async fn test_negative_caching() {
         .remove(&key)
         .await;
 }
+
+/// Test writing to a primary while reading from a replica
+#[tokio::test]
+async fn test_read_write_split() {
+    use multi_tier_cache::{L2CacheBackend, RedisCache};
+
+    let primary = redis_url();
+    let replica = redis_replica_url();
+    let cache = RedisCache::with_read_write_urls(&primary, &[replica.as_str()])
+        .await
+        .unwrap_or_else(|_| panic!("Failed to connect to primary and replica"));
+    let key = test_key("replica");
+    let value = test_data::bytes_user(1);
+
+    cache
+        .set_with_ttl(&key, value.clone(), Duration::from_mins(1))
+        .await
+        .unwrap_or_else(|_| panic!("Failed to set on primary"));
+
+    // Replication is asynchronous, so allow the replica to catch up
+    let mut replicated = None;
+    for _ in 0..50 {
+        replicated = cache.get_with_ttl(&key).await;
+        if replicated.is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    let (cached, ttl) = replicated.unwrap_or_else(|| panic!("Key never reached the replica"));
+    assert_eq!(cached, value);
+    assert!(ttl.is_some());
+
+    let keys = cache
+        .scan_keys(&key)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to scan replica"));
+    assert_eq!(keys, vec![key.clone()]);
+
+    cache
+        .remove(&key)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to remove on primary"));
+    let mut removed = false;
+    for _ in 0..50 {
+        if cache.get(&key).await.is_none() {
+            removed = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(removed, "Removal never reached the replica");
+}
+
+/// Test field-level updates and full-object reads in Redis hash mode
+#[tokio::test]
+async fn test_redis_hash_field_update() {
+    use multi_tier_cache::{L2CacheBackend, RedisHashCache};
+
+    let cache = RedisHashCache::with_url(&redis_url())
+        .await
+        .unwrap_or_else(|_| panic!("Failed to connect Redis hash cache"));
+    let key = test_key("hash");
+    let user = test_data::json_user(1);
+
+    cache
+        .set_with_ttl(
+            &key,
+            bytes::Bytes::from(user.to_string()),
+            Duration::from_mins(1),
+        )
+        .await
+        .unwrap_or_else(|_| panic!("Failed to set hash"));
+
+    let name: Option<String> = cache
+        .get_field(&key, "name")
+        .await
+        .unwrap_or_else(|_| panic!("Failed to get field"));
+    assert_eq!(name.as_deref(), Some("User 1"));
+
+    let updated = cache
+        .update_field(&key, "last_seen", &1_700_000_000_u64)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to update field"));
+    assert!(updated);
+
+    // The full object reflects the update and keeps its TTL
+    let (bytes, ttl) = cache
+        .get_with_ttl(&key)
+        .await
+        .unwrap_or_else(|| panic!("Hash missing after update"));
+    let object: serde_json::Value =
+        serde_json::from_slice(&bytes).unwrap_or_else(|_| panic!("Object is not JSON"));
+    assert_eq!(
+        object.get("last_seen"),
+        Some(&serde_json::json!(1_700_000_000_u64))
+    );
+    assert_eq!(object.get("id"), user.get("id"));
+    assert!(ttl.is_some());
+
+    // Updating a missing object doesn't create a partial one
+    let missing = test_key("hash_missing");
+    let updated = cache
+        .update_field(&missing, "last_seen", &1_u64)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to update missing field"));
+    assert!(!updated);
+    assert!(cache.get(&missing).await.is_none());
+
+    cache
+        .remove(&key)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to remove hash"));
+}
+
+/// Test `WAIT`-based durable writes (a standalone server has no replicas)
+#[tokio::test]
+async fn test_set_durable() {
+    let cache = setup_cache_system()
+        .await
+        .unwrap_or_else(|_| panic!("Failed to setup cache"));
+    let manager = cache.cache_manager();
+    let key = test_key("durable");
+
+    manager
+        .set_durable(
+            &key,
+            test_data::bytes_user(1),
+            CacheStrategy::ShortTerm,
+            0,
+            Duration::from_millis(100),
+        )
+        .await
+        .unwrap_or_else(|_| panic!("Durable write with 0 replicas should succeed"));
+    assert!(manager.get(&key).await.ok().flatten().is_some());
+
+    // No replica can acknowledge, so this times out with an error
+    let result = manager
+        .set_durable(
+            &key,
+            test_data::bytes_user(2),
+            CacheStrategy::ShortTerm,
+            1,
+            Duration::from_millis(100),
+        )
+        .await;
+    assert!(result.is_err());
+
+    let _ = manager.invalidate(&key).await;
+}
+
+/// Test `SET XX` replaces existing keys and never creates missing ones
+#[tokio::test]
+async fn test_set_if_present() {
+    let cache = setup_cache_system()
+        .await
+        .unwrap_or_else(|_| panic!("Failed to setup cache"));
+    let manager = cache.cache_manager();
+    let present = test_key("xx_present");
+    let absent = test_key("xx_absent");
+
+    manager
+        .set_with_strategy(&present, test_data::bytes_user(1), CacheStrategy::ShortTerm)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to set value"));
+
+    let replaced = manager
+        .set_if_present(&present, &test_data::json_user(2), Duration::from_mins(5))
+        .await
+        .unwrap_or_else(|_| panic!("set_if_present failed"));
+    assert!(replaced);
+    let value = manager.get(&present).await.ok().flatten();
+    assert_eq!(value, Some(test_data::bytes_user(2)));
+
+    let replaced = manager
+        .set_if_present(&absent, &test_data::json_user(3), Duration::from_mins(5))
+        .await
+        .unwrap_or_else(|_| panic!("set_if_present failed"));
+    assert!(!replaced);
+    assert!(manager.get(&absent).await.ok().flatten().is_none());
+
+    let _ = manager.invalidate(&present).await;
+}
+
+/// Test a rotating AUTH token is fetched again when the connection is re-established
+#[tokio::test]
+async fn test_auth_token_provider_reauthenticates() {
+    use multi_tier_cache::RedisCache;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let client =
+        redis::Client::open(redis_url()).unwrap_or_else(|_| panic!("Failed to create client"));
+    let mut admin = redis::aio::ConnectionManager::new(client)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to connect to Redis"));
+
+    // ACL user accepting both tokens the provider alternates between
+    let user = format!("mtc-token-{}", rand::random::<u32>());
+    let _: () = redis::cmd("ACL")
+        .arg(&[
+            "SETUSER", &user, "on", ">token-a", ">token-b", "~*", "+@all",
+        ])
+        .query_async(&mut admin)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to create ACL user"));
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let url = redis_url().replacen("redis://", &format!("redis://{user}@"), 1);
+    let cache = RedisCache::with_auth_token_provider(&url, move || {
+        if counter.fetch_add(1, Ordering::SeqCst).is_multiple_of(2) {
+            "token-a".to_string()
+        } else {
+            "token-b".to_string()
+        }
+    })
+    .await
+    .unwrap_or_else(|e| panic!("Failed to connect with auth token: {e}"));
+
+    let key = test_key("auth_token");
+    cache
+        .set_with_ttl(&key, test_data::bytes_user(1), Duration::from_mins(1))
+        .await
+        .unwrap_or_else(|_| panic!("Failed to set value"));
+    let connected_calls = calls.load(Ordering::SeqCst);
+    assert!(connected_calls >= 1);
+
+    // Drop the user's connections; the next commands must reconnect and re-auth
+    let _: i64 = redis::cmd("CLIENT")
+        .arg(&["KILL", "USER", &user])
+        .query_async(&mut admin)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to kill client"));
+
+    let mut value = None;
+    for _ in 0..20 {
+        value = cache.get(&key).await;
+        if value.is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert_eq!(value, Some(test_data::bytes_user(1)));
+    assert!(calls.load(Ordering::SeqCst) > connected_calls);
+
+    let _ = cache.remove(&key).await;
+    let _: i64 = redis::cmd("ACL")
+        .arg(&["DELUSER", &user])
+        .query_async(&mut admin)
+        .await
+        .unwrap_or(0);
+}
+
+/// Test the configured backoff: connecting to a closed port retries, then gives up
+#[tokio::test]
+async fn test_reconnect_config_backoff() {
+    use multi_tier_cache::{RedisCache, RedisReconnectConfig};
+    use std::time::Instant;
+
+    let reconnect = RedisReconnectConfig {
+        max_retries: 3,
+        min_delay: Duration::from_millis(50),
+        backoff_factor: 2.0,
+        max_delay: Some(Duration::from_millis(100)),
+    };
+    let started = Instant::now();
+    let result = RedisCache::with_reconnect_config("redis://127.0.0.1:1", reconnect).await;
+    let elapsed = started.elapsed();
+
+    assert!(result.is_err());
+    // Three delays of at least 50, 100 and 100 ms (capped), each with jitter
+    // of up to the delay again
+    assert!(
+        elapsed >= Duration::from_millis(250),
+        "gave up after {elapsed:?}"
+    );
+    assert!(
+        elapsed < Duration::from_secs(2),
+        "gave up after {elapsed:?}"
+    );
+}
+
+/// Test commands recover after the connection is dropped
+#[tokio::test]
+async fn test_reconnect_config_recovers_from_connection_loss() {
+    use multi_tier_cache::{RedisCache, RedisReconnectConfig};
+
+    let client =
+        redis::Client::open(redis_url()).unwrap_or_else(|_| panic!("Failed to create client"));
+    let mut admin = redis::aio::ConnectionManager::new(client)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to connect to Redis"));
+
+    let cache = RedisCache::with_reconnect_config(
+        &redis_url(),
+        RedisReconnectConfig {
+            max_retries: 5,
+            min_delay: Duration::from_millis(20),
+            max_delay: Some(Duration::from_millis(200)),
+            ..RedisReconnectConfig::default()
+        },
+    )
+    .await
+    .unwrap_or_else(|e| panic!("Failed to connect: {e}"));
+    let key = test_key("reconnect");
+    cache
+        .set_with_ttl(&key, test_data::bytes_user(1), Duration::from_mins(1))
+        .await
+        .unwrap_or_else(|_| panic!("Failed to set value"));
+
+    // Drop every normal client connection except the admin one
+    let _: i64 = redis::cmd("CLIENT")
+        .arg(&["KILL", "TYPE", "normal", "SKIPME", "yes"])
+        .query_async(&mut admin)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to kill clients"));
+
+    let mut value = None;
+    for _ in 0..20 {
+        value = cache.get(&key).await;
+        if value.is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert_eq!(value, Some(test_data::bytes_user(1)));
+
+    let _ = cache.remove(&key).await;
+}
+
+/// Test that a value in another format is treated as a miss and evicted
+#[tokio::test]
+async fn test_undecodable_l2_value_is_evicted() {
+    use multi_tier_cache::{CacheSystemBuilder, UndecodablePolicy};
+
+    let cache = CacheSystemBuilder::new()
+        .with_undecodable_policy(UndecodablePolicy::Evict)
+        .build()
+        .await
+        .unwrap_or_else(|_| panic!("Failed to setup cache"));
+    let manager = cache.cache_manager();
+    let l2 = cache
+        .l2_cache
+        .as_ref()
+        .unwrap_or_else(|| panic!("L2 cache missing"));
+    let key = test_key("undecodable");
+
+    // Written by another service in a format we can't read
+    l2.set_with_ttl(
+        &key,
+        bytes::Bytes::from_static(b"\x00\x01not json"),
+        Duration::from_mins(1),
+    )
+    .await
+    .unwrap_or_else(|_| panic!("Failed to write raw value"));
+
+    let user = manager
+        .get_typed::<test_data::User>(&key)
+        .await
+        .unwrap_or_else(|e| panic!("Undecodable value should be a miss: {e}"));
+    assert_eq!(user, None);
+    assert_eq!(l2.get(&key).await, None, "Offending key should be evicted");
+
+    // The next typed lookup recomputes and stores a readable value
+    let user: test_data::User = manager
+        .get_or_compute_typed(&key, CacheStrategy::ShortTerm, || async {
+            Ok(test_data::User::new(9))
+        })
+        .await
+        .unwrap_or_else(|_| panic!("Failed to recompute"));
+    assert_eq!(user, test_data::User::new(9));
+
+    let _ = l2.remove(&key).await;
+}
+
+/// Test batched reads of values and TTLs from Redis
+#[tokio::test]
+async fn test_redis_get_many_with_ttl() {
+    use multi_tier_cache::{L2CacheBackend, RedisCache};
+
+    let redis = RedisCache::with_url(&redis_url())
+        .await
+        .unwrap_or_else(|_| panic!("Failed to connect to Redis"));
+    let keys = vec![test_key("batch_a"), test_key("batch_missing")];
+    let present = keys.first().unwrap_or_else(|| panic!("Key missing"));
+    redis
+        .set_with_ttl(present, test_data::bytes_user(1), Duration::from_mins(1))
+        .await
+        .unwrap_or_else(|_| panic!("Failed to set"));
+
+    let results = redis.get_many_with_ttl(&keys).await;
+    assert_eq!(results.len(), 2);
+    let (value, ttl) = results
+        .first()
+        .cloned()
+        .flatten()
+        .unwrap_or_else(|| panic!("Value missing"));
+    assert_eq!(value, test_data::bytes_user(1));
+    assert!(ttl.is_some_and(|ttl| ttl <= Duration::from_mins(1)));
+    assert_eq!(results.get(1), Some(&None));
+
+    let _ = redis.remove(present).await;
+}
+
+/// Test an atomic two-key update, and that a failed precondition writes nothing
+#[tokio::test]
+async fn test_transaction_all_or_nothing() {
+    use multi_tier_cache::{Bytes, Transaction};
+
+    let cache = setup_cache_system()
+        .await
+        .unwrap_or_else(|_| panic!("Failed to setup cache"));
+    let manager = cache.cache_manager();
+    let key_a = test_key("tx_a");
+    let key_b = test_key("tx_b");
+    let ttl = Duration::from_mins(1);
+
+    manager
+        .set_with_strategy(&key_a, Bytes::from("10"), CacheStrategy::ShortTerm)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to set value"));
+    manager
+        .set_with_strategy(&key_b, Bytes::from("5"), CacheStrategy::ShortTerm)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to set value"));
+
+    // Move 3 from a to b
+    let applied = manager
+        .execute_transaction(
+            Transaction::new()
+                .expect(&key_a, Some(Bytes::from("10")))
+                .expect(&key_b, Some(Bytes::from("5")))
+                .set(&key_a, Bytes::from("7"), ttl)
+                .set(&key_b, Bytes::from("8"), ttl),
+        )
+        .await
+        .unwrap_or_else(|e| panic!("Transaction failed: {e}"));
+    assert!(applied);
+    // L1 held the old values; they must not be served after the transaction
+    assert_eq!(
+        manager.get(&key_a).await.ok().flatten(),
+        Some(Bytes::from("7"))
+    );
+    assert_eq!(
+        manager.get(&key_b).await.ok().flatten(),
+        Some(Bytes::from("8"))
+    );
+
+    // Simulated conflict: a stale precondition aborts the whole transaction
+    let applied = manager
+        .execute_transaction(
+            Transaction::new()
+                .expect(&key_a, Some(Bytes::from("10")))
+                .set(&key_a, Bytes::from("0"), ttl)
+                .remove(&key_b),
+        )
+        .await
+        .unwrap_or_else(|e| panic!("Transaction failed: {e}"));
+    assert!(!applied);
+    assert_eq!(
+        manager.get(&key_a).await.ok().flatten(),
+        Some(Bytes::from("7"))
+    );
+    assert_eq!(
+        manager.get(&key_b).await.ok().flatten(),
+        Some(Bytes::from("8"))
+    );
+
+    // Without preconditions the writes go through MULTI/EXEC
+    let applied = manager
+        .execute_transaction(Transaction::new().remove(&key_a).remove(&key_b))
+        .await
+        .unwrap_or_else(|e| panic!("Transaction failed: {e}"));
+    assert!(applied);
+    assert_eq!(manager.get(&key_a).await.ok().flatten(), None);
+    assert_eq!(manager.get(&key_b).await.ok().flatten(), None);
+}
+
+/// Test swapping two cached values
+#[tokio::test]
+async fn test_swap() {
+    use multi_tier_cache::Bytes;
+
+    let cache = setup_cache_system()
+        .await
+        .unwrap_or_else(|_| panic!("Failed to setup cache"));
+    let manager = cache.cache_manager();
+    let key_a = test_key("swap_a");
+    let key_b = test_key("swap_b");
+
+    manager
+        .set_with_strategy(&key_a, Bytes::from("a"), CacheStrategy::ShortTerm)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to set value"));
+    manager
+        .set_with_strategy(&key_b, Bytes::from("b"), CacheStrategy::ShortTerm)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to set value"));
+
+    assert!(
+        manager
+            .swap(&key_a, &key_b)
+            .await
+            .unwrap_or_else(|e| panic!("Swap failed: {e}"))
+    );
+    assert_eq!(
+        manager.get(&key_a).await.ok().flatten(),
+        Some(Bytes::from("b"))
+    );
+    assert_eq!(
+        manager.get(&key_b).await.ok().flatten(),
+        Some(Bytes::from("a"))
+    );
+}
@@ -1,6 +1,9 @@
 use bytes::Bytes;
+use common::run_length::RunLength;
+use common::test_data::User;
 use multi_tier_cache::error::CacheError;
-use multi_tier_cache::{CacheStrategy, CacheSystem};
+use multi_tier_cache::{CacheBackend, CacheStrategy, CacheSystem, DashMapCache};
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::sleep;
 
@@ -92,3 +95,3870 @@ async fn test_get_or_compute_error_propagation() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_get_or_compute_or_default_serves_fallback() -> anyhow::Result<()> {
+    let manager = common::in_memory_manager_with_l2(Arc::new(DashMapCache::new()))?;
+
+    let posts: Vec<String> = manager
+        .get_or_compute_or_default(
+            "posts:latest",
+            CacheStrategy::ShortTerm,
+            || async { Err(CacheError::InternalError("Database failure".to_string())) },
+            Vec::new(),
+        )
+        .await;
+    assert!(posts.is_empty());
+
+    // The fallback is not cached: the next call computes again
+    assert!(manager.get("posts:latest").await?.is_none());
+    let posts: Vec<String> = manager
+        .get_or_compute_or_default(
+            "posts:latest",
+            CacheStrategy::ShortTerm,
+            || async { Ok(vec!["hello".to_string()]) },
+            Vec::new(),
+        )
+        .await;
+    assert_eq!(posts, vec!["hello".to_string()]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_or_compute_or_default_computes_after_read_error() -> anyhow::Result<()> {
+    let manager = common::in_memory_manager_with_l2(Arc::new(DashMapCache::new()))?;
+    // Not a `Vec<String>`: the typed read fails
+    manager
+        .set_with_strategy(
+            "posts:broken",
+            Bytes::from("oops"),
+            CacheStrategy::ShortTerm,
+        )
+        .await?;
+    assert!(
+        manager
+            .get_typed::<Vec<String>>("posts:broken")
+            .await
+            .is_err()
+    );
+
+    let posts: Vec<String> = manager
+        .get_or_compute_or_default(
+            "posts:broken",
+            CacheStrategy::ShortTerm,
+            || async { Ok(vec!["hello".to_string()]) },
+            Vec::new(),
+        )
+        .await;
+    assert_eq!(posts, vec!["hello".to_string()]);
+    // The computed value replaced the broken one
+    assert_eq!(
+        manager.get_typed::<Vec<String>>("posts:broken").await?,
+        Some(vec!["hello".to_string()])
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_codec_guard_rejects_untagged_value() -> anyhow::Result<()> {
+    let shared_l2 = Arc::new(DashMapCache::new());
+    let unguarded = common::in_memory_manager_with_l2(Arc::clone(&shared_l2))?;
+    let guarded = common::in_memory_manager_with_l2(shared_l2)?.with_codec_guard(true);
+
+    // Written without the guard: no codec tag
+    let user: User = unguarded
+        .get_or_compute_typed("codec:untagged", CacheStrategy::ShortTerm, || async {
+            Ok(User::new(1))
+        })
+        .await?;
+    assert_eq!(user, User::new(1));
+
+    // Guarded reader can't verify the codec, so it's a clean miss
+    assert_eq!(guarded.get_typed::<User>("codec:untagged").await?, None);
+
+    // Guarded writes round-trip
+    let user: User = guarded
+        .get_or_compute_typed("codec:tagged", CacheStrategy::ShortTerm, || async {
+            Ok(User::new(2))
+        })
+        .await?;
+    assert_eq!(user, User::new(2));
+    assert_eq!(
+        guarded.get_typed::<User>("codec:tagged").await?,
+        Some(User::new(2))
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "msgpack")]
+#[tokio::test]
+async fn test_codec_guard_mismatch_is_clean_miss() -> anyhow::Result<()> {
+    use multi_tier_cache::CacheSerializer;
+    use multi_tier_cache::serialization::MsgPackSerializer;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    let shared_l2 = Arc::new(DashMapCache::new());
+    let mut writer = common::in_memory_manager_with_l2(Arc::clone(&shared_l2))?;
+    writer.set_serializer(CacheSerializer::MsgPack(MsgPackSerializer));
+    let writer = writer.with_codec_guard(true);
+    let reader = common::in_memory_manager_with_l2(shared_l2)?.with_codec_guard(true);
+
+    let _: User = writer
+        .get_or_compute_typed("codec:shared", CacheStrategy::ShortTerm, || async {
+            Ok(User::new(1))
+        })
+        .await?;
+
+    // JSON reader sees a MessagePack value: miss, not a deserialization error
+    assert_eq!(reader.get_typed::<User>("codec:shared").await?, None);
+
+    // get_or_compute treats the foreign value as a miss and recomputes
+    let compute_count = AtomicU32::new(0);
+    let user: User = reader
+        .get_or_compute_typed("codec:shared", CacheStrategy::ShortTerm, || async {
+            compute_count.fetch_add(1, Ordering::SeqCst);
+            Ok(User::new(1))
+        })
+        .await?;
+    assert_eq!(user, User::new(1));
+    assert_eq!(compute_count.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+#[cfg(feature = "msgpack")]
+#[tokio::test]
+async fn test_fallback_codecs_read_old_values() -> anyhow::Result<()> {
+    use multi_tier_cache::serialization::MsgPackSerializer;
+    use multi_tier_cache::{CacheSerializer, JsonSerializer};
+
+    let shared_l2 = Arc::new(DashMapCache::new());
+    let old = common::in_memory_manager_with_l2(Arc::clone(&shared_l2))?;
+    let mut migrated = common::in_memory_manager_with_l2(shared_l2)?;
+    migrated.set_serializer(CacheSerializer::MsgPack(MsgPackSerializer));
+    let migrated = migrated.with_fallback_codecs(vec![CacheSerializer::Json(JsonSerializer)]);
+
+    let _: User = old
+        .get_or_compute_typed("codec:migrating", CacheStrategy::ShortTerm, || async {
+            Ok(User::new(1))
+        })
+        .await?;
+
+    // The MessagePack manager reads the JSON value through its fallback
+    assert_eq!(
+        migrated.get_typed::<User>("codec:migrating").await?,
+        Some(User::new(1))
+    );
+    let user: User = migrated
+        .get_or_compute_typed("codec:migrating", CacheStrategy::ShortTerm, || async {
+            Err(CacheError::InternalError(
+                "should be a cache hit".to_string(),
+            ))
+        })
+        .await?;
+    assert_eq!(user, User::new(1));
+
+    // Writes still use the primary codec
+    let _: User = migrated
+        .get_or_compute_typed("codec:new", CacheStrategy::ShortTerm, || async {
+            Ok(User::new(2))
+        })
+        .await?;
+    let stored = old
+        .get("codec:new")
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("missing value"))?;
+    assert!(serde_json::from_slice::<User>(&stored).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_take_is_consumed_once() -> anyhow::Result<()> {
+    let cache = common::setup_in_memory_cache()?;
+    let payload = serde_json::to_vec(&"one-time-token")?;
+    cache
+        .manager
+        .set_with_strategy("token:abc", Bytes::from(payload), CacheStrategy::ShortTerm)
+        .await?;
+
+    let mut tasks = tokio::task::JoinSet::new();
+    for _ in 0..50 {
+        let manager = Arc::clone(&cache.manager);
+        tasks.spawn(async move { manager.take::<String>("token:abc").await });
+    }
+
+    let mut winners = Vec::new();
+    while let Some(result) = tasks.join_next().await {
+        if let Some(value) = result?? {
+            winners.push(value);
+        }
+    }
+
+    assert_eq!(winners, vec!["one-time-token".to_string()]);
+    assert!(cache.manager.get("token:abc").await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_take_is_consumed_once_across_instances() -> anyhow::Result<()> {
+    let l2 = Arc::new(DashMapCache::new());
+    let first = common::in_memory_manager_with_l2(Arc::clone(&l2))?;
+    let second = common::in_memory_manager_with_l2(Arc::clone(&l2))?;
+    let payload = Bytes::from(serde_json::to_vec(&"shared-token")?);
+    first
+        .set_with_strategy("token:shared", payload, CacheStrategy::ShortTerm)
+        .await?;
+    // Both instances hold the value in their own L1
+    assert!(second.get("token:shared").await?.is_some());
+
+    let taken_first = first.take::<String>("token:shared").await?;
+    let taken_second = second.take::<String>("token:shared").await?;
+    assert_eq!(taken_first.as_deref(), Some("shared-token"));
+    assert_eq!(taken_second, None, "the L1 copy must not be served");
+    assert!(second.get("token:shared").await?.is_none());
+
+    Ok(())
+}
+
+/// L2 backend that records the maximum number of concurrent calls
+#[derive(Default)]
+struct ConcurrencyProbe {
+    current: std::sync::atomic::AtomicUsize,
+    max_seen: std::sync::atomic::AtomicUsize,
+}
+
+impl ConcurrencyProbe {
+    async fn track(&self) {
+        use std::sync::atomic::Ordering;
+
+        let now = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_seen.fetch_max(now, Ordering::SeqCst);
+        sleep(Duration::from_millis(20)).await;
+        self.current.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl CacheBackend for ConcurrencyProbe {
+    fn get<'a>(&'a self, _key: &'a str) -> futures_util::future::BoxFuture<'a, Option<Bytes>> {
+        Box::pin(async move {
+            self.track().await;
+            None
+        })
+    }
+
+    fn set_with_ttl<'a>(
+        &'a self,
+        _key: &'a str,
+        _value: Bytes,
+        _ttl: Duration,
+    ) -> futures_util::future::BoxFuture<'a, multi_tier_cache::CacheResult<()>> {
+        Box::pin(async move {
+            self.track().await;
+            Ok(())
+        })
+    }
+
+    fn remove<'a>(
+        &'a self,
+        _key: &'a str,
+    ) -> futures_util::future::BoxFuture<'a, multi_tier_cache::CacheResult<()>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn health_check(&self) -> futures_util::future::BoxFuture<'_, bool> {
+        Box::pin(async { true })
+    }
+
+    fn name(&self) -> &'static str {
+        "ConcurrencyProbe"
+    }
+}
+
+impl multi_tier_cache::L2CacheBackend for ConcurrencyProbe {
+    fn get_with_ttl<'a>(
+        &'a self,
+        _key: &'a str,
+    ) -> futures_util::future::BoxFuture<'a, Option<(Bytes, Option<Duration>)>> {
+        Box::pin(async move {
+            self.track().await;
+            None
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_l2_concurrency_limit() -> anyhow::Result<()> {
+    let probe = Arc::new(ConcurrencyProbe::default());
+    let manager = Arc::new(
+        multi_tier_cache::CacheManager::new_with_backends(
+            Arc::new(DashMapCache::new()),
+            Arc::clone(&probe) as Arc<dyn multi_tier_cache::L2CacheBackend>,
+            None,
+        )?
+        .with_l2_concurrency_limit(3),
+    );
+
+    let mut tasks = tokio::task::JoinSet::new();
+    for i in 0..20 {
+        let manager = Arc::clone(&manager);
+        tasks.spawn(async move {
+            let key = format!("limited:{i}");
+            manager.get(&key).await?;
+            manager
+                .set_with_strategy(&key, Bytes::from("value"), CacheStrategy::ShortTerm)
+                .await
+        });
+    }
+    while let Some(result) = tasks.join_next().await {
+        result??;
+    }
+
+    let max_seen = probe.max_seen.load(std::sync::atomic::Ordering::SeqCst);
+    assert!(
+        max_seen <= 3,
+        "Saw {max_seen} concurrent L2 calls, limit is 3"
+    );
+    assert!(
+        max_seen > 1,
+        "Calls should still run concurrently up to the limit"
+    );
+    assert_eq!(manager.get_stats().l2_permits_in_use, 0);
+
+    Ok(())
+}
+
+/// Repeating-key XOR: a stand-in cipher
+struct XorCipher;
+
+impl multi_tier_cache::serialization::ByteTransform for XorCipher {
+    fn name(&self) -> &'static str {
+        "XorCipher"
+    }
+
+    fn forward(&self, bytes: &[u8]) -> multi_tier_cache::CacheResult<Vec<u8>> {
+        let key = [0x5A_u8, 0xA5, 0x3C];
+        Ok(bytes
+            .iter()
+            .zip(key.iter().cycle())
+            .map(|(byte, key)| byte ^ key)
+            .collect())
+    }
+
+    fn backward(&self, bytes: &[u8]) -> multi_tier_cache::CacheResult<Vec<u8>> {
+        self.forward(bytes)
+    }
+}
+
+#[tokio::test]
+async fn test_codec_chain() -> anyhow::Result<()> {
+    use multi_tier_cache::serialization::CodecChain;
+    use multi_tier_cache::{CacheSerializer, JsonSerializer};
+
+    let json = || CacheSerializer::Json(JsonSerializer);
+    let compress_then_encrypt = CacheSerializer::Chain(
+        CodecChain::new(json())
+            .with_transform(RunLength)
+            .with_transform(XorCipher),
+    );
+    let encrypt_then_compress = CacheSerializer::Chain(
+        CodecChain::new(json())
+            .with_transform(XorCipher)
+            .with_transform(RunLength),
+    );
+
+    // Round trip through a manager
+    let value = "a".repeat(300);
+    let mut manager = common::in_memory_manager_with_l2(Arc::new(DashMapCache::new()))?;
+    manager.set_serializer(compress_then_encrypt.clone());
+    let _: String = manager
+        .get_or_compute_typed("chained", CacheStrategy::ShortTerm, || async {
+            Ok(value.clone())
+        })
+        .await?;
+    assert_eq!(
+        manager.get_typed::<String>("chained").await?,
+        Some(value.clone())
+    );
+
+    // Order matters: ciphertext has no runs left to compress
+    let good = compress_then_encrypt.serialize(&value)?;
+    let bad = encrypt_then_compress.serialize(&value)?;
+    assert_ne!(good, bad);
+    assert!(good.len() < json().serialize(&value)?.len());
+    assert!(bad.len() > good.len());
+    assert_eq!(encrypt_then_compress.deserialize::<String>(&bad)?, value);
+    assert!(encrypt_then_compress.deserialize::<String>(&good).is_err());
+
+    Ok(())
+}
+
+#[cfg(feature = "json-arbitrary-precision")]
+#[tokio::test]
+async fn test_json_arbitrary_precision_round_trip() -> anyhow::Result<()> {
+    use multi_tier_cache::{CacheSerializer, JsonSerializer};
+
+    // More significant digits than an f64 holds
+    let price = "1234567890.123456789012345678901234567890";
+    let value: serde_json::Value = serde_json::from_str(&format!("{{\"price\":{price}}}"))?;
+
+    let mut manager = common::in_memory_manager_with_l2(Arc::new(DashMapCache::new()))?;
+    manager.set_serializer(CacheSerializer::Json(JsonSerializer));
+    let _: serde_json::Value = manager
+        .get_or_compute_typed("decimal", CacheStrategy::ShortTerm, || async {
+            Ok(value.clone())
+        })
+        .await?;
+
+    let cached = manager
+        .get_typed::<serde_json::Value>("decimal")
+        .await?
+        .unwrap_or_else(|| panic!("Value missing"));
+    assert_eq!(cached, value);
+    assert_eq!(
+        cached.get("price").map(ToString::to_string).as_deref(),
+        Some(price)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_json_value_round_trip_across_codecs() -> anyhow::Result<()> {
+    use multi_tier_cache::{CacheSerializer, JsonSerializer};
+
+    let value = serde_json::json!({
+        "id": 7,
+        "delta": -3,
+        "big": u64::MAX,
+        "price": 9.5,
+        "name": "widget",
+        "tags": ["a", "b"],
+        "meta": { "active": true, "parent": null }
+    });
+
+    #[allow(unused_mut)]
+    let mut serializers = vec![CacheSerializer::Json(JsonSerializer)];
+    // arbitrary_precision stores `Value` numbers as strings in MsgPack
+    #[cfg(all(feature = "msgpack", not(feature = "json-arbitrary-precision")))]
+    serializers.push(CacheSerializer::MsgPack(
+        multi_tier_cache::serialization::MsgPackSerializer,
+    ));
+
+    for serializer in serializers {
+        let name = serializer.name();
+        let mut manager = common::in_memory_manager_with_l2(Arc::new(DashMapCache::new()))?;
+        manager.set_serializer(serializer);
+
+        let computed: serde_json::Value = manager
+            .get_or_compute_typed("schemaless", CacheStrategy::ShortTerm, || async {
+                Ok(value.clone())
+            })
+            .await?;
+        assert_eq!(computed, value, "{name} compute round-trip");
+
+        let cached = manager.get_typed::<serde_json::Value>("schemaless").await?;
+        assert_eq!(cached.as_ref(), Some(&value), "{name} cached round-trip");
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "bincode")]
+#[tokio::test]
+async fn test_bincode_rejects_json_value() -> anyhow::Result<()> {
+    use multi_tier_cache::CacheSerializer;
+    use multi_tier_cache::serialization::BincodeSerializer;
+
+    let mut manager = common::in_memory_manager_with_l2(Arc::new(DashMapCache::new()))?;
+    manager.set_serializer(CacheSerializer::Bincode(BincodeSerializer));
+
+    let result = manager
+        .get_or_compute_typed("schemaless", CacheStrategy::ShortTerm, || async {
+            Ok(serde_json::json!({ "id": 1 }))
+        })
+        .await;
+    let error = result
+        .err()
+        .unwrap_or_else(|| panic!("Bincode should reject serde_json::Value"));
+    assert!(error.to_string().contains("serde_json::Value"), "{error}");
+    // Rejected before caching, so later reads don't keep failing
+    assert!(manager.get("schemaless").await?.is_none());
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Flattened {
+    id: u64,
+    #[serde(flatten)]
+    extra: std::collections::BTreeMap<String, String>,
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+enum Untagged {
+    Number(u64),
+    Text(String),
+}
+
+#[test]
+fn test_self_describing_codecs() -> anyhow::Result<()> {
+    use multi_tier_cache::serialization::{CodecChain, RawSerializer};
+    use multi_tier_cache::{CacheSerializer, JsonSerializer};
+
+    let flattened = Flattened {
+        id: 1,
+        extra: [("color".to_string(), "red".to_string())].into(),
+    };
+    let untagged = [Untagged::Number(7), Untagged::Text("seven".to_string())];
+
+    #[allow(unused_mut)]
+    let mut self_describing = vec![
+        CacheSerializer::Json(JsonSerializer),
+        CacheSerializer::Chain(CodecChain::new(CacheSerializer::Json(JsonSerializer))),
+    ];
+    #[cfg(feature = "msgpack")]
+    self_describing.push(CacheSerializer::MsgPack(
+        multi_tier_cache::serialization::MsgPackSerializer,
+    ));
+    for serializer in self_describing {
+        assert!(
+            serializer.supports_self_describing(),
+            "{}",
+            serializer.name()
+        );
+        serializer.verify_round_trip(&flattened)?;
+        for value in &untagged {
+            serializer.verify_round_trip(value)?;
+        }
+    }
+
+    // Rejected with an error, never a corrupt value
+    let raw = CacheSerializer::Raw(RawSerializer);
+    assert!(!raw.supports_self_describing());
+    assert!(raw.verify_round_trip(&flattened).is_err());
+
+    #[cfg(feature = "bincode")]
+    {
+        let bincode = CacheSerializer::Bincode(multi_tier_cache::serialization::BincodeSerializer);
+        assert!(!bincode.supports_self_describing());
+        let error = bincode
+            .verify_round_trip(&flattened)
+            .err()
+            .unwrap_or_else(|| panic!("Bincode should reject flattened fields"));
+        assert!(error.to_string().contains("flatten"), "{error}");
+        let error = bincode
+            .verify_round_trip(&Untagged::Number(7))
+            .err()
+            .unwrap_or_else(|| panic!("Bincode should reject untagged enums"));
+        assert!(error.to_string().contains("untagged"), "{error}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_codec_content_types() -> anyhow::Result<()> {
+    use multi_tier_cache::serialization::{CodecChain, RawSerializer};
+    use multi_tier_cache::{CacheSerializer, JsonSerializer};
+
+    let json = CacheSerializer::Json(JsonSerializer);
+    assert_eq!(json.content_type(), "application/json");
+    assert_eq!(
+        CacheSerializer::Raw(RawSerializer).content_type(),
+        "application/octet-stream"
+    );
+    assert_eq!(
+        CacheSerializer::Chain(CodecChain::new(json.clone())).content_type(),
+        "application/octet-stream"
+    );
+    #[cfg(feature = "msgpack")]
+    assert_eq!(
+        CacheSerializer::MsgPack(multi_tier_cache::serialization::MsgPackSerializer).content_type(),
+        "application/msgpack"
+    );
+    #[cfg(feature = "bincode")]
+    assert_eq!(
+        CacheSerializer::Bincode(multi_tier_cache::serialization::BincodeSerializer).content_type(),
+        "application/octet-stream"
+    );
+    #[cfg(feature = "encryption")]
+    assert_eq!(
+        CacheSerializer::Encrypted(multi_tier_cache::serialization::EncryptingSerializer::new(
+            json, &[7; 32]
+        )?)
+        .content_type(),
+        "application/octet-stream"
+    );
+
+    // The manager reports its serializer's type, unless values carry a codec tag
+    let cache = common::setup_in_memory_cache()?;
+    assert_eq!(cache.manager.content_type(), "application/json");
+    let guarded =
+        common::in_memory_manager_with_l2(Arc::new(DashMapCache::new()))?.with_codec_guard(true);
+    assert_eq!(guarded.content_type(), "application/octet-stream");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_entry_or_insert() -> anyhow::Result<()> {
+    let cache = common::setup_in_memory_cache()?;
+    let entry = cache.manager.entry("entry:or_insert");
+    assert_eq!(entry.key(), "entry:or_insert");
+
+    let first = entry
+        .or_insert(CacheStrategy::ShortTerm, Bytes::from("first"))
+        .await?;
+    assert_eq!(first, Bytes::from("first"));
+
+    // An existing value wins over the default
+    let second = cache
+        .manager
+        .entry("entry:or_insert")
+        .or_insert(CacheStrategy::ShortTerm, Bytes::from("second"))
+        .await?;
+    assert_eq!(second, Bytes::from("first"));
+    assert_eq!(
+        cache.l2.get("entry:or_insert").await,
+        Some(Bytes::from("first"))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_entry_or_insert_with() -> anyhow::Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let cache = common::setup_in_memory_cache()?;
+    let calls = AtomicUsize::new(0);
+
+    for _ in 0..3 {
+        let value = cache
+            .manager
+            .entry("entry:or_insert_with")
+            .or_insert_with(CacheStrategy::ShortTerm, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(Bytes::from("computed"))
+            })
+            .await?;
+        assert_eq!(value, Bytes::from("computed"));
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 1, "Compute should run once");
+
+    Ok(())
+}
+
+/// Parse a counter stored as a decimal string
+fn parse_count(bytes: &Bytes) -> u64 {
+    std::str::from_utf8(bytes)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or_else(|| panic!("Counter value is not a number"))
+}
+
+#[tokio::test]
+async fn test_entry_and_modify() -> anyhow::Result<()> {
+    let cache = common::setup_in_memory_cache()?;
+    let increment = |current: Bytes| Bytes::from((parse_count(&current) + 1).to_string());
+
+    // Missing key: the modifier is skipped and the default is inserted
+    let value = cache
+        .manager
+        .entry("entry:counter")
+        .and_modify(|_| panic!("Modifier must not run for a missing key"))
+        .or_insert(CacheStrategy::ShortTerm, Bytes::from("1"))
+        .await?;
+    assert_eq!(value, Bytes::from("1"));
+
+    // Present key: the modifier's result is stored in every tier
+    let value = cache
+        .manager
+        .entry("entry:counter")
+        .and_modify(increment)
+        .or_insert(CacheStrategy::ShortTerm, Bytes::from("1"))
+        .await?;
+    assert_eq!(value, Bytes::from("2"));
+    assert_eq!(cache.l1.get("entry:counter").await, Some(Bytes::from("2")));
+    assert_eq!(cache.l2.get("entry:counter").await, Some(Bytes::from("2")));
+
+    // Concurrent read-modify-writes are serialized by the in-flight lock
+    let mut tasks = tokio::task::JoinSet::new();
+    for _ in 0..20 {
+        let manager = Arc::clone(&cache.manager);
+        tasks.spawn(async move {
+            manager
+                .entry("entry:counter")
+                .and_modify(increment)
+                .or_insert(CacheStrategy::ShortTerm, Bytes::from("1"))
+                .await
+        });
+    }
+    while let Some(result) = tasks.join_next().await {
+        result??;
+    }
+    assert_eq!(
+        cache.manager.get("entry:counter").await?,
+        Some(Bytes::from("22"))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stats_by_prefix() -> anyhow::Result<()> {
+    let manager = common::in_memory_manager_with_l2(Arc::new(DashMapCache::new()))?
+        .with_stats_by_prefix(|key| key.split_once(':').map(|(tenant, _)| tenant.to_string()));
+
+    // tenant-a: one set, two L1 hits, one miss
+    manager
+        .set_with_strategy(
+            "tenant-a:user:1",
+            Bytes::from("a"),
+            CacheStrategy::ShortTerm,
+        )
+        .await?;
+    manager.get("tenant-a:user:1").await?;
+    manager.get("tenant-a:user:1").await?;
+    manager.get("tenant-a:user:2").await?;
+
+    // tenant-b: two misses
+    manager.get("tenant-b:user:1").await?;
+    manager.get("tenant-b:user:2").await?;
+
+    // Keys without a bucket only count globally
+    manager.get("unscoped").await?;
+
+    let buckets = manager.stats_by_bucket();
+    assert_eq!(buckets.len(), 2);
+
+    let tenant_a = buckets
+        .get("tenant-a")
+        .unwrap_or_else(|| panic!("Missing tenant-a bucket"));
+    assert_eq!(tenant_a.total_requests, 3);
+    assert_eq!(tenant_a.l1_hits, 2);
+    assert_eq!(tenant_a.misses, 1);
+    assert_eq!(tenant_a.sets, 1);
+
+    let tenant_b = buckets
+        .get("tenant-b")
+        .unwrap_or_else(|| panic!("Missing tenant-b bucket"));
+    assert_eq!(tenant_b.total_requests, 2);
+    assert_eq!(tenant_b.total_hits, 0);
+    assert_eq!(tenant_b.misses, 2);
+    assert!(tenant_b.hit_rate.abs() < f64::EPSILON);
+
+    let global = manager.get_stats();
+    assert_eq!(global.total_requests, 6);
+    assert_eq!(global.sets, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stats_by_prefix_is_bounded() -> anyhow::Result<()> {
+    use multi_tier_cache::{MAX_STATS_BUCKETS, OVERFLOW_STATS_BUCKET};
+
+    let manager = common::in_memory_manager_with_l2(Arc::new(DashMapCache::new()))?
+        .with_stats_by_prefix(|key| Some(key.to_string()));
+
+    for i in 0..MAX_STATS_BUCKETS + 10 {
+        manager.get(&format!("bucket-{i}")).await?;
+    }
+
+    let buckets = manager.stats_by_bucket();
+    assert_eq!(buckets.len(), MAX_STATS_BUCKETS + 1);
+    let overflow = buckets
+        .get(OVERFLOW_STATS_BUCKET)
+        .unwrap_or_else(|| panic!("Missing overflow bucket"));
+    assert_eq!(overflow.total_requests, 10);
+
+    Ok(())
+}
+
+/// Spawner that counts tasks (and records their names) before handing them to Tokio
+#[derive(Default)]
+struct RecordingSpawner {
+    spawned: std::sync::atomic::AtomicUsize,
+    names: std::sync::Mutex<Vec<&'static str>>,
+}
+
+impl multi_tier_cache::Spawner for RecordingSpawner {
+    fn spawn(&self, task: futures_util::future::BoxFuture<'static, ()>) {
+        self.spawned
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        tokio::spawn(task);
+    }
+
+    fn spawn_named(&self, name: &'static str, task: futures_util::future::BoxFuture<'static, ()>) {
+        self.names
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(name);
+        self.spawn(task);
+    }
+}
+
+#[tokio::test]
+async fn test_custom_spawner_runs_background_tasks() -> anyhow::Result<()> {
+    use multi_tier_cache::{CacheSystemBuilder, TierConfig};
+
+    let spawner = Arc::new(RecordingSpawner::default());
+    let l1 = Arc::new(DashMapCache::new());
+    let l2 = Arc::new(DashMapCache::new());
+    let cache = CacheSystemBuilder::new()
+        .with_tier(Arc::clone(&l1) as _, TierConfig::as_l1())
+        .with_tier(
+            Arc::clone(&l2) as _,
+            TierConfig::as_l2().with_promotion_frequency(1),
+        )
+        .with_async_promotion(true)
+        .with_spawner(Arc::clone(&spawner) as _)
+        .build()
+        .await?;
+    let manager = cache.cache_manager();
+
+    for i in 0..5 {
+        let key = format!("spawned:{i}");
+        l2.set_with_ttl(&key, Bytes::from("value"), Duration::from_mins(1))
+            .await?;
+        assert!(manager.get(&key).await?.is_some());
+    }
+
+    // One promotion worker, started lazily through the custom spawner
+    assert_eq!(spawner.spawned.load(std::sync::atomic::Ordering::SeqCst), 1);
+    assert_eq!(
+        *spawner
+            .names
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner),
+        vec!["mtc-promotion-worker"]
+    );
+
+    let mut promoted = false;
+    for _ in 0..50 {
+        if l1.get("spawned:4").await.is_some() {
+            promoted = true;
+            break;
+        }
+        sleep(Duration::from_millis(10)).await;
+    }
+    assert!(
+        promoted,
+        "Worker on the custom spawner should apply promotions"
+    );
+
+    Ok(())
+}
+
+/// Canonical form of `path?query`: query parameters sorted
+fn sort_query(key: &str) -> String {
+    match key.split_once('?') {
+        Some((path, query)) => {
+            let mut params: Vec<&str> = query.split('&').collect();
+            params.sort_unstable();
+            format!("{path}?{}", params.join("&"))
+        }
+        None => key.to_string(),
+    }
+}
+
+/// Run `get_or_compute_with` for two key spellings concurrently, returning the compute count
+async fn compute_both_spellings(
+    manager: &Arc<multi_tier_cache::CacheManager>,
+    first: &str,
+    second: &str,
+) -> anyhow::Result<usize> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let compute = |key: String| {
+        let manager = Arc::clone(manager);
+        let calls = Arc::clone(&calls);
+        tokio::spawn(async move {
+            manager
+                .get_or_compute_with(&key, CacheStrategy::ShortTerm, || async move {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    sleep(Duration::from_millis(50)).await;
+                    Ok(Bytes::from("items"))
+                })
+                .await
+        })
+    };
+
+    let (a, b) = tokio::join!(compute(first.to_string()), compute(second.to_string()));
+    assert_eq!(a??, Bytes::from("items"));
+    assert_eq!(b??, Bytes::from("items"));
+    Ok(calls.load(Ordering::SeqCst))
+}
+
+#[tokio::test]
+async fn test_key_canonicalizer_coalesces_equivalent_keys() -> anyhow::Result<()> {
+    let l2 = Arc::new(DashMapCache::new());
+    let manager = Arc::new(
+        common::in_memory_manager_with_l2(Arc::clone(&l2))?.with_key_canonicalizer(sort_query),
+    );
+
+    let calls = compute_both_spellings(&manager, "items?a=1&b=2", "items?b=2&a=1").await?;
+    assert_eq!(calls, 1, "Equivalent keys should share one compute");
+
+    // Coalescing only: exactly one spelling was stored
+    let stored = [l2.get("items?a=1&b=2").await, l2.get("items?b=2&a=1").await];
+    assert_eq!(stored.iter().filter(|v| v.is_some()).count(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_key_canonicalizer_with_canonical_storage() -> anyhow::Result<()> {
+    let l2 = Arc::new(DashMapCache::new());
+    let manager = Arc::new(
+        common::in_memory_manager_with_l2(Arc::clone(&l2))?
+            .with_key_canonicalizer(sort_query)
+            .with_canonical_storage(true),
+    );
+
+    let calls = compute_both_spellings(&manager, "items?b=2&a=1", "items?a=1&b=2").await?;
+    assert_eq!(calls, 1);
+
+    // Stored once, under the canonical key, and readable through any spelling
+    assert!(l2.get("items?a=1&b=2").await.is_some());
+    assert!(l2.get("items?b=2&a=1").await.is_none());
+    assert_eq!(
+        manager.get("items?b=2&a=1").await?,
+        Some(Bytes::from("items"))
+    );
+
+    manager.invalidate("items?b=2&a=1").await?;
+    assert!(l2.get("items?a=1&b=2").await.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_or_compute_with_source() -> anyhow::Result<()> {
+    use multi_tier_cache::CacheSource;
+
+    let cache = common::setup_in_memory_cache()?;
+    let key = "source:user";
+    let compute = || async {
+        Ok(User {
+            id: 1,
+            name: "Ada".to_string(),
+            email: "ada@example.com".to_string(),
+        })
+    };
+
+    let (user, source) = cache
+        .manager
+        .get_or_compute_with_source::<User, _, _>(key, CacheStrategy::ShortTerm, compute)
+        .await?;
+    assert_eq!(user.name, "Ada");
+    assert_eq!(source, CacheSource::Computed);
+
+    let (_, source) = cache
+        .manager
+        .get_or_compute_with_source::<User, _, _>(key, CacheStrategy::ShortTerm, compute)
+        .await?;
+    assert_eq!(source, CacheSource::L1);
+
+    // Served from L2 (and promoted) once L1 has lost the value
+    cache.l1.remove(key).await?;
+    let (user, source) = cache
+        .manager
+        .get_or_compute_with_source::<User, _, _>(key, CacheStrategy::ShortTerm, compute)
+        .await?;
+    assert_eq!(user.id, 1);
+    assert_eq!(source, CacheSource::LowerTier(2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_age_increases_over_time() -> anyhow::Result<()> {
+    let cache = common::setup_in_memory_cache()?;
+    assert_eq!(cache.manager.age("age:missing").await?, None);
+
+    cache
+        .manager
+        .set_with_strategy("age:key", Bytes::from("value"), CacheStrategy::ShortTerm)
+        .await?;
+    let first = cache
+        .manager
+        .age("age:key")
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Age should be known after a write"))?;
+
+    sleep(Duration::from_millis(50)).await;
+    let second = cache
+        .manager
+        .age("age:key")
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Age should still be known"))?;
+    assert!(second >= first + Duration::from_millis(50));
+
+    // Rewriting the value resets its age
+    cache
+        .manager
+        .set_with_strategy("age:key", Bytes::from("fresh"), CacheStrategy::ShortTerm)
+        .await?;
+    let rewritten = cache.manager.age("age:key").await?;
+    assert!(rewritten.is_some_and(|age| age < second));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reconcile_to_l2() -> anyhow::Result<()> {
+    use multi_tier_cache::L2CacheBackend;
+
+    let cache = common::setup_in_memory_cache()?;
+    // L1-only entry, as left behind by a failed L2 write
+    cache
+        .l1
+        .set_with_ttl("reconcile:a", Bytes::from("a"), Duration::from_secs(30))
+        .await?;
+    // Present in both tiers with different values: L2 must be left alone
+    cache
+        .l1
+        .set_with_ttl("reconcile:b", Bytes::from("local"), Duration::from_secs(30))
+        .await?;
+    cache
+        .l2
+        .set_with_ttl(
+            "reconcile:b",
+            Bytes::from("shared"),
+            Duration::from_secs(30),
+        )
+        .await?;
+
+    let written = cache
+        .manager
+        .reconcile_to_l2(&["reconcile:a", "reconcile:b", "reconcile:missing"])
+        .await?;
+    assert_eq!(written, 1);
+
+    let (value, ttl) = cache
+        .l2
+        .get_with_ttl("reconcile:a")
+        .await
+        .ok_or_else(|| anyhow::anyhow!("L2 should hold the reconciled key"))?;
+    assert_eq!(value, Bytes::from("a"));
+    assert!(ttl.is_some_and(|ttl| ttl <= Duration::from_secs(30)));
+    assert_eq!(
+        cache.l2.get("reconcile:b").await,
+        Some(Bytes::from("shared"))
+    );
+    assert_eq!(cache.l2.get("reconcile:missing").await, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dump_restore_l1() -> anyhow::Result<()> {
+    use multi_tier_cache::L2CacheBackend;
+
+    let cache = common::setup_in_memory_cache()?;
+    for (key, value) in [("dump:a", "alpha"), ("dump:b", "beta")] {
+        cache
+            .l1
+            .set_with_ttl(key, Bytes::from(value), Duration::from_secs(30))
+            .await?;
+    }
+
+    let mut dump = Vec::new();
+    assert_eq!(cache.manager.dump_l1(&mut dump).await?, 2);
+
+    cache.l1.remove_pattern("dump:*").await?;
+    assert!(cache.l1.is_empty());
+
+    assert_eq!(cache.manager.restore_l1(dump.as_slice()).await?, 2);
+    for (key, value) in [("dump:a", "alpha"), ("dump:b", "beta")] {
+        let (restored, ttl) = cache
+            .l1
+            .get_with_ttl(key)
+            .await
+            .ok_or_else(|| anyhow::anyhow!("{key} should be restored to L1"))?;
+        assert_eq!(restored, Bytes::from(value));
+        assert!(ttl.is_some_and(|ttl| ttl <= Duration::from_secs(30)));
+    }
+
+    // Garbage input is rejected rather than half-restored
+    assert!(cache.manager.restore_l1(&b"not a dump"[..]).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reject_null_values() -> anyhow::Result<()> {
+    let l2 = Arc::new(DashMapCache::new());
+    let manager = common::in_memory_manager_with_l2(Arc::clone(&l2))?.with_reject_null_values(true);
+
+    manager
+        .set_with_strategy("null:set", Bytes::from("null"), CacheStrategy::ShortTerm)
+        .await?;
+    manager
+        .update_cache("null:update", Bytes::from(" null\n"), None)
+        .await?;
+    let computed: Option<u32> = manager
+        .get_or_compute_typed("null:computed", CacheStrategy::ShortTerm, || async {
+            Ok(None)
+        })
+        .await?;
+    assert_eq!(computed, None);
+
+    for key in ["null:set", "null:update", "null:computed"] {
+        assert_eq!(manager.get(key).await?, None, "{key} must not be cached");
+        assert_eq!(l2.get(key).await, None);
+    }
+    assert_eq!(manager.get_stats().rejected_nulls, 3);
+
+    // Other values are still cached
+    manager
+        .set_with_strategy("null:other", Bytes::from("0"), CacheStrategy::ShortTerm)
+        .await?;
+    assert_eq!(manager.get("null:other").await?, Some(Bytes::from("0")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tiered_backend_composes_managers() -> anyhow::Result<()> {
+    use multi_tier_cache::{CacheSystemBuilder, L2CacheBackend, TierConfig, TieredBackend};
+
+    // Inner "region" cache: its own L1 + L2
+    let region = common::setup_in_memory_cache()?;
+    let outer_l1 = Arc::new(DashMapCache::new());
+    let cache = CacheSystemBuilder::new()
+        .with_tier(Arc::clone(&outer_l1) as _, TierConfig::as_l1())
+        .with_tier(
+            Arc::new(TieredBackend::new(Arc::clone(&region.manager))),
+            TierConfig::as_l2().with_promotion_frequency(1),
+        )
+        .build()
+        .await?;
+    let manager = cache.cache_manager();
+
+    // Writes reach every inner tier
+    manager
+        .set_with_strategy("tiered:a", Bytes::from("a"), CacheStrategy::ShortTerm)
+        .await?;
+    assert_eq!(region.l1.get("tiered:a").await, Some(Bytes::from("a")));
+    assert_eq!(region.l2.get("tiered:a").await, Some(Bytes::from("a")));
+
+    // Reads fall through to the inner cache and promote with its TTL
+    region
+        .l2
+        .set_with_ttl("tiered:b", Bytes::from("b"), Duration::from_secs(30))
+        .await?;
+    assert_eq!(manager.get("tiered:b").await?, Some(Bytes::from("b")));
+    let (_, ttl) = outer_l1
+        .get_with_ttl("tiered:b")
+        .await
+        .ok_or_else(|| anyhow::anyhow!("tiered:b should be promoted to the outer L1"))?;
+    assert!(ttl.is_some_and(|ttl| ttl <= Duration::from_secs(30)));
+
+    // Invalidation clears the inner tiers too
+    manager.invalidate("tiered:a").await?;
+    assert_eq!(region.l1.get("tiered:a").await, None);
+    assert_eq!(region.l2.get("tiered:a").await, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_durable_requires_replication_support() -> anyhow::Result<()> {
+    let cache = common::setup_in_memory_cache()?;
+
+    let result = cache
+        .manager
+        .set_durable(
+            "durable:key",
+            Bytes::from("value"),
+            CacheStrategy::ShortTerm,
+            1,
+            Duration::from_millis(10),
+        )
+        .await;
+    assert!(matches!(result, Err(CacheError::BackendError(_))));
+    // The write itself went through
+    assert_eq!(
+        cache.l2.get("durable:key").await,
+        Some(Bytes::from("value"))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admission_controlled_l1() -> anyhow::Result<()> {
+    use multi_tier_cache::backends::{AdmissionConfig, AdmissionControlled};
+    use multi_tier_cache::{CacheManager, CacheTier};
+
+    let l1 = Arc::new(AdmissionControlled::new(
+        DashMapCache::new(),
+        AdmissionConfig::default(),
+    ));
+    let l2 = Arc::new(DashMapCache::new());
+    let tiers = vec![
+        CacheTier::new(Arc::clone(&l1) as _, 1, false, 1, 1.0),
+        CacheTier::new(Arc::clone(&l2) as _, 2, true, 1, 1.0),
+    ];
+    let manager = CacheManager::new_with_tiers(tiers, None)?;
+
+    // First access: computed and stored in L2 only
+    manager
+        .get_or_compute_with("admission:key", CacheStrategy::ShortTerm, || async {
+            Ok(Bytes::from("value"))
+        })
+        .await?;
+    assert_eq!(l1.inner().get("admission:key").await, None);
+    assert!(l2.get("admission:key").await.is_some());
+    assert_eq!(l1.rejected(), 1);
+
+    // Second access: an L2 hit, now frequent enough to be promoted into L1
+    assert_eq!(
+        manager.get("admission:key").await?,
+        Some(Bytes::from("value"))
+    );
+    assert_eq!(
+        l1.inner().get("admission:key").await,
+        Some(Bytes::from("value"))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admission_filters_only_near_capacity() -> anyhow::Result<()> {
+    use multi_tier_cache::backends::{AdmissionConfig, AdmissionControlled};
+    use multi_tier_cache::{MokaCache, MokaCacheConfig};
+
+    let l1 = AdmissionControlled::new(
+        MokaCache::new(MokaCacheConfig {
+            max_capacity: 10,
+            ..MokaCacheConfig::default()
+        })?,
+        AdmissionConfig::default(),
+    );
+    let ttl = Duration::from_mins(1);
+
+    // Plenty of room: never-read keys are admitted
+    for i in 0..9 {
+        l1.set_with_ttl(&format!("admission:{i}"), Bytes::from("value"), ttl)
+            .await?;
+    }
+    assert_eq!(l1.rejected(), 0);
+    l1.inner().run_pending_tasks().await;
+
+    // At 90% of capacity the frequency filter kicks in
+    l1.set_with_ttl("admission:cold", Bytes::from("value"), ttl)
+        .await?;
+    assert_eq!(l1.rejected(), 1);
+    assert_eq!(l1.inner().get("admission:cold").await, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_compute_duration_stats() -> anyhow::Result<()> {
+    let cache = common::setup_in_memory_cache()?;
+    let manager = &cache.manager;
+    assert_eq!(manager.get_stats().avg_compute_duration(), None);
+
+    // Five concurrent callers, one slow compute
+    let lookups = (0..5).map(|_| {
+        manager.get_or_compute_with("compute:slow", CacheStrategy::ShortTerm, || async {
+            sleep(Duration::from_millis(50)).await;
+            Ok(Bytes::from("value"))
+        })
+    });
+    for result in futures_util::future::join_all(lookups).await {
+        assert_eq!(result?, Bytes::from("value"));
+    }
+
+    let stats = manager.get_stats();
+    assert_eq!(stats.computes, 1);
+    assert_eq!(stats.coalesced_waiters, 4);
+    let avg = stats
+        .avg_compute_duration()
+        .ok_or_else(|| anyhow::anyhow!("one compute should be recorded"))?;
+    assert!(avg >= Duration::from_millis(50), "avg compute {avg:?}");
+    assert!(avg < Duration::from_secs(1), "avg compute {avg:?}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_locked_backend_concurrent_increment() -> anyhow::Result<()> {
+    use multi_tier_cache::backends::LockedBackend;
+
+    let backend = Arc::new(LockedBackend::new(DashMapCache::new()));
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let backend = Arc::clone(&backend);
+            tokio::spawn(async move {
+                for _ in 0..100 {
+                    backend
+                        .increment("locked:counter", 1, Duration::from_mins(1))
+                        .await?;
+                }
+                Ok::<_, CacheError>(())
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.await??;
+    }
+
+    assert_eq!(
+        backend.get("locked:counter").await,
+        Some(Bytes::from("800"))
+    );
+
+    // compare_and_set only writes over the expected value
+    let ttl = Duration::from_mins(1);
+    assert!(
+        !backend
+            .compare_and_set("locked:counter", Some(b"1"), Bytes::from("x"), ttl)
+            .await?
+    );
+    assert!(
+        backend
+            .compare_and_set("locked:counter", Some(b"800"), Bytes::from("0"), ttl)
+            .await?
+    );
+    assert!(
+        backend
+            .compare_and_set("locked:new", None, Bytes::from("1"), ttl)
+            .await?
+    );
+    assert_eq!(backend.get("locked:counter").await, Some(Bytes::from("0")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_warm_l1_from_l2() -> anyhow::Result<()> {
+    let cache = common::setup_in_memory_cache()?;
+    for key in ["warm:1", "warm:2", "warm:3", "other:1"] {
+        cache
+            .l2
+            .set_with_ttl(key, Bytes::from(key), Duration::from_secs(30))
+            .await?;
+    }
+
+    // Bounded by max_keys
+    assert_eq!(cache.manager.warm_l1_from_l2("warm:*", 2).await?, 2);
+    assert_eq!(cache.l1.len(), 2);
+
+    assert_eq!(cache.manager.warm_l1_from_l2("warm:*", 100).await?, 3);
+    for key in ["warm:1", "warm:2", "warm:3"] {
+        assert_eq!(cache.l1.get(key).await, Some(Bytes::from(key)));
+    }
+    assert_eq!(cache.l1.get("other:1").await, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deadline_in_past_times_out() -> anyhow::Result<()> {
+    use multi_tier_cache::DeadlinePolicy;
+
+    let cache = common::setup_in_memory_cache()?;
+    let past = tokio::time::Instant::now()
+        .checked_sub(Duration::from_millis(1))
+        .ok_or_else(|| anyhow::anyhow!("clock too close to its epoch"))?;
+
+    let result = cache.manager.get_until("deadline:key", past).await;
+    assert!(matches!(result, Err(CacheError::Timeout(_))));
+
+    let computed = std::sync::atomic::AtomicBool::new(false);
+    let result = cache
+        .manager
+        .get_or_compute_until("deadline:key", CacheStrategy::ShortTerm, past, || async {
+            computed.store(true, std::sync::atomic::Ordering::SeqCst);
+            Ok(Bytes::from("value"))
+        })
+        .await;
+    assert!(matches!(result, Err(CacheError::Timeout(_))));
+    assert!(!computed.load(std::sync::atomic::Ordering::SeqCst));
+
+    // A slow compute is cut short by a future deadline
+    let soon = tokio::time::Instant::now() + Duration::from_millis(20);
+    let result = cache
+        .manager
+        .get_or_compute_until("deadline:slow", CacheStrategy::ShortTerm, soon, || async {
+            sleep(Duration::from_secs(5)).await;
+            Ok(Bytes::from("late"))
+        })
+        .await;
+    assert!(matches!(result, Err(CacheError::Timeout(_))));
+
+    // With the Miss policy, the deadline reads as a miss
+    let manager = common::in_memory_manager_with_l2(Arc::new(DashMapCache::new()))?
+        .with_deadline_policy(DeadlinePolicy::Miss);
+    assert_eq!(manager.get_until("deadline:key", past).await?, None);
+
+    Ok(())
+}
+
+#[cfg(feature = "encryption")]
+#[tokio::test]
+async fn test_encrypted_serializer_round_trip_and_rotation() -> anyhow::Result<()> {
+    use multi_tier_cache::CacheSerializer;
+    use multi_tier_cache::serialization::{EncryptingSerializer, JsonSerializer};
+
+    let old_key = [7u8; 32];
+    let new_key = [9u8; 32];
+    let encrypted = |key: &[u8; 32]| {
+        EncryptingSerializer::new(CacheSerializer::Json(JsonSerializer), key)
+            .map(CacheSerializer::Encrypted)
+    };
+
+    let shared_l2 = Arc::new(DashMapCache::new());
+    let mut writer = common::in_memory_manager_with_l2(Arc::clone(&shared_l2))?;
+    writer.set_serializer(encrypted(&old_key)?);
+    let _: User = writer
+        .get_or_compute_typed("secret:user", CacheStrategy::ShortTerm, || async {
+            Ok(User::new(1))
+        })
+        .await?;
+
+    // Stored bytes are not plain JSON
+    let stored = shared_l2
+        .get("secret:user")
+        .await
+        .unwrap_or_else(|| panic!("value should be in L2"));
+    assert!(serde_json::from_slice::<User>(&stored).is_err());
+    assert_eq!(
+        writer.get_typed::<User>("secret:user").await?,
+        Some(User::new(1))
+    );
+
+    // After rotation, values written with the previous key still decrypt
+    let mut rotated = common::in_memory_manager_with_l2(Arc::clone(&shared_l2))?;
+    rotated.set_serializer(CacheSerializer::Encrypted(
+        EncryptingSerializer::new(CacheSerializer::Json(JsonSerializer), &new_key)?
+            .with_previous_key(&old_key)?,
+    ));
+    assert_eq!(
+        rotated.get_typed::<User>("secret:user").await?,
+        Some(User::new(1))
+    );
+
+    // Without the old key the value is unreadable: a miss, not an error
+    let mut stranger = common::in_memory_manager_with_l2(shared_l2)?;
+    stranger.set_serializer(encrypted(&new_key)?);
+    assert_eq!(stranger.get_typed::<User>("secret:user").await?, None);
+
+    Ok(())
+}
+
+#[cfg(feature = "encryption")]
+#[tokio::test]
+async fn test_encrypted_serializer_tamper_is_miss() -> anyhow::Result<()> {
+    use multi_tier_cache::CacheSerializer;
+    use multi_tier_cache::serialization::{EncryptingSerializer, JsonSerializer};
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    let key = [3u8; 32];
+    let shared_l2 = Arc::new(DashMapCache::new());
+    let mut writer = common::in_memory_manager_with_l2(Arc::clone(&shared_l2))?;
+    writer.set_serializer(CacheSerializer::Encrypted(EncryptingSerializer::new(
+        CacheSerializer::Json(JsonSerializer),
+        &key,
+    )?));
+    let _: User = writer
+        .get_or_compute_typed("secret:tampered", CacheStrategy::ShortTerm, || async {
+            Ok(User::new(1))
+        })
+        .await?;
+
+    // Flip one ciphertext byte in L2
+    let mut stored = shared_l2
+        .get("secret:tampered")
+        .await
+        .unwrap_or_else(|| panic!("value should be in L2"))
+        .to_vec();
+    let last = stored
+        .last_mut()
+        .unwrap_or_else(|| panic!("stored value should not be empty"));
+    *last ^= 0x01;
+    shared_l2
+        .set_with_ttl(
+            "secret:tampered",
+            Bytes::from(stored),
+            Duration::from_mins(5),
+        )
+        .await?;
+
+    let mut reader = common::in_memory_manager_with_l2(shared_l2)?;
+    reader.set_serializer(CacheSerializer::Encrypted(EncryptingSerializer::new(
+        CacheSerializer::Json(JsonSerializer),
+        &key,
+    )?));
+    assert_eq!(reader.get_typed::<User>("secret:tampered").await?, None);
+
+    // get_or_compute treats the tampered value as a miss and recomputes
+    let compute_count = AtomicU32::new(0);
+    let user: User = reader
+        .get_or_compute_typed("secret:tampered", CacheStrategy::ShortTerm, || async {
+            compute_count.fetch_add(1, Ordering::SeqCst);
+            Ok(User::new(2))
+        })
+        .await?;
+    assert_eq!(user, User::new(2));
+    assert_eq!(compute_count.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_if_present() -> anyhow::Result<()> {
+    let cache = common::setup_in_memory_cache()?;
+    cache
+        .manager
+        .set_with_strategy(
+            "xx:present",
+            Bytes::from(serde_json::to_vec(&User::new(1))?),
+            CacheStrategy::ShortTerm,
+        )
+        .await?;
+
+    // Present: replaced in every tier
+    let replaced = cache
+        .manager
+        .set_if_present("xx:present", &User::new(2), Duration::from_mins(5))
+        .await?;
+    assert!(replaced);
+    assert_eq!(
+        cache.manager.get_typed::<User>("xx:present").await?,
+        Some(User::new(2))
+    );
+    let l1_value = cache
+        .l1
+        .get("xx:present")
+        .await
+        .unwrap_or_else(|| panic!("L1 should hold the new value"));
+    assert_eq!(serde_json::from_slice::<User>(&l1_value)?, User::new(2));
+
+    // Absent: nothing is created in any tier
+    let replaced = cache
+        .manager
+        .set_if_present("xx:absent", &User::new(3), Duration::from_mins(5))
+        .await?;
+    assert!(!replaced);
+    assert!(cache.l1.get("xx:absent").await.is_none());
+    assert!(cache.l2.get("xx:absent").await.is_none());
+
+    // Backends with a locked check-then-set agree
+    let moka = multi_tier_cache::MokaCache::new(multi_tier_cache::MokaCacheConfig::default())?;
+    assert!(
+        !moka
+            .set_if_present("k", Bytes::from("v1"), Duration::from_mins(5))
+            .await?
+    );
+    moka.set_with_ttl("k", Bytes::from("v1"), Duration::from_mins(5))
+        .await?;
+    assert!(
+        moka.set_if_present("k", Bytes::from("v2"), Duration::from_mins(5))
+            .await?
+    );
+    assert_eq!(moka.get("k").await, Some(Bytes::from("v2")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cache_system_from_config_value() -> anyhow::Result<()> {
+    use multi_tier_cache::CacheConfig;
+
+    let config = serde_json::json!({
+        "codec": "json",
+        "codec_guard": true,
+        "key_prefix": "app:",
+        "strategies": { "short_term_secs": 30 },
+        "tiers": [
+            { "backend": "moka", "capacity": 100 },
+            { "backend": "dashmap", "ttl_scale": 2.0, "promotion_frequency": 1 }
+        ]
+    });
+    let parsed: CacheConfig = serde_json::from_value(config.clone())?;
+    assert_eq!(parsed.tiers.len(), 2);
+    assert_eq!(
+        parsed.strategies.durations().short_term,
+        Duration::from_secs(30)
+    );
+
+    let cache = CacheSystem::from_config_value(config).await?;
+    let manager = cache.cache_manager();
+    let user: User = manager
+        .get_or_compute_typed("user:1", CacheStrategy::ShortTerm, || async {
+            Ok(User::new(1))
+        })
+        .await?;
+    assert_eq!(user, User::new(1));
+    assert_eq!(
+        manager.get_typed::<User>("user:1").await?,
+        Some(User::new(1))
+    );
+    // Stored under the prefixed key
+    assert!(manager.get("app:user:1").await?.is_some());
+    assert_eq!(manager.get_tier_stats().len(), 2);
+
+    // Typos and unsupported codecs are rejected
+    for invalid in [
+        serde_json::json!({ "codec": "postcard" }),
+        serde_json::json!({ "tierz": [] }),
+        serde_json::json!({ "tiers": [{ "backend": "floppy" }] }),
+    ] {
+        let error = CacheSystem::from_config_value(invalid)
+            .await
+            .err()
+            .unwrap_or_else(|| panic!("invalid config should be rejected"));
+        assert!(matches!(error, CacheError::ConfigError(_)), "{error}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scan_streams_matching_entries() -> anyhow::Result<()> {
+    use futures_util::StreamExt;
+    use multi_tier_cache::{CacheSystemBuilder, TierConfig};
+
+    let cache = common::setup_in_memory_cache()?;
+    for id in 1..=3 {
+        let payload = serde_json::to_vec(&User::new(id))?;
+        cache
+            .manager
+            .set_with_strategy(
+                &format!("scan:user:{id}"),
+                Bytes::from(payload),
+                CacheStrategy::ShortTerm,
+            )
+            .await?;
+    }
+    // Not a `User`: skipped
+    cache
+        .manager
+        .set_with_strategy(
+            "scan:user:bad",
+            Bytes::from("oops"),
+            CacheStrategy::ShortTerm,
+        )
+        .await?;
+    // Outside the pattern
+    let payload = serde_json::to_vec(&User::new(9))?;
+    cache
+        .manager
+        .set_with_strategy(
+            "other:user:9",
+            Bytes::from(payload),
+            CacheStrategy::ShortTerm,
+        )
+        .await?;
+
+    let mut entries = cache
+        .manager
+        .scan::<User>("scan:user:*")
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let expected: Vec<(String, User)> = (1..=3)
+        .map(|id| (format!("scan:user:{id}"), User::new(id)))
+        .collect();
+    assert_eq!(entries, expected);
+
+    // Without an L2 there is nothing to scan
+    let l1_only = CacheSystemBuilder::new()
+        .with_tier(Arc::new(DashMapCache::new()), TierConfig::as_l1())
+        .build()
+        .await?;
+    let results: Vec<_> = l1_only
+        .cache_manager()
+        .scan::<User>("scan:*")
+        .collect()
+        .await;
+    assert!(matches!(
+        results.as_slice(),
+        [Err(CacheError::ConfigError(_))]
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scan_pages_through_l2() -> anyhow::Result<()> {
+    use futures_util::StreamExt;
+
+    let l2 = Arc::new(DashMapCache::new());
+    let manager = common::in_memory_manager_with_l2(Arc::clone(&l2))?;
+    for id in 0..250 {
+        let payload = serde_json::to_vec(&User::new(id))?;
+        manager
+            .set_with_strategy(
+                &format!("paged:{id:03}"),
+                Bytes::from(payload),
+                CacheStrategy::ShortTerm,
+            )
+            .await?;
+    }
+
+    // 250 keys come in three pages of at most 100
+    let mut cursor = 0;
+    let mut pages = 0;
+    loop {
+        let (next, keys) = l2
+            .scan_keys_page("paged:*", cursor, 100)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("DashMap can't list keys"))?;
+        assert!(keys.len() <= 100);
+        pages += 1;
+        cursor = next;
+        if cursor == 0 {
+            break;
+        }
+    }
+    assert_eq!(pages, 3);
+
+    let entries = manager
+        .scan::<User>("paged:*")
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(entries.len(), 250);
+    assert_eq!(entries.first().map(|(_, user)| user.id), Some(0));
+    assert_eq!(entries.last().map(|(_, user)| user.id), Some(249));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_concrete_l1_access() -> anyhow::Result<()> {
+    use multi_tier_cache::{CacheSystemBuilder, MokaCache};
+
+    let cache = CacheSystemBuilder::new()
+        .with_concrete_l1(Arc::new(DashMapCache::new()))
+        .with_l2(Arc::new(DashMapCache::new()))
+        .build()
+        .await?;
+    let l1 = cache
+        .l1_as::<DashMapCache>()
+        .unwrap_or_else(|| panic!("concrete L1 should be retained"));
+    assert!(cache.l1_as::<MokaCache>().is_none());
+
+    // The handle is the manager's L1: its entries are visible through both
+    cache
+        .cache_manager()
+        .set_with_strategy("concrete:fresh", Bytes::from("v"), CacheStrategy::ShortTerm)
+        .await?;
+    assert_eq!(l1.get("concrete:fresh").await, Some(Bytes::from("v")));
+
+    l1.set_with_ttl("concrete:stale", Bytes::from("v"), Duration::from_millis(1))
+        .await?;
+    sleep(Duration::from_millis(10)).await;
+    assert_eq!(l1.cleanup_expired(), 1);
+    assert!(l1.get("concrete:fresh").await.is_some());
+
+    // A type-erased L1 can't be recovered
+    let erased = CacheSystemBuilder::new()
+        .with_l1(Arc::new(DashMapCache::new()))
+        .with_l2(Arc::new(DashMapCache::new()))
+        .build()
+        .await?;
+    assert!(erased.l1_as::<DashMapCache>().is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tier_introspection() -> anyhow::Result<()> {
+    use multi_tier_cache::{CacheManager, CacheTier};
+
+    let manager = common::setup_in_memory_cache()?.manager;
+    assert!(!manager.is_multi_tier());
+    assert_eq!(manager.tier_count(), 2);
+    assert_eq!(manager.tier_levels(), vec![1, 2]);
+
+    let tiers = vec![
+        CacheTier::new(Arc::new(DashMapCache::new()), 1, false, 1, 1.0),
+        CacheTier::new(Arc::new(DashMapCache::new()), 2, true, 1, 1.0),
+        CacheTier::new(Arc::new(DashMapCache::new()), 3, true, 1, 2.0),
+    ];
+    let manager = CacheManager::new_with_tiers(tiers, None)?;
+    assert!(manager.is_multi_tier());
+    assert_eq!(manager.tier_count(), 3);
+    assert_eq!(manager.tier_levels(), vec![1, 2, 3]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_undecodable_value_policy() -> anyhow::Result<()> {
+    use multi_tier_cache::UndecodablePolicy;
+
+    let common::InMemoryCache { manager, l1, l2 } = common::setup_in_memory_cache()?;
+    l2.set_with_ttl("foreign", Bytes::from("<xml/>"), Duration::from_mins(1))
+        .await?;
+
+    // Default: typed reads fail
+    assert!(matches!(
+        manager.get_typed::<User>("foreign").await,
+        Err(CacheError::SerializationError(_))
+    ));
+
+    // Miss: the computed value replaces the unreadable one
+    let manager = Arc::try_unwrap(manager)
+        .unwrap_or_else(|_| panic!("Manager still shared"))
+        .with_undecodable_policy(UndecodablePolicy::Miss);
+    l1.remove("foreign").await?;
+    assert_eq!(manager.get_typed::<User>("foreign").await?, None);
+    assert!(l2.get("foreign").await.is_some());
+    let user: User = manager
+        .get_or_compute_typed("foreign", CacheStrategy::ShortTerm, || async {
+            Ok(User::new(3))
+        })
+        .await?;
+    assert_eq!(user, User::new(3));
+    assert_eq!(
+        manager.get_typed::<User>("foreign").await?,
+        Some(User::new(3))
+    );
+
+    // Evict: the key is removed from every tier
+    let manager = manager.with_undecodable_policy(UndecodablePolicy::Evict);
+    l1.set_with_ttl("foreign", Bytes::from("<xml/>"), Duration::from_mins(1))
+        .await?;
+    assert_eq!(manager.get_typed::<User>("foreign").await?, None);
+    assert_eq!(l1.get("foreign").await, None);
+    assert_eq!(l2.get("foreign").await, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_get_with_metadata() -> anyhow::Result<()> {
+    use multi_tier_cache::{CacheManager, CacheSource, CacheTier};
+
+    let l1 = Arc::new(DashMapCache::new());
+    let l2 = Arc::new(DashMapCache::new());
+    let l3 = Arc::new(DashMapCache::new());
+    let tiers = vec![
+        CacheTier::new(Arc::clone(&l1) as _, 1, false, 1, 1.0),
+        CacheTier::new(Arc::clone(&l2) as _, 2, true, 1, 1.0),
+        CacheTier::new(Arc::clone(&l3) as _, 3, true, 1, 1.0),
+    ];
+    let manager = CacheManager::new_with_tiers(tiers, None)?;
+
+    let encode = |id| serde_json::to_vec(&User::new(id)).map(Bytes::from);
+    l1.set_with_ttl("meta:a", encode(1)?, Duration::from_mins(1))
+        .await?;
+    l2.set_with_ttl("meta:b", encode(2)?, Duration::from_mins(10))
+        .await?;
+    l3.set_with_ttl("meta:c", encode(3)?, Duration::from_hours(1))
+        .await?;
+    // An L1 copy shadows the lower tiers
+    l3.set_with_ttl("meta:a", encode(9)?, Duration::from_hours(1))
+        .await?;
+
+    let hits = manager
+        .get_batch_with_metadata::<User>(&["meta:a", "meta:b", "meta:missing", "meta:c"])
+        .await?;
+    assert_eq!(hits.len(), 4);
+
+    let expected = [
+        Some((User::new(1), CacheSource::L1, Duration::from_mins(1))),
+        Some((
+            User::new(2),
+            CacheSource::LowerTier(2),
+            Duration::from_mins(10),
+        )),
+        None,
+        Some((
+            User::new(3),
+            CacheSource::LowerTier(3),
+            Duration::from_hours(1),
+        )),
+    ];
+    for (hit, expected) in hits.iter().zip(expected) {
+        match (hit, expected) {
+            (Some(hit), Some((user, source, max_ttl))) => {
+                assert_eq!(hit.value, user);
+                assert_eq!(hit.source, source);
+                let ttl = hit.ttl.unwrap_or_else(|| panic!("TTL missing"));
+                assert!(ttl <= max_ttl && ttl + Duration::from_secs(5) > max_ttl);
+            }
+            (None, None) => {}
+            (hit, expected) => panic!("Got {hit:?}, expected {expected:?}"),
+        }
+    }
+
+    // Read-only: nothing promoted, no requests counted
+    assert_eq!(l1.get("meta:b").await, None);
+    assert_eq!(manager.get_stats().total_requests, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_conditional_get_by_version() -> anyhow::Result<()> {
+    use multi_tier_cache::ConditionalResult;
+
+    let manager = common::setup_in_memory_cache()?.manager;
+
+    assert_eq!(
+        manager.get_if_newer::<User>("versioned", 0).await?,
+        ConditionalResult::Missing
+    );
+
+    let v1 = manager
+        .set_versioned("versioned", &User::new(1), CacheStrategy::ShortTerm)
+        .await?;
+    // A client without a copy gets the value
+    assert_eq!(
+        manager.get_if_newer::<User>("versioned", 0).await?,
+        ConditionalResult::Modified(User::new(1), v1)
+    );
+    // A client holding the current version doesn't
+    assert_eq!(
+        manager.get_if_newer::<User>("versioned", v1).await?,
+        ConditionalResult::NotModified
+    );
+
+    let v2 = manager
+        .set_versioned("versioned", &User::new(2), CacheStrategy::ShortTerm)
+        .await?;
+    assert!(v2 > v1);
+    assert_eq!(
+        manager.get_if_newer::<User>("versioned", v1).await?,
+        ConditionalResult::Modified(User::new(2), v2)
+    );
+    assert_eq!(
+        manager.get_versioned::<User>("versioned").await?,
+        Some((User::new(2), v2))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_versions_are_ordered_across_instances() -> anyhow::Result<()> {
+    use multi_tier_cache::{ConditionalResult, L2CacheBackend};
+
+    let shared_l2 = Arc::new(DashMapCache::new());
+    let first = common::in_memory_manager_with_l2(Arc::clone(&shared_l2))?;
+    let second = common::in_memory_manager_with_l2(Arc::clone(&shared_l2))?;
+
+    let v1 = first
+        .set_versioned("versioned", &User::new(1), CacheStrategy::ShortTerm)
+        .await?;
+    // An instance whose clock runs an hour ahead wrote in between
+    let ahead = v1 + 3_600_000_000;
+    assert_eq!(
+        shared_l2.next_version("versioned", ahead).await?,
+        Some(ahead)
+    );
+
+    // The next write still gets a newer version than every earlier one
+    let v2 = second
+        .set_versioned("versioned", &User::new(2), CacheStrategy::ShortTerm)
+        .await?;
+    assert!(v2 > ahead);
+    assert_eq!(
+        second.get_if_newer::<User>("versioned", ahead).await?,
+        ConditionalResult::Modified(User::new(2), v2)
+    );
+
+    Ok(())
+}
+
+/// Spawner that never runs its tasks, leaving background work queued
+struct IdleSpawner;
+
+impl multi_tier_cache::Spawner for IdleSpawner {
+    fn spawn(&self, _task: futures_util::future::BoxFuture<'static, ()>) {}
+}
+
+#[tokio::test]
+async fn test_flush_pending_writes() -> anyhow::Result<()> {
+    use multi_tier_cache::{CacheSystemBuilder, TierConfig};
+
+    let l1 = Arc::new(DashMapCache::new());
+    let l2 = Arc::new(DashMapCache::new());
+    let cache = CacheSystemBuilder::new()
+        .with_tier(Arc::clone(&l1) as _, TierConfig::as_l1())
+        .with_tier(
+            Arc::clone(&l2) as _,
+            TierConfig::as_l2().with_promotion_frequency(1),
+        )
+        .with_async_promotion(true)
+        .with_spawner(Arc::new(IdleSpawner))
+        .build()
+        .await?;
+    let manager = cache.cache_manager();
+
+    // Nothing queued yet
+    manager.flush_pending_writes().await?;
+
+    for i in 0..3 {
+        let key = format!("flush:{i}");
+        l2.set_with_ttl(&key, Bytes::from("value"), Duration::from_mins(1))
+            .await?;
+        assert!(manager.get(&key).await?.is_some());
+    }
+    // The worker never runs, so the promotions are still queued
+    assert_eq!(l1.get("flush:0").await, None);
+
+    manager.flush_pending_writes().await?;
+    for i in 0..3 {
+        assert_eq!(
+            l1.get(&format!("flush:{i}")).await,
+            Some(Bytes::from("value"))
+        );
+    }
+    assert_eq!(manager.get_stats().promotions, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_expired_promotions_are_counted() -> anyhow::Result<()> {
+    use multi_tier_cache::{CacheSystemBuilder, TierConfig};
+
+    let l1 = Arc::new(DashMapCache::new());
+    let l2 = Arc::new(DashMapCache::new());
+    let cache = CacheSystemBuilder::new()
+        .with_tier(Arc::clone(&l1) as _, TierConfig::as_l1())
+        .with_tier(
+            Arc::clone(&l2) as _,
+            TierConfig::as_l2().with_promotion_frequency(1),
+        )
+        .with_async_promotion(true)
+        .with_spawner(Arc::new(IdleSpawner))
+        .build()
+        .await?;
+    let manager = cache.cache_manager();
+
+    l2.set_with_ttl("late", Bytes::from("value"), Duration::from_mins(1))
+        .await?;
+    assert!(manager.get("late").await?.is_some());
+
+    // Applied after the maximum queueing age, the promotion is dropped
+    sleep(Duration::from_millis(600)).await;
+    manager.flush_pending_writes().await?;
+    assert_eq!(l1.get("late").await, None);
+    let stats = manager.get_stats();
+    assert_eq!(stats.async_promotions_expired, 1);
+    assert_eq!(stats.promotions, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_read_your_writes_with_async_promotion() -> anyhow::Result<()> {
+    use multi_tier_cache::{CacheSystemBuilder, TierConfig};
+
+    let l1 = Arc::new(DashMapCache::new());
+    let l2 = Arc::new(DashMapCache::new());
+    let cache = CacheSystemBuilder::new()
+        .with_tier(Arc::clone(&l1) as _, TierConfig::as_l1())
+        .with_tier(
+            Arc::clone(&l2) as _,
+            TierConfig::as_l2().with_promotion_frequency(1),
+        )
+        .with_async_promotion(true)
+        .with_spawner(Arc::new(IdleSpawner))
+        .build()
+        .await?;
+    let manager = cache.cache_manager();
+
+    // A promotion of the old value is queued, then the key is overwritten
+    l2.set_with_ttl("ryw", Bytes::from("old"), Duration::from_mins(1))
+        .await?;
+    assert_eq!(manager.get("ryw").await?, Some(Bytes::from("old")));
+    manager
+        .set_with_strategy("ryw", Bytes::from("new"), CacheStrategy::ShortTerm)
+        .await?;
+
+    // Applying the stale promotion must not bring the old value back
+    manager.flush_pending_writes().await?;
+    assert_eq!(manager.get("ryw").await?, Some(Bytes::from("new")));
+    assert_eq!(l1.get("ryw").await, Some(Bytes::from("new")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sharded_backend_routing() -> anyhow::Result<()> {
+    use multi_tier_cache::backends::ShardedBackend;
+    use multi_tier_cache::{CacheManager, CacheTier, L2CacheBackend};
+
+    let shards: Vec<Arc<DashMapCache>> = (0..4).map(|_| Arc::new(DashMapCache::new())).collect();
+    let sharded = Arc::new(ShardedBackend::new(shards.clone())?);
+    let l2 = Arc::new(DashMapCache::new());
+    let tiers = vec![
+        CacheTier::new(Arc::clone(&sharded) as _, 1, false, 1, 1.0),
+        CacheTier::new(Arc::clone(&l2) as _, 2, true, 1, 1.0),
+    ];
+    let manager = CacheManager::new_with_tiers(tiers, None)?;
+
+    for id in 0..1000 {
+        manager
+            .set_with_strategy(
+                &format!("shard:{id}"),
+                Bytes::from(id.to_string()),
+                CacheStrategy::ShortTerm,
+            )
+            .await?;
+    }
+
+    // Each key lives in exactly the shard it routes to
+    for id in 0..1000 {
+        let key = format!("shard:{id}");
+        let index = sharded.shard_index(&key);
+        for (i, shard) in shards.iter().enumerate() {
+            assert_eq!(shard.get(&key).await.is_some(), i == index, "{key}");
+        }
+        assert_eq!(sharded.get(&key).await, Some(Bytes::from(id.to_string())));
+        assert!(sharded.get_with_ttl(&key).await.is_some());
+    }
+
+    // Batched reads are split per shard and reassembled in key order
+    let keys: Vec<String> = (995..1005).map(|id| format!("shard:{id}")).collect();
+    let values = sharded.get_many_with_ttl(&keys).await;
+    assert_eq!(values.iter().filter(|value| value.is_some()).count(), 5);
+    assert_eq!(
+        values.first().cloned().flatten().map(|(value, _)| value),
+        Some(Bytes::from("995"))
+    );
+
+    // Keys spread evenly-ish (an even split would be 250 per shard)
+    for shard in &shards {
+        let count = shard.keys().await?.map_or(0, |keys| keys.len());
+        assert!(
+            (150..=350).contains(&count),
+            "Unbalanced shard: {count} keys"
+        );
+    }
+
+    // Enumeration and pattern removal fan out to every shard
+    assert_eq!(sharded.keys().await?.map(|keys| keys.len()), Some(1000));
+    sharded.remove_pattern("shard:1*").await?;
+    assert_eq!(
+        sharded.keys().await?.map(|keys| keys.len()),
+        Some(1000 - 111)
+    );
+    assert!(sharded.health_check().await);
+
+    Ok(())
+}
+
+/// Log writer collecting output for assertions
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<std::sync::Mutex<Vec<u8>>>);
+
+impl std::io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if let Ok(mut logs) = self.0.lock() {
+            logs.extend_from_slice(buf);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_large_value_warning() -> anyhow::Result<()> {
+    use multi_tier_cache::{CacheSystemBuilder, TierConfig};
+    use tracing_subscriber::util::SubscriberInitExt;
+
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+    let _guard = tracing_subscriber::fmt()
+        .with_writer(move || writer.clone())
+        .with_ansi(false)
+        .finish()
+        .set_default();
+
+    let cache = CacheSystemBuilder::new()
+        .with_tier(Arc::new(DashMapCache::new()), TierConfig::as_l1())
+        .with_tier(Arc::new(DashMapCache::new()), TierConfig::as_l2())
+        .with_large_value_threshold(1024)
+        .build()
+        .await?;
+    let manager = cache.cache_manager();
+
+    manager
+        .set_with_strategy(
+            "small",
+            Bytes::from(vec![0; 1024]),
+            CacheStrategy::ShortTerm,
+        )
+        .await?;
+    assert_eq!(manager.get_stats().large_values, 0);
+
+    manager
+        .set_with_strategy("huge", Bytes::from(vec![0; 4096]), CacheStrategy::ShortTerm)
+        .await?;
+    assert_eq!(manager.get_stats().large_values, 1);
+    // Observational only: the value is still cached
+    assert!(manager.get("huge").await?.is_some());
+
+    let output = String::from_utf8(logs.0.lock().map(|l| l.clone()).unwrap_or_default())?;
+    assert!(output.contains("WARN"));
+    assert!(output.contains("Cached large value for key 'huge'"));
+    assert!(!output.contains("key 'small'"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pinned_key_survives_l1_eviction() -> anyhow::Result<()> {
+    use multi_tier_cache::{
+        CacheSystemBuilder, L2CacheBackend, MokaCache, MokaCacheConfig, TierConfig,
+    };
+
+    let l1 = Arc::new(MokaCache::new(MokaCacheConfig {
+        max_capacity: 10,
+        ..MokaCacheConfig::default()
+    })?);
+    let cache = CacheSystemBuilder::new()
+        .with_tier(
+            Arc::clone(&l1) as Arc<dyn L2CacheBackend>,
+            TierConfig::as_l1(),
+        )
+        .with_tier(Arc::new(DashMapCache::new()), TierConfig::as_l2())
+        .build()
+        .await?;
+    let manager = cache.cache_manager();
+
+    assert!(manager.pin("config").await?);
+    manager
+        .set_with_strategy("config", Bytes::from("flags"), CacheStrategy::LongTerm)
+        .await?;
+    for i in 0..500 {
+        manager
+            .set_with_strategy(
+                &format!("filler:{i}"),
+                Bytes::from("x"),
+                CacheStrategy::LongTerm,
+            )
+            .await?;
+    }
+
+    // L1 evicted most fillers but kept the pinned key
+    let l1_keys = l1.keys().await?.unwrap_or_default();
+    assert!(l1_keys.len() < 500, "L1 should have evicted for capacity");
+    assert!(l1_keys.iter().any(|key| key == "config"));
+    assert_eq!(l1.get("config").await, Some(Bytes::from("flags")));
+
+    // Unpinned, the key competes for capacity again
+    assert!(manager.unpin("config").await?);
+    assert_eq!(l1.get("config").await, Some(Bytes::from("flags")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tier_ttl_bounds() -> anyhow::Result<()> {
+    use multi_tier_cache::{CacheSystemBuilder, L2CacheBackend, TierConfig};
+
+    let l1 = Arc::new(DashMapCache::new());
+    let l2 = Arc::new(DashMapCache::new());
+    let l3 = Arc::new(DashMapCache::new());
+    let cache = CacheSystemBuilder::new()
+        .with_tier(
+            Arc::clone(&l1) as Arc<dyn L2CacheBackend>,
+            TierConfig::as_l1().with_ttl_bounds(None, Some(Duration::from_mins(1))),
+        )
+        .with_tier(
+            Arc::clone(&l2) as Arc<dyn L2CacheBackend>,
+            TierConfig::as_l2(),
+        )
+        .with_tier(
+            Arc::clone(&l3) as Arc<dyn L2CacheBackend>,
+            TierConfig::as_l3().with_ttl_bounds(Some(Duration::from_hours(24)), None),
+        )
+        .build()
+        .await?;
+
+    // LongTerm is 3h: L1 clamps it down, L2 keeps it, L3 (2x = 6h) clamps it up
+    cache
+        .cache_manager()
+        .set_with_strategy("bounded", Bytes::from("v"), CacheStrategy::LongTerm)
+        .await?;
+
+    let ttl_of = |entry: Option<(Bytes, Option<Duration>)>| entry.and_then(|(_, ttl)| ttl);
+    let l1_ttl = ttl_of(l1.get_with_ttl("bounded").await).unwrap_or_default();
+    let l2_ttl = ttl_of(l2.get_with_ttl("bounded").await).unwrap_or_default();
+    let l3_ttl = ttl_of(l3.get_with_ttl("bounded").await).unwrap_or_default();
+    assert!(l1_ttl <= Duration::from_mins(1) && l1_ttl > Duration::from_secs(55));
+    assert!(l2_ttl <= Duration::from_hours(3) && l2_ttl > Duration::from_mins(179));
+    assert!(l3_ttl <= Duration::from_hours(24) && l3_ttl > Duration::from_hours(23));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_explain_key_resolution() -> anyhow::Result<()> {
+    use multi_tier_cache::{CacheManager, CacheSource, CacheTier, L2CacheBackend, TierPresence};
+
+    let l1 = Arc::new(DashMapCache::new());
+    let l2 = Arc::new(DashMapCache::new());
+    let l3 = Arc::new(DashMapCache::new());
+    let tiers = vec![
+        CacheTier::new(Arc::clone(&l1) as Arc<dyn L2CacheBackend>, 1, false, 1, 1.0),
+        CacheTier::new(Arc::clone(&l2) as Arc<dyn L2CacheBackend>, 2, true, 1, 1.0),
+        CacheTier::new(Arc::clone(&l3) as Arc<dyn L2CacheBackend>, 3, true, 5, 1.0),
+    ];
+    let manager = CacheManager::new_with_tiers(tiers, None)?;
+
+    // Only L3 holds the key: a get would serve from L3 and promote upward
+    l3.set_with_ttl("cold", Bytes::from("v"), Duration::from_mins(10))
+        .await?;
+    let explain = manager.explain("cold").await;
+    assert_eq!(explain.storage_key, "cold");
+    assert_eq!(explain.served_by, Some(CacheSource::LowerTier(3)));
+    assert_eq!(explain.promotes_to, vec![2, 1]);
+    assert_eq!(explain.promotion_frequency, 5);
+    let presence: Vec<(usize, bool)> = explain
+        .tiers
+        .iter()
+        .map(|tier| (tier.tier_level, tier.present))
+        .collect();
+    assert_eq!(presence, vec![(1, false), (2, false), (3, true)]);
+    let l3_ttl = explain.tiers.get(2).and_then(|tier| tier.ttl);
+    assert!(l3_ttl.is_some_and(|ttl| ttl > Duration::from_mins(9)));
+
+    // Read-only: nothing promoted or counted
+    assert_eq!(l1.get("cold").await, None);
+    assert_eq!(l2.get("cold").await, None);
+    assert_eq!(manager.get_stats().total_requests, 0);
+
+    // In L1: served from L1 without promotion
+    l1.set_with_ttl("cold", Bytes::from("v"), Duration::from_mins(1))
+        .await?;
+    let explain = manager.explain("cold").await;
+    assert_eq!(explain.served_by, Some(CacheSource::L1));
+    assert!(explain.promotes_to.is_empty());
+
+    // Missing everywhere
+    let explain = manager.explain("absent").await;
+    assert_eq!(explain.served_by, None);
+    assert!(explain.tiers.iter().all(|tier| *tier
+        == TierPresence {
+            tier_level: tier.tier_level,
+            present: false,
+            ttl: None,
+        }));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalidate_l1_by_predicate() -> anyhow::Result<()> {
+    use multi_tier_cache::{
+        CacheSystemBuilder, L2CacheBackend, MokaCache, MokaCacheConfig, TierConfig,
+    };
+
+    let moka = Arc::new(MokaCache::new(MokaCacheConfig::default())?);
+    let dashmap = Arc::new(DashMapCache::new());
+    for l1 in [
+        Arc::clone(&moka) as Arc<dyn L2CacheBackend>,
+        Arc::clone(&dashmap) as Arc<dyn L2CacheBackend>,
+    ] {
+        let l2 = Arc::new(DashMapCache::new());
+        let cache = CacheSystemBuilder::new()
+            .with_tier(Arc::clone(&l1), TierConfig::as_l1())
+            .with_tier(
+                Arc::clone(&l2) as Arc<dyn L2CacheBackend>,
+                TierConfig::as_l2(),
+            )
+            .build()
+            .await?;
+        let manager = cache.cache_manager();
+        for key in ["tenant:1:a", "tenant:1:b", "tenant:2:a"] {
+            manager
+                .set_with_strategy(key, Bytes::from("v"), CacheStrategy::ShortTerm)
+                .await?;
+        }
+
+        manager
+            .invalidate_l1_if(|key| key.starts_with("tenant:1:"))
+            .await?;
+
+        assert_eq!(l1.get("tenant:1:a").await, None, "{}", l1.name());
+        assert_eq!(l1.get("tenant:1:b").await, None, "{}", l1.name());
+        assert!(l1.get("tenant:2:a").await.is_some(), "{}", l1.name());
+        // L2 is untouched
+        assert!(l2.get("tenant:1:a").await.is_some());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_l1_fill_ratio() -> anyhow::Result<()> {
+    use multi_tier_cache::{
+        CacheSystemBuilder, L2CacheBackend, MokaCache, MokaCacheConfig, TierConfig,
+    };
+
+    let l1 = Arc::new(MokaCache::new(MokaCacheConfig {
+        max_capacity: 100,
+        ..MokaCacheConfig::default()
+    })?);
+    let cache = CacheSystemBuilder::new()
+        .with_tier(
+            Arc::clone(&l1) as Arc<dyn L2CacheBackend>,
+            TierConfig::as_l1(),
+        )
+        .with_tier(Arc::new(DashMapCache::new()), TierConfig::as_l2())
+        .build()
+        .await?;
+    let manager = cache.cache_manager();
+    assert_eq!(l1.capacity(), Some(100));
+    assert_eq!(manager.l1_fill_ratio(), Some(0.0));
+
+    let mut last_ratio = 0.0;
+    for batch in 0..3 {
+        for i in 0..25 {
+            manager
+                .set_with_strategy(
+                    &format!("fill:{batch}:{i}"),
+                    Bytes::from("v"),
+                    CacheStrategy::ShortTerm,
+                )
+                .await?;
+        }
+        l1.run_pending_tasks().await;
+        let ratio = manager.l1_fill_ratio().unwrap_or_default();
+        assert!(
+            ratio > last_ratio,
+            "fill ratio should rise: {ratio} <= {last_ratio}"
+        );
+        last_ratio = ratio;
+    }
+    assert!((last_ratio - 0.75).abs() < f64::EPSILON);
+
+    // Unbounded L1: no ratio
+    let manager = common::setup_in_memory_cache()?.manager;
+    assert_eq!(manager.l1_fill_ratio(), None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_or_compute_in_tiers() -> anyhow::Result<()> {
+    use multi_tier_cache::TierMask;
+
+    let common::InMemoryCache { manager, l1, l2 } = common::setup_in_memory_cache()?;
+    let value = Bytes::from(vec![1_u8; 4096]);
+
+    let computed = manager
+        .get_or_compute_in_tiers(
+            "huge",
+            CacheStrategy::ShortTerm,
+            TierMask::L1_ONLY,
+            || async { Ok(value.clone()) },
+        )
+        .await?;
+    assert_eq!(computed, value);
+    assert_eq!(l1.get("huge").await, Some(value.clone()));
+    assert_eq!(l2.get("huge").await, None, "L2 must not receive the value");
+
+    // Served from L1 afterwards, without recomputing
+    let cached = manager
+        .get_or_compute_in_tiers(
+            "huge",
+            CacheStrategy::ShortTerm,
+            TierMask::L1_ONLY,
+            || async {
+                Err(CacheError::InternalError(
+                    "should not recompute".to_string(),
+                ))
+            },
+        )
+        .await?;
+    assert_eq!(cached, value);
+
+    // The other way round: L2 only
+    manager
+        .get_or_compute_in_tiers(
+            "shared",
+            CacheStrategy::ShortTerm,
+            TierMask::level(2),
+            || async { Ok(Bytes::from("v")) },
+        )
+        .await?;
+    assert_eq!(l1.get("shared").await, None);
+    assert_eq!(l2.get("shared").await, Some(Bytes::from("v")));
+
+    assert!(TierMask::ALL.contains(100));
+    assert!(!TierMask::L1_ONLY.with_level(3).contains(2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_in_flight_wait_timeout() -> anyhow::Result<()> {
+    use std::time::Instant;
+
+    let manager = Arc::new(
+        common::in_memory_manager_with_l2(Arc::new(DashMapCache::new()))?
+            .with_in_flight_wait_timeout(Duration::from_millis(100)),
+    );
+
+    // Holds the in-flight lock far longer than the timeout
+    let holder = {
+        let manager = Arc::clone(&manager);
+        tokio::spawn(async move {
+            manager
+                .get_or_compute_with("slow", CacheStrategy::ShortTerm, || async {
+                    tokio::time::sleep(Duration::from_secs(2)).await;
+                    Ok(Bytes::from("holder"))
+                })
+                .await
+        })
+    };
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    // Gives up waiting and computes on its own
+    let started = Instant::now();
+    let value = manager
+        .get_or_compute_with("slow", CacheStrategy::ShortTerm, || async {
+            Ok(Bytes::from("waiter"))
+        })
+        .await?;
+    assert_eq!(value, Bytes::from("waiter"));
+    assert!(started.elapsed() < Duration::from_secs(1));
+
+    holder.abort();
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_raw_serializer_stores_bytes_unencoded() -> anyhow::Result<()> {
+    use multi_tier_cache::serialization::RawSerializer;
+    use multi_tier_cache::{CacheSerializer, JsonSerializer};
+
+    // A protobuf-like payload covering every byte value
+    let payload: Bytes = (0..4).flat_map(|_| 0..=u8::MAX).collect();
+
+    let raw = CacheSerializer::Raw(RawSerializer);
+    let json = CacheSerializer::Json(JsonSerializer);
+    let raw_size = raw.serialize(&payload)?.len();
+    let json_size = json.serialize(&payload)?.len();
+    assert_eq!(raw_size, payload.len());
+    assert!(
+        json_size > 3 * raw_size,
+        "JSON should inflate bytes: {json_size} vs {raw_size}"
+    );
+
+    // Vec<u8> and strings are raw too; structured values are rejected
+    let vec = payload.to_vec();
+    assert_eq!(raw.serialize(&vec)?, payload);
+    assert_eq!(raw.deserialize::<Vec<u8>>(&payload)?, vec);
+    assert_eq!(raw.deserialize::<String>(&raw.serialize(&"text")?)?, "text");
+    assert!(raw.serialize(&42u32).is_err());
+
+    // Typed round trip through the cache
+    let l2 = Arc::new(DashMapCache::new());
+    let mut manager = common::in_memory_manager_with_l2(Arc::clone(&l2))?;
+    manager.set_serializer(raw);
+    let cached: Bytes = manager
+        .get_or_compute_typed("proto:1", CacheStrategy::ShortTerm, || {
+            let payload = payload.clone();
+            async move { Ok(payload) }
+        })
+        .await?;
+    assert_eq!(cached, payload);
+    assert_eq!(l2.get("proto:1").await, Some(payload.clone()));
+    assert_eq!(manager.get_typed::<Bytes>("proto:1").await?, Some(payload));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_run_until_shutdown_signal() -> anyhow::Result<()> {
+    use multi_tier_cache::{CacheSystemBuilder, TierConfig};
+
+    let l1 = Arc::new(DashMapCache::new());
+    let l2 = Arc::new(DashMapCache::new());
+    let cache = CacheSystemBuilder::new()
+        .with_tier(Arc::clone(&l1) as Arc<_>, TierConfig::as_l1())
+        .with_tier(
+            Arc::clone(&l2) as Arc<_>,
+            TierConfig::as_l2().with_promotion_frequency(1),
+        )
+        .with_async_promotion(true)
+        .build()
+        .await?;
+
+    let (stop, signal) = tokio::sync::oneshot::channel::<()>();
+    let lifecycle = tokio::spawn({
+        let cache = cache.clone();
+        async move {
+            cache
+                .run_until(async {
+                    let _ = signal.await;
+                })
+                .await
+        }
+    });
+
+    // Still running: queue an async promotion
+    l2.set_with_ttl("queued", Bytes::from("v"), Duration::from_mins(1))
+        .await?;
+    assert_eq!(
+        cache.cache_manager().get("queued").await?,
+        Some(Bytes::from("v"))
+    );
+    sleep(Duration::from_millis(50)).await;
+    assert!(!lifecycle.is_finished(), "Should run until the signal");
+
+    stop.send(())
+        .unwrap_or_else(|()| panic!("Lifecycle task should be waiting"));
+    tokio::time::timeout(Duration::from_secs(1), lifecycle).await???;
+
+    // The promotion was applied by the time it returned, and the manager still works
+    assert_eq!(l1.get("queued").await, Some(Bytes::from("v")));
+    assert!(cache.cache_manager().get("queued").await?.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalidate_overlapping_patterns() -> anyhow::Result<()> {
+    let cache = common::setup_in_memory_cache()?;
+    for key in [
+        "user:42:profile",
+        "user:42:orders",
+        "session:42:web",
+        "user:7:profile",
+    ] {
+        cache
+            .manager
+            .set_with_strategy(key, Bytes::from("v"), CacheStrategy::ShortTerm)
+            .await?;
+    }
+
+    // `user:*` overlaps `user:42:*`; each key still counts once
+    let removed = cache
+        .manager
+        .invalidate_patterns(&["user:42:*", "user:*", "session:42:*"])
+        .await?;
+    assert_eq!(removed, 4);
+
+    for key in [
+        "user:42:profile",
+        "user:42:orders",
+        "session:42:web",
+        "user:7:profile",
+    ] {
+        assert_eq!(cache.l1.get(key).await, None);
+        assert_eq!(cache.l2.get(key).await, None);
+    }
+    assert_eq!(cache.manager.invalidate_patterns(&["user:*"]).await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_coherence_sampling_detects_stale_l1() -> anyhow::Result<()> {
+    let l2 = Arc::new(DashMapCache::new());
+    let manager = common::in_memory_manager_with_l2(Arc::clone(&l2))?.with_coherence_sampling(1.0);
+
+    manager
+        .set_with_strategy("coherent", Bytes::from("v1"), CacheStrategy::ShortTerm)
+        .await?;
+    manager
+        .set_with_strategy("diverged", Bytes::from("v1"), CacheStrategy::ShortTerm)
+        .await?;
+    manager
+        .set_with_strategy("gone", Bytes::from("v1"), CacheStrategy::ShortTerm)
+        .await?;
+
+    // Another instance changed or deleted the keys in L2, but no invalidation arrived
+    l2.set_with_ttl("diverged", Bytes::from("v2"), Duration::from_mins(1))
+        .await?;
+    l2.remove("gone").await?;
+
+    for key in ["coherent", "diverged", "gone"] {
+        // L1 still serves its copy
+        assert_eq!(manager.get(key).await?, Some(Bytes::from("v1")));
+    }
+    assert_eq!(manager.get_stats().stale_l1_hits, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_nan_sample_rates_disable_sampling() -> anyhow::Result<()> {
+    use multi_tier_cache::L2MissPolicy;
+
+    let l2 = Arc::new(DashMapCache::new());
+    let manager = common::in_memory_manager_with_l2(Arc::clone(&l2))?
+        .with_l2_miss_policy(L2MissPolicy::FlushL1)
+        .with_l2_miss_sampling(f64::NAN)
+        .with_coherence_sampling(f64::NAN);
+
+    manager
+        .set_with_strategy("sampled", Bytes::from("v1"), CacheStrategy::ShortTerm)
+        .await?;
+    l2.remove("sampled").await?;
+
+    // Neither check runs (nor panics): L1 keeps serving its copy
+    assert_eq!(manager.get("sampled").await?, Some(Bytes::from("v1")));
+    assert_eq!(manager.get_stats().stale_l1_hits, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalidate_tag_intersection() -> anyhow::Result<()> {
+    let cache = common::setup_in_memory_cache()?;
+    let tagged: [(&str, &[&str]); 4] = [
+        ("order:1", &["user:42", "orders"]),
+        ("profile:42", &["user:42"]),
+        ("order:2", &["orders"]),
+        ("order:3", &["user:42", "orders", "vip"]),
+    ];
+    for (key, tags) in tagged {
+        cache
+            .manager
+            .set_with_tags(key, Bytes::from("v"), CacheStrategy::ShortTerm, tags)
+            .await?;
+    }
+
+    // Only keys carrying both tags go
+    let removed = cache
+        .manager
+        .invalidate_tags_all(&["user:42", "orders"])
+        .await?;
+    assert_eq!(removed, 2);
+    for key in ["order:1", "order:3"] {
+        assert_eq!(cache.manager.get(key).await?, None);
+    }
+    for key in ["profile:42", "order:2"] {
+        assert!(cache.l1.get(key).await.is_some());
+        assert!(cache.l2.get(key).await.is_some());
+    }
+
+    // A single tag matches every key carrying it; removed keys left the sets
+    assert_eq!(cache.manager.invalidate_tags_all(&["user:42"]).await?, 1);
+    assert_eq!(cache.manager.get("profile:42").await?, None);
+    assert_eq!(cache.manager.invalidate_tags_all(&["orders"]).await?, 1);
+    assert_eq!(cache.manager.invalidate_tags_all(&[]).await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_secondary_index_members() -> anyhow::Result<()> {
+    let cache = common::setup_in_memory_cache()?;
+    for id in [3, 1, 2] {
+        let key = format!("user:42:order:{id}");
+        cache
+            .manager
+            .set_indexed(
+                &key,
+                &User::new(id),
+                CacheStrategy::ShortTerm,
+                "user:42",
+                &key,
+            )
+            .await?;
+    }
+    cache
+        .manager
+        .set_indexed(
+            "user:7:order:1",
+            &User::new(1),
+            CacheStrategy::ShortTerm,
+            "user:7",
+            "user:7:order:1",
+        )
+        .await?;
+
+    // Members come back sorted, and only those of the requested index
+    assert_eq!(
+        cache.manager.get_index_members("user:42").await?,
+        ["user:42:order:1", "user:42:order:2", "user:42:order:3"]
+    );
+    assert_eq!(
+        cache.manager.get_index_members("user:7").await?,
+        ["user:7:order:1"]
+    );
+    assert!(cache.manager.get_index_members("user:0").await?.is_empty());
+
+    // Values are stored like any typed write
+    assert_eq!(
+        cache.manager.get_typed::<User>("user:42:order:2").await?,
+        Some(User::new(2))
+    );
+
+    // Invalidating through the index removes the member as well
+    cache
+        .manager
+        .invalidate_indexed("user:42:order:2", "user:42", "user:42:order:2")
+        .await?;
+    assert_eq!(
+        cache.manager.get_typed::<User>("user:42:order:2").await?,
+        None
+    );
+    assert_eq!(
+        cache.manager.get_index_members("user:42").await?,
+        ["user:42:order:1", "user:42:order:3"]
+    );
+    cache
+        .manager
+        .remove_index_member("user:42", "user:42:order:1")
+        .await?;
+    assert_eq!(
+        cache.manager.get_index_members("user:42").await?,
+        ["user:42:order:3"]
+    );
+
+    // Dropping an index leaves its keys cached
+    cache.manager.drop_index("user:7").await?;
+    assert!(cache.manager.get_index_members("user:7").await?.is_empty());
+    assert_eq!(
+        cache.manager.get_typed::<User>("user:7:order:1").await?,
+        Some(User::new(1))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_adaptive_ttl_extends_hot_keys() -> anyhow::Result<()> {
+    use multi_tier_cache::AdaptiveTtl;
+    use multi_tier_cache::traits::L2CacheBackend;
+
+    let l2 = Arc::new(DashMapCache::new());
+    let manager =
+        common::in_memory_manager_with_l2(Arc::clone(&l2))?.with_adaptive_ttl(AdaptiveTtl {
+            min_hits: 3,
+            max_ttl: Duration::from_hours(1),
+            ..AdaptiveTtl::default()
+        });
+    let written = Duration::from_mins(5);
+    for key in ["hot", "cold"] {
+        manager
+            .set_with_strategy(key, Bytes::from("v"), CacheStrategy::Custom(written))
+            .await?;
+    }
+
+    for _ in 0..10 {
+        manager.get("hot").await?;
+    }
+    manager.get("cold").await?;
+
+    let remaining = |key: &'static str| {
+        let l2 = Arc::clone(&l2);
+        async move {
+            l2.get_with_ttl(key)
+                .await
+                .and_then(|(_, ttl)| ttl)
+                .unwrap_or_default()
+        }
+    };
+    let hot = remaining("hot").await;
+    assert!(hot > written, "Hot key TTL should grow, got {hot:?}");
+    assert!(hot <= Duration::from_hours(1));
+    assert!(remaining("cold").await <= written);
+    assert!(manager.get_stats().ttl_extensions > 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_adaptive_ttl_keeps_newer_lower_tier_value() -> anyhow::Result<()> {
+    use multi_tier_cache::AdaptiveTtl;
+    use multi_tier_cache::traits::{CacheBackend, L2CacheBackend};
+
+    let l1 = Arc::new(DashMapCache::new());
+    let l2 = Arc::new(DashMapCache::new());
+    let l3 = Arc::new(DashMapCache::new());
+    let cache = multi_tier_cache::CacheSystemBuilder::new()
+        .with_tier(Arc::clone(&l1) as _, multi_tier_cache::TierConfig::as_l1())
+        .with_tier(Arc::clone(&l2) as _, multi_tier_cache::TierConfig::as_l2())
+        .with_tier(Arc::clone(&l3) as _, multi_tier_cache::TierConfig::as_l3())
+        .with_adaptive_ttl(AdaptiveTtl {
+            min_hits: 1,
+            max_ttl: Duration::from_hours(1),
+            ..AdaptiveTtl::default()
+        })
+        .build()
+        .await?;
+    let manager = cache.cache_manager();
+
+    let written = Duration::from_mins(5);
+    manager
+        .set_with_strategy("hot", Bytes::from("old"), CacheStrategy::Custom(written))
+        .await?;
+    // Another instance wrote a newer value to L2; this L1 copy is stale
+    l2.set_with_ttl("hot", Bytes::from("new"), written).await?;
+    // And the key was never placed in L3
+    l3.remove("hot").await?;
+
+    for _ in 0..5 {
+        manager.get("hot").await?;
+    }
+    assert!(manager.get_stats().ttl_extensions > 0);
+
+    let (value, ttl) = l2
+        .get_with_ttl("hot")
+        .await
+        .ok_or_else(|| anyhow::anyhow!("L2 lost the key"))?;
+    assert_eq!(value, Bytes::from("new"));
+    assert!(
+        ttl.unwrap_or_default() > written,
+        "L2 expiry should be extended"
+    );
+    assert!(l3.get("hot").await.is_none(), "L3 must not gain the key");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sliding_expiration_keeps_read_keys_alive() -> anyhow::Result<()> {
+    use multi_tier_cache::traits::CacheBackend;
+
+    let window = Duration::from_millis(300);
+    let l2 = Arc::new(DashMapCache::new());
+    let manager =
+        common::in_memory_manager_with_l2(Arc::clone(&l2))?.with_sliding_expiration(window);
+    manager
+        .set_with_strategy("session", Bytes::from("v"), CacheStrategy::Custom(window))
+        .await?;
+
+    // Read well past the original TTL
+    for _ in 0..10 {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(manager.get("session").await?.is_some());
+    }
+    assert!(
+        l2.get("session").await.is_some(),
+        "L2 should be touched too"
+    );
+
+    // Without reads it expires after one window
+    tokio::time::sleep(window + Duration::from_millis(100)).await;
+    assert!(manager.get("session").await?.is_none());
+
+    // Reads stop extending a key at the lifetime cap
+    let capped = common::in_memory_manager_with_l2(Arc::new(DashMapCache::new()))?
+        .with_sliding_expiration(Duration::from_millis(200))
+        .with_sliding_expiration_limit(Duration::from_millis(400));
+    capped
+        .set_with_strategy(
+            "session",
+            Bytes::from("v"),
+            CacheStrategy::Custom(Duration::from_millis(200)),
+        )
+        .await?;
+    let mut alive = true;
+    for _ in 0..8 {
+        alive = capped.get("session").await?.is_some();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(!alive, "Key should expire at the lifetime cap");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_many_isolates_key_failures() -> anyhow::Result<()> {
+    let common::InMemoryCache { manager, l1, l2 } = common::setup_in_memory_cache()?;
+    for id in [1, 2] {
+        manager
+            .get_or_compute_typed(
+                &format!("user:{id}"),
+                CacheStrategy::ShortTerm,
+                || async move { Ok(User::new(id)) },
+            )
+            .await?;
+    }
+    l1.remove("user:2").await?;
+    l2.set_with_ttl("user:3", Bytes::from("<xml/>"), Duration::from_mins(1))
+        .await?;
+
+    let results = manager
+        .get_many::<User>(&["user:1", "user:2", "user:3", "user:4"])
+        .await;
+    assert_eq!(results.len(), 4);
+    let mut results = results.into_iter();
+    assert_eq!(results.next().transpose()?.flatten(), Some(User::new(1)));
+    assert_eq!(results.next().transpose()?.flatten(), Some(User::new(2)));
+    assert!(matches!(
+        results.next(),
+        Some(Err(CacheError::SerializationError(_)))
+    ));
+    assert!(matches!(results.next(), Some(Ok(None))));
+
+    // The L2 hit was promoted
+    assert!(l1.get("user:2").await.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_merge_stats_weights_by_requests() -> anyhow::Result<()> {
+    let busy = common::setup_in_memory_cache()?.manager;
+    let quiet = common::setup_in_memory_cache()?.manager;
+
+    // Quiet shard: 10 requests, all hits. Busy shard: 90 requests, all misses.
+    quiet
+        .set_with_strategy("hot", Bytes::from("v"), CacheStrategy::ShortTerm)
+        .await?;
+    for _ in 0..10 {
+        quiet.get("hot").await?;
+    }
+    for i in 0..90 {
+        busy.get(&format!("cold:{i}")).await?;
+    }
+
+    let (quiet, busy) = (quiet.get_stats(), busy.get_stats());
+    let merged = quiet.merge(&busy);
+    assert_eq!(merged.total_requests, 100);
+    assert_eq!(merged.total_hits, 10);
+    assert_eq!(merged.misses, 90);
+    assert_eq!(merged.sets, 1);
+    // The true aggregate, not the average of 100% and 0%
+    assert!((merged.hit_rate - 10.0).abs() < f64::EPSILON);
+    assert!((merged.l1_hit_rate - 10.0).abs() < f64::EPSILON);
+    assert!((f64::midpoint(quiet.hit_rate, busy.hit_rate) - merged.hit_rate).abs() > 1.0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_or_compute_with_ttl_uses_returned_ttl() -> anyhow::Result<()> {
+    use multi_tier_cache::L2CacheBackend;
+
+    let cache = common::setup_in_memory_cache()?;
+
+    let short: String = cache
+        .manager
+        .get_or_compute_with_ttl("ttl:short", || async {
+            Ok(("short".to_string(), Duration::from_mins(1)))
+        })
+        .await?;
+    let long: String = cache
+        .manager
+        .get_or_compute_with_ttl("ttl:long", || async {
+            Ok(("long".to_string(), Duration::from_hours(1)))
+        })
+        .await?;
+    assert_eq!(short, "short");
+    assert_eq!(long, "long");
+
+    let remaining = |key: &'static str| {
+        let l2 = Arc::clone(&cache.l2);
+        async move {
+            l2.get_with_ttl(key)
+                .await
+                .and_then(|(_, ttl)| ttl)
+                .ok_or_else(|| anyhow::anyhow!("{key} should be cached with a TTL"))
+        }
+    };
+    let short_ttl = remaining("ttl:short").await?;
+    let long_ttl = remaining("ttl:long").await?;
+    assert!(short_ttl <= Duration::from_mins(1));
+    assert!(long_ttl > Duration::from_mins(1) && long_ttl <= Duration::from_hours(1));
+
+    // Hits return the cached value without recomputing
+    let cached: String = cache
+        .manager
+        .get_or_compute_with_ttl("ttl:short", || async {
+            Err(CacheError::InternalError(
+                "should not recompute".to_string(),
+            ))
+        })
+        .await?;
+    assert_eq!(cached, "short");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_requires_supporting_backend() -> anyhow::Result<()> {
+    use multi_tier_cache::Transaction;
+
+    let cache = common::setup_in_memory_cache()?;
+    cache
+        .manager
+        .set_with_strategy("tx:a", Bytes::from("1"), CacheStrategy::ShortTerm)
+        .await?;
+
+    let result = cache
+        .manager
+        .execute_transaction(
+            Transaction::new()
+                .set("tx:a", Bytes::from("2"), Duration::from_secs(30))
+                .remove("tx:b"),
+        )
+        .await;
+    assert!(matches!(result, Err(CacheError::BackendError(_))));
+    assert_eq!(cache.l2.get("tx:a").await, Some(Bytes::from("1")));
+    assert!(cache.manager.swap("tx:a", "tx:b").await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_global_memory_cap_evicts_coldest_tier_first() -> anyhow::Result<()> {
+    use multi_tier_cache::{CacheManager, CacheTier};
+
+    let l1 = Arc::new(DashMapCache::new());
+    let l2 = Arc::new(DashMapCache::new());
+    let l3 = Arc::new(DashMapCache::new());
+    let tiers = vec![
+        CacheTier::new(Arc::clone(&l1) as _, 1, false, 1, 1.0),
+        CacheTier::new(Arc::clone(&l2) as _, 2, true, 1, 1.0),
+        CacheTier::new(Arc::clone(&l3) as _, 3, true, 1, 1.0),
+    ];
+    let max_bytes = 3000;
+    let manager = CacheManager::new_with_tiers(tiers, None)?.with_global_max_bytes(max_bytes);
+
+    // Each write stores 6 + 100 bytes in every tier
+    let value = Bytes::from(vec![b'x'; 100]);
+    for i in 0..20 {
+        manager
+            .set_with_strategy(
+                &format!("mem:{i:02}"),
+                value.clone(),
+                CacheStrategy::ShortTerm,
+            )
+            .await?;
+        assert!(manager.estimated_memory_bytes() <= max_bytes);
+    }
+
+    // The hottest tier keeps everything; the coldest shed entries first
+    assert_eq!(l1.len(), 20);
+    assert!(l3.len() < l2.len(), "L3 {} vs L2 {}", l3.len(), l2.len());
+    assert!(l2.len() < l1.len());
+    assert_eq!(
+        manager.estimated_memory_bytes(),
+        [&l1, &l2, &l3]
+            .iter()
+            .filter_map(|tier| tier.estimated_size_bytes())
+            .sum::<u64>()
+    );
+    assert!(manager.get_stats().memory_evicted_bytes > 0);
+
+    // Evicted keys are still served from the hotter tiers
+    assert_eq!(manager.get("mem:00").await?, Some(value));
+
+    Ok(())
+}
+
+#[cfg(feature = "moka")]
+#[tokio::test]
+async fn test_moka_estimated_size_tracks_writes() -> anyhow::Result<()> {
+    use multi_tier_cache::{MokaCache, MokaCacheConfig};
+
+    let moka = MokaCache::new(MokaCacheConfig::default())?;
+    let ttl = Duration::from_mins(1);
+    moka.set_with_ttl("size:a", Bytes::from(vec![0; 100]), ttl)
+        .await?;
+    moka.set_with_ttl("size:b", Bytes::from(vec![0; 50]), ttl)
+        .await?;
+    assert_eq!(moka.estimated_size_bytes(), Some(106 + 56));
+
+    // Overwrites release the replaced value
+    moka.set_with_ttl("size:a", Bytes::from(vec![0; 10]), ttl)
+        .await?;
+    assert!(
+        moka.set_if_present("size:b", Bytes::from(vec![0; 20]), ttl)
+            .await?
+    );
+    assert_eq!(moka.estimated_size_bytes(), Some(16 + 26));
+
+    // Pinned entries still count, removed ones don't
+    moka.set_pinned("size:a", true).await?;
+    moka.remove("size:b").await?;
+    assert_eq!(moka.estimated_size_bytes(), Some(16));
+    moka.set_pinned("size:a", false).await?;
+    assert_eq!(moka.estimated_size_bytes(), Some(16));
+    assert!(moka.get_and_remove("size:a").await?.is_some());
+    assert_eq!(moka.estimated_size_bytes(), Some(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_moka_touch_keeps_value_and_typed_entry() -> anyhow::Result<()> {
+    use multi_tier_cache::{L2CacheBackend, MokaCache, MokaCacheConfig};
+
+    let moka = MokaCache::new(MokaCacheConfig::default())?;
+    let short = Duration::from_secs(5);
+    moka.set_with_ttl("touch:a", Bytes::from("value"), short)
+        .await?;
+    moka.set_typed("touch:a", Arc::new(7_u32), short).await?;
+
+    let long = Duration::from_mins(10);
+    assert!(moka.touch("touch:a", long).await?);
+    assert!(!moka.touch("touch:missing", long).await?);
+
+    let (value, ttl) = moka
+        .get_with_ttl("touch:a")
+        .await
+        .ok_or_else(|| anyhow::anyhow!("touched key is gone"))?;
+    assert_eq!(value, Bytes::from("value"));
+    assert!(ttl.is_some_and(|ttl| ttl > short));
+    // The typed entry is kept, and re-inserting doesn't change the size
+    let typed = moka
+        .get_typed("touch:a")
+        .await
+        .and_then(|value| value.downcast::<u32>().ok());
+    assert_eq!(typed.as_deref(), Some(&7));
+    assert_eq!(moka.estimated_size_bytes(), Some(12));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_l1_hits_skip_in_flight_map() -> anyhow::Result<()> {
+    let cache = common::setup_in_memory_cache()?;
+    let manager = &cache.manager;
+    manager
+        .set_with_strategy("fast:hit", Bytes::from("1"), CacheStrategy::ShortTerm)
+        .await?;
+
+    for _ in 0..10 {
+        assert!(manager.get("fast:hit").await?.is_some());
+        manager
+            .get_or_compute_with("fast:hit", CacheStrategy::ShortTerm, || async {
+                Ok(Bytes::from("recomputed"))
+            })
+            .await?;
+        let value: u64 = manager
+            .get_or_compute_typed("fast:hit", CacheStrategy::ShortTerm, || async { Ok(2) })
+            .await?;
+        assert_eq!(value, 1);
+    }
+    assert_eq!(manager.get_stats().in_flight_lookups, 0);
+
+    // Misses do go through the map to coalesce
+    assert!(manager.get("fast:miss").await?.is_none());
+    assert_eq!(manager.get_stats().in_flight_lookups, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tier_byte_counters() -> anyhow::Result<()> {
+    let cache = common::setup_in_memory_cache()?;
+    let value = Bytes::from(vec![7_u8; 1000]);
+
+    cache
+        .manager
+        .set_with_strategy("bytes:key", value.clone(), CacheStrategy::ShortTerm)
+        .await?;
+    // Force an L2 read (and promotion back into L1)
+    cache.l1.remove("bytes:key").await?;
+    assert_eq!(cache.manager.get("bytes:key").await?, Some(value));
+
+    let stats = cache.manager.get_stats();
+    assert_eq!(stats.l2_bytes_written, 1000);
+    assert_eq!(stats.l2_bytes_read, 1000);
+
+    let tiers = cache.manager.get_tier_stats();
+    let l1 = tiers
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("Missing L1 stats"))?;
+    assert_eq!(l1.bytes_written(), 2000, "Set plus promotion");
+    assert_eq!(l1.bytes_read(), 0);
+
+    Ok(())
+}
+
+#[cfg(feature = "metrics-facade")]
+#[test]
+fn test_metrics_facade_emits_cache_metrics() -> anyhow::Result<()> {
+    use metrics_util::debugging::DebuggingRecorder;
+    use multi_tier_cache::metrics_facade;
+    use std::collections::HashSet;
+
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    // A local recorder only sees this thread, so run the manager on it
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    metrics::with_local_recorder(&recorder, || {
+        runtime.block_on(async {
+            let cache = common::setup_in_memory_cache()?;
+            let manager = &cache.manager;
+            manager
+                .set_with_strategy(
+                    "metrics:key",
+                    Bytes::from("value"),
+                    CacheStrategy::ShortTerm,
+                )
+                .await?;
+            assert!(manager.get("metrics:key").await?.is_some());
+            assert!(manager.get("metrics:missing").await?.is_none());
+            manager
+                .get_or_compute_with("metrics:computed", CacheStrategy::ShortTerm, || async {
+                    Ok(Bytes::from("computed"))
+                })
+                .await?;
+            anyhow::Ok(())
+        })
+    })?;
+
+    let names: HashSet<String> = snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .map(|(key, _, _, _)| key.key().name().to_string())
+        .collect();
+    for expected in [
+        metrics_facade::REQUESTS,
+        metrics_facade::HITS,
+        metrics_facade::MISSES,
+        metrics_facade::SETS,
+        metrics_facade::OPERATION_DURATION,
+        metrics_facade::COMPUTE_DURATION,
+        metrics_facade::VALUE_SIZE,
+    ] {
+        assert!(names.contains(expected), "missing {expected} in {names:?}");
+    }
+
+    Ok(())
+}
+
+/// Backend that records single and batch removals
+#[derive(Default)]
+struct RecordingRemovals {
+    removes: std::sync::atomic::AtomicUsize,
+    batches: std::sync::Mutex<Vec<Vec<String>>>,
+}
+
+impl RecordingRemovals {
+    fn batches(&self) -> Vec<Vec<String>> {
+        self.batches.lock().map(|b| b.clone()).unwrap_or_default()
+    }
+}
+
+impl CacheBackend for RecordingRemovals {
+    fn get<'a>(&'a self, _key: &'a str) -> futures_util::future::BoxFuture<'a, Option<Bytes>> {
+        Box::pin(async { None })
+    }
+
+    fn set_with_ttl<'a>(
+        &'a self,
+        _key: &'a str,
+        _value: Bytes,
+        _ttl: Duration,
+    ) -> futures_util::future::BoxFuture<'a, multi_tier_cache::CacheResult<()>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn remove<'a>(
+        &'a self,
+        _key: &'a str,
+    ) -> futures_util::future::BoxFuture<'a, multi_tier_cache::CacheResult<()>> {
+        self.removes
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        Box::pin(async { Ok(()) })
+    }
+
+    fn batch_remove<'a>(
+        &'a self,
+        keys: &'a [String],
+    ) -> futures_util::future::BoxFuture<'a, multi_tier_cache::CacheResult<()>> {
+        if let Ok(mut batches) = self.batches.lock() {
+            batches.push(keys.to_vec());
+        }
+        Box::pin(async { Ok(()) })
+    }
+
+    fn health_check(&self) -> futures_util::future::BoxFuture<'_, bool> {
+        Box::pin(async { true })
+    }
+
+    fn name(&self) -> &'static str {
+        "RecordingRemovals"
+    }
+}
+
+impl multi_tier_cache::L2CacheBackend for RecordingRemovals {
+    fn get_with_ttl<'a>(
+        &'a self,
+        _key: &'a str,
+    ) -> futures_util::future::BoxFuture<'a, Option<(Bytes, Option<Duration>)>> {
+        Box::pin(async { None })
+    }
+}
+
+#[tokio::test]
+async fn test_invalidate_many_batches_per_tier() -> anyhow::Result<()> {
+    let l1 = Arc::new(RecordingRemovals::default());
+    let l2 = Arc::new(RecordingRemovals::default());
+    let manager = multi_tier_cache::CacheManager::new_with_backends(
+        Arc::clone(&l1) as Arc<dyn CacheBackend>,
+        Arc::clone(&l2) as Arc<dyn multi_tier_cache::L2CacheBackend>,
+        None,
+    )?;
+
+    manager.invalidate_many(&["a", "b", "c"]).await?;
+
+    let expected = vec![vec!["a".to_string(), "b".to_string(), "c".to_string()]];
+    for tier in [&l1, &l2] {
+        assert_eq!(tier.batches(), expected);
+        assert_eq!(tier.removes.load(std::sync::atomic::Ordering::SeqCst), 0);
+    }
+
+    // Nothing to remove means no backend call at all
+    manager.invalidate_many(&[]).await?;
+    assert_eq!(l1.batches().len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_self_test_round_trip() -> anyhow::Result<()> {
+    let l2 = Arc::new(DashMapCache::new());
+    let manager = common::in_memory_manager_with_l2(Arc::clone(&l2))?;
+    manager.self_test().await?;
+    // The probe key is cleaned up
+    assert!(l2.is_empty());
+
+    // An L2 that silently drops writes passes a ping but not the self-test
+    let manager = multi_tier_cache::CacheManager::new_with_backends(
+        Arc::new(DashMapCache::new()),
+        Arc::new(RecordingRemovals::default()) as Arc<dyn multi_tier_cache::L2CacheBackend>,
+        None,
+    )?;
+    let err = manager
+        .self_test()
+        .await
+        .err()
+        .ok_or_else(|| anyhow::anyhow!("self-test passed with a lossy L2"))?;
+    assert!(
+        err.to_string().contains("lower tier read"),
+        "unexpected error: {err}"
+    );
+    Ok(())
+}
+
+/// In-memory stream that keeps every appended entry, honouring `maxlen`
+#[derive(Default)]
+struct MemoryStream {
+    entries: std::sync::Mutex<Vec<multi_tier_cache::traits::StreamEntry>>,
+}
+
+impl MemoryStream {
+    fn entries(&self) -> Vec<multi_tier_cache::traits::StreamEntry> {
+        self.entries.lock().map(|e| e.clone()).unwrap_or_default()
+    }
+}
+
+impl multi_tier_cache::StreamingBackend for MemoryStream {
+    fn stream_add<'a>(
+        &'a self,
+        _stream_key: &'a str,
+        fields: Vec<(String, String)>,
+        maxlen: Option<usize>,
+    ) -> futures_util::future::BoxFuture<'a, multi_tier_cache::CacheResult<String>> {
+        Box::pin(async move {
+            let mut entries = self
+                .entries
+                .lock()
+                .map_err(|_| CacheError::InternalError("poisoned".to_string()))?;
+            let id = format!("{}-0", entries.len());
+            entries.push((id.clone(), fields));
+            if let Some(maxlen) = maxlen {
+                let excess = entries.len().saturating_sub(maxlen);
+                entries.drain(..excess);
+            }
+            Ok(id)
+        })
+    }
+
+    fn stream_read_latest<'a>(
+        &'a self,
+        _stream_key: &'a str,
+        _count: usize,
+    ) -> futures_util::future::BoxFuture<
+        'a,
+        multi_tier_cache::CacheResult<Vec<multi_tier_cache::traits::StreamEntry>>,
+    > {
+        Box::pin(async move { Ok(self.entries()) })
+    }
+
+    fn stream_read<'a>(
+        &'a self,
+        _stream_key: &'a str,
+        _last_id: &'a str,
+        _count: usize,
+        _block_ms: Option<usize>,
+    ) -> futures_util::future::BoxFuture<
+        'a,
+        multi_tier_cache::CacheResult<Vec<multi_tier_cache::traits::StreamEntry>>,
+    > {
+        Box::pin(async move { Ok(self.entries()) })
+    }
+
+    fn stream_create_group<'a>(
+        &'a self,
+        _stream_key: &'a str,
+        _group_name: &'a str,
+        _id: &'a str,
+    ) -> futures_util::future::BoxFuture<'a, multi_tier_cache::CacheResult<()>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn stream_read_group<'a>(
+        &'a self,
+        _stream_key: &'a str,
+        _group_name: &'a str,
+        _consumer_name: &'a str,
+        _count: usize,
+        _block_ms: Option<usize>,
+    ) -> futures_util::future::BoxFuture<
+        'a,
+        multi_tier_cache::CacheResult<Vec<multi_tier_cache::traits::StreamEntry>>,
+    > {
+        Box::pin(async move { Ok(self.entries()) })
+    }
+
+    fn stream_ack<'a>(
+        &'a self,
+        _stream_key: &'a str,
+        _group_name: &'a str,
+        _ids: &'a [String],
+    ) -> futures_util::future::BoxFuture<'a, multi_tier_cache::CacheResult<()>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Wait until `stream` holds `count` entries
+async fn wait_for_entries(stream: &MemoryStream, count: usize) -> anyhow::Result<()> {
+    tokio::time::timeout(Duration::from_secs(2), async {
+        while stream.entries().len() < count {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await?;
+    Ok(())
+}
+
+fn field<'a>(fields: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    fields
+        .iter()
+        .find(|(field, _)| field == name)
+        .map(|(_, value)| value.as_str())
+}
+
+#[tokio::test]
+async fn test_moka_evictions_published_to_stream() -> anyhow::Result<()> {
+    use multi_tier_cache::eviction::{EvictionStreamConfig, EvictionStreamPublisher};
+
+    let stream = Arc::new(MemoryStream::default());
+    let publisher = EvictionStreamPublisher::start(
+        Arc::clone(&stream) as Arc<dyn multi_tier_cache::StreamingBackend>,
+        EvictionStreamConfig {
+            maxlen: 1000,
+            ..EvictionStreamConfig::default()
+        },
+    );
+    let l1 = multi_tier_cache::MokaCache::with_eviction_listener(
+        multi_tier_cache::MokaCacheConfig {
+            max_capacity: 10,
+            ..Default::default()
+        },
+        publisher.listener(),
+    )?;
+
+    for i in 0..50 {
+        l1.set_with_ttl(
+            &format!("fill:{i}"),
+            Bytes::from("v"),
+            Duration::from_mins(5),
+        )
+        .await?;
+    }
+    l1.run_pending_tasks().await;
+    // Explicit removals are not evictions
+    l1.remove("fill:49").await?;
+    l1.run_pending_tasks().await;
+
+    let evicted = 50 - l1.entry_count().unwrap_or(0);
+    assert!(evicted >= 40, "only {evicted} entries evicted");
+    wait_for_entries(&stream, 1).await?;
+    sleep(Duration::from_millis(50)).await;
+
+    let entries = stream.entries();
+    assert!(
+        entries.len() >= 39,
+        "only {} events published",
+        entries.len()
+    );
+    for (_, fields) in &entries {
+        assert_eq!(field(fields, "cause"), Some("size"));
+        assert!(field(fields, "key").is_some_and(|key| key.starts_with("fill:")));
+        assert!(field(fields, "timestamp").is_some_and(|ts| ts.parse::<u64>().is_ok()));
+    }
+    assert_eq!(publisher.published(), entries.len() as u64);
+    assert_eq!(publisher.dropped(), 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dashmap_expiry_published_to_stream() -> anyhow::Result<()> {
+    use multi_tier_cache::eviction::{EvictionStreamConfig, EvictionStreamPublisher};
+
+    let stream = Arc::new(MemoryStream::default());
+    let publisher = EvictionStreamPublisher::start(
+        Arc::clone(&stream) as Arc<dyn multi_tier_cache::StreamingBackend>,
+        EvictionStreamConfig {
+            maxlen: 2,
+            ..EvictionStreamConfig::default()
+        },
+    );
+    let l1 = DashMapCache::new().with_eviction_listener(publisher.listener());
+
+    for i in 0..3 {
+        l1.set_with_ttl(
+            &format!("short:{i}"),
+            Bytes::from("v"),
+            Duration::from_millis(10),
+        )
+        .await?;
+    }
+    l1.set_with_ttl("kept", Bytes::from("v"), Duration::from_mins(5))
+        .await?;
+    sleep(Duration::from_millis(30)).await;
+    assert_eq!(l1.cleanup_expired(), 3);
+
+    wait_for_entries(&stream, 2).await?;
+    sleep(Duration::from_millis(50)).await;
+    // Bounded by maxlen
+    let entries = stream.entries();
+    assert_eq!(entries.len(), 2);
+    assert!(
+        entries
+            .iter()
+            .all(|(_, fields)| field(fields, "cause") == Some("expired"))
+    );
+    assert_eq!(publisher.published(), 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_opts_compression_override() -> anyhow::Result<()> {
+    use multi_tier_cache::SetOpts;
+    use multi_tier_cache::serialization::{CompressionMode, ValueCompression};
+
+    // Threshold above both values: only the override compresses
+    let manager = common::in_memory_manager_with_l2(Arc::new(DashMapCache::new()))?
+        .with_value_compression(ValueCompression::new(RunLength).with_min_size(1 << 20));
+    let payload = "a".repeat(4096);
+
+    manager
+        .set_with_strategy_opts(
+            "image",
+            &payload,
+            CacheStrategy::ShortTerm,
+            SetOpts::default().with_compression(CompressionMode::Always),
+        )
+        .await?;
+    manager
+        .set_with_strategy_opts(
+            "archive",
+            &payload,
+            CacheStrategy::ShortTerm,
+            SetOpts::default().with_compression(CompressionMode::Never),
+        )
+        .await?;
+
+    let image = manager.get("image").await?.unwrap_or_default();
+    assert!(ValueCompression::is_compressed(&image));
+    assert!(image.len() < 100, "compressed to {} bytes", image.len());
+
+    let archive = manager.get("archive").await?.unwrap_or_default();
+    assert!(!ValueCompression::is_compressed(&archive));
+    // Header byte, then the JSON string as serialized
+    assert_eq!(archive.len(), 1 + payload.len() + 2);
+    assert!(archive.ends_with(b"aaaa\""));
+
+    assert_eq!(
+        manager.get_typed::<String>("image").await?,
+        Some(payload.clone())
+    );
+    assert_eq!(manager.get_typed::<String>("archive").await?, Some(payload));
+
+    // Forcing compression needs a configured compressor
+    let plain = common::in_memory_manager_with_l2(Arc::new(DashMapCache::new()))?;
+    let forced = plain
+        .set_with_strategy_opts(
+            "image",
+            &"a",
+            CacheStrategy::ShortTerm,
+            SetOpts::default().with_compression(CompressionMode::Always),
+        )
+        .await;
+    assert!(matches!(forced, Err(CacheError::ConfigError(_))));
+    Ok(())
+}
+
+#[cfg(feature = "encryption")]
+#[tokio::test]
+async fn test_value_compression_with_encryption() -> anyhow::Result<()> {
+    use multi_tier_cache::serialization::{EncryptingSerializer, ValueCompression};
+    use multi_tier_cache::{CacheSerializer, JsonSerializer};
+
+    let mut manager = common::in_memory_manager_with_l2(Arc::new(DashMapCache::new()))?
+        .with_value_compression(ValueCompression::new(RunLength).with_min_size(64));
+    manager.set_serializer(CacheSerializer::Encrypted(EncryptingSerializer::new(
+        CacheSerializer::Json(JsonSerializer),
+        &[7; 32],
+    )?));
+    let payload = "a".repeat(4096);
+
+    manager
+        .set_with_strategy_opts(
+            "secret",
+            &payload,
+            CacheStrategy::ShortTerm,
+            multi_tier_cache::SetOpts::default(),
+        )
+        .await?;
+
+    // Compressed before encryption: nonce + tag + a few run-length pairs
+    let stored = manager.get("secret").await?.unwrap_or_default();
+    assert!(stored.len() < 100, "stored {} bytes", stored.len());
+    assert_eq!(manager.get_typed::<String>("secret").await?, Some(payload));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tuple_cache_keys() -> anyhow::Result<()> {
+    use multi_tier_cache::{CacheKey, DisplayKey};
+
+    let l2 = Arc::new(DashMapCache::new());
+    let manager = common::in_memory_manager_with_l2(Arc::clone(&l2))?;
+
+    let alice = ("acme".to_string(), 42_u64);
+    let bob = ("acme", 43_u64);
+    assert_eq!(alice.cache_key(), "acme:42");
+    assert_eq!(("eu", 7_u32, DisplayKey(1.5)).cache_key(), "eu:7:1.5");
+
+    manager
+        .set_with_strategy(&alice, Bytes::from("alice"), CacheStrategy::ShortTerm)
+        .await?;
+    manager
+        .set_with_strategy_opts(
+            &bob,
+            &"bob",
+            CacheStrategy::ShortTerm,
+            multi_tier_cache::SetOpts::default(),
+        )
+        .await?;
+
+    // Structured and formatted keys address the same entry
+    assert_eq!(manager.get(&alice).await?, Some(Bytes::from("alice")));
+    assert_eq!(manager.get("acme:42").await?, Some(Bytes::from("alice")));
+    assert_eq!(
+        manager.get_typed::<String>(&bob).await?,
+        Some("bob".to_string())
+    );
+    let computed: String = manager
+        .get_or_compute_typed(&("acme", 44_u64), CacheStrategy::ShortTerm, || async {
+            Ok("carol".to_string())
+        })
+        .await?;
+    assert_eq!(computed, "carol");
+
+    // Patterns match the formatted keys
+    let mut keys = l2
+        .keys_matching("acme:*", usize::MAX)
+        .await?
+        .unwrap_or_default();
+    keys.sort();
+    assert_eq!(keys, vec!["acme:42", "acme:43", "acme:44"]);
+    manager.invalidate_pattern("acme:*").await?;
+    assert!(manager.get(&alice).await?.is_none());
+    Ok(())
+}
+
+/// Deserializations of `CountedString` so far
+static COUNTED_DESERIALIZATIONS: std::sync::atomic::AtomicUsize =
+    std::sync::atomic::AtomicUsize::new(0);
+
+/// String that counts how often it is deserialized
+#[derive(Debug, PartialEq, serde::Serialize)]
+struct CountedString(String);
+
+impl<'de> serde::Deserialize<'de> for CountedString {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        COUNTED_DESERIALIZATIONS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        String::deserialize(deserializer).map(Self)
+    }
+}
+
+#[tokio::test]
+async fn test_get_lazy_defers_deserialization() -> anyhow::Result<()> {
+    let manager = common::in_memory_manager_with_l2(Arc::new(DashMapCache::new()))?;
+    for candidate in ["banner:segment", "banner:region", "banner:default"] {
+        manager
+            .set_with_strategy_opts(
+                candidate,
+                &CountedString(candidate.to_string()),
+                CacheStrategy::ShortTerm,
+                multi_tier_cache::SetOpts::default(),
+            )
+            .await?;
+    }
+
+    let candidates = [
+        "banner:user",
+        "banner:segment",
+        "banner:region",
+        "banner:default",
+        "banner:missing",
+    ];
+    let mut fetched = Vec::new();
+    for candidate in candidates {
+        fetched.push(manager.get_lazy(candidate).await?);
+    }
+    assert_eq!(fetched.iter().flatten().count(), 3);
+    assert_eq!(
+        COUNTED_DESERIALIZATIONS.load(std::sync::atomic::Ordering::SeqCst),
+        0
+    );
+
+    let first = fetched
+        .into_iter()
+        .flatten()
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("no candidate found"))?;
+    assert_eq!(first.key(), "banner:segment");
+    assert_eq!(
+        first.deserialize::<CountedString>()?,
+        CountedString("banner:segment".to_string())
+    );
+    assert_eq!(
+        COUNTED_DESERIALIZATIONS.load(std::sync::atomic::Ordering::SeqCst),
+        1
+    );
+    assert!(first.deserialize::<u64>().is_err());
+    Ok(())
+}
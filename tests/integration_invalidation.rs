@@ -63,7 +63,7 @@ async fn test_update_cache() {
 
     // Update cache
     cache
-        .update_cache(&key, value2.clone(), Some(Duration::from_secs(300)))
+        .update_cache(&key, value2.clone(), Some(Duration::from_mins(5)))
         .await
         .unwrap_or_else(|_| panic!("Failed to update cache"));
 
@@ -225,6 +225,174 @@ async fn test_bulk_invalidation() {
     }
 }
 
+/// Test that a burst of invalidations is published as a few `RemoveBulk` messages
+#[tokio::test]
+async fn test_batched_invalidation() {
+    use multi_tier_cache::backends::MokaCacheConfig;
+    use multi_tier_cache::{CacheManager, InvalidationConfig, L1Cache, L2Cache};
+    use std::sync::Arc;
+
+    let l1 = Arc::new(
+        L1Cache::new(MokaCacheConfig::default()).unwrap_or_else(|_| panic!("Failed to create L1")),
+    );
+    let l2 = Arc::new(
+        L2Cache::new()
+            .await
+            .unwrap_or_else(|_| panic!("Failed to create L2")),
+    );
+    let config = InvalidationConfig {
+        channel: format!("test:invalidate:{}", rand::random::<u32>()),
+        ..Default::default()
+    };
+    let cache = CacheManager::new_with_invalidation(l1, l2, &redis_url(), config)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to setup cache"))
+        .with_invalidation_batch_window(Duration::from_millis(10));
+
+    let keys: Vec<String> = (0..200).map(|i| test_key(&format!("batch{i}"))).collect();
+    for key in &keys {
+        cache
+            .set_with_strategy(key, test_data::bytes_user(1), CacheStrategy::ShortTerm)
+            .await
+            .unwrap_or_else(|_| panic!("Failed to set cache"));
+    }
+    for key in &keys {
+        cache
+            .invalidate(key)
+            .await
+            .unwrap_or_else(|_| panic!("Failed to invalidate"));
+        // Local removal is not delayed by the batch window
+        let cached = cache
+            .get(key)
+            .await
+            .unwrap_or_else(|_| panic!("Failed to get cache"));
+        assert_eq!(cached, None);
+    }
+
+    // Let the last window close
+    sleep(Duration::from_millis(200)).await;
+
+    let stats = cache
+        .invalidation_stats()
+        .unwrap_or_else(|| panic!("Invalidation stats should be available"));
+    assert!(stats.messages_sent >= 1, "Batches should be published");
+    assert!(
+        stats.messages_sent < 20,
+        "200 invalidations took {} messages",
+        stats.messages_sent
+    );
+}
+
+/// Test that `flush_pending_writes` publishes batched invalidations right away
+#[tokio::test]
+async fn test_flush_pending_invalidations() {
+    use multi_tier_cache::backends::MokaCacheConfig;
+    use multi_tier_cache::{CacheManager, InvalidationConfig, L1Cache, L2Cache};
+    use std::sync::Arc;
+
+    let channel = format!("test:invalidate:{}", rand::random::<u32>());
+    let mut instances = Vec::new();
+    for _ in 0..2 {
+        let l1 = Arc::new(
+            L1Cache::new(MokaCacheConfig::default())
+                .unwrap_or_else(|_| panic!("Failed to create L1")),
+        );
+        let l2 = Arc::new(
+            L2Cache::new()
+                .await
+                .unwrap_or_else(|_| panic!("Failed to create L2")),
+        );
+        let config = InvalidationConfig {
+            channel: channel.clone(),
+            ..Default::default()
+        };
+        let cache = CacheManager::new_with_invalidation(l1, l2, &redis_url(), config)
+            .await
+            .unwrap_or_else(|_| panic!("Failed to setup cache"))
+            .with_invalidation_batch_window(Duration::from_mins(1));
+        instances.push(cache);
+    }
+    let (Some(writer), Some(reader)) = (instances.first(), instances.get(1)) else {
+        panic!("Expected two instances");
+    };
+
+    // Give the subscribers time to subscribe
+    sleep(Duration::from_millis(200)).await;
+
+    let key = test_key("flush");
+    let value = test_data::bytes_user(1);
+    writer
+        .set_with_strategy(&key, value.clone(), CacheStrategy::MediumTerm)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to set cache"));
+    // Promoted into the reader's L1
+    let cached = reader
+        .get(&key)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to get cache"));
+    assert_eq!(cached, Some(value));
+
+    writer
+        .invalidate(&key)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to invalidate"));
+    // Without a flush, the batch would only go out after a minute
+    writer
+        .flush_pending_writes()
+        .await
+        .unwrap_or_else(|_| panic!("Failed to flush"));
+    sleep(Duration::from_millis(500)).await;
+
+    let cached = reader
+        .get(&key)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to get cache"));
+    assert_eq!(cached, None);
+}
+
+/// Test measuring Pub/Sub propagation latency on this instance's own messages
+#[tokio::test]
+async fn test_propagation_latency_loopback() {
+    use multi_tier_cache::backends::MokaCacheConfig;
+    use multi_tier_cache::{CacheManager, InvalidationConfig, L1Cache, L2Cache};
+    use std::sync::Arc;
+
+    let l1 = Arc::new(
+        L1Cache::new(MokaCacheConfig::default()).unwrap_or_else(|_| panic!("Failed to create L1")),
+    );
+    let l2 = Arc::new(
+        L2Cache::new()
+            .await
+            .unwrap_or_else(|_| panic!("Failed to create L2")),
+    );
+    let config = InvalidationConfig {
+        channel: format!("test:invalidate:{}", rand::random::<u32>()),
+        track_propagation_latency: true,
+        ..Default::default()
+    };
+    let cache = CacheManager::new_with_invalidation(l1, l2, &redis_url(), config)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to setup cache"));
+
+    // Give the subscriber time to subscribe
+    sleep(Duration::from_millis(200)).await;
+
+    cache
+        .invalidate(&test_key("latency"))
+        .await
+        .unwrap_or_else(|_| panic!("Failed to invalidate"));
+    sleep(Duration::from_millis(500)).await;
+
+    let stats = cache
+        .invalidation_stats()
+        .unwrap_or_else(|| panic!("Invalidation stats should be available"));
+    assert_eq!(stats.propagation_samples, 1);
+    let latency = stats
+        .propagation_latency_avg
+        .unwrap_or_else(|| panic!("Latency should be recorded"));
+    assert!(latency < Duration::from_millis(500), "{latency:?}");
+}
+
 /// Test invalidation message serialization is compact hex
 #[tokio::test]
 async fn test_invalidation_message_hex_serialization() {
@@ -265,3 +433,281 @@ async fn test_invalidation_message_hex_serialization() {
         panic!("Parsed wrong message variant");
     }
 }
+
+/// Test that an instance does not re-apply its own broadcasts
+#[tokio::test]
+async fn test_self_published_messages_ignored() {
+    use multi_tier_cache::backends::MokaCacheConfig;
+    use multi_tier_cache::{CacheManager, InvalidationConfig, L1Cache, L2Cache};
+    use std::sync::Arc;
+
+    let l1 = Arc::new(
+        L1Cache::new(MokaCacheConfig::default()).unwrap_or_else(|_| panic!("Failed to create L1")),
+    );
+    let l2 = Arc::new(
+        L2Cache::new()
+            .await
+            .unwrap_or_else(|_| panic!("Failed to create L2")),
+    );
+    // Dedicated channel so messages from concurrently running tests don't interfere
+    let config = InvalidationConfig {
+        channel: format!("test:invalidate:{}", rand::random::<u32>()),
+        ..Default::default()
+    };
+    let cache = CacheManager::new_with_invalidation(l1, l2, &redis_url(), config)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to setup cache"));
+
+    // Give the subscriber time to subscribe
+    sleep(Duration::from_millis(200)).await;
+
+    let key = test_key("loopback");
+    cache
+        .update_cache(&key, test_data::bytes_user(1), Some(Duration::from_mins(5)))
+        .await
+        .unwrap_or_else(|_| panic!("Failed to update cache"));
+    cache
+        .invalidate(&key)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to invalidate"));
+
+    // Wait for pub/sub round-trip
+    sleep(Duration::from_millis(500)).await;
+
+    let stats = cache
+        .invalidation_stats()
+        .unwrap_or_else(|| panic!("Invalidation stats should be available"));
+    assert_eq!(stats.messages_sent, 2);
+    assert_eq!(
+        stats.messages_received, 0,
+        "Self-published messages must not be reprocessed"
+    );
+    assert_eq!(stats.self_messages_ignored, 2);
+
+    // The loopback Update must not resurrect the invalidated key
+    let cached = cache
+        .get(&key)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to get cache"));
+    assert_eq!(cached, None);
+}
+
+/// Test observing invalidation messages through `InvalidationSubscriber::into_stream`
+#[tokio::test]
+async fn test_subscriber_into_stream() {
+    use futures_util::StreamExt;
+    use multi_tier_cache::{
+        InvalidationConfig, InvalidationMessage, InvalidationPublisher, InvalidationSubscriber,
+    };
+
+    // Dedicated channel so messages from concurrently running tests don't interfere
+    let config = InvalidationConfig {
+        channel: format!("test:invalidate:{}", rand::random::<u32>()),
+        ..Default::default()
+    };
+    let subscriber = InvalidationSubscriber::new(&redis_url(), config.clone())
+        .unwrap_or_else(|_| panic!("Failed to create subscriber"));
+    let mut messages = Box::pin(subscriber.into_stream());
+
+    // Give the subscriber time to subscribe
+    sleep(Duration::from_millis(200)).await;
+
+    let client =
+        redis::Client::open(redis_url()).unwrap_or_else(|_| panic!("Failed to create client"));
+    let connection = redis::aio::ConnectionManager::new(client)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to connect to Redis"));
+    let mut publisher = InvalidationPublisher::new(connection, config);
+    let key = test_key("stream");
+    publisher
+        .publish(&InvalidationMessage::remove(key.clone()))
+        .await
+        .unwrap_or_else(|_| panic!("Failed to publish"));
+
+    let received = tokio::time::timeout(Duration::from_secs(2), messages.next())
+        .await
+        .unwrap_or_else(|_| panic!("Timed out waiting for invalidation message"));
+    match received {
+        Some(InvalidationMessage::Remove { key: received_key }) => assert_eq!(received_key, key),
+        other => panic!("Unexpected message: {other:?}"),
+    }
+}
+
+/// Test that a subscriber restores the compressed value of a large `Update`
+#[tokio::test]
+async fn test_compressed_update_broadcast() {
+    use futures_util::StreamExt;
+    use multi_tier_cache::serialization::ByteTransform;
+    use multi_tier_cache::{
+        CacheResult, InvalidationConfig, InvalidationMessage, InvalidationPublisher,
+        InvalidationSubscriber, UpdateCompression,
+    };
+
+    /// Stand-in compressor: any reversible transform exercises the flag
+    struct Reverse;
+
+    impl ByteTransform for Reverse {
+        fn name(&self) -> &'static str {
+            "Reverse"
+        }
+        fn forward(&self, bytes: &[u8]) -> CacheResult<Vec<u8>> {
+            Ok(bytes.iter().rev().copied().collect())
+        }
+        fn backward(&self, bytes: &[u8]) -> CacheResult<Vec<u8>> {
+            self.forward(bytes)
+        }
+    }
+
+    let config = InvalidationConfig {
+        channel: format!("test:invalidate:{}", rand::random::<u32>()),
+        update_compression: Some(UpdateCompression::new(Reverse)),
+        ..Default::default()
+    };
+    let subscriber = InvalidationSubscriber::new(&redis_url(), config.clone())
+        .unwrap_or_else(|_| panic!("Failed to create subscriber"));
+    let mut messages = Box::pin(subscriber.into_stream());
+    sleep(Duration::from_millis(200)).await;
+
+    let client =
+        redis::Client::open(redis_url()).unwrap_or_else(|_| panic!("Failed to create client"));
+    let connection = redis::aio::ConnectionManager::new(client)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to connect to Redis"));
+    let mut publisher = InvalidationPublisher::new(connection, config);
+    let key = test_key("compressed");
+    let value = test_data::bytes_data_sized(64);
+    publisher
+        .publish(&InvalidationMessage::update(
+            key.clone(),
+            value.clone(),
+            None,
+        ))
+        .await
+        .unwrap_or_else(|_| panic!("Failed to publish"));
+
+    let received = tokio::time::timeout(Duration::from_secs(2), messages.next())
+        .await
+        .unwrap_or_else(|_| panic!("Timed out waiting for invalidation message"));
+    match received {
+        Some(InvalidationMessage::Update {
+            key: received_key,
+            value: received_value,
+            ..
+        }) => {
+            assert_eq!(received_key, key);
+            assert_eq!(received_value, value);
+        }
+        other => panic!("Unexpected message: {other:?}"),
+    }
+}
+
+/// Test evicting L1 entries deleted in Redis by another client
+#[tokio::test]
+async fn test_keyspace_invalidation() {
+    use multi_tier_cache::backends::MokaCacheConfig;
+    use multi_tier_cache::{CacheBackend, CacheManager, KeyspaceSubscriber, L1Cache, L2Cache};
+    use std::sync::Arc;
+
+    let client =
+        redis::Client::open(redis_url()).unwrap_or_else(|_| panic!("Failed to create client"));
+    let mut connection = redis::aio::ConnectionManager::new(client)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to connect to Redis"));
+    let _: () = redis::cmd("CONFIG")
+        .arg("SET")
+        .arg("notify-keyspace-events")
+        .arg("Egxe")
+        .query_async(&mut connection)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to enable keyspace notifications"));
+
+    let l1 = Arc::new(
+        L1Cache::new(MokaCacheConfig::default()).unwrap_or_else(|_| panic!("Failed to create L1")),
+    );
+    let l2 = Arc::new(
+        L2Cache::new()
+            .await
+            .unwrap_or_else(|_| panic!("Failed to create L2")),
+    );
+    let subscriber = KeyspaceSubscriber::new(&redis_url(), 0)
+        .unwrap_or_else(|_| panic!("Failed to create keyspace subscriber"));
+    let cache = CacheManager::new(l1.clone(), l2)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to create cache"))
+        .with_keyspace_invalidation(subscriber);
+
+    // Give the subscriber time to subscribe
+    sleep(Duration::from_millis(200)).await;
+
+    let key = test_key("keyspace");
+    cache
+        .set_with_strategy(&key, test_data::bytes_user(1), CacheStrategy::MediumTerm)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to set cache"));
+    assert!(l1.get(&key).await.is_some());
+
+    // Delete behind the cache's back
+    let _: () = redis::cmd("DEL")
+        .arg(&key)
+        .query_async(&mut connection)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to delete key"));
+
+    // Wait for the notification
+    sleep(Duration::from_millis(500)).await;
+
+    assert!(l1.get(&key).await.is_none(), "L1 copy should be evicted");
+}
+
+/// Test that namespaced channels don't deliver other namespaces' invalidations
+#[tokio::test]
+async fn test_namespaced_invalidation_channels() {
+    use futures_util::StreamExt;
+    use multi_tier_cache::{
+        InvalidationConfig, InvalidationMessage, InvalidationPublisher, InvalidationSubscriber,
+    };
+
+    // Dedicated base channel so messages from concurrently running tests don't interfere
+    let base = InvalidationConfig {
+        channel: format!("test:invalidate:{}", rand::random::<u32>()),
+        ..Default::default()
+    };
+    let orders = base.clone().with_key_prefix("orders:");
+    let users = base.clone().with_key_prefix("users:");
+    assert_eq!(orders.channel, format!("{}:orders", base.channel));
+    assert_ne!(orders.channel, users.channel);
+
+    let orders_subscriber = InvalidationSubscriber::new(&redis_url(), orders.clone())
+        .unwrap_or_else(|_| panic!("Failed to create subscriber"));
+    let users_subscriber = InvalidationSubscriber::new(&redis_url(), users)
+        .unwrap_or_else(|_| panic!("Failed to create subscriber"));
+    let mut orders_messages = Box::pin(orders_subscriber.into_stream());
+    let mut users_messages = Box::pin(users_subscriber.into_stream());
+
+    // Give the subscribers time to subscribe
+    sleep(Duration::from_millis(200)).await;
+
+    let client =
+        redis::Client::open(redis_url()).unwrap_or_else(|_| panic!("Failed to create client"));
+    let connection = redis::aio::ConnectionManager::new(client)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to connect to Redis"));
+    let mut publisher = InvalidationPublisher::new(connection, orders);
+    let key = format!("orders:{}", test_key("namespaced"));
+    publisher
+        .publish(&InvalidationMessage::remove(key.clone()))
+        .await
+        .unwrap_or_else(|_| panic!("Failed to publish"));
+
+    let received = tokio::time::timeout(Duration::from_secs(2), orders_messages.next())
+        .await
+        .unwrap_or_else(|_| panic!("Timed out waiting for invalidation message"));
+    match received {
+        Some(InvalidationMessage::Remove { key: received_key }) => assert_eq!(received_key, key),
+        other => panic!("Unexpected message: {other:?}"),
+    }
+
+    // The other namespace never sees it
+    let leaked = tokio::time::timeout(Duration::from_millis(500), users_messages.next()).await;
+    assert!(leaked.is_err(), "Cross-namespace message delivered");
+}
@@ -369,7 +369,7 @@ async fn test_multi_tier_stampede_protection() {
         .await
         .unwrap_or_else(|_| panic!("Failed to build cache system"));
 
-    let manager = Arc::new(cache.cache_manager().clone());
+    let manager = Arc::clone(cache.cache_manager());
     let key = test_key("stampede_multi_tier");
     let compute_count = Arc::new(AtomicU32::new(0));
 
@@ -447,12 +447,12 @@ async fn test_stampede_retrieves_from_l3() {
         .await
         .unwrap_or_else(|_| panic!("Failed to build cache system"));
 
-    let manager = Arc::new(cache.cache_manager().clone());
+    let manager = Arc::clone(cache.cache_manager());
     let key = test_key("stampede_l3_hit");
     let data = test_data::bytes_user(777);
 
     // Pre-populate ONLY L3 (skip L1 and L2)
-    l3.set_with_ttl(&key, data.clone(), std::time::Duration::from_secs(300))
+    l3.set_with_ttl(&key, data.clone(), std::time::Duration::from_mins(5))
         .await
         .unwrap_or_else(|_| panic!("Failed to set L3"));
 
@@ -556,3 +556,787 @@ async fn test_memcached_tier_integration() {
         Some("Memcached")
     );
 }
+
+/// Test promotion TTL histogram bucketing
+#[tokio::test]
+async fn test_promotion_ttl_histogram() {
+    let cache = common::setup_in_memory_cache().unwrap_or_else(|_| panic!("Failed to setup cache"));
+
+    let ttls = [
+        ("ttl:sub_second", Duration::from_millis(500)),
+        ("ttl:seconds", Duration::from_secs(5)),
+        ("ttl:tens_of_seconds", Duration::from_secs(30)),
+        ("ttl:minutes", Duration::from_mins(5)),
+        ("ttl:hours", Duration::from_hours(1)),
+    ];
+
+    // Seed L2 only, then read through the manager to promote into L1
+    for (key, ttl) in ttls {
+        cache
+            .l2
+            .set_with_ttl(key, Bytes::from("value"), ttl)
+            .await
+            .unwrap_or_else(|_| panic!("Failed to seed L2"));
+        let value = cache
+            .manager
+            .get(key)
+            .await
+            .unwrap_or_else(|_| panic!("Failed to get"));
+        assert!(value.is_some());
+    }
+
+    let histogram = cache.manager.promotion_ttl_histogram();
+    assert_eq!(histogram.under_1s, 1);
+    assert_eq!(histogram.from_1s_to_10s, 1);
+    assert_eq!(histogram.from_10s_to_60s, 1);
+    assert_eq!(histogram.over_60s, 2);
+    assert_eq!(histogram.total(), 5);
+    assert_eq!(
+        usize::try_from(histogram.total()).unwrap_or(usize::MAX),
+        cache.manager.get_stats().promotions
+    );
+}
+
+/// Test that `get_tracked` reports promotions of lower tier hits
+#[tokio::test]
+async fn test_get_tracked_reports_promotion() {
+    use multi_tier_cache::CacheSource;
+
+    let cache = common::setup_in_memory_cache().unwrap_or_else(|_| panic!("Failed to setup cache"));
+    cache
+        .l2
+        .set_with_ttl("tracked:key", Bytes::from("7"), Duration::from_mins(1))
+        .await
+        .unwrap_or_else(|_| panic!("Failed to seed L2"));
+
+    // L2-only hit: promoted into L1
+    let (value, outcome) = cache
+        .manager
+        .get_tracked::<u32>("tracked:key")
+        .await
+        .unwrap_or_else(|_| panic!("Failed to get"));
+    assert_eq!(value, Some(7));
+    assert_eq!(outcome.served_by, Some(CacheSource::LowerTier(2)));
+    assert!(outcome.promoted);
+    assert!(
+        outcome
+            .remaining_ttl
+            .is_some_and(|ttl| ttl <= Duration::from_mins(1))
+    );
+
+    // Now an L1 hit: nothing to promote
+    let (value, outcome) = cache
+        .manager
+        .get_tracked::<u32>("tracked:key")
+        .await
+        .unwrap_or_else(|_| panic!("Failed to get"));
+    assert_eq!(value, Some(7));
+    assert_eq!(outcome.served_by, Some(CacheSource::L1));
+    assert!(!outcome.promoted);
+
+    let (value, outcome) = cache
+        .manager
+        .get_tracked::<u32>("tracked:missing")
+        .await
+        .unwrap_or_else(|_| panic!("Failed to get"));
+    assert_eq!(value, None);
+    assert_eq!(outcome.served_by, None);
+}
+
+/// L1 backend whose writes take 50ms, to make promotion cost visible on reads
+struct SlowWrites(multi_tier_cache::DashMapCache);
+
+impl CacheBackend for SlowWrites {
+    fn get<'a>(&'a self, key: &'a str) -> futures_util::future::BoxFuture<'a, Option<Bytes>> {
+        self.0.get(key)
+    }
+
+    fn set_with_ttl<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> futures_util::future::BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            self.0.set_with_ttl(key, value, ttl).await
+        })
+    }
+
+    fn remove<'a>(&'a self, key: &'a str) -> futures_util::future::BoxFuture<'a, CacheResult<()>> {
+        self.0.remove(key)
+    }
+
+    fn health_check(&self) -> futures_util::future::BoxFuture<'_, bool> {
+        self.0.health_check()
+    }
+
+    fn name(&self) -> &'static str {
+        "SlowWrites"
+    }
+}
+
+impl multi_tier_cache::L2CacheBackend for SlowWrites {
+    fn get_with_ttl<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> futures_util::future::BoxFuture<'a, Option<(Bytes, Option<Duration>)>> {
+        self.0.get_with_ttl(key)
+    }
+}
+
+/// Build a manager over a slow-write L1, returning it with the L1 and L2 backends
+fn slow_l1_manager() -> (
+    CacheManager,
+    Arc<SlowWrites>,
+    Arc<multi_tier_cache::DashMapCache>,
+) {
+    use multi_tier_cache::{CacheTier, L2CacheBackend};
+
+    let l1 = Arc::new(SlowWrites(multi_tier_cache::DashMapCache::new()));
+    let l2 = Arc::new(multi_tier_cache::DashMapCache::new());
+    let tiers = vec![
+        CacheTier::new(Arc::clone(&l1) as Arc<dyn L2CacheBackend>, 1, false, 1, 1.0),
+        CacheTier::new(Arc::clone(&l2) as Arc<dyn L2CacheBackend>, 2, true, 1, 1.0),
+    ];
+    let manager =
+        CacheManager::new_with_tiers(tiers, None).unwrap_or_else(|_| panic!("Failed to build"));
+    (manager, l1, l2)
+}
+
+/// Slowest of `count` reads of L2-resident keys
+async fn slowest_l2_read(
+    manager: &CacheManager,
+    l2: &multi_tier_cache::DashMapCache,
+    prefix: &str,
+    count: usize,
+) -> Duration {
+    let mut slowest = Duration::ZERO;
+    for i in 0..count {
+        let key = format!("{prefix}:{i}");
+        l2.set_with_ttl(&key, Bytes::from("value"), Duration::from_mins(5))
+            .await
+            .unwrap_or_else(|_| panic!("Failed to seed L2"));
+
+        let start = std::time::Instant::now();
+        let value = manager
+            .get(&key)
+            .await
+            .unwrap_or_else(|_| panic!("Failed to get"));
+        slowest = slowest.max(start.elapsed());
+        assert!(value.is_some());
+    }
+    slowest
+}
+
+/// Test that async promotion takes the upper tier write off the read path
+#[tokio::test]
+async fn test_async_promotion_read_latency() {
+    let (inline, _, inline_l2) = slow_l1_manager();
+    let inline_slowest = slowest_l2_read(&inline, &inline_l2, "inline", 10).await;
+    assert!(
+        inline_slowest >= Duration::from_millis(50),
+        "Inline promotion should wait for the L1 write, slowest read took {inline_slowest:?}"
+    );
+
+    let (manager, l1, l2) = slow_l1_manager();
+    let manager = manager.with_async_promotion(true);
+    let async_slowest = slowest_l2_read(&manager, &l2, "async", 10).await;
+    assert!(
+        async_slowest < Duration::from_millis(25),
+        "Async promotion should not block reads, slowest read took {async_slowest:?}"
+    );
+
+    // Promotions still land in L1 in the background
+    let mut promoted = false;
+    for _ in 0..100 {
+        if l1.get("async:9").await.is_some() {
+            promoted = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(promoted, "Queued promotion should eventually reach L1");
+    assert_eq!(manager.get_stats().promotions, 10);
+    assert_eq!(manager.get_stats().async_promotions_dropped, 0);
+}
+
+/// Test that a full promotion queue drops the oldest pending promotions
+#[tokio::test]
+async fn test_async_promotion_queue_drops_oldest() {
+    let (manager, l1, l2) = slow_l1_manager();
+    let manager = manager.with_async_promotion_capacity(2);
+
+    // The worker can't keep up with 50ms writes, so most promotions are dropped
+    slowest_l2_read(&manager, &l2, "pressure", 10).await;
+
+    let dropped = manager.get_stats().async_promotions_dropped;
+    assert!(dropped > 0, "Expected dropped promotions under pressure");
+
+    // The newest promotion is kept
+    let mut promoted = false;
+    for _ in 0..100 {
+        if l1.get("pressure:9").await.is_some() {
+            promoted = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(
+        promoted,
+        "Newest promotion should survive the drop-oldest policy"
+    );
+}
+
+/// Concurrently read an L3-only key; returns the bytes written to L1 and L2
+async fn concurrent_l3_reads(dedup: bool, readers: usize) -> (CacheManager, u64, u64) {
+    use multi_tier_cache::{CacheTier, DashMapCache, L2CacheBackend};
+    use tokio::sync::Barrier;
+    use tokio::task::JoinSet;
+
+    let l3 = Arc::new(DashMapCache::new());
+    let tiers = vec![
+        CacheTier::new(
+            Arc::new(SlowWrites(DashMapCache::new())) as Arc<dyn L2CacheBackend>,
+            1,
+            false,
+            1,
+            1.0,
+        ),
+        CacheTier::new(
+            Arc::new(SlowWrites(DashMapCache::new())) as Arc<dyn L2CacheBackend>,
+            2,
+            true,
+            1,
+            1.0,
+        ),
+        CacheTier::new(Arc::clone(&l3) as Arc<dyn L2CacheBackend>, 3, true, 1, 1.0),
+    ];
+    let manager = Arc::new(
+        CacheManager::new_with_tiers(tiers, None)
+            .unwrap_or_else(|_| panic!("Failed to build"))
+            .with_promotion_dedup(dedup),
+    );
+    l3.set_with_ttl("cold", Bytes::from("value"), Duration::from_mins(5))
+        .await
+        .unwrap_or_else(|_| panic!("Failed to seed L3"));
+
+    let barrier = Arc::new(Barrier::new(readers));
+    let mut tasks = JoinSet::new();
+    for _ in 0..readers {
+        let manager = Arc::clone(&manager);
+        let barrier = Arc::clone(&barrier);
+        tasks.spawn(async move {
+            barrier.wait().await;
+            // Not coalesced on a hit, so every reader may reach L3
+            manager
+                .get_or_compute_with("cold", CacheStrategy::ShortTerm, || async {
+                    panic!("L3 holds the key, nothing to compute")
+                })
+                .await
+        });
+    }
+    while let Some(result) = tasks.join_next().await {
+        let value = result
+            .unwrap_or_else(|_| panic!("Reader panicked"))
+            .unwrap_or_else(|_| panic!("Read failed"));
+        assert_eq!(value, Bytes::from("value"));
+    }
+
+    let stats = manager.get_tier_stats();
+    let written = |index: usize| {
+        stats
+            .get(index)
+            .map_or(0, multi_tier_cache::TierStats::bytes_written)
+    };
+    let (l1_written, l2_written) = (written(0), written(1));
+    let manager = Arc::try_unwrap(manager).unwrap_or_else(|_| panic!("Manager still shared"));
+    (manager, l1_written, l2_written)
+}
+
+/// Test that concurrent hits on a lower tier key promote it only once
+#[tokio::test]
+async fn test_promotion_deduplication() {
+    let value_len = "value".len() as u64;
+
+    let (manager, l1_written, l2_written) = concurrent_l3_reads(true, 20).await;
+    assert_eq!(
+        l1_written, value_len,
+        "expected a single L1 promotion write"
+    );
+    assert_eq!(
+        l2_written, value_len,
+        "expected a single L2 promotion write"
+    );
+    let stats = manager.get_stats();
+    assert_eq!(stats.promotions, 2);
+    assert!(stats.deduplicated_promotions > 0);
+
+    // Without deduplication every overlapping hit writes the upper tiers again
+    let (manager, l1_written, _) = concurrent_l3_reads(false, 20).await;
+    assert!(l1_written > value_len);
+    assert_eq!(manager.get_stats().deduplicated_promotions, 0);
+}
+
+/// Assert that `validate()` (and therefore `build()`) rejects a builder
+fn assert_invalid(builder: &CacheSystemBuilder, expected: &str) {
+    match builder.validate() {
+        Err(multi_tier_cache::CacheError::ConfigError(message)) => assert!(
+            message.contains(expected),
+            "Expected error mentioning '{expected}', got: {message}"
+        ),
+        other => panic!("Expected ConfigError, got {other:?}"),
+    }
+}
+
+/// Test that builder validation rejects conflicting configurations
+#[tokio::test]
+async fn test_builder_validate_conflicts() {
+    use multi_tier_cache::DashMapCache;
+
+    let dashmap = || Arc::new(DashMapCache::new());
+
+    // Legacy backends combined with tiers
+    assert_invalid(
+        &CacheSystemBuilder::new()
+            .with_l1(dashmap())
+            .with_tier(dashmap(), TierConfig::as_l2()),
+        "with_tier()",
+    );
+    assert_invalid(
+        &CacheSystemBuilder::new()
+            .with_tier(dashmap(), TierConfig::as_l1())
+            .with_l2(dashmap()),
+        "with_tier()",
+    );
+
+    // Duplicate levels
+    assert_invalid(
+        &CacheSystemBuilder::new()
+            .with_tier(dashmap(), TierConfig::as_l1())
+            .with_tier(dashmap(), TierConfig::as_l2())
+            .with_tier(dashmap(), TierConfig::new(2)),
+        "Duplicate tier level L2",
+    );
+
+    // Level 0
+    assert_invalid(
+        &CacheSystemBuilder::new().with_tier(dashmap(), TierConfig::new(0)),
+        "level 0",
+    );
+
+    // Tier mode without an L1 tier
+    assert_invalid(&CacheSystemBuilder::new().with_l3(dashmap()), "no L1 tier");
+
+    // build() runs the same checks before touching any backend
+    let result = CacheSystemBuilder::new()
+        .with_tier(dashmap(), TierConfig::as_l1())
+        .with_tier(dashmap(), TierConfig::as_l1())
+        .build()
+        .await;
+    assert!(matches!(
+        result,
+        Err(multi_tier_cache::CacheError::ConfigError(_))
+    ));
+
+    // Valid configurations pass
+    let valid = CacheSystemBuilder::new()
+        .with_tier(dashmap(), TierConfig::as_l1())
+        .with_tier(dashmap(), TierConfig::as_l2())
+        .with_l3(dashmap());
+    assert!(valid.validate().is_ok());
+    assert!(CacheSystemBuilder::new().validate().is_ok());
+}
+
+/// Test that a Moka config is rejected when the default Moka L1 isn't used
+#[cfg(feature = "moka")]
+#[test]
+fn test_builder_validate_moka_config_conflict() {
+    use multi_tier_cache::{DashMapCache, MokaCacheConfig};
+
+    assert_invalid(
+        &CacheSystemBuilder::new()
+            .with_moka_config(MokaCacheConfig::default())
+            .with_l1(Arc::new(DashMapCache::new())),
+        "with_moka_config()",
+    );
+    assert_invalid(
+        &CacheSystemBuilder::new()
+            .with_moka_config(MokaCacheConfig::default())
+            .with_tier(Arc::new(DashMapCache::new()), TierConfig::as_l1()),
+        "with_moka_config()",
+    );
+    assert!(
+        CacheSystemBuilder::new()
+            .with_moka_config(MokaCacheConfig::default())
+            .validate()
+            .is_ok()
+    );
+}
+
+/// L2 backend whose writes always fail
+struct FailingWrites;
+
+impl CacheBackend for FailingWrites {
+    fn get<'a>(&'a self, _key: &'a str) -> futures_util::future::BoxFuture<'a, Option<Bytes>> {
+        Box::pin(async { None })
+    }
+
+    fn set_with_ttl<'a>(
+        &'a self,
+        _key: &'a str,
+        _value: Bytes,
+        _ttl: Duration,
+    ) -> futures_util::future::BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async {
+            Err(multi_tier_cache::error::CacheError::BackendError(
+                "L2 unavailable".to_string(),
+            ))
+        })
+    }
+
+    fn remove<'a>(&'a self, _key: &'a str) -> futures_util::future::BoxFuture<'a, CacheResult<()>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn health_check(&self) -> futures_util::future::BoxFuture<'_, bool> {
+        Box::pin(async { false })
+    }
+
+    fn name(&self) -> &'static str {
+        "FailingWrites"
+    }
+}
+
+impl multi_tier_cache::L2CacheBackend for FailingWrites {
+    fn get_with_ttl<'a>(
+        &'a self,
+        _key: &'a str,
+    ) -> futures_util::future::BoxFuture<'a, Option<(Bytes, Option<Duration>)>> {
+        Box::pin(async { None })
+    }
+
+    fn wait_for_replicas(
+        &self,
+        replicas: usize,
+        _timeout: Duration,
+    ) -> futures_util::future::BoxFuture<'_, CacheResult<usize>> {
+        Box::pin(async move { Ok(replicas) })
+    }
+}
+
+/// Build a manager over a working L1 and an L2 whose writes fail
+fn failing_l2_manager(
+    policy: multi_tier_cache::L2WriteFailurePolicy,
+) -> (CacheManager, Arc<multi_tier_cache::DashMapCache>) {
+    use multi_tier_cache::{CacheTier, L2CacheBackend};
+
+    let l1 = Arc::new(multi_tier_cache::DashMapCache::new());
+    let tiers = vec![
+        CacheTier::new(Arc::clone(&l1) as Arc<dyn L2CacheBackend>, 1, false, 1, 1.0),
+        CacheTier::new(Arc::new(FailingWrites), 2, true, 1, 1.0),
+    ];
+    let manager = CacheManager::new_with_tiers(tiers, None)
+        .unwrap_or_else(|_| panic!("Failed to build"))
+        .with_l2_write_failure_policy(policy);
+    (manager, l1)
+}
+
+/// Test each policy for the L1 copy when the L2 write fails
+#[tokio::test]
+async fn test_l2_write_failure_policy() {
+    use multi_tier_cache::{L2CacheBackend, L2WriteFailurePolicy};
+
+    let value = Bytes::from("value");
+
+    // KeepL1: the write succeeds and L1 keeps the full TTL
+    let (manager, l1) = failing_l2_manager(L2WriteFailurePolicy::KeepL1);
+    manager
+        .set_with_strategy("keep", value.clone(), CacheStrategy::MediumTerm)
+        .await
+        .unwrap_or_else(|_| panic!("KeepL1 write should succeed"));
+    let (_, ttl) = l1
+        .get_with_ttl("keep")
+        .await
+        .unwrap_or_else(|| panic!("KeepL1 should keep the L1 copy"));
+    assert!(ttl.is_some_and(|ttl| ttl > Duration::from_mins(59)));
+
+    // EvictL1: nothing stored anywhere, so the write fails
+    let (manager, l1) = failing_l2_manager(L2WriteFailurePolicy::EvictL1);
+    let result = manager
+        .set_with_strategy("evict", value.clone(), CacheStrategy::MediumTerm)
+        .await;
+    assert!(result.is_err(), "EvictL1 should report the failed write");
+    assert_eq!(l1.get("evict").await, None);
+
+    // ShortenL1Ttl: L1 keeps the value only briefly
+    let (manager, l1) =
+        failing_l2_manager(L2WriteFailurePolicy::ShortenL1Ttl(Duration::from_secs(2)));
+    manager
+        .set_with_strategy("shorten", value.clone(), CacheStrategy::MediumTerm)
+        .await
+        .unwrap_or_else(|_| panic!("ShortenL1Ttl write should succeed"));
+    let (cached, ttl) = l1
+        .get_with_ttl("shorten")
+        .await
+        .unwrap_or_else(|| panic!("ShortenL1Ttl should keep the L1 copy"));
+    assert_eq!(cached, value);
+    assert!(ttl.is_some_and(|ttl| ttl <= Duration::from_secs(2)));
+}
+
+/// Test that strict writes surface the L2 failure that default writes swallow
+#[tokio::test]
+async fn test_set_with_strategy_strict() {
+    use multi_tier_cache::L2WriteFailurePolicy;
+
+    let (manager, l1) = failing_l2_manager(L2WriteFailurePolicy::KeepL1);
+    let value = Bytes::from("value");
+
+    manager
+        .set_with_strategy("lenient", value.clone(), CacheStrategy::MediumTerm)
+        .await
+        .unwrap_or_else(|_| panic!("Default write should succeed"));
+
+    let result = manager
+        .set_with_strategy_strict("strict", value.clone(), CacheStrategy::MediumTerm)
+        .await;
+    assert!(result.is_err(), "Strict write should report the L2 failure");
+    // KeepL1 still leaves the L1 copy in place
+    assert_eq!(l1.get("strict").await, Some(value));
+
+    // With EvictL1 the strict failure leaves nothing behind
+    let (manager, l1) = failing_l2_manager(L2WriteFailurePolicy::EvictL1);
+    let result = manager
+        .set_with_strategy_strict("strict", Bytes::from("value"), CacheStrategy::MediumTerm)
+        .await;
+    assert!(result.is_err());
+    assert_eq!(l1.get("strict").await, None);
+}
+
+/// Test that `set_durable` fails when only L1 stored the value
+#[tokio::test]
+async fn test_set_durable_reports_failed_l2_write() {
+    use multi_tier_cache::L2WriteFailurePolicy;
+
+    let (manager, _l1) = failing_l2_manager(L2WriteFailurePolicy::KeepL1);
+    let result = manager
+        .set_durable(
+            "durable",
+            Bytes::from("value"),
+            CacheStrategy::MediumTerm,
+            1,
+            Duration::from_millis(100),
+        )
+        .await;
+    assert!(result.is_err(), "L2 never received the write");
+}
+
+/// Test both policies when L1 still holds a key that L2 no longer has
+#[tokio::test]
+async fn test_l2_miss_policy() {
+    use multi_tier_cache::{CacheTier, DashMapCache, L2CacheBackend, L2MissPolicy};
+
+    for policy in [L2MissPolicy::TrustL1, L2MissPolicy::FlushL1] {
+        let l1 = Arc::new(DashMapCache::new());
+        let l2 = Arc::new(DashMapCache::new());
+        let tiers = vec![
+            CacheTier::new(Arc::clone(&l1) as Arc<dyn L2CacheBackend>, 1, false, 1, 1.0),
+            CacheTier::new(Arc::clone(&l2) as Arc<dyn L2CacheBackend>, 2, true, 1, 1.0),
+        ];
+        let manager = CacheManager::new_with_tiers(tiers, None)
+            .unwrap_or_else(|_| panic!("Failed to build"))
+            .with_l2_miss_policy(policy);
+
+        manager
+            .set_with_strategy("diverged", Bytes::from("v"), CacheStrategy::ShortTerm)
+            .await
+            .unwrap_or_else(|_| panic!("Failed to set"));
+        // L2 drops the key (expiry, eviction, ...) while L1 still holds it
+        l2.remove("diverged")
+            .await
+            .unwrap_or_else(|_| panic!("Failed to remove from L2"));
+
+        let value = manager
+            .get("diverged")
+            .await
+            .unwrap_or_else(|_| panic!("Failed to get"));
+        match policy {
+            L2MissPolicy::TrustL1 => {
+                assert_eq!(value, Some(Bytes::from("v")));
+                assert!(l1.get("diverged").await.is_some());
+            }
+            L2MissPolicy::FlushL1 => {
+                assert_eq!(value, None);
+                assert!(
+                    l1.get("diverged").await.is_none(),
+                    "L1 copy should be flushed"
+                );
+            }
+        }
+    }
+}
+
+/// Sampled `FlushL1` checks skip L2 on unchecked L1 hits
+#[tokio::test]
+async fn test_l2_miss_sampling() {
+    use multi_tier_cache::{CacheTier, DashMapCache, L2CacheBackend, L2MissPolicy};
+
+    let l1 = Arc::new(DashMapCache::new());
+    let l2 = Arc::new(DashMapCache::new());
+    let tiers = vec![
+        CacheTier::new(Arc::clone(&l1) as Arc<dyn L2CacheBackend>, 1, false, 1, 1.0),
+        CacheTier::new(Arc::clone(&l2) as Arc<dyn L2CacheBackend>, 2, true, 1, 1.0),
+    ];
+    let manager = CacheManager::new_with_tiers(tiers, None)
+        .unwrap_or_else(|_| panic!("Failed to build"))
+        .with_l2_miss_policy(L2MissPolicy::FlushL1)
+        .with_l2_miss_sampling(0.0);
+
+    manager
+        .set_with_strategy("sampled", Bytes::from("v"), CacheStrategy::ShortTerm)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to set"));
+    l2.remove("sampled")
+        .await
+        .unwrap_or_else(|_| panic!("Failed to remove from L2"));
+
+    for _ in 0..10 {
+        let value = manager
+            .get("sampled")
+            .await
+            .unwrap_or_else(|_| panic!("Failed to get"));
+        assert_eq!(value, Some(Bytes::from("v")), "unchecked hits keep L1");
+    }
+}
+
+/// Durable store mock: every read takes 20ms and is counted
+struct SlowDurable {
+    inner: multi_tier_cache::DashMapCache,
+    reads: std::sync::atomic::AtomicUsize,
+}
+
+impl SlowDurable {
+    fn new() -> Self {
+        Self {
+            inner: multi_tier_cache::DashMapCache::new(),
+            reads: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    fn reads(&self) -> usize {
+        self.reads.load(std::sync::atomic::Ordering::SeqCst)
+    }
+}
+
+impl CacheBackend for SlowDurable {
+    fn get<'a>(&'a self, key: &'a str) -> futures_util::future::BoxFuture<'a, Option<Bytes>> {
+        Box::pin(async move {
+            multi_tier_cache::L2CacheBackend::get_with_ttl(self, key)
+                .await
+                .map(|(value, _)| value)
+        })
+    }
+
+    fn set_with_ttl<'a>(
+        &'a self,
+        key: &'a str,
+        value: Bytes,
+        ttl: Duration,
+    ) -> futures_util::future::BoxFuture<'a, CacheResult<()>> {
+        self.inner.set_with_ttl(key, value, ttl)
+    }
+
+    fn remove<'a>(&'a self, key: &'a str) -> futures_util::future::BoxFuture<'a, CacheResult<()>> {
+        self.inner.remove(key)
+    }
+
+    fn health_check(&self) -> futures_util::future::BoxFuture<'_, bool> {
+        self.inner.health_check()
+    }
+
+    fn name(&self) -> &'static str {
+        "SlowDurable"
+    }
+}
+
+impl multi_tier_cache::L2CacheBackend for SlowDurable {
+    fn get_with_ttl<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> futures_util::future::BoxFuture<'a, Option<(Bytes, Option<Duration>)>> {
+        Box::pin(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.reads.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            self.inner.get_with_ttl(key).await
+        })
+    }
+}
+
+/// Test a durable tier behind L1 + L2 is read on a miss and promoted back up
+#[tokio::test]
+async fn test_durable_tier_promotion() -> anyhow::Result<()> {
+    use multi_tier_cache::{DashMapCache, L2CacheBackend};
+
+    let l1 = Arc::new(DashMapCache::new());
+    let l2 = Arc::new(DashMapCache::new());
+    let durable = Arc::new(SlowDurable::new());
+    let cache = CacheSystemBuilder::new()
+        .with_l1(Arc::clone(&l1) as _)
+        .with_l2(Arc::clone(&l2) as _)
+        .with_durable_tier(Arc::clone(&durable) as _)
+        .build()
+        .await?;
+    let manager = cache.cache_manager();
+
+    let levels: Vec<usize> = manager
+        .get_tier_stats()
+        .iter()
+        .map(|tier| tier.tier_level)
+        .collect();
+    assert_eq!(levels, [1, 2, 3]);
+
+    // Only the durable store has the key: read through and promote everywhere
+    durable
+        .set_with_ttl("durable:1", Bytes::from("v1"), Duration::from_mins(5))
+        .await?;
+    assert_eq!(manager.get("durable:1").await?, Some(Bytes::from("v1")));
+    assert_eq!(durable.reads(), 1);
+    assert_eq!(l2.get("durable:1").await, Some(Bytes::from("v1")));
+    assert_eq!(l1.get("durable:1").await, Some(Bytes::from("v1")));
+    let (_, promoted_ttl) = l2
+        .get_with_ttl("durable:1")
+        .await
+        .unwrap_or_else(|| panic!("L2 copy missing"));
+    assert!(promoted_ttl.is_some_and(|ttl| ttl > Duration::from_mins(4)));
+
+    // Later reads never reach the slow store
+    assert_eq!(manager.get("durable:1").await?, Some(Bytes::from("v1")));
+    l1.remove("durable:1").await?;
+    assert_eq!(manager.get("durable:1").await?, Some(Bytes::from("v1")));
+    assert_eq!(durable.reads(), 1);
+
+    // Computed values are written down to the durable store too
+    let computed = manager
+        .get_or_compute_with("durable:2", CacheStrategy::ShortTerm, || async {
+            Ok(Bytes::from("v2"))
+        })
+        .await?;
+    assert_eq!(computed, Bytes::from("v2"));
+    assert_eq!(
+        durable.inner.get("durable:2").await,
+        Some(Bytes::from("v2"))
+    );
+
+    // Same cascade on explicit tiers
+    let cache = CacheSystemBuilder::new()
+        .with_tier(Arc::new(DashMapCache::new()), TierConfig::as_l1())
+        .with_tier(Arc::new(DashMapCache::new()), TierConfig::as_l2())
+        .with_durable_tier(Arc::new(SlowDurable::new()))
+        .build()
+        .await?;
+    let tiers = cache.cache_manager().get_tier_stats();
+    assert_eq!(tiers.len(), 3);
+    assert_eq!(tiers.last().map(|tier| tier.tier_level), Some(3));
+
+    Ok(())
+}
@@ -156,3 +156,113 @@ async fn test_stampede_latency_reduction() {
         .remove(&key)
         .await;
 }
+
+/// Test per-key coalescing for overlapping batch requests
+#[tokio::test]
+async fn test_get_many_each_overlapping_keys() {
+    let cache = setup_in_memory_cache().unwrap_or_else(|_| panic!("Failed to setup cache"));
+    let compute_count = Arc::new(AtomicU32::new(0));
+
+    let batches = [
+        ["batch:a", "batch:b", "batch:c"],
+        ["batch:b", "batch:c", "batch:d"],
+    ];
+
+    let mut tasks = JoinSet::new();
+    for batch in batches {
+        let manager = Arc::clone(&cache.manager);
+        let counter = Arc::clone(&compute_count);
+
+        tasks.spawn(async move {
+            manager
+                .get_many_each(&batch, CacheStrategy::ShortTerm, |key| {
+                    let counter = Arc::clone(&counter);
+                    async move {
+                        counter.fetch_add(1, Ordering::SeqCst);
+                        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                        Ok(format!("value:{key}"))
+                    }
+                })
+                .await
+                .map(|values: Vec<String>| (batch, values))
+        });
+    }
+
+    while let Some(result) = tasks.join_next().await {
+        let (batch, values) = result
+            .unwrap_or_else(|_| panic!("Task panicked"))
+            .unwrap_or_else(|_| panic!("Failed to get/compute batch"));
+        let expected: Vec<String> = batch.iter().map(|key| format!("value:{key}")).collect();
+        assert_eq!(values, expected);
+    }
+
+    // Four distinct keys, each computed exactly once
+    assert_eq!(compute_count.load(Ordering::SeqCst), 4);
+}
+
+/// Test that waiters on a long computation are all released when it finishes
+///
+/// Waiters share one broadcast channel, so none queue behind others: each one's
+/// latency is bounded by the remaining compute time plus wake-up overhead.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_waiter_latency_under_long_compute() {
+    use std::time::{Duration, Instant};
+
+    let cache = setup_in_memory_cache().unwrap_or_else(|_| panic!("Failed to setup cache"));
+    let compute_time = Duration::from_millis(500);
+    let computed_at = Arc::new(std::sync::Mutex::new(None::<Instant>));
+
+    let mut tasks = JoinSet::new();
+    for i in 0..200_u64 {
+        let manager = Arc::clone(&cache.manager);
+        let computed_at = Arc::clone(&computed_at);
+        tasks.spawn(async move {
+            // Arrivals spread over most of the computation
+            tokio::time::sleep(Duration::from_millis(i * 2)).await;
+            manager
+                .get_or_compute_with("fair:key", CacheStrategy::ShortTerm, || async move {
+                    tokio::time::sleep(compute_time).await;
+                    if let Ok(mut computed_at) = computed_at.lock() {
+                        *computed_at = Some(Instant::now());
+                    }
+                    Ok(test_data::bytes_user(1))
+                })
+                .await
+                .map(|_| Instant::now())
+        });
+    }
+
+    let mut finished = Vec::new();
+    while let Some(result) = tasks.join_next().await {
+        finished.push(
+            result
+                .unwrap_or_else(|_| panic!("Task panicked"))
+                .unwrap_or_else(|_| panic!("Compute failed")),
+        );
+    }
+    let computed_at = computed_at
+        .lock()
+        .ok()
+        .and_then(|computed_at| *computed_at)
+        .unwrap_or_else(|| panic!("Compute never ran"));
+
+    // Time each waiter spent past the end of the computation
+    let mut excess: Vec<Duration> = finished
+        .iter()
+        .map(|done| done.saturating_duration_since(computed_at))
+        .collect();
+    excess.sort_unstable();
+    let percentile = |p: usize| {
+        excess
+            .get(excess.len() * p / 100)
+            .or_else(|| excess.last())
+            .copied()
+            .unwrap_or_default()
+    };
+    let (p50, p99) = (percentile(50), percentile(99));
+    let max = excess.last().copied().unwrap_or_default();
+    println!("Waiter latency past compute: p50={p50:?} p99={p99:?} max={max:?}");
+
+    assert!(p99 < Duration::from_millis(50), "p99 too high: {p99:?}");
+    assert!(max < Duration::from_millis(100), "max too high: {max:?}");
+}
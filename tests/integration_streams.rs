@@ -3,6 +3,7 @@
 mod common;
 
 use common::*;
+use multi_tier_cache::{RedisStreams, StreamingBackend};
 
 /// Test publishing to Redis Stream
 #[tokio::test]
@@ -87,3 +88,188 @@ async fn test_stream_maxlen_trimming() {
     // Should be trimmed to ~5 entries (approximate trimming)
     assert!(entries.len() <= 10);
 }
+
+/// Test reclaiming an entry left pending by a stalled consumer
+#[tokio::test]
+async fn test_stream_autoclaim() {
+    let cache = setup_cache_system()
+        .await
+        .unwrap_or_else(|_| panic!("Failed to setup cache system"));
+    let streams = RedisStreams::new(&redis_url())
+        .await
+        .unwrap_or_else(|_| panic!("Failed to connect Redis streams"));
+    let stream_key = format!("test:stream:{}", rand::random::<u32>());
+
+    streams
+        .stream_create_group(&stream_key, "workers", "0")
+        .await
+        .unwrap_or_else(|_| panic!("Failed to create consumer group"));
+    let entry_id = cache
+        .cache_manager()
+        .publish_to_stream(
+            &stream_key,
+            vec![("job".to_string(), "1".to_string())],
+            None,
+        )
+        .await
+        .unwrap_or_else(|_| panic!("Failed to publish to stream"));
+
+    // Consumer A reads the entry and never acknowledges it
+    let delivered = streams
+        .stream_read_group(&stream_key, "workers", "consumer-a", 10, None)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to read as consumer group"));
+    assert_eq!(delivered.len(), 1);
+
+    let claimed = cache
+        .cache_manager()
+        .stream_autoclaim("workers", "consumer-b", &stream_key, 0, "0-0", 10)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to autoclaim"));
+
+    assert_eq!(claimed.len(), 1);
+    let (id, fields) = claimed
+        .first()
+        .unwrap_or_else(|| panic!("Expected a reclaimed entry"));
+    assert_eq!(id, &entry_id);
+    assert_eq!(fields, &vec![("job".to_string(), "1".to_string())]);
+}
+
+/// Test pending counts and ack statistics of a consumer group
+#[tokio::test]
+async fn test_stream_pending_count_and_stats() {
+    let cache = setup_cache_system()
+        .await
+        .unwrap_or_else(|_| panic!("Failed to setup cache system"));
+    let manager = cache.cache_manager();
+    let stream_key = format!("test:stream:{}", rand::random::<u32>());
+
+    manager
+        .create_stream_group(&stream_key, "workers", "0")
+        .await
+        .unwrap_or_else(|_| panic!("Failed to create consumer group"));
+    for i in 1..=5 {
+        manager
+            .publish_to_stream(&stream_key, vec![("job".to_string(), i.to_string())], None)
+            .await
+            .unwrap_or_else(|_| panic!("Failed to publish to stream"));
+    }
+    assert_eq!(
+        manager
+            .stream_pending_count("workers", &stream_key)
+            .await
+            .unwrap_or_else(|_| panic!("Failed to read pending count")),
+        0
+    );
+
+    // Read everything without acknowledging: all entries are pending
+    let delivered = manager
+        .read_stream_group(&stream_key, "workers", "consumer-a", 10, None)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to read as consumer group"));
+    assert_eq!(delivered.len(), 5);
+    let pending = manager
+        .stream_pending_count("workers", &stream_key)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to read pending count"));
+    assert_eq!(pending, delivered.len());
+
+    let acked: Vec<String> = delivered.iter().take(2).map(|(id, _)| id.clone()).collect();
+    manager
+        .ack_stream_entries(&stream_key, "workers", &acked)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to ack entries"));
+    let pending = manager
+        .stream_pending_count("workers", &stream_key)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to read pending count"));
+    assert_eq!(pending, 3);
+
+    let stats = manager.get_stream_stats();
+    assert_eq!(stats.entries_published, 5);
+    assert_eq!(stats.entries_delivered, 5);
+    assert_eq!(stats.entries_acked, 2);
+    assert_eq!(stats.unacked(), 3);
+}
+
+/// Test deleting specific stream entries by ID
+#[tokio::test]
+async fn test_stream_delete() {
+    let cache = setup_cache_system()
+        .await
+        .unwrap_or_else(|_| panic!("Failed to setup cache system"));
+    let manager = cache.cache_manager();
+    let stream_key = format!("test:stream:{}", rand::random::<u32>());
+
+    let mut ids = Vec::new();
+    for user in ["alice", "bob", "carol"] {
+        let id = manager
+            .publish_to_stream(
+                &stream_key,
+                vec![("user".to_string(), user.to_string())],
+                None,
+            )
+            .await
+            .unwrap_or_else(|_| panic!("Failed to publish to stream"));
+        ids.push(id);
+    }
+    let bob = ids
+        .get(1)
+        .cloned()
+        .unwrap_or_else(|| panic!("Missing entry ID"));
+
+    let deleted = manager
+        .stream_delete(&stream_key, &[bob.as_str(), "0-1"])
+        .await
+        .unwrap_or_else(|_| panic!("Failed to delete stream entries"));
+    assert_eq!(deleted, 1);
+
+    let entries = manager
+        .read_stream_latest(&stream_key, 10)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to read from stream"));
+    let remaining: Vec<&str> = entries.iter().map(|(id, _)| id.as_str()).collect();
+    assert_eq!(remaining.len(), 2);
+    assert!(!remaining.contains(&bob.as_str()));
+}
+
+/// Test publishing a batch of entries in one pipeline
+#[tokio::test]
+async fn test_stream_publish_batch() {
+    let cache = setup_cache_system()
+        .await
+        .unwrap_or_else(|_| panic!("Failed to setup cache system"));
+    let manager = cache.cache_manager();
+    let stream_key = format!("test:stream:{}", rand::random::<u32>());
+
+    let entries: Vec<Vec<(String, String)>> = (0..100)
+        .map(|i| vec![("seq".to_string(), i.to_string())])
+        .collect();
+    let ids = manager
+        .publish_to_stream_batch(&stream_key, entries, None)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to publish batch to stream"));
+    assert_eq!(ids.len(), 100);
+    assert_eq!(manager.get_stream_stats().entries_published, 100);
+
+    let client =
+        redis::Client::open(redis_url()).unwrap_or_else(|_| panic!("Failed to create client"));
+    let mut conn = redis::aio::ConnectionManager::new(client)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to connect to Redis"));
+    let len: usize = redis::cmd("XLEN")
+        .arg(&stream_key)
+        .query_async(&mut conn)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to read stream length"));
+    assert_eq!(len, 100);
+
+    // IDs come back in publishing order
+    let entries = manager
+        .read_stream_latest(&stream_key, 100)
+        .await
+        .unwrap_or_else(|_| panic!("Failed to read from stream"));
+    let newest_first: Vec<&str> = entries.iter().map(|(id, _)| id.as_str()).collect();
+    let published_last_first: Vec<&str> = ids.iter().rev().map(String::as_str).collect();
+    assert_eq!(newest_first, published_last_first);
+}